@@ -0,0 +1,143 @@
+//! Lazy combinatoric generators: [`permutations`] and [`combinations`].
+//!
+//! Both iterators walk index vectors in lexicographic order and clone the
+//! selected elements into a fresh `Vec` per item, so memory use stays at
+//! `O(n)` no matter how many items are produced.
+
+/// Iterator over the `k`-length permutations of a slice; see [`permutations`].
+#[derive(Clone, Debug)]
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    k: usize,
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.current());
+        }
+        let n = self.items.len();
+        // Classic cycle-counting algorithm (as in Python's itertools.permutations)
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = n - self.cycles[i];
+                self.indices.swap(i, j);
+                return Some(self.current());
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+impl<T: Clone> Permutations<'_, T> {
+    fn current(&self) -> Vec<T> {
+        self.indices[..self.k]
+            .iter()
+            .map(|&i| self.items[i].clone())
+            .collect()
+    }
+}
+
+/// Lazily generate every ordered selection of `k` elements from `slice`.
+///
+/// Items come out in lexicographic order of positions; duplicates in the
+/// input are treated as distinct. Yields nothing when `k > slice.len()` and
+/// a single empty `Vec` when `k == 0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::permutations;
+/// let all: Vec<Vec<char>> = permutations(&['a', 'b', 'c'], 2).collect();
+/// assert_eq!(all, [['a', 'b'], ['a', 'c'], ['b', 'a'], ['b', 'c'], ['c', 'a'], ['c', 'b']]);
+/// assert_eq!(permutations(&[1, 2, 3, 4], 4).count(), 24);
+/// ```
+pub fn permutations<T: Clone>(slice: &[T], k: usize) -> Permutations<'_, T> {
+    let n = slice.len();
+    Permutations {
+        items: slice,
+        k,
+        indices: (0..n).collect(),
+        cycles: (0..k.min(n)).map(|i| n - i).collect(),
+        started: false,
+        done: k > n,
+    }
+}
+
+/// Iterator over the `k`-element combinations of a slice; see [`combinations`].
+#[derive(Clone, Debug)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if self.started {
+            let (n, k) = (self.items.len(), self.indices.len());
+            // Rightmost position that can still move right
+            let Some(i) = (0..k).rev().find(|&i| self.indices[i] != i + n - k) else {
+                self.done = true;
+                return None;
+            };
+            self.indices[i] += 1;
+            for j in i + 1..k {
+                self.indices[j] = self.indices[j - 1] + 1;
+            }
+        }
+        self.started = true;
+        Some(
+            self.indices
+                .iter()
+                .map(|&i| self.items[i].clone())
+                .collect(),
+        )
+    }
+}
+
+/// Lazily generate every unordered selection of `k` elements from `slice`.
+///
+/// Each combination keeps the input order, and combinations come out in
+/// lexicographic order of positions. Yields nothing when `k > slice.len()`
+/// and a single empty `Vec` when `k == 0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::combinations;
+/// let pairs: Vec<Vec<i32>> = combinations(&[1, 2, 3, 4], 2).collect();
+/// assert_eq!(pairs, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+/// assert_eq!(combinations(&[0; 10], 3).count(), 120);
+/// ```
+pub fn combinations<T: Clone>(slice: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items: slice,
+        indices: if k > slice.len() {
+            Vec::new()
+        } else {
+            (0..k).collect()
+        },
+        started: false,
+        done: k > slice.len(),
+    }
+}
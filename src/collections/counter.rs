@@ -0,0 +1,176 @@
+//! Multiset counter similar to Python's `collections.Counter`.
+//!
+//! [`Counter`] tracks how many times each element occurs. Counts are always
+//! positive: decrementing to zero removes the entry. Arithmetic operators
+//! combine counters element-wise:
+//! - `a + b` adds counts
+//! - `a - b` subtracts counts, dropping anything that reaches zero
+//! - `a & b` keeps the minimum count (multiset intersection)
+//! - `a | b` keeps the maximum count (multiset union)
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::collections::Counter;
+//!
+//! let a: Counter<char> = "abracadabra".chars().collect();
+//! assert_eq!(a.get(&'a'), 5);
+//! assert_eq!(a.most_common(1), vec![(&'a', 5)]);
+//!
+//! let b: Counter<char> = "aab".chars().collect();
+//! let diff = a.clone() - b.clone();
+//! assert_eq!(diff.get(&'a'), 3);
+//! assert_eq!((a & b).get(&'b'), 1);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, BitAnd, BitOr, Sub};
+
+/// Counts occurrences of hashable elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Default for Counter<T> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    /// Create an empty counter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increment the count of `item` by one.
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Increment the count of `item` by `n`.
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+        }
+    }
+
+    /// Decrement the count of `item` by `n`, removing it when it reaches zero.
+    ///
+    /// Returns the number actually removed.
+    pub fn remove_n(&mut self, item: &T, n: usize) -> usize {
+        match self.counts.get_mut(item) {
+            Some(c) if *c > n => {
+                *c -= n;
+                n
+            }
+            Some(_) => self.counts.remove(item).unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    /// Count of `item` (zero if absent).
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct elements.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// True if no elements are counted.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Iterate over `(element, count)` pairs in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(k, &v)| (k, v))
+    }
+
+    /// The `n` most common elements, highest count first.
+    ///
+    /// Ties keep no particular order.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut all: Vec<(&T, usize)> = self.iter().collect();
+        all.sort_by_key(|&(_, c)| std::cmp::Reverse(c));
+        all.truncate(n);
+        all
+    }
+
+    /// Consume the counter and return the underlying map.
+    pub fn into_map(self) -> HashMap<T, usize> {
+        self.counts
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut c = Counter::new();
+        c.extend(iter);
+        c
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Eq + Hash> Add for Counter<T> {
+    type Output = Counter<T>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        for (k, v) in rhs.counts {
+            self.add_n(k, v);
+        }
+        self
+    }
+}
+
+impl<T: Eq + Hash> Sub for Counter<T> {
+    type Output = Counter<T>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        for (k, v) in rhs.counts {
+            self.remove_n(&k, v);
+        }
+        self
+    }
+}
+
+impl<T: Eq + Hash> BitAnd for Counter<T> {
+    type Output = Counter<T>;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let counts = self
+            .counts
+            .into_iter()
+            .filter_map(|(k, v)| {
+                let m = v.min(rhs.get(&k));
+                (m > 0).then_some((k, m))
+            })
+            .collect();
+        Counter { counts }
+    }
+}
+
+impl<T: Eq + Hash> BitOr for Counter<T> {
+    type Output = Counter<T>;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        for (k, v) in rhs.counts {
+            let e = self.counts.entry(k).or_insert(0);
+            *e = (*e).max(v);
+        }
+        self
+    }
+}
@@ -0,0 +1,220 @@
+//! Hash map that fills in missing values, similar to Python's `defaultdict`.
+//!
+//! [`DefaultHashMap`] replaces the `map.entry(k).or_default()` dance:
+//! mutable indexing inserts `V::default()` for a missing key, and shared
+//! indexing returns a reference to a default without inserting anything.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::collections::DefaultHashMap;
+//!
+//! let mut by_len: DefaultHashMap<usize, Vec<&str>> = DefaultHashMap::new();
+//! for word in ["a", "bb", "c"] {
+//!     by_len[&word.len()].push(word);
+//! }
+//! assert_eq!(by_len[&1], ["a", "c"]);
+//! assert!(by_len[&7].is_empty());
+//! assert_eq!(by_len.len(), 2);
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+
+/// A `HashMap` whose missing values default to `V::default()`.
+#[derive(Clone, Debug)]
+pub struct DefaultHashMap<K, V> {
+    map: HashMap<K, V>,
+    // Returned by shared indexing for missing keys
+    default: V,
+}
+
+impl<K, V: Default> Default for DefaultHashMap<K, V> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+            default: V::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Default> DefaultHashMap<K, V> {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty map with room for `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            default: V::default(),
+        }
+    }
+
+    /// Value for `key`, or the default if it is absent (nothing is inserted).
+    pub fn get<Q>(&self, key: &Q) -> &V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).unwrap_or(&self.default)
+    }
+
+    /// Mutable value for `key`, inserting the default first if it is absent.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::collections::DefaultHashMap;
+    /// let mut counts: DefaultHashMap<String, u32> = DefaultHashMap::new();
+    /// for w in "to be or not to be".split(' ') {
+    ///     *counts.get_or_insert(w.to_string()) += 1;
+    /// }
+    /// assert_eq!(counts["to"], 2);
+    /// assert_eq!(counts["maybe"], 0);
+    /// ```
+    pub fn get_or_insert(&mut self, key: K) -> &mut V {
+        self.map.entry(key).or_default()
+    }
+
+    /// Insert a value, returning the previous one if present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    /// Remove `key`, returning its value if it was present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key)
+    }
+
+    /// True if `key` has been inserted (explicitly or by mutable indexing).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+}
+
+impl<K, V> DefaultHashMap<K, V> {
+    /// Number of stored entries.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// True if no entries are stored.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Iterate over stored `(key, value)` pairs in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// Iterate over stored keys in arbitrary order.
+    pub fn keys(&self) -> hash_map::Keys<'_, K, V> {
+        self.map.keys()
+    }
+
+    /// Iterate over stored values in arbitrary order.
+    pub fn values(&self) -> hash_map::Values<'_, K, V> {
+        self.map.values()
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Borrow the underlying map.
+    pub fn as_map(&self) -> &HashMap<K, V> {
+        &self.map
+    }
+
+    /// Consume the wrapper and return the underlying map.
+    pub fn into_map(self) -> HashMap<K, V> {
+        self.map
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for DefaultHashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for DefaultHashMap<K, V> {}
+
+impl<K, V, Q> Index<&Q> for DefaultHashMap<K, V>
+where
+    K: Eq + Hash + Borrow<Q>,
+    V: Default,
+    Q: Hash + Eq + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key)
+    }
+}
+
+impl<K, V, Q> IndexMut<&Q> for DefaultHashMap<K, V>
+where
+    K: Eq + Hash + Borrow<Q>,
+    V: Default,
+    Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+{
+    /// Inserts `V::default()` under `key.to_owned()` when the key is missing.
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        if !self.map.contains_key(key) {
+            self.map.insert(key.to_owned(), V::default());
+        }
+        self.map.get_mut(key).expect("key was just inserted")
+    }
+}
+
+impl<K: Eq + Hash, V: Default> From<HashMap<K, V>> for DefaultHashMap<K, V> {
+    fn from(map: HashMap<K, V>) -> Self {
+        Self {
+            map,
+            default: V::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Default> FromIterator<(K, V)> for DefaultHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        HashMap::from_iter(iter).into()
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for DefaultHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map.extend(iter);
+    }
+}
+
+impl<K, V> IntoIterator for DefaultHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a DefaultHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
@@ -0,0 +1,181 @@
+//! LCS-based edit scripts between two slices.
+//!
+//! [`diff`] returns runs of equal, deleted, and inserted elements. Each run
+//! records where it starts in both inputs, so callers can patch a list in
+//! place, render a line diff, or reconcile UI rows.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::collections::{diff, SliceDiffOp};
+//! let ops = diff(&["a", "b", "c"], &["a", "x", "c"]);
+//! assert_eq!(ops[1], SliceDiffOp::Delete { a_start: 1, b_start: 1, items: vec!["b"] });
+//! assert_eq!(ops[2], SliceDiffOp::Insert { a_start: 2, b_start: 1, items: vec!["x"] });
+//! ```
+
+/// One run of an edit script produced by [`diff`].
+///
+/// `a_start`/`b_start` are the positions in the old and new slice where the
+/// run begins. For inserts `a_start` is where the items go in the old slice;
+/// for deletes `b_start` is where the gap falls in the new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SliceDiffOp<T> {
+    /// Items present in both slices
+    Equal {
+        /// Start in the old slice
+        a_start: usize,
+        /// Start in the new slice
+        b_start: usize,
+        /// The shared items
+        items: Vec<T>,
+    },
+    /// Items only in the old slice
+    Delete {
+        /// Start in the old slice
+        a_start: usize,
+        /// Position in the new slice
+        b_start: usize,
+        /// The removed items
+        items: Vec<T>,
+    },
+    /// Items only in the new slice
+    Insert {
+        /// Position in the old slice
+        a_start: usize,
+        /// Start in the new slice
+        b_start: usize,
+        /// The added items
+        items: Vec<T>,
+    },
+}
+
+impl<T> SliceDiffOp<T> {
+    /// Items in this run
+    pub fn items(&self) -> &[T] {
+        match self {
+            Self::Equal { items, .. } | Self::Delete { items, .. } | Self::Insert { items, .. } => {
+                items
+            }
+        }
+    }
+
+    /// Number of items in this run
+    pub fn len(&self) -> usize {
+        self.items().len()
+    }
+
+    /// True if the run is empty (never produced by [`diff`])
+    pub fn is_empty(&self) -> bool {
+        self.items().is_empty()
+    }
+
+    /// True for [`SliceDiffOp::Equal`]
+    pub fn is_equal(&self) -> bool {
+        matches!(self, Self::Equal { .. })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Edit script turning `a` into `b`, based on a longest common subsequence.
+///
+/// Adjacent steps of the same kind are merged into runs; within a changed
+/// region deletions come before insertions. Common prefixes and suffixes are
+/// matched directly, and the remaining middle costs O(n·m) time and memory.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::diff;
+/// let old = [1, 2, 3, 4, 5];
+/// let new = [1, 3, 4, 6, 5];
+/// let changed: usize = diff(&old, &new).iter().filter(|op| !op.is_equal()).map(|op| op.len()).sum();
+/// assert_eq!(changed, 2); // delete 2, insert 6
+/// ```
+pub fn diff<T: PartialEq + Clone>(a: &[T], b: &[T]) -> Vec<SliceDiffOp<T>> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let mid_a = &a[prefix..a.len() - suffix];
+    let mid_b = &b[prefix..b.len() - suffix];
+
+    // lcs[i][j] = LCS length of mid_a[i..] and mid_b[j..]
+    let (n, m) = (mid_a.len(), mid_b.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if mid_a[i] == mid_b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut steps = Vec::with_capacity(a.len().max(b.len()));
+    steps.extend(std::iter::repeat(Kind::Equal).take(prefix));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && mid_a[i] == mid_b[j] {
+            steps.push(Kind::Equal);
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            steps.push(Kind::Delete);
+            i += 1;
+        } else {
+            steps.push(Kind::Insert);
+            j += 1;
+        }
+    }
+    steps.extend(std::iter::repeat(Kind::Equal).take(suffix));
+
+    let mut ops: Vec<SliceDiffOp<T>> = Vec::new();
+    let (mut ai, mut bi) = (0, 0);
+    for kind in steps {
+        let (item, advance_a, advance_b) = match kind {
+            Kind::Equal => (&a[ai], true, true),
+            Kind::Delete => (&a[ai], true, false),
+            Kind::Insert => (&b[bi], false, true),
+        };
+        let extend = match (ops.last_mut(), kind) {
+            (Some(SliceDiffOp::Equal { items, .. }), Kind::Equal)
+            | (Some(SliceDiffOp::Delete { items, .. }), Kind::Delete)
+            | (Some(SliceDiffOp::Insert { items, .. }), Kind::Insert) => Some(items),
+            _ => None,
+        };
+        match extend {
+            Some(items) => items.push(item.clone()),
+            None => {
+                let (a_start, b_start, items) = (ai, bi, vec![item.clone()]);
+                ops.push(match kind {
+                    Kind::Equal => SliceDiffOp::Equal {
+                        a_start,
+                        b_start,
+                        items,
+                    },
+                    Kind::Delete => SliceDiffOp::Delete {
+                        a_start,
+                        b_start,
+                        items,
+                    },
+                    Kind::Insert => SliceDiffOp::Insert {
+                        a_start,
+                        b_start,
+                        items,
+                    },
+                });
+            }
+        }
+        ai += usize::from(advance_a);
+        bi += usize::from(advance_b);
+    }
+    ops
+}
@@ -6,11 +6,21 @@
 //!
 //! Highlights:
 //! - Chunking: [`chunk`]
-//! - De-duplication: [`uniq`]
+//! - De-duplication: [`uniq`], [`uniq_by`], [`dedup_consecutive_by_key`]
 //! - Set ops: [`difference`], [`intersection`], [`union`]
-//! - Grouping: [`group_by`], [`key_by`], [`count_by`]
-//! - Windows: [`sliding_window`]
+//! - Multiset ops: [`multiset_difference`], [`multiset_intersection`],
+//!   [`multiset_union`], and the [`Counter`] type
+//! - Containers: [`DefaultHashMap`] (auto-inserting defaults), [`SparseVec`]
+//! - Grouping: [`group_by`], [`group_by_owned`], [`key_by`], [`count_by`]
+//! - Partitioning: [`partition`], [`partition_owned`], [`partition_map`]
+//! - Frequency / top-k: [`most_common`], [`least_common`], [`top_k_by`]
+//! - Windows: [`sliding_window`], [`window_map`] (no per-window allocation), [`pairwise`]
+//! - Combinatorics: [`cartesian_product`], [`permutations`], [`combinations`] (lazy)
+//! - Parallel mapping: [`map_chunked_parallel`]
+//! - Edit scripts: [`diff()`] (LCS-based)
 //! - Sampling: [`sample`], [`shuffle_in_place`]
+//! - Probabilistic sketches: [`BloomFilter`], [`HyperLogLog`]
+//! - Sorting: [`sort_by_keys`], [`sort_desc_by_key`]
 //!
 //! Basic examples:
 //! ```rust
@@ -22,9 +32,24 @@
 //! assert_eq!(sliding_window(&[1,2,3,4], 2, 1), vec![vec![1,2], vec![2,3], vec![3,4]]);
 //! ```
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
 
+pub mod combinatorics;
+pub mod counter;
+pub mod default_map;
+pub mod diff;
+pub mod sketch;
+pub mod sparse_vec;
+
+pub use combinatorics::{combinations, permutations, Combinations, Permutations};
+pub use counter::Counter;
+pub use default_map::DefaultHashMap;
+pub use diff::{diff, SliceDiffOp};
+pub use sketch::{BloomFilter, HyperLogLog};
+pub use sparse_vec::SparseVec;
+
 /// Split a slice into chunks of size `size`.
 ///
 /// Returns an empty vector when `size == 0`.
@@ -41,6 +66,72 @@ pub fn chunk<T: Clone>(slice: &[T], size: usize) -> Vec<Vec<T>> {
     slice.chunks(size).map(|c| c.to_vec()).collect()
 }
 
+/// Split a slice into chunks of exactly `size`, padding the last with `fill`.
+///
+/// Returns an empty vector when `size == 0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::chunk_padded;
+/// assert_eq!(chunk_padded(&[1,2,3,4,5], 2, 0), vec![vec![1,2], vec![3,4], vec![5,0]]);
+/// ```
+pub fn chunk_padded<T: Clone>(slice: &[T], size: usize, fill: T) -> Vec<Vec<T>> {
+    let mut out = chunk(slice, size);
+    if let Some(last) = out.last_mut() {
+        last.resize(size, fill);
+    }
+    out
+}
+
+/// Split a slice into chunks of exactly `size`, dropping any remainder.
+///
+/// Returns an empty vector when `size == 0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::chunk_exact;
+/// assert_eq!(chunk_exact(&[1,2,3,4,5], 2), vec![vec![1,2], vec![3,4]]);
+/// ```
+pub fn chunk_exact<T: Clone>(slice: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        return vec![];
+    }
+    slice.chunks_exact(size).map(|c| c.to_vec()).collect()
+}
+
+/// Map `f` over `slice` on up to `threads` scoped threads, `chunk_size` elements at a time.
+///
+/// Results keep the input order. This is
+/// [`concurrency::par_map_chunked`](crate::concurrency::par_map_chunked); see
+/// it for scheduling and panic behaviour.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::map_chunked_parallel;
+/// let lens = map_chunked_parallel(&["a", "bb", "ccc", "dddd"], 1, 2, |s| s.len());
+/// assert_eq!(lens, vec![1, 2, 3, 4]);
+/// ```
+pub fn map_chunked_parallel<T, U, F>(slice: &[T], chunk_size: usize, threads: usize, f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+{
+    crate::concurrency::par_map_chunked(slice, chunk_size, threads, f)
+}
+
+/// Split a slice at `idx`, or `None` if `idx > slice.len()`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::split_at_checked;
+/// assert_eq!(split_at_checked(&[1,2,3], 1), Some((&[1][..], &[2,3][..])));
+/// assert_eq!(split_at_checked(&[1,2,3], 4), None);
+/// ```
+pub fn split_at_checked<T>(slice: &[T], idx: usize) -> Option<(&[T], &[T])> {
+    (idx <= slice.len()).then(|| slice.split_at(idx))
+}
+
 /// Remove `None` values from a slice of `Option<T>`.
 ///
 /// Example:
@@ -70,6 +161,43 @@ pub fn uniq<T: Eq + Hash + Clone>(slice: &[T]) -> Vec<T> {
     out
 }
 
+/// Remove elements whose key was already seen, keeping the first occurrence.
+///
+/// Only the key needs `Eq + Hash`, so structs can be deduplicated by an ID
+/// field.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::uniq_by;
+/// let users = [(1, "ann"), (2, "bob"), (1, "ann (dup)")];
+/// assert_eq!(uniq_by(&users, |u| u.0), vec![(1, "ann"), (2, "bob")]);
+/// ```
+pub fn uniq_by<T: Clone, K: Eq + Hash, F: FnMut(&T) -> K>(slice: &[T], mut f: F) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::with_capacity(slice.len());
+    for v in slice {
+        if seen.insert(f(v)) {
+            out.push(v.clone());
+        }
+    }
+    out
+}
+
+/// Remove consecutive elements with equal keys in place, keeping the first.
+///
+/// Like `Vec::dedup_by_key`, but the key function takes a shared reference.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::dedup_consecutive_by_key;
+/// let mut v = vec![(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+/// dedup_consecutive_by_key(&mut v, |x| x.0);
+/// assert_eq!(v, vec![(1, 'a'), (2, 'c'), (1, 'd')]);
+/// ```
+pub fn dedup_consecutive_by_key<T, K: PartialEq, F: FnMut(&T) -> K>(v: &mut Vec<T>, mut f: F) {
+    v.dedup_by_key(|x| f(x))
+}
+
 /// Elements in `a` that are not in `b`.
 ///
 /// Example:
@@ -108,6 +236,77 @@ pub fn union<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
     uniq(&out)
 }
 
+fn counts<T: Eq + Hash>(slice: &[T]) -> HashMap<&T, usize> {
+    let mut map = HashMap::new();
+    for x in slice {
+        *map.entry(x).or_insert(0) += 1;
+    }
+    map
+}
+
+/// Elements of `a` minus elements of `b`, respecting duplicate counts.
+///
+/// Each element of `b` cancels at most one matching element of `a`; order of
+/// the remaining elements of `a` is preserved.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::multiset_difference;
+/// assert_eq!(multiset_difference(&[1,1,2], &[1]), vec![1,2]);
+/// ```
+pub fn multiset_difference<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut remaining = counts(b);
+    a.iter()
+        .filter(|x| match remaining.get_mut(x) {
+            Some(c) if *c > 0 => {
+                *c -= 1;
+                false
+            }
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Elements present in both `a` and `b`, keeping the smaller duplicate count.
+///
+/// Order follows `a`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::multiset_intersection;
+/// assert_eq!(multiset_intersection(&[1,1,1,2], &[1,1,3]), vec![1,1]);
+/// ```
+pub fn multiset_intersection<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut remaining = counts(b);
+    a.iter()
+        .filter(|x| match remaining.get_mut(x) {
+            Some(c) if *c > 0 => {
+                *c -= 1;
+                true
+            }
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Union of `a` and `b`, keeping the larger duplicate count of each element.
+///
+/// All of `a` comes first, followed by the elements of `b` that exceed their
+/// count in `a`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::multiset_union;
+/// assert_eq!(multiset_union(&[1,2], &[1,1,3]), vec![1,2,1,3]);
+/// ```
+pub fn multiset_union<T: Eq + Hash + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut out = a.to_vec();
+    out.extend(multiset_difference(b, a));
+    out
+}
+
 /// Flatten one level of nested vectors.
 ///
 /// Example:
@@ -144,6 +343,30 @@ where
     map
 }
 
+/// Group owned elements by a key function, preserving their order.
+///
+/// Like [`group_by`] but moves the elements, so the result does not borrow
+/// the input.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::group_by_owned;
+/// let words = vec!["apple".to_string(), "bob".into(), "avocado".into()];
+/// let map = group_by_owned(words, |s| s.chars().next());
+/// assert_eq!(map[&Some('a')], ["apple", "avocado"]);
+/// ```
+pub fn group_by_owned<T, K, F>(items: Vec<T>, mut f: F) -> HashMap<K, Vec<T>>
+where
+    K: Eq + Hash,
+    F: FnMut(&T) -> K,
+{
+    let mut map: HashMap<K, Vec<T>> = HashMap::new();
+    for item in items {
+        map.entry(f(&item)).or_default().push(item);
+    }
+    map
+}
+
 /// Map elements by a key function.
 ///
 /// Example:
@@ -164,6 +387,82 @@ where
     map
 }
 
+/// Error returned by [`try_key_by`] when two elements share a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyError<K> {
+    /// The repeated key
+    pub key: K,
+    /// Index of the first element with this key
+    pub first_index: usize,
+    /// Index of the element that repeated it
+    pub duplicate_index: usize,
+}
+
+impl<K: std::fmt::Debug> std::fmt::Display for DuplicateKeyError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "duplicate key {:?} at index {} (first seen at index {})",
+            self.key, self.duplicate_index, self.first_index
+        )
+    }
+}
+
+impl<K: std::fmt::Debug> std::error::Error for DuplicateKeyError<K> {}
+
+/// Map elements by a key function, failing on the first duplicate key.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::try_key_by;
+/// let ids = [(1, "a"), (2, "b")];
+/// assert_eq!(try_key_by(&ids, |p| p.0).unwrap()[&2].1, "b");
+/// let err = try_key_by(&[(1, "a"), (1, "b")], |p| p.0).unwrap_err();
+/// assert_eq!((err.key, err.first_index, err.duplicate_index), (1, 0, 1));
+/// ```
+pub fn try_key_by<T, K, F>(slice: &[T], f: F) -> Result<HashMap<K, &T>, DuplicateKeyError<K>>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut map: HashMap<K, (usize, &T)> = HashMap::with_capacity(slice.len());
+    for (i, item) in slice.iter().enumerate() {
+        match map.entry(f(item)) {
+            std::collections::hash_map::Entry::Occupied(e) => {
+                let first_index = e.get().0;
+                return Err(DuplicateKeyError {
+                    key: e.remove_entry().0,
+                    first_index,
+                    duplicate_index: i,
+                });
+            }
+            std::collections::hash_map::Entry::Vacant(e) => {
+                e.insert((i, item));
+            }
+        }
+    }
+    Ok(map.into_iter().map(|(k, (_, v))| (k, v)).collect())
+}
+
+/// Index elements by key, keeping every element per key in input order.
+///
+/// Same semantics as [`group_by`]; use it where the intent is a lookup index
+/// that must not drop colliding elements the way [`key_by`] does.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::index_by;
+/// let idx = index_by(&[(1, "a"), (2, "b"), (1, "c")], |p| p.0);
+/// assert_eq!(idx[&1], vec![&(1, "a"), &(1, "c")]);
+/// ```
+pub fn index_by<T, K, F>(slice: &[T], f: F) -> HashMap<K, Vec<&T>>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    group_by(slice, f)
+}
+
 /// Count elements by a key function.
 ///
 /// Example:
@@ -184,6 +483,100 @@ where
     map
 }
 
+/// Largest `k` entries of `items`, in descending order, keeping a heap of at
+/// most `k` entries (O(n log k)).
+fn bounded_top<E: Ord>(items: impl Iterator<Item = E>, k: usize) -> Vec<E> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for item in items {
+        heap.push(std::cmp::Reverse(item));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    let mut out: Vec<E> = heap.into_iter().map(|r| r.0).collect();
+    out.sort_unstable_by(|a, b| b.cmp(a));
+    out
+}
+
+/// The `k` elements with the largest keys, largest first.
+///
+/// Uses a bounded heap (O(n log k)) instead of sorting everything. Equal keys
+/// keep their original order.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::top_k_by;
+/// let words = ["kiwi", "banana", "fig", "cherry"];
+/// assert_eq!(top_k_by(&words, 2, |w| w.len()), vec![&"banana", &"cherry"]);
+/// ```
+pub fn top_k_by<T, K, F>(slice: &[T], k: usize, mut key: F) -> Vec<&T>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let keyed = slice
+        .iter()
+        .enumerate()
+        .map(|(i, item)| (key(item), std::cmp::Reverse(i)));
+    bounded_top(keyed, k)
+        .into_iter()
+        .map(|(_, std::cmp::Reverse(i))| &slice[i])
+        .collect()
+}
+
+/// `(count, index of first appearance)` for each distinct element.
+fn counts_with_first_seen<T: Eq + Hash>(slice: &[T]) -> Vec<(usize, usize)> {
+    let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+    for (i, item) in slice.iter().enumerate() {
+        counts.entry(item).or_insert((0, i)).0 += 1;
+    }
+    counts.into_values().collect()
+}
+
+/// The `k` most frequent elements with their counts, most frequent first.
+///
+/// Ties are broken by first appearance. Runs in O(n + m log k) for `m`
+/// distinct elements.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::most_common;
+/// let v = ["b", "a", "b", "c", "a", "b"];
+/// assert_eq!(most_common(&v, 2), vec![(&"b", 3), (&"a", 2)]);
+/// ```
+pub fn most_common<T: Eq + Hash>(slice: &[T], k: usize) -> Vec<(&T, usize)> {
+    let keyed = counts_with_first_seen(slice)
+        .into_iter()
+        .map(|(count, first)| (count, std::cmp::Reverse(first)));
+    bounded_top(keyed, k)
+        .into_iter()
+        .map(|(count, std::cmp::Reverse(first))| (&slice[first], count))
+        .collect()
+}
+
+/// The `k` least frequent elements with their counts, least frequent first.
+///
+/// Ties are broken by first appearance.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::least_common;
+/// let v = ["b", "a", "b", "c", "a", "b"];
+/// assert_eq!(least_common(&v, 2), vec![(&"c", 1), (&"a", 2)]);
+/// ```
+pub fn least_common<T: Eq + Hash>(slice: &[T], k: usize) -> Vec<(&T, usize)> {
+    let keyed = counts_with_first_seen(slice)
+        .into_iter()
+        .map(|(count, first)| (std::cmp::Reverse(count), std::cmp::Reverse(first)));
+    bounded_top(keyed, k)
+        .into_iter()
+        .map(|(std::cmp::Reverse(count), std::cmp::Reverse(first))| (&slice[first], count))
+        .collect()
+}
+
 /// Partition elements into `(true, false)` by predicate.
 ///
 /// Example:
@@ -209,6 +602,57 @@ where
     (t, fvec)
 }
 
+/// Partition owned elements into `(true, false)` by predicate, preserving order.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::partition_owned;
+/// let (even, odd) = partition_owned(vec![1, 2, 3, 4], |x| x % 2 == 0);
+/// assert_eq!((even, odd), (vec![2, 4], vec![1, 3]));
+/// ```
+pub fn partition_owned<T, F>(items: Vec<T>, mut f: F) -> (Vec<T>, Vec<T>)
+where
+    F: FnMut(&T) -> bool,
+{
+    items.into_iter().partition(|item| f(item))
+}
+
+/// One of two values; returned by the closure passed to [`partition_map`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// Goes to the first output
+    Left(L),
+    /// Goes to the second output
+    Right(R),
+}
+
+/// Split elements into two vectors of possibly different types, preserving order.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::{partition_map, Either};
+/// let (nums, words) = partition_map(&["1", "x", "3"], |s| match s.parse::<i32>() {
+///     Ok(n) => Either::Left(n),
+///     Err(_) => Either::Right(s.to_uppercase()),
+/// });
+/// assert_eq!(nums, [1, 3]);
+/// assert_eq!(words, ["X"]);
+/// ```
+pub fn partition_map<T, L, R, F>(slice: &[T], mut f: F) -> (Vec<L>, Vec<R>)
+where
+    F: FnMut(&T) -> Either<L, R>,
+{
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for item in slice {
+        match f(item) {
+            Either::Left(l) => left.push(l),
+            Either::Right(r) => right.push(r),
+        }
+    }
+    (left, right)
+}
+
 // Simple PRNG (LCG) for shuffle/sample
 struct Lcg {
     state: u128,
@@ -264,6 +708,39 @@ pub fn sample<T>(slice: &[T]) -> Option<&T> {
     slice.get(idx)
 }
 
+/// Shuffle in place with a fixed seed; the same seed gives the same order.
+///
+/// Uses [`Rng`](crate::random::Rng), so results match
+/// `Rng::seeded(seed).shuffle(slice)`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::shuffle_seeded;
+/// let mut a = vec![1, 2, 3, 4, 5, 6];
+/// let mut b = a.clone();
+/// shuffle_seeded(&mut a, 42);
+/// shuffle_seeded(&mut b, 42);
+/// assert_eq!(a, b);
+/// ```
+pub fn shuffle_seeded<T>(slice: &mut [T], seed: u64) {
+    crate::random::Rng::seeded(seed).shuffle(slice)
+}
+
+/// Pick an element deterministically from `seed`.
+///
+/// Returns `None` if the slice is empty.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::sample_seeded;
+/// let v = ["a", "b", "c"];
+/// assert_eq!(sample_seeded(&v, 7), sample_seeded(&v, 7));
+/// assert_eq!(sample_seeded::<u8>(&[], 7), None);
+/// ```
+pub fn sample_seeded<T>(slice: &[T], seed: u64) -> Option<&T> {
+    crate::random::Rng::seeded(seed).choose(slice)
+}
+
 /// Zip keys and values into a `HashMap`.
 ///
 /// Example:
@@ -299,6 +776,62 @@ pub fn unzip<K: Clone, V: Clone>(pairs: &[(K, V)]) -> (Vec<K>, Vec<V>) {
     (ks, vs)
 }
 
+/// Pair up two slices, padding the shorter one with fill values.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::zip_longest;
+/// let z = zip_longest(&[1, 2, 3], &["a"], 0, "-");
+/// assert_eq!(z, vec![(1, "a"), (2, "-"), (3, "-")]);
+/// ```
+pub fn zip_longest<A: Clone, B: Clone>(a: &[A], b: &[B], fill_a: A, fill_b: B) -> Vec<(A, B)> {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| {
+            (
+                a.get(i).unwrap_or(&fill_a).clone(),
+                b.get(i).unwrap_or(&fill_b).clone(),
+            )
+        })
+        .collect()
+}
+
+/// Combine three slices into triples, stopping at the shortest.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::zip3;
+/// let z = zip3(&[1, 2], &['a', 'b', 'c'], &[true, false]);
+/// assert_eq!(z, vec![(1, 'a', true), (2, 'b', false)]);
+/// ```
+pub fn zip3<A: Clone, B: Clone, C: Clone>(a: &[A], b: &[B], c: &[C]) -> Vec<(A, B, C)> {
+    a.iter()
+        .zip(b)
+        .zip(c)
+        .map(|((x, y), z)| (x.clone(), y.clone(), z.clone()))
+        .collect()
+}
+
+/// Split a slice of triples into three vectors.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::unzip3;
+/// let (a, b, c) = unzip3(&[(1, 'a', "x"), (2, 'b', "y")]);
+/// assert_eq!((a, b, c), (vec![1, 2], vec!['a', 'b'], vec!["x", "y"]));
+/// ```
+pub fn unzip3<A: Clone, B: Clone, C: Clone>(triples: &[(A, B, C)]) -> (Vec<A>, Vec<B>, Vec<C>) {
+    let mut xs = Vec::with_capacity(triples.len());
+    let mut ys = Vec::with_capacity(triples.len());
+    let mut zs = Vec::with_capacity(triples.len());
+    for (x, y, z) in triples {
+        xs.push(x.clone());
+        ys.push(y.clone());
+        zs.push(z.clone());
+    }
+    (xs, ys, zs)
+}
+
 /// First index of item.
 ///
 /// Example:
@@ -446,24 +979,94 @@ pub fn intersperse<T: Clone>(slice: &[T], sep: T) -> Vec<T> {
     out
 }
 
+/// Alternate elements from two slices; the rest of the longer one follows.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::interleave;
+/// assert_eq!(interleave(&[1,3,5,7], &[2,4]), vec![1,2,3,4,5,7]);
+/// ```
+pub fn interleave<T: Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    round_robin(&[a, b])
+}
+
+/// Take one element from each slice in turn, skipping exhausted ones,
+/// until all are drained.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::round_robin;
+/// let a = [1, 4];
+/// let b = [2];
+/// let c = [3, 5, 6];
+/// assert_eq!(round_robin(&[&a[..], &b[..], &c[..]]), vec![1,2,3,4,5,6]);
+/// ```
+pub fn round_robin<T: Clone>(sources: &[&[T]]) -> Vec<T> {
+    let total = sources.iter().map(|s| s.len()).sum();
+    let longest = sources.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut out = Vec::with_capacity(total);
+    for i in 0..longest {
+        for source in sources {
+            if let Some(item) = source.get(i) {
+                out.push(item.clone());
+            }
+        }
+    }
+    out
+}
+
 /// Sliding windows of given size/step.
 ///
+/// Each window is copied into its own `Vec`; prefer [`window_map`] when you
+/// only need a value computed from each window.
+///
 /// Example:
 /// ```rust
 /// use toolchest::collections::sliding_window;
 /// assert_eq!(sliding_window(&[1,2,3,4], 2, 2), vec![vec![1,2], vec![3,4]]);
 /// ```
 pub fn sliding_window<T: Clone>(slice: &[T], size: usize, step: usize) -> Vec<Vec<T>> {
-    if size == 0 || step == 0 {
+    window_map(slice, size, step, <[T]>::to_vec)
+}
+
+/// Apply `f` to each window of `size` elements, advancing by `step`.
+///
+/// Windows are passed as borrowed sub-slices, so nothing is cloned. Windows
+/// that would run past the end are skipped; returns an empty vector when
+/// `size == 0` or `step == 0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::window_map;
+/// let sums = window_map(&[1, 2, 3, 4, 5], 3, 1, |w| w.iter().sum::<i32>());
+/// assert_eq!(sums, vec![6, 9, 12]);
+/// let firsts = window_map(&["a", "b", "c", "d", "e"], 2, 2, |w| w[0]);
+/// assert_eq!(firsts, vec!["a", "c"]);
+/// ```
+pub fn window_map<T, U, F>(slice: &[T], size: usize, step: usize, mut f: F) -> Vec<U>
+where
+    F: FnMut(&[T]) -> U,
+{
+    if size == 0 || step == 0 || size > slice.len() {
         return vec![];
     }
-    let mut out = Vec::new();
-    let mut i = 0usize;
-    while i + size <= slice.len() {
-        out.push(slice[i..i + size].to_vec());
-        i += step;
-    }
-    out
+    (0..=slice.len() - size)
+        .step_by(step)
+        .map(|i| f(&slice[i..i + size]))
+        .collect()
+}
+
+/// Adjacent pairs `(slice[i], slice[i + 1])`, borrowed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::pairwise;
+/// let deltas: Vec<i32> = pairwise(&[1, 4, 9, 16]).into_iter().map(|(a, b)| b - a).collect();
+/// assert_eq!(deltas, vec![3, 5, 7]);
+/// assert!(pairwise(&[1]).is_empty());
+/// ```
+pub fn pairwise<T>(slice: &[T]) -> Vec<(&T, &T)> {
+    slice.windows(2).map(|w| (&w[0], &w[1])).collect()
 }
 
 /// Cartesian product of two slices.
@@ -569,6 +1172,165 @@ pub fn binary_search_by<T, F: Fn(&T) -> std::cmp::Ordering>(v: &[T], cmp: F) ->
     None
 }
 
+/// First index whose element is not less than `item` (insertion point before equals).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::lower_bound;
+/// assert_eq!(lower_bound(&[1, 2, 2, 4], &2), 1);
+/// assert_eq!(lower_bound(&[1, 2, 2, 4], &3), 3);
+/// ```
+pub fn lower_bound<T: Ord>(v: &[T], item: &T) -> usize {
+    v.partition_point(|x| x < item)
+}
+
+/// First index whose element is greater than `item` (insertion point after equals).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::upper_bound;
+/// assert_eq!(upper_bound(&[1, 2, 2, 4], &2), 3);
+/// assert_eq!(upper_bound(&[1, 2, 2, 4], &0), 0);
+/// ```
+pub fn upper_bound<T: Ord>(v: &[T], item: &T) -> usize {
+    v.partition_point(|x| x <= item)
+}
+
+/// Insert into a sorted Vec, keeping it sorted; returns the insertion index.
+///
+/// Equal elements keep insertion order (the new item goes after them).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::insert_sorted;
+/// let mut v = vec![1, 3, 5];
+/// assert_eq!(insert_sorted(&mut v, 4), 2);
+/// assert_eq!(v, vec![1, 3, 4, 5]);
+/// ```
+pub fn insert_sorted<T: Ord>(v: &mut Vec<T>, item: T) -> usize {
+    let idx = upper_bound(v, &item);
+    v.insert(idx, item);
+    idx
+}
+
+/// Merge two sorted slices into one sorted Vec in O(n + m).
+///
+/// Stable: on ties, elements from `a` come first.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::merge_sorted;
+/// assert_eq!(merge_sorted(&[1, 4, 6], &[2, 4, 5]), vec![1, 2, 4, 4, 5, 6]);
+/// ```
+pub fn merge_sorted<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if b[j] < a[i] {
+            out.push(b[j].clone());
+            j += 1;
+        } else {
+            out.push(a[i].clone());
+            i += 1;
+        }
+    }
+    out.extend_from_slice(&a[i..]);
+    out.extend_from_slice(&b[j..]);
+    out
+}
+
+/// Index of the element with the smallest key (first one on ties).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::arg_min;
+/// assert_eq!(arg_min(&["ccc", "a", "bb", "z"], |s| s.len()), Some(1));
+/// assert_eq!(arg_min(&[] as &[i32], |x| *x), None);
+/// ```
+pub fn arg_min<T, K: Ord, F: FnMut(&T) -> K>(slice: &[T], mut key: F) -> Option<usize> {
+    let mut best: Option<(usize, K)> = None;
+    for (i, item) in slice.iter().enumerate() {
+        let k = key(item);
+        if best.as_ref().map_or(true, |(_, b)| k < *b) {
+            best = Some((i, k));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Index of the element with the largest key (first one on ties).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::arg_max;
+/// assert_eq!(arg_max(&[3, 9, 2, 9], |x| *x), Some(1));
+/// ```
+pub fn arg_max<T, K: Ord, F: FnMut(&T) -> K>(slice: &[T], mut key: F) -> Option<usize> {
+    let mut best: Option<(usize, K)> = None;
+    for (i, item) in slice.iter().enumerate() {
+        let k = key(item);
+        if best.as_ref().map_or(true, |(_, b)| k > *b) {
+            best = Some((i, k));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Smallest and largest elements in a single pass (first occurrence of each).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::min_max;
+/// assert_eq!(min_max(&[4, 1, 7, 1]), Some((&1, &7)));
+/// assert_eq!(min_max::<u8>(&[]), None);
+/// ```
+pub fn min_max<T: Ord>(slice: &[T]) -> Option<(&T, &T)> {
+    let (first, rest) = slice.split_first()?;
+    Some(rest.iter().fold((first, first), |(lo, hi), x| {
+        (if x < lo { x } else { lo }, if x > hi { x } else { hi })
+    }))
+}
+
+/// How [`min_max_f64`] treats NaN values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// Skip NaNs; `None` if every value is NaN
+    #[default]
+    Ignore,
+    /// Any NaN makes the result `(NaN, NaN)`
+    Propagate,
+}
+
+/// Minimum and maximum of floats in a single pass, with explicit NaN handling.
+///
+/// Returns `None` for an empty slice (or all-NaN input with
+/// [`NanPolicy::Ignore`]).
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::{min_max_f64, NanPolicy};
+/// let v = [2.5, f64::NAN, -1.0, 8.0];
+/// assert_eq!(min_max_f64(&v, NanPolicy::Ignore), Some((-1.0, 8.0)));
+/// let (lo, hi) = min_max_f64(&v, NanPolicy::Propagate).unwrap();
+/// assert!(lo.is_nan() && hi.is_nan());
+/// ```
+pub fn min_max_f64(values: &[f64], policy: NanPolicy) -> Option<(f64, f64)> {
+    let mut acc: Option<(f64, f64)> = None;
+    for &x in values {
+        if x.is_nan() {
+            match policy {
+                NanPolicy::Ignore => continue,
+                NanPolicy::Propagate => return Some((f64::NAN, f64::NAN)),
+            }
+        }
+        acc = Some(match acc {
+            None => (x, x),
+            Some((lo, hi)) => (lo.min(x), hi.max(x)),
+        });
+    }
+    acc
+}
+
 /// Find duplicate elements (unique list).
 ///
 /// Example:
@@ -611,3 +1373,62 @@ pub fn is_sorted<T: Ord>(slice: &[T]) -> bool {
 pub fn stable_sort_by<T, F: FnMut(&T, &T) -> std::cmp::Ordering>(v: &mut [T], mut f: F) {
     v.sort_by(|a, b| f(a, b))
 }
+
+type Comparator<'a, T> = Box<dyn Fn(&T, &T) -> Ordering + 'a>;
+
+/// One level of a multi-key sort: a key extractor plus a direction.
+///
+/// Build with [`SortKey::asc`] or [`SortKey::desc`] and pass a list of them to
+/// [`sort_by_keys`]. Each level may extract a different key type.
+pub struct SortKey<'a, T> {
+    cmp: Comparator<'a, T>,
+}
+
+impl<'a, T> SortKey<'a, T> {
+    /// Order ascending by the extracted key.
+    pub fn asc<K: Ord, F: Fn(&T) -> K + 'a>(f: F) -> Self {
+        Self {
+            cmp: Box::new(move |a, b| f(a).cmp(&f(b))),
+        }
+    }
+
+    /// Order descending by the extracted key.
+    pub fn desc<K: Ord, F: Fn(&T) -> K + 'a>(f: F) -> Self {
+        Self {
+            cmp: Box::new(move |a, b| f(b).cmp(&f(a))),
+        }
+    }
+}
+
+/// Stable sort by several keys; later keys break ties in earlier ones.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::{sort_by_keys, SortKey};
+/// let mut v = vec![("b", 2), ("a", 2), ("c", 1)];
+/// sort_by_keys(&mut v, &[SortKey::desc(|p: &(&str, i32)| p.1), SortKey::asc(|p: &(&str, i32)| p.0)]);
+/// assert_eq!(v, vec![("a", 2), ("b", 2), ("c", 1)]);
+/// ```
+pub fn sort_by_keys<T>(v: &mut [T], keys: &[SortKey<'_, T>]) {
+    v.sort_by(|a, b| {
+        keys.iter()
+            .map(|k| (k.cmp)(a, b))
+            .find(|o| *o != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+/// Stable sort in descending order of the extracted key.
+///
+/// Equal keys keep their original relative order.
+///
+/// Example:
+/// ```rust
+/// use toolchest::collections::sort_desc_by_key;
+/// let mut v = vec!["bb", "a", "ccc", "dd"];
+/// sort_desc_by_key(&mut v, |s| s.len());
+/// assert_eq!(v, vec!["ccc", "bb", "dd", "a"]);
+/// ```
+pub fn sort_desc_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut f: F) {
+    v.sort_by_key(|x| std::cmp::Reverse(f(x)))
+}
@@ -0,0 +1,219 @@
+//! Probabilistic sketches: [`BloomFilter`] and [`HyperLogLog`].
+//!
+//! Both work on byte strings (anything `AsRef<[u8]>`, including `str`) and
+//! hash with the crate's own [`fnv1a`] and [`murmur3_32`], so results are
+//! stable across runs and platforms.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::collections::sketch::{BloomFilter, HyperLogLog};
+//!
+//! let mut seen = BloomFilter::new(1_000, 0.01);
+//! seen.insert("alice");
+//! assert!(seen.contains("alice"));
+//!
+//! let mut visitors = HyperLogLog::new(12);
+//! for id in 0..5_000 {
+//!     visitors.add(format!("user-{id}"));
+//! }
+//! let estimate = visitors.count() as f64;
+//! assert!((estimate - 5_000.0).abs() < 5_000.0 * 0.05);
+//! ```
+
+use crate::hash::{fnv1a, murmur3_32};
+
+/// FNV-1a followed by a 64-bit avalanche finalizer (MurmurHash3 `fmix64`).
+///
+/// Plain FNV-1a mixes the high bits poorly for short keys; HyperLogLog and
+/// double hashing both depend on well-distributed bits.
+fn hash64(bytes: &[u8]) -> u64 {
+    let mut h = fnv1a(bytes);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^ (h >> 33)
+}
+
+/// Space-efficient set membership with a tunable false-positive rate.
+///
+/// `contains` never returns `false` for an inserted item; it may return `true`
+/// for an item that was never inserted with roughly the configured
+/// probability (once `expected_items` have been added).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+    items: usize,
+}
+
+impl BloomFilter {
+    /// Size a filter for `expected_items` at false-positive rate `fp_rate`.
+    ///
+    /// `fp_rate` is clamped to `(0, 1)`; at least one bit and one hash are used.
+    pub fn new(expected_items: usize, fp_rate: f64) -> Self {
+        let n = expected_items.max(1) as f64;
+        let p = fp_rate.clamp(f64::MIN_POSITIVE, 0.999_999);
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * p.ln() / (ln2 * ln2)).ceil().max(1.0) as u64;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as u32;
+        Self::with_params(num_bits, num_hashes)
+    }
+
+    /// Create a filter with an explicit bit count and number of hash functions.
+    pub fn with_params(num_bits: u64, num_hashes: u32) -> Self {
+        let num_bits = num_bits.max(1);
+        Self {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes: num_hashes.max(1),
+            items: 0,
+        }
+    }
+
+    /// Bit positions for `bytes` via Kirsch–Mitzenmacher double hashing.
+    fn positions(&self, bytes: &[u8]) -> impl Iterator<Item = u64> {
+        let h1 = hash64(bytes);
+        let h2 = (murmur3_32(bytes, 0x9747b28c) as u64) << 1 | 1;
+        let m = self.num_bits;
+        (0..self.num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % m)
+    }
+
+    /// Add an item.
+    pub fn insert<T: AsRef<[u8]> + ?Sized>(&mut self, item: &T) {
+        let positions: Vec<u64> = self.positions(item.as_ref()).collect();
+        for pos in positions {
+            self.bits[(pos / 64) as usize] |= 1 << (pos % 64);
+        }
+        self.items += 1;
+    }
+
+    /// True if the item may have been inserted; false if it definitely was not.
+    pub fn contains<T: AsRef<[u8]> + ?Sized>(&self, item: &T) -> bool {
+        self.positions(item.as_ref())
+            .all(|pos| self.bits[(pos / 64) as usize] & (1 << (pos % 64)) != 0)
+    }
+
+    /// Number of `insert` calls (duplicates included).
+    pub fn len(&self) -> usize {
+        self.items
+    }
+
+    /// True if nothing has been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.items == 0
+    }
+
+    /// Size of the bit array.
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Number of hash functions per item.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Estimated false-positive probability given the current fill.
+    pub fn estimated_fp_rate(&self) -> f64 {
+        let set: u64 = self.bits.iter().map(|w| w.count_ones() as u64).sum();
+        (set as f64 / self.num_bits as f64).powi(self.num_hashes as i32)
+    }
+
+    /// Merge another filter with identical parameters into this one.
+    ///
+    /// Returns `false` (and leaves `self` unchanged) if the sizes differ.
+    pub fn union(&mut self, other: &BloomFilter) -> bool {
+        if self.num_bits != other.num_bits || self.num_hashes != other.num_hashes {
+            return false;
+        }
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a |= b;
+        }
+        self.items += other.items;
+        true
+    }
+
+    /// Remove all items.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+        self.items = 0;
+    }
+}
+
+/// Cardinality estimator using `2^precision` registers.
+///
+/// Standard error is about `1.04 / sqrt(2^precision)` (≈1.6% at precision 12,
+/// which uses 4 KiB).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Create an estimator; `precision` is clamped to `4..=16`.
+    pub fn new(precision: u8) -> Self {
+        let precision = precision.clamp(4, 16);
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    /// Record an item.
+    pub fn add<T: AsRef<[u8]>>(&mut self, item: T) {
+        let h = hash64(item.as_ref());
+        let idx = (h >> (64 - self.precision)) as usize;
+        let rest = h << self.precision;
+        let rank = (rest.leading_zeros() + 1).min(64 - self.precision as u32 + 1) as u8;
+        if rank > self.registers[idx] {
+            self.registers[idx] = rank;
+        }
+    }
+
+    /// Estimated number of distinct items added.
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        // Linear counting is more accurate while many registers are empty
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+        estimate.round() as u64
+    }
+
+    /// Merge another estimator with the same precision into this one.
+    ///
+    /// Returns `false` (and leaves `self` unchanged) if precisions differ.
+    pub fn merge(&mut self, other: &HyperLogLog) -> bool {
+        if self.precision != other.precision {
+            return false;
+        }
+        for (a, &b) in self.registers.iter_mut().zip(&other.registers) {
+            *a = (*a).max(b);
+        }
+        true
+    }
+
+    /// Precision (log2 of the register count).
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Reset to an empty estimator.
+    pub fn clear(&mut self) {
+        self.registers.fill(0);
+    }
+}
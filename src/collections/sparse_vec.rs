@@ -0,0 +1,177 @@
+//! Sparse vector storing only the occupied positions.
+//!
+//! [`SparseVec`] keeps `(index, value)` pairs sorted by index, so a mostly
+//! empty vector of length one million costs memory proportional to the
+//! number of values actually set. Unset positions read as `None`, and
+//! [`SparseVec::iter_dense`] walks every position in order.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::collections::SparseVec;
+//!
+//! let mut v = SparseVec::new();
+//! v.insert(2, 'c');
+//! v.insert(0, 'a');
+//! assert_eq!(v.len(), 3);
+//! assert_eq!(v.nnz(), 2);
+//! assert_eq!(v.iter_dense().collect::<Vec<_>>(), [Some(&'a'), None, Some(&'c')]);
+//! ```
+
+/// A vector whose unset positions take no space.
+///
+/// The logical [`len`](Self::len) is one past the highest index ever set (or
+/// the length given to [`with_len`](Self::with_len), if larger); removing
+/// values does not shrink it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseVec<T> {
+    entries: Vec<(usize, T)>,
+    len: usize,
+}
+
+impl<T> Default for SparseVec<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> SparseVec<T> {
+    /// Create an empty sparse vector of length zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty sparse vector of logical length `len`.
+    pub fn with_len(len: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            len,
+        }
+    }
+
+    /// Logical length, counting unset positions.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if the logical length is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of positions that hold a value.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn search(&self, index: usize) -> Result<usize, usize> {
+        self.entries.binary_search_by_key(&index, |&(i, _)| i)
+    }
+
+    /// Value at `index`, or `None` if unset or out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.search(index).ok().map(|pos| &self.entries[pos].1)
+    }
+
+    /// Mutable value at `index`, or `None` if unset or out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.search(index).ok().map(|pos| &mut self.entries[pos].1)
+    }
+
+    /// Set the value at `index`, growing the length if needed.
+    ///
+    /// Returns the previous value. Inserting out of index order costs
+    /// `O(nnz)` per call; appending in increasing order is amortized `O(1)`.
+    pub fn insert(&mut self, index: usize, value: T) -> Option<T> {
+        self.len = self.len.max(index + 1);
+        if !matches!(self.entries.last(), Some(&(last, _)) if last >= index) {
+            self.entries.push((index, value));
+            return None;
+        }
+        match self.search(index) {
+            Ok(pos) => Some(std::mem::replace(&mut self.entries[pos].1, value)),
+            Err(pos) => {
+                self.entries.insert(pos, (index, value));
+                None
+            }
+        }
+    }
+
+    /// Unset `index`, returning its value if it had one.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.search(index)
+            .ok()
+            .map(|pos| self.entries.remove(pos).1)
+    }
+
+    /// Unset every position, keeping the logical length.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Iterate over set positions as `(index, &value)` in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.entries.iter().map(|(i, v)| (*i, v))
+    }
+
+    /// Iterate over every position `0..len()`, yielding `None` for unset ones.
+    pub fn iter_dense(&self) -> impl Iterator<Item = Option<&T>> + '_ {
+        let mut entries = self.entries.iter().peekable();
+        (0..self.len).map(move |i| entries.next_if(|(j, _)| *j == i).map(|(_, v)| v))
+    }
+
+    /// Build a sparse vector from a dense slice, skipping `T::default()` values.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::collections::SparseVec;
+    /// let v = SparseVec::from_dense(&[0, 0, 5, 0, 7]);
+    /// assert_eq!(v.nnz(), 2);
+    /// assert_eq!(v.get(4), Some(&7));
+    /// assert_eq!(v.to_dense(), [0, 0, 5, 0, 7]);
+    /// ```
+    pub fn from_dense(values: &[T]) -> Self
+    where
+        T: Clone + Default + PartialEq,
+    {
+        let zero = T::default();
+        Self {
+            entries: values
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| **v != zero)
+                .map(|(i, v)| (i, v.clone()))
+                .collect(),
+            len: values.len(),
+        }
+    }
+
+    /// Expand into a dense `Vec`, filling unset positions with `T::default()`.
+    pub fn to_dense(&self) -> Vec<T>
+    where
+        T: Clone + Default,
+    {
+        self.iter_dense()
+            .map(|v| v.cloned().unwrap_or_default())
+            .collect()
+    }
+}
+
+impl<T> FromIterator<(usize, T)> for SparseVec<T> {
+    /// Later pairs overwrite earlier ones at the same index.
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut v = SparseVec::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<T> Extend<(usize, T)> for SparseVec<T> {
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        for (i, value) in iter {
+            self.insert(i, value);
+        }
+    }
+}
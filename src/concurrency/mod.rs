@@ -0,0 +1,166 @@
+//! Lightweight data parallelism on scoped threads.
+//!
+//! [`par_map`] and [`par_for_each`] split a slice into contiguous chunks and
+//! process each chunk on its own scoped thread (`std::thread::scope`), so the
+//! closure may borrow from the caller's stack and no thread pool or external
+//! dependency is needed. [`par_map_chunked`] instead hands out fixed-size
+//! chunks to workers as they free up, which balances uneven per-element work.
+//!
+//! Notes:
+//! - `n_threads == 0` uses [`std::thread::available_parallelism`].
+//! - Output order always matches input order.
+//! - If any worker panics, the first panic (in chunk order) is re-raised on the
+//!   calling thread with its original payload once all workers have finished.
+//! - Threads are spawned per call; this is meant for CPU-bound batches, not
+//!   tiny slices in hot loops.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::concurrency::{par_map, par_for_each};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//!
+//! let squares = par_map(&[1u64, 2, 3, 4, 5], 2, |x| x * x);
+//! assert_eq!(squares, vec![1, 4, 9, 16, 25]);
+//!
+//! let total = AtomicU64::new(0);
+//! par_for_each(&[1u64, 2, 3], 0, |x| { total.fetch_add(*x, Ordering::Relaxed); });
+//! assert_eq!(total.load(Ordering::Relaxed), 6);
+//! ```
+
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+fn effective_threads(n_threads: usize, len: usize) -> usize {
+    let n = if n_threads == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        n_threads
+    };
+    n.min(len).max(1)
+}
+
+/// Map `f` over `slice` in parallel using up to `n_threads` scoped threads.
+///
+/// Results are returned in input order. Panics in `f` are propagated to the
+/// caller.
+///
+/// Example:
+/// ```rust
+/// use toolchest::concurrency::par_map;
+/// let words = ["a", "bb", "ccc"];
+/// assert_eq!(par_map(&words, 3, |w| w.len()), vec![1, 2, 3]);
+/// ```
+pub fn par_map<T, R, F>(slice: &[T], n_threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if slice.is_empty() {
+        return Vec::new();
+    }
+    let threads = effective_threads(n_threads, slice.len());
+    if threads == 1 {
+        return slice.iter().map(&f).collect();
+    }
+    let chunk_size = slice.len().div_ceil(threads);
+    let f = &f;
+    let results: Vec<thread::Result<Vec<R>>> = thread::scope(|s| {
+        let handles: Vec<_> = slice
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+        handles.into_iter().map(|h| h.join()).collect()
+    });
+    let mut out = Vec::with_capacity(slice.len());
+    for res in results {
+        match res {
+            Ok(part) => out.extend(part),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+    out
+}
+
+/// Run `f` on every element of `slice` in parallel using up to `n_threads`
+/// scoped threads.
+///
+/// Panics in `f` are propagated to the caller.
+///
+/// Example:
+/// ```rust
+/// use toolchest::concurrency::par_for_each;
+/// use std::sync::Mutex;
+/// let seen = Mutex::new(Vec::new());
+/// par_for_each(&[3, 1, 2], 2, |x| seen.lock().unwrap().push(*x));
+/// let mut v = seen.into_inner().unwrap();
+/// v.sort();
+/// assert_eq!(v, vec![1, 2, 3]);
+/// ```
+pub fn par_for_each<T, F>(slice: &[T], n_threads: usize, f: F)
+where
+    T: Sync,
+    F: Fn(&T) + Sync,
+{
+    par_map(slice, n_threads, f);
+}
+
+/// Map `f` over `slice` in chunks of `chunk_size`, claimed dynamically by up
+/// to `n_threads` scoped workers.
+///
+/// Each worker repeatedly takes the next unprocessed chunk, so a few slow
+/// elements do not leave other threads idle. Results are returned in input
+/// order and panics in `f` are propagated. A `chunk_size` of 0 is treated as 1.
+///
+/// Example:
+/// ```rust
+/// use toolchest::concurrency::par_map_chunked;
+/// let v: Vec<u64> = (0..1000).collect();
+/// let out = par_map_chunked(&v, 64, 4, |x| x * 2);
+/// assert_eq!(out, v.iter().map(|x| x * 2).collect::<Vec<_>>());
+/// ```
+pub fn par_map_chunked<T, R, F>(slice: &[T], chunk_size: usize, n_threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let chunk_size = chunk_size.max(1);
+    let n_chunks = slice.len().div_ceil(chunk_size);
+    let threads = effective_threads(n_threads, n_chunks);
+    if threads <= 1 {
+        return slice.iter().map(&f).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let (f, next) = (&f, &next);
+    let results: Vec<_> = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(move || {
+                    let mut done: Vec<(usize, Vec<R>)> = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        if idx >= n_chunks {
+                            break;
+                        }
+                        let start = idx * chunk_size;
+                        let end = (start + chunk_size).min(slice.len());
+                        done.push((idx, slice[start..end].iter().map(f).collect()));
+                    }
+                    done
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join()).collect()
+    });
+    let mut chunks = Vec::with_capacity(n_chunks);
+    for res in results {
+        match res {
+            Ok(part) => chunks.extend(part),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+    chunks.sort_unstable_by_key(|(idx, _)| *idx);
+    chunks.into_iter().flat_map(|(_, part)| part).collect()
+}
@@ -6,7 +6,9 @@ pub trait DeepClone {
     fn deep_clone(&self) -> Self;
 }
 
-// Blanket implementation for types that implement Clone
+// Blanket implementation for types that implement Clone. `Clone` on owned
+// containers (`Vec`, `Option`, `HashMap`, `serde_json::Value`, ...) already
+// copies the whole tree, so this covers every leaf and container type.
 impl<T: Clone> DeepClone for T {
     fn deep_clone(&self) -> Self {
         self.clone()
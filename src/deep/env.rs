@@ -0,0 +1,199 @@
+//! Nested configuration from and to environment variables.
+//!
+//! Variables such as `APP__SERVER__PORT=8080` become nested maps
+//! (`server → port → "8080"`): the prefix is stripped, the remaining name
+//! is split on the separator (`__` by default), and each segment is
+//! lowercased.
+//! Numeric segments turn into list indices just like
+//! [`unflatten_keys`](super::unflatten_keys). [`to_env_lines`] goes the other
+//! way, e.g. to generate a `.env` file.
+//!
+//! Because [`Nested`] implements [`DeepMerge`](super::DeepMerge), layering
+//! 12-factor style is one [`merge`](fn@super::merge) call: defaults, then a
+//! config file, then the environment.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::deep::{from_vars, merge, to_env_lines, unflatten_keys_with, Nested};
+//! use std::collections::HashMap;
+//!
+//! let defaults = unflatten_keys_with(
+//!     HashMap::from([
+//!         ("server__host".to_string(), "localhost".to_string()),
+//!         ("server__port".to_string(), "80".to_string()),
+//!     ]),
+//!     "__",
+//! );
+//! let env = from_vars([("APP__SERVER__PORT", "8080"), ("OTHER", "x")], "APP", "__");
+//! let config = merge(&defaults, &env);
+//! assert_eq!(
+//!     to_env_lines(&config, "APP"),
+//!     ["APP__SERVER__HOST=localhost", "APP__SERVER__PORT=8080"]
+//! );
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use super::flatten::{unflatten_keys_with, Nested};
+
+/// Read variables named `{prefix}__…` from the process environment into a
+/// nested map; see the [module docs](self).
+///
+/// Variables whose name or value is not valid UTF-8 are skipped.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{from_env, Nested};
+/// std::env::set_var("DOCS_FROM_ENV__DB__POOL_SIZE", "16");
+/// let config = from_env("DOCS_FROM_ENV");
+/// let Nested::Map(db) = &config["db"] else { panic!() };
+/// assert_eq!(db["pool_size"], Nested::Leaf("16".to_string()));
+/// ```
+pub fn from_env(prefix: &str) -> HashMap<String, Nested<String>> {
+    from_env_with(prefix, "__")
+}
+
+/// [`from_env`] with a custom separator between path segments.
+pub fn from_env_with(prefix: &str, sep: &str) -> HashMap<String, Nested<String>> {
+    let vars = std::env::vars_os()
+        .filter_map(|(k, v)| Some((k.into_string().ok()?, v.into_string().ok()?)));
+    from_vars(vars, prefix, sep)
+}
+
+/// Build a nested map from explicit `(name, value)` pairs, as [`from_env`]
+/// does for the process environment.
+///
+/// Only names starting with `prefix` followed by `sep` are used (every name
+/// when `prefix` is empty). Names with an empty segment, like `APP____X`, are
+/// ignored.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{from_vars, Nested};
+/// let config = from_vars([("APP.HOSTS.0", "a"), ("APP.HOSTS.1", "b")], "APP", ".");
+/// assert_eq!(
+///     config["hosts"],
+///     Nested::List(vec![Nested::Leaf("a".to_string()), Nested::Leaf("b".to_string())])
+/// );
+/// ```
+pub fn from_vars<I, K, V>(vars: I, prefix: &str, sep: &str) -> HashMap<String, Nested<String>>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: Into<String>,
+{
+    let mut flat = HashMap::new();
+    for (name, value) in vars {
+        let name = name.as_ref();
+        let rest = if prefix.is_empty() {
+            Some(name)
+        } else {
+            name.strip_prefix(prefix).and_then(|r| r.strip_prefix(sep))
+        };
+        let Some(rest) = rest else {
+            continue;
+        };
+        if sep.is_empty() || rest.is_empty() || rest.split(sep).any(str::is_empty) {
+            continue;
+        }
+        flat.insert(rest.to_lowercase(), value.into());
+    }
+    unflatten_keys_with(flat, sep)
+}
+
+/// Render a nested map as sorted `NAME=value` lines with the default `__`
+/// separator; see [`to_env_lines_with`].
+pub fn to_env_lines<V: Display>(value: &HashMap<String, Nested<V>>, prefix: &str) -> Vec<String> {
+    to_env_lines_with(value, prefix, "__")
+}
+
+/// Render a nested map as sorted `NAME=value` lines.
+///
+/// Names are `prefix`, then each path segment uppercased, joined by `sep`;
+/// characters other than ASCII letters, digits, and `_` in a segment become
+/// `_`. Values containing whitespace, quotes, `#`, `$`, `` ` ``, or `\` are
+/// double-quoted with backslash escapes, as `.env` loaders expect.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{to_env_lines_with, Nested};
+/// use std::collections::HashMap;
+/// let config = HashMap::from([
+///     ("log-level".to_string(), Nested::Leaf("debug info")),
+///     ("hosts".to_string(), Nested::List(vec![Nested::Leaf("a")])),
+/// ]);
+/// assert_eq!(
+///     to_env_lines_with(&config, "", "_"),
+///     ["HOSTS_0=a", "LOG_LEVEL=\"debug info\""]
+/// );
+/// ```
+pub fn to_env_lines_with<V: Display>(
+    value: &HashMap<String, Nested<V>>,
+    prefix: &str,
+    sep: &str,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (key, child) in value {
+        let name = if prefix.is_empty() {
+            env_segment(key)
+        } else {
+            format!("{prefix}{sep}{}", env_segment(key))
+        };
+        push_lines(child, name, sep, &mut lines);
+    }
+    lines.sort();
+    lines
+}
+
+fn push_lines<V: Display>(node: &Nested<V>, name: String, sep: &str, out: &mut Vec<String>) {
+    match node {
+        Nested::Leaf(v) => out.push(format!("{name}={}", env_quote(&v.to_string()))),
+        Nested::Map(children) => {
+            for (k, child) in children {
+                push_lines(child, format!("{name}{sep}{}", env_segment(k)), sep, out);
+            }
+        }
+        Nested::List(items) => {
+            for (i, child) in items.iter().enumerate() {
+                push_lines(child, format!("{name}{sep}{i}"), sep, out);
+            }
+        }
+    }
+}
+
+fn env_segment(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn env_quote(value: &str) -> String {
+    let needs_quotes = value
+        .chars()
+        .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '#' | '$' | '`' | '\\'));
+    if !needs_quotes {
+        return value.to_string();
+    }
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' | '$' | '`' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,252 @@
+//! Stable structural hashing of nested values
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::flatten::Nested;
+use crate::hash::{combine, fnv1a};
+
+/// Types with a stable 64-bit structural hash.
+///
+/// Unlike [`std::hash::Hash`] with the default hasher, fingerprints are the
+/// same across runs, processes, and platforms: leaves are hashed with
+/// [`fnv1a`] over a type tag and little-endian bytes, children are folded with
+/// [`combine`], and map/set entries are visited in key order so `HashMap`
+/// iteration order does not matter.
+pub trait Fingerprint {
+    /// Structural hash of `self`
+    fn fingerprint(&self) -> u64;
+}
+
+fn leaf(tag: u8, bytes: &[u8]) -> u64 {
+    combine(tag as u64, fnv1a(bytes))
+}
+
+fn seq<'a, T: Fingerprint + 'a>(tag: u8, len: usize, items: impl Iterator<Item = &'a T>) -> u64 {
+    items.fold(combine(tag as u64, len as u64), |acc, item| {
+        combine(acc, item.fingerprint())
+    })
+}
+
+fn entries<'a, K, V>(len: usize, pairs: impl Iterator<Item = (&'a K, &'a V)>) -> u64
+where
+    K: Fingerprint + Ord + 'a,
+    V: Fingerprint + 'a,
+{
+    let mut pairs: Vec<_> = pairs.collect();
+    pairs.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    pairs
+        .into_iter()
+        .fold(combine(TAG_MAP, len as u64), |acc, (k, v)| {
+            combine(combine(acc, k.fingerprint()), v.fingerprint())
+        })
+}
+
+const TAG_MAP: u64 = 0x6d;
+const TAG_SEQ: u8 = 0x73;
+const TAG_SET: u8 = 0x74;
+
+macro_rules! impl_int_fingerprint {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Fingerprint for $t {
+                fn fingerprint(&self) -> u64 {
+                    leaf(b'i', &(*self as i128).to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+// All integers share one encoding so `1u8` and `1i64` fingerprint alike,
+// which keeps fingerprints stable when a field's integer type is widened.
+impl_int_fingerprint!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl Fingerprint for u128 {
+    fn fingerprint(&self) -> u64 {
+        match i128::try_from(*self) {
+            Ok(v) => v.fingerprint(),
+            Err(_) => leaf(b'I', &self.to_le_bytes()),
+        }
+    }
+}
+
+impl Fingerprint for f64 {
+    fn fingerprint(&self) -> u64 {
+        // Treat -0.0 as 0.0, matching `==`
+        let v = if *self == 0.0 { 0.0f64 } else { *self };
+        leaf(b'f', &v.to_bits().to_le_bytes())
+    }
+}
+
+impl Fingerprint for f32 {
+    fn fingerprint(&self) -> u64 {
+        (*self as f64).fingerprint()
+    }
+}
+
+impl Fingerprint for bool {
+    fn fingerprint(&self) -> u64 {
+        leaf(b'b', &[*self as u8])
+    }
+}
+
+impl Fingerprint for char {
+    fn fingerprint(&self) -> u64 {
+        leaf(b'c', &(*self as u32).to_le_bytes())
+    }
+}
+
+impl Fingerprint for str {
+    fn fingerprint(&self) -> u64 {
+        leaf(b's', self.as_bytes())
+    }
+}
+
+impl Fingerprint for String {
+    fn fingerprint(&self) -> u64 {
+        self.as_str().fingerprint()
+    }
+}
+
+impl Fingerprint for () {
+    fn fingerprint(&self) -> u64 {
+        leaf(b'u', &[])
+    }
+}
+
+impl<T: Fingerprint + ?Sized> Fingerprint for &T {
+    fn fingerprint(&self) -> u64 {
+        (**self).fingerprint()
+    }
+}
+
+impl<T: Fingerprint + ?Sized> Fingerprint for Box<T> {
+    fn fingerprint(&self) -> u64 {
+        (**self).fingerprint()
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for Option<T> {
+    fn fingerprint(&self) -> u64 {
+        match self {
+            None => leaf(b'n', &[]),
+            Some(v) => combine(b'o' as u64, v.fingerprint()),
+        }
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for [T] {
+    fn fingerprint(&self) -> u64 {
+        seq(TAG_SEQ, self.len(), self.iter())
+    }
+}
+
+impl<T: Fingerprint, const N: usize> Fingerprint for [T; N] {
+    fn fingerprint(&self) -> u64 {
+        self.as_slice().fingerprint()
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for Vec<T> {
+    fn fingerprint(&self) -> u64 {
+        self.as_slice().fingerprint()
+    }
+}
+
+macro_rules! impl_tuple_fingerprint {
+    ($($name:ident)+) => {
+        impl<$($name: Fingerprint),+> Fingerprint for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn fingerprint(&self) -> u64 {
+                let ($($name,)+) = self;
+                let acc = b't' as u64;
+                $(let acc = combine(acc, $name.fingerprint());)+
+                acc
+            }
+        }
+    };
+}
+
+impl_tuple_fingerprint!(A);
+impl_tuple_fingerprint!(A B);
+impl_tuple_fingerprint!(A B C);
+impl_tuple_fingerprint!(A B C D);
+
+impl<K: Fingerprint + Ord, V: Fingerprint, S> Fingerprint for HashMap<K, V, S> {
+    fn fingerprint(&self) -> u64 {
+        entries(self.len(), self.iter())
+    }
+}
+
+impl<K: Fingerprint + Ord, V: Fingerprint> Fingerprint for BTreeMap<K, V> {
+    fn fingerprint(&self) -> u64 {
+        entries(self.len(), self.iter())
+    }
+}
+
+impl<T: Fingerprint + Ord, S> Fingerprint for HashSet<T, S> {
+    fn fingerprint(&self) -> u64 {
+        let mut items: Vec<&T> = self.iter().collect();
+        items.sort_unstable();
+        seq(TAG_SET, items.len(), items.into_iter())
+    }
+}
+
+impl<T: Fingerprint + Ord> Fingerprint for BTreeSet<T> {
+    fn fingerprint(&self) -> u64 {
+        seq(TAG_SET, self.len(), self.iter())
+    }
+}
+
+impl<V: Fingerprint> Fingerprint for Nested<V> {
+    fn fingerprint(&self) -> u64 {
+        match self {
+            Nested::Leaf(v) => v.fingerprint(),
+            Nested::Map(m) => m.fingerprint(),
+            Nested::List(l) => l.fingerprint(),
+        }
+    }
+}
+
+/// Numbers hash like the matching Rust integer or `f64`, so a config parsed
+/// from JSON and one built in code fingerprint alike.
+#[cfg(feature = "json")]
+impl Fingerprint for serde_json::Value {
+    fn fingerprint(&self) -> u64 {
+        use serde_json::Value;
+        match self {
+            Value::Null => None::<()>.fingerprint(),
+            Value::Bool(b) => b.fingerprint(),
+            Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+                (Some(i), _) => i.fingerprint(),
+                (None, Some(u)) => u.fingerprint(),
+                _ => n.as_f64().unwrap_or(f64::NAN).fingerprint(),
+            },
+            Value::String(s) => s.fingerprint(),
+            Value::Array(a) => a.fingerprint(),
+            Value::Object(o) => entries(o.len(), o.iter()),
+        }
+    }
+}
+
+/// Stable 64-bit hash of a nested value, for cheap "did this change" checks.
+///
+/// See [`Fingerprint`] for the guarantees. Equal values always produce equal
+/// fingerprints; different values collide only with hash-collision
+/// probability.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::fingerprint;
+/// use std::collections::HashMap;
+/// let a = HashMap::from([("port", vec![80, 443]), ("debug", vec![])]);
+/// let mut b = HashMap::new();
+/// b.insert("debug", vec![]);
+/// b.insert("port", vec![80, 443]);
+/// assert_eq!(fingerprint(&a), fingerprint(&b));
+/// b.get_mut("port").unwrap().push(8080);
+/// assert_ne!(fingerprint(&a), fingerprint(&b));
+/// ```
+pub fn fingerprint<T: Fingerprint + ?Sized>(value: &T) -> u64 {
+    value.fingerprint()
+}
@@ -0,0 +1,274 @@
+//! Flatten nested maps into dotted keys and back.
+//!
+//! Paths use `.` by default (`server.ports.0`); the `_with` variants take any
+//! separator, e.g. `__` for environment variables. List elements are keyed by
+//! index. When unflattening, a level whose keys are exactly `0..n` becomes a
+//! list, everything else a map. Keys that themselves contain the separator
+//! are not escaped.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::deep::{flatten_keys, unflatten_keys, Nested};
+//! use std::collections::HashMap;
+//!
+//! let config = HashMap::from([(
+//!     "server".to_string(),
+//!     Nested::Map(HashMap::from([
+//!         ("host".to_string(), Nested::Leaf("localhost")),
+//!         ("ports".to_string(), Nested::List(vec![Nested::Leaf("80"), Nested::Leaf("443")])),
+//!     ])),
+//! )]);
+//! let flat = flatten_keys(&config);
+//! assert_eq!(flat["server.ports.1"], "443");
+//! assert_eq!(unflatten_keys(flat), config);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A tree of maps and lists with leaves of type `V`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Nested<V> {
+    /// A single value
+    Leaf(V),
+    /// Named children
+    Map(HashMap<String, Nested<V>>),
+    /// Indexed children
+    List(Vec<Nested<V>>),
+}
+
+// Intermediate form used while unflattening
+enum Tree<L> {
+    Leaf(L),
+    Branch(BTreeMap<String, Tree<L>>),
+}
+
+impl<L> Tree<L> {
+    // Deeper keys win: a leaf on the way down is replaced by a branch, and a
+    // leaf never overwrites an existing branch
+    fn insert(&mut self, path: &[&str], leaf: L) {
+        let Tree::Branch(children) = self else {
+            *self = Tree::Branch(BTreeMap::new());
+            return self.insert(path, leaf);
+        };
+        match path {
+            [] => {}
+            [last] => {
+                if !matches!(children.get(*last), Some(Tree::Branch(_))) {
+                    children.insert(last.to_string(), Tree::Leaf(leaf));
+                }
+            }
+            [first, rest @ ..] => children
+                .entry(first.to_string())
+                .or_insert_with(|| Tree::Branch(BTreeMap::new()))
+                .insert(rest, leaf),
+        }
+    }
+
+    fn from_flat(flat: HashMap<String, L>, sep: &str) -> BTreeMap<String, Tree<L>> {
+        let mut entries: Vec<_> = flat.into_iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut root = Tree::Branch(BTreeMap::new());
+        for (key, leaf) in entries {
+            root.insert(&key.split(sep).collect::<Vec<_>>(), leaf);
+        }
+        match root {
+            Tree::Branch(children) => children,
+            Tree::Leaf(_) => unreachable!("root is always a branch"),
+        }
+    }
+}
+
+// Children in index order if the keys are exactly "0".."n-1"
+fn as_list<L>(
+    mut children: BTreeMap<String, Tree<L>>,
+) -> Result<Vec<Tree<L>>, BTreeMap<String, Tree<L>>> {
+    let n = children.len();
+    if n == 0 || !(0..n).all(|i| children.contains_key(&i.to_string())) {
+        return Err(children);
+    }
+    Ok((0..n)
+        .map(|i| children.remove(&i.to_string()).expect("checked above"))
+        .collect())
+}
+
+fn join(prefix: &str, sep: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}{sep}{key}")
+    }
+}
+
+fn flatten_nested<V: Clone>(
+    node: &Nested<V>,
+    prefix: String,
+    sep: &str,
+    out: &mut HashMap<String, V>,
+) {
+    match node {
+        Nested::Leaf(v) => {
+            out.insert(prefix, v.clone());
+        }
+        Nested::Map(map) => {
+            for (k, child) in map {
+                flatten_nested(child, join(&prefix, sep, k), sep, out);
+            }
+        }
+        Nested::List(items) => {
+            for (i, child) in items.iter().enumerate() {
+                flatten_nested(child, join(&prefix, sep, &i.to_string()), sep, out);
+            }
+        }
+    }
+}
+
+/// Flatten a nested map into `"a.b.0"`-style keys.
+///
+/// Empty maps and lists have no leaves and therefore disappear.
+pub fn flatten_keys<V: Clone>(map: &HashMap<String, Nested<V>>) -> HashMap<String, V> {
+    flatten_keys_with(map, ".")
+}
+
+/// Flatten a nested map, joining path segments with `sep`.
+pub fn flatten_keys_with<V: Clone>(
+    map: &HashMap<String, Nested<V>>,
+    sep: &str,
+) -> HashMap<String, V> {
+    let mut out = HashMap::new();
+    for (k, child) in map {
+        flatten_nested(child, k.clone(), sep, &mut out);
+    }
+    out
+}
+
+fn tree_to_nested<V>(tree: Tree<V>) -> Nested<V> {
+    match tree {
+        Tree::Leaf(v) => Nested::Leaf(v),
+        Tree::Branch(children) => match as_list(children) {
+            Ok(items) => Nested::List(items.into_iter().map(tree_to_nested).collect()),
+            Err(children) => Nested::Map(
+                children
+                    .into_iter()
+                    .map(|(k, t)| (k, tree_to_nested(t)))
+                    .collect(),
+            ),
+        },
+    }
+}
+
+/// Rebuild a nested map from dotted keys (inverse of [`flatten_keys`]).
+///
+/// If a key is both a leaf and a prefix of other keys (`"a"` and `"a.b"`),
+/// the deeper keys win.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{unflatten_keys, Nested};
+/// use std::collections::HashMap;
+/// let nested = unflatten_keys(HashMap::from([("db.hosts.0".to_string(), "a")]));
+/// assert_eq!(
+///     nested["db"],
+///     Nested::Map(HashMap::from([("hosts".to_string(), Nested::List(vec![Nested::Leaf("a")]))]))
+/// );
+/// ```
+pub fn unflatten_keys<V>(flat: HashMap<String, V>) -> HashMap<String, Nested<V>> {
+    unflatten_keys_with(flat, ".")
+}
+
+/// Rebuild a nested map from keys whose segments are joined with `sep`.
+pub fn unflatten_keys_with<V>(flat: HashMap<String, V>, sep: &str) -> HashMap<String, Nested<V>> {
+    Tree::from_flat(flat, sep)
+        .into_iter()
+        .map(|(k, t)| (k, tree_to_nested(t)))
+        .collect()
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::{as_list, join, Tree};
+    use serde_json::Value;
+    use std::collections::HashMap;
+
+    fn flatten_value(value: &Value, prefix: String, sep: &str, out: &mut HashMap<String, Value>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (k, child) in map {
+                    flatten_value(child, join(&prefix, sep, k), sep, out);
+                }
+            }
+            Value::Array(items) if !items.is_empty() => {
+                for (i, child) in items.iter().enumerate() {
+                    flatten_value(child, join(&prefix, sep, &i.to_string()), sep, out);
+                }
+            }
+            leaf => {
+                out.insert(prefix, leaf.clone());
+            }
+        }
+    }
+
+    /// Flatten a JSON value into dotted keys (`"server.ports.0"`).
+    ///
+    /// Empty objects and arrays are kept as leaves so
+    /// [`unflatten_json`] restores them. A scalar root is keyed by `""`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::deep::{flatten_json, unflatten_json};
+    /// use serde_json::json;
+    /// let v = json!({"server": {"ports": [80, 443], "tls": {}}});
+    /// let flat = flatten_json(&v);
+    /// assert_eq!(flat["server.ports.1"], json!(443));
+    /// assert_eq!(unflatten_json(flat), v);
+    /// ```
+    pub fn flatten_json(value: &Value) -> HashMap<String, Value> {
+        flatten_json_with(value, ".")
+    }
+
+    /// Flatten a JSON value, joining path segments with `sep`.
+    pub fn flatten_json_with(value: &Value, sep: &str) -> HashMap<String, Value> {
+        let mut out = HashMap::new();
+        flatten_value(value, String::new(), sep, &mut out);
+        out
+    }
+
+    fn tree_to_value(tree: Tree<Value>) -> Value {
+        match tree {
+            Tree::Leaf(v) => v,
+            Tree::Branch(children) => match as_list(children) {
+                Ok(items) => Value::Array(items.into_iter().map(tree_to_value).collect()),
+                Err(children) => Value::Object(
+                    children
+                        .into_iter()
+                        .map(|(k, t)| (k, tree_to_value(t)))
+                        .collect(),
+                ),
+            },
+        }
+    }
+
+    /// Rebuild a JSON value from dotted keys (inverse of [`flatten_json`]).
+    ///
+    /// The root is always an object unless the only key is `""`.
+    pub fn unflatten_json(flat: HashMap<String, Value>) -> Value {
+        unflatten_json_with(flat, ".")
+    }
+
+    /// Rebuild a JSON value from keys whose segments are joined with `sep`.
+    pub fn unflatten_json_with(mut flat: HashMap<String, Value>, sep: &str) -> Value {
+        if flat.len() == 1 {
+            if let Some(root) = flat.remove("") {
+                return root;
+            }
+        }
+        Value::Object(
+            Tree::from_flat(flat, sep)
+                .into_iter()
+                .map(|(k, t)| (k, tree_to_value(t)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "json")]
+pub use json::{flatten_json, flatten_json_with, unflatten_json, unflatten_json_with};
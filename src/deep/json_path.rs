@@ -74,3 +74,164 @@ pub fn json_set(value: &mut Value, path: &str, new_value: Value) -> bool {
 pub fn json_has(value: &Value, path: &str) -> bool {
     json_get(value, path).is_some()
 }
+
+#[cfg(feature = "json")]
+impl crate::deep::PathAccess for Value {
+    type Value = Value;
+
+    fn get_path<'a>(&'a self, path: &str) -> Option<&'a Value> {
+        json_get(self, path)
+    }
+
+    fn set_path(&mut self, path: &str, value: Value) -> bool {
+        json_set(self, path, value)
+    }
+
+    fn remove_path(&mut self, path: &str) -> Option<Value> {
+        let (parent, last) = match path.rsplit_once('.') {
+            Some((parent, last)) => (json_get_mut(self, parent)?, last),
+            None => (self, path),
+        };
+        match parent {
+            Value::Object(map) => map.remove(last),
+            Value::Array(arr) => {
+                let idx: usize = last.parse().ok()?;
+                (idx < arr.len()).then(|| arr.remove(idx))
+            }
+            _ => None,
+        }
+    }
+
+    /// Leaf paths as in [`flatten_json`](crate::deep::flatten_json): empty
+    /// objects and arrays count as leaves, and a scalar root yields `""`.
+    fn paths(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        collect_paths(self, String::new(), &mut out);
+        out
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_get_mut<'a>(value: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    let mut current = value;
+    for part in path.split('.') {
+        current = match current {
+            Value::Object(map) => map.get_mut(part)?,
+            Value::Array(arr) => arr.get_mut(part.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+#[cfg(feature = "json")]
+fn collect_paths(value: &Value, prefix: String, out: &mut Vec<String>) {
+    let child = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (k, v) in map {
+                collect_paths(v, child(k), out);
+            }
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            for (i, v) in arr.iter().enumerate() {
+                collect_paths(v, child(&i.to_string()), out);
+            }
+        }
+        _ => out.push(prefix),
+    }
+}
+
+/// Which selected paths continue below `key`, and whether one ends at it
+#[cfg(feature = "json")]
+fn descend<'p>(selected: &[&'p str], key: &str) -> (bool, Vec<&'p str>) {
+    let mut exact = false;
+    let mut rest = Vec::new();
+    for path in selected {
+        match path.split_once('.') {
+            Some((head, tail)) if head == key => rest.push(tail),
+            None if *path == key => exact = true,
+            _ => {}
+        }
+    }
+    (exact, rest)
+}
+
+#[cfg(feature = "json")]
+fn project(value: &Value, selected: &[&str], keep: bool) -> Option<Value> {
+    let visit = |key: &str, child: &Value| -> Option<Value> {
+        let (exact, rest) = descend(selected, key);
+        match (exact, keep) {
+            (true, true) => Some(child.clone()),
+            (true, false) => None,
+            (false, _) if rest.is_empty() => (!keep).then(|| child.clone()),
+            (false, _) => project(child, &rest, keep),
+        }
+    };
+    match value {
+        Value::Object(map) => {
+            let out: serde_json::Map<String, Value> = map
+                .iter()
+                .filter_map(|(k, v)| visit(k, v).map(|v| (k.clone(), v)))
+                .collect();
+            (!keep || !out.is_empty()).then_some(Value::Object(out))
+        }
+        Value::Array(arr) => {
+            let out: Vec<Value> = arr
+                .iter()
+                .enumerate()
+                .filter_map(|(i, v)| visit(&i.to_string(), v))
+                .collect();
+            (!keep || !out.is_empty()).then_some(Value::Array(out))
+        }
+        // A path that goes through a scalar doesn't match anything
+        other => (!keep).then(|| other.clone()),
+    }
+}
+
+/// Copy of `value` containing only the given paths.
+///
+/// Parents of picked paths keep their shape; picked array elements are
+/// compacted in their original order. Missing paths are ignored, and an empty
+/// object is returned when nothing matches.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::pick;
+/// use serde_json::json;
+/// let v = json!({"user": {"name": "ann", "password": "x"}, "tags": ["a", "b", "c"]});
+/// assert_eq!(
+///     pick(&v, &["user.name", "tags.2"]),
+///     json!({"user": {"name": "ann"}, "tags": ["c"]})
+/// );
+/// ```
+#[cfg(feature = "json")]
+pub fn pick(value: &Value, paths: &[&str]) -> Value {
+    project(value, paths, true).unwrap_or_else(|| Value::Object(Default::default()))
+}
+
+/// Copy of `value` without the given paths.
+///
+/// Array indices refer to positions in the original value, so omitting
+/// several elements of one array removes exactly those.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::omit;
+/// use serde_json::json;
+/// let v = json!({"user": {"name": "ann", "password": "x"}, "tags": ["a", "b", "c"]});
+/// assert_eq!(
+///     omit(&v, &["user.password", "tags.0", "tags.1"]),
+///     json!({"user": {"name": "ann"}, "tags": ["c"]})
+/// );
+/// ```
+#[cfg(feature = "json")]
+pub fn omit(value: &Value, paths: &[&str]) -> Value {
+    project(value, paths, false).unwrap_or(Value::Null)
+}
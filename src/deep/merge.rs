@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use super::flatten::Nested;
+
 /// Trait for types that can be deeply merged
 pub trait DeepMerge {
     /// Merge `other` into `self`
@@ -9,12 +11,22 @@ pub trait DeepMerge {
 }
 
 // Overwrite semantics for common leaf values
-impl DeepMerge for i32 {
-    fn deep_merge(&mut self, other: &Self) {
-        *self = *other;
-    }
+macro_rules! impl_overwrite_merge {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DeepMerge for $t {
+                fn deep_merge(&mut self, other: &Self) {
+                    self.clone_from(other);
+                }
+            }
+        )*
+    };
 }
 
+impl_overwrite_merge!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String,
+);
+
 impl<K, V> DeepMerge for HashMap<K, V>
 where
     K: Clone + Eq + std::hash::Hash,
@@ -32,6 +44,117 @@ where
     }
 }
 
+/// `None` on the right never overwrites; `Some` merges into an existing value
+/// or fills an empty slot.
+impl<T> DeepMerge for Option<T>
+where
+    T: Clone + DeepMerge,
+{
+    fn deep_merge(&mut self, other: &Self) {
+        match (self.as_mut(), other) {
+            (_, None) => {}
+            (Some(existing), Some(value)) => existing.deep_merge(value),
+            (None, Some(value)) => *self = Some(value.clone()),
+        }
+    }
+}
+
+/// Vectors are replaced wholesale; use [`merge_vec`] to pick another strategy.
+impl<T: Clone> DeepMerge for Vec<T> {
+    fn deep_merge(&mut self, other: &Self) {
+        self.clone_from(other);
+    }
+}
+
+/// How [`merge_vec`] combines two vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VecMergeStrategy {
+    /// Right replaces left entirely
+    #[default]
+    Replace,
+    /// Right elements are appended after left
+    Append,
+    /// Elements at the same index are deep-merged; extra right elements are appended
+    MergeByIndex,
+}
+
+/// Merge `right` into `left` using the given [`VecMergeStrategy`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{merge_vec, VecMergeStrategy};
+/// let mut v = vec![Some(1), None, Some(3)];
+/// merge_vec(&mut v, &[None, Some(2)], VecMergeStrategy::MergeByIndex);
+/// assert_eq!(v, vec![Some(1), Some(2), Some(3)]);
+///
+/// let mut w = vec![1, 2];
+/// merge_vec(&mut w, &[3], VecMergeStrategy::Append);
+/// assert_eq!(w, vec![1, 2, 3]);
+/// ```
+pub fn merge_vec<T>(left: &mut Vec<T>, right: &[T], strategy: VecMergeStrategy)
+where
+    T: Clone + DeepMerge,
+{
+    match strategy {
+        VecMergeStrategy::Replace => {
+            left.clear();
+            left.extend_from_slice(right);
+        }
+        VecMergeStrategy::Append => left.extend_from_slice(right),
+        VecMergeStrategy::MergeByIndex => {
+            for (i, value) in right.iter().enumerate() {
+                match left.get_mut(i) {
+                    Some(existing) => existing.deep_merge(value),
+                    None => left.push(value.clone()),
+                }
+            }
+        }
+    }
+}
+
+/// Objects merge key-by-key, `null` on the right never overwrites, and any
+/// other value (including arrays) replaces the left side.
+#[cfg(feature = "json")]
+impl DeepMerge for serde_json::Value {
+    fn deep_merge(&mut self, other: &Self) {
+        use serde_json::Value;
+        match (self, other) {
+            (_, Value::Null) => {}
+            (Value::Object(left), Value::Object(right)) => {
+                for (key, value) in right {
+                    match left.get_mut(key) {
+                        Some(existing) => existing.deep_merge(value),
+                        None => {
+                            left.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+            }
+            (slot, value) => *slot = value.clone(),
+        }
+    }
+}
+
+/// Maps merge key-by-key; any other right-hand node (including lists, as
+/// for [`Vec`]) replaces the left side.
+impl<V: Clone> DeepMerge for Nested<V> {
+    fn deep_merge(&mut self, other: &Self) {
+        match (self, other) {
+            (Nested::Map(left), Nested::Map(right)) => {
+                for (key, value) in right {
+                    match left.get_mut(key) {
+                        Some(existing) => existing.deep_merge(value),
+                        None => {
+                            left.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+            }
+            (slot, value) => slot.clone_from(value),
+        }
+    }
+}
+
 /// Merge two values, with right overwriting left
 pub fn merge<T: DeepMerge + Clone>(left: &T, right: &T) -> T {
     let mut result = left.clone();
@@ -0,0 +1,530 @@
+//! Configurable deep merging with strategies, conflict callbacks, and path selection
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use super::merge::VecMergeStrategy;
+use super::path::{match_segments, matches_below, split_path};
+
+/// Which side wins when two leaf values (or depth-limited containers) collide
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// The right value overwrites the left (same as [`merge`](fn@super::merge))
+    #[default]
+    PreferRight,
+    /// The left value is kept; the right only fills gaps
+    PreferLeft,
+}
+
+/// Outcome chosen by an `on_conflict` callback
+pub enum Resolution {
+    /// Apply the configured strategies (recursing into containers)
+    Merge,
+    /// Keep the left value unchanged
+    KeepLeft,
+    /// Replace with a clone of the right value
+    TakeRight,
+    /// Replace with this value; ignored (falling back to [`Resolution::Merge`])
+    /// if its type does not match the conflicting slot
+    Replace(Box<dyn Any>),
+}
+
+type ConflictFn<'a> = dyn Fn(&str, &dyn Any, &dyn Any) -> Resolution + 'a;
+
+// Glob patterns restricting which paths the right side may touch
+struct PathSelection {
+    // Each pattern pre-split into segments
+    patterns: Vec<Vec<String>>,
+    include: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    // The whole subtree may be merged
+    All,
+    // Only some descendants may be merged
+    Partial,
+    // Nothing here may be merged
+    None,
+}
+
+fn split_patterns(patterns: &[&str]) -> Vec<Vec<String>> {
+    patterns
+        .iter()
+        .map(|p| split_path(p).into_iter().map(str::to_string).collect())
+        .collect()
+}
+
+impl PathSelection {
+    fn classify(&self, path: &[String]) -> Selection {
+        // A pattern covers a path when it matches the path or one of its ancestors
+        let covered = self
+            .patterns
+            .iter()
+            .any(|p| (0..=path.len()).any(|k| match_segments(p, &path[..k])));
+        let below = || self.patterns.iter().any(|p| matches_below(p, path));
+        match (self.include, covered) {
+            (true, true) => Selection::All,
+            (false, true) => Selection::None,
+            _ if below() => Selection::Partial,
+            (true, false) => Selection::None,
+            (false, false) => Selection::All,
+        }
+    }
+}
+
+/// Options for [`merge_with`]
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{MergeOptions, MergeStrategy, VecMergeStrategy};
+/// let opts = MergeOptions::new()
+///     .with_strategy(MergeStrategy::PreferLeft)
+///     .with_vec_strategy(VecMergeStrategy::Append)
+///     .with_max_depth(2);
+/// # let _ = opts;
+/// ```
+#[derive(Default)]
+pub struct MergeOptions<'a> {
+    strategy: MergeStrategy,
+    vec_strategy: VecMergeStrategy,
+    max_depth: Option<usize>,
+    on_conflict: Option<Box<ConflictFn<'a>>>,
+    selection: Option<PathSelection>,
+}
+
+impl<'a> MergeOptions<'a> {
+    /// Right-wins, replace-vectors, unlimited depth: equivalent to [`merge`](fn@super::merge)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set which side wins for leaf conflicts
+    pub fn with_strategy(mut self, strategy: MergeStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set how vectors (and JSON arrays) present on both sides combine
+    pub fn with_vec_strategy(mut self, strategy: VecMergeStrategy) -> Self {
+        self.vec_strategy = strategy;
+        self
+    }
+
+    /// Only recurse `depth` levels; deeper containers are treated as leaves.
+    ///
+    /// `with_max_depth(1)` is a shallow merge of top-level keys.
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Consult `f(path, left, right)` wherever both sides hold a value.
+    ///
+    /// `path` is dot-separated (`"server.ports.0"`, empty at the root); the
+    /// values can be inspected with `downcast_ref`.
+    pub fn on_conflict<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, &dyn Any, &dyn Any) -> Resolution + 'a,
+    {
+        self.on_conflict = Some(Box::new(f));
+        self
+    }
+
+    /// Only let the right side change paths matching one of `patterns`.
+    ///
+    /// Patterns use [`path_matches`](super::path_matches) syntax and select
+    /// whole subtrees: `"server"` covers `"server.port"`. Replaces any earlier
+    /// `with_only`/`with_except` selection.
+    pub fn with_only(mut self, patterns: &[&str]) -> Self {
+        self.selection = Some(PathSelection {
+            patterns: split_patterns(patterns),
+            include: true,
+        });
+        self
+    }
+
+    /// Protect paths matching any of `patterns` from the right side.
+    ///
+    /// The inverse of [`with_only`](Self::with_only).
+    pub fn with_except(mut self, patterns: &[&str]) -> Self {
+        self.selection = Some(PathSelection {
+            patterns: split_patterns(patterns),
+            include: false,
+        });
+        self
+    }
+}
+
+/// Traversal state passed through [`DeepMergeWith`] implementations
+pub struct MergeContext<'o, 'a> {
+    options: &'o MergeOptions<'a>,
+    path: Vec<String>,
+    // Values copied from the right so far; tells partial copies if anything was selected
+    copied: usize,
+}
+
+impl MergeContext<'_, '_> {
+    /// Current dot-separated path
+    pub fn path(&self) -> String {
+        self.path.join(".")
+    }
+
+    /// Number of segments in the current path
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// The vector strategy in effect
+    pub fn vec_strategy(&self) -> VecMergeStrategy {
+        self.options.vec_strategy
+    }
+
+    /// Run `f` one level deeper, under path segment `segment`
+    pub fn with_segment<R>(&mut self, segment: impl Display, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.path.push(segment.to_string());
+        let out = f(self);
+        self.path.pop();
+        out
+    }
+
+    /// Ask the conflict callback about `left`/`right`.
+    ///
+    /// Returns `true` if the conflict was fully handled and the caller should
+    /// not merge further.
+    pub fn resolve<T: Clone + 'static>(&mut self, left: &mut T, right: &T) -> bool {
+        let Some(callback) = &self.options.on_conflict else {
+            return false;
+        };
+        match callback(&self.path(), &*left, right) {
+            Resolution::Merge => false,
+            Resolution::KeepLeft => true,
+            Resolution::TakeRight => {
+                left.clone_from(right);
+                true
+            }
+            Resolution::Replace(value) => match value.downcast::<T>() {
+                Ok(value) => {
+                    *left = *value;
+                    true
+                }
+                Err(_) => false,
+            },
+        }
+    }
+
+    /// True when containers at this depth should be merged recursively
+    pub fn should_recurse(&self) -> bool {
+        self.options
+            .max_depth
+            .map_or(true, |max| self.depth() < max)
+    }
+
+    /// Merge two leaf values: consult the callback, then apply the strategy
+    ///
+    /// Does nothing when a path selection excludes the current path.
+    pub fn merge_leaf<T: Clone + 'static>(&mut self, left: &mut T, right: &T) {
+        if self.selection() == Selection::All && !self.resolve(left, right) {
+            self.apply_strategy(left, right);
+        }
+    }
+
+    /// The part of a right-only `value` that the path selection lets through
+    pub fn copy_selected<T: DeepMergeWith>(&mut self, value: &T) -> Option<T> {
+        match self.selection() {
+            Selection::All => {
+                self.copied += 1;
+                Some(value.clone())
+            }
+            Selection::Partial => {
+                let mut fresh = value.empty_like()?;
+                let before = self.copied;
+                fresh.deep_merge_with(value, self);
+                (self.copied > before).then_some(fresh)
+            }
+            Selection::None => None,
+        }
+    }
+
+    fn selection(&self) -> Selection {
+        match &self.options.selection {
+            Some(selection) => selection.classify(&self.path),
+            None => Selection::All,
+        }
+    }
+
+    fn apply_strategy<T: Clone>(&self, left: &mut T, right: &T) {
+        if self.options.strategy == MergeStrategy::PreferRight {
+            left.clone_from(right);
+        }
+    }
+}
+
+/// Deep merge driven by [`MergeOptions`]
+///
+/// Implemented for the same leaf types as [`DeepMerge`](super::DeepMerge),
+/// `Option`, `Vec`, `HashMap` with displayable keys, and (with the `json`
+/// feature) `serde_json::Value`. Implement it for your own structs by merging
+/// each field inside [`MergeContext::with_segment`].
+pub trait DeepMergeWith: Clone + 'static {
+    /// Merge `other` into `self` according to `ctx`
+    fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>);
+
+    /// An empty container of the same shape, used to copy part of a value
+    /// when a path selection only covers some of it.
+    ///
+    /// The default (`None`) means a partially selected value is skipped.
+    fn empty_like(&self) -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_leaf_merge_with {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DeepMergeWith for $t {
+                fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>) {
+                    ctx.merge_leaf(self, other);
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf_merge_with!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String,
+);
+
+/// `None` on the right never overwrites; `None` on the left is filled
+/// without a conflict.
+impl<T: DeepMergeWith> DeepMergeWith for Option<T> {
+    fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>) {
+        match (self.as_mut(), other) {
+            (_, None) => {}
+            (Some(existing), Some(value)) => existing.deep_merge_with(value, ctx),
+            (None, Some(value)) => {
+                if let Some(value) = ctx.copy_selected(value) {
+                    *self = Some(value);
+                }
+            }
+        }
+    }
+
+    fn empty_like(&self) -> Option<Self> {
+        self.as_ref()?.empty_like().map(Some)
+    }
+}
+
+impl<T: DeepMergeWith> DeepMergeWith for Vec<T> {
+    fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>) {
+        if ctx.selection() == Selection::All && ctx.resolve(self, other) {
+            return;
+        }
+        merge_elements(self, other, ctx);
+    }
+
+    fn empty_like(&self) -> Option<Self> {
+        Some(Vec::new())
+    }
+}
+
+fn merge_elements<T: DeepMergeWith>(
+    left: &mut Vec<T>,
+    right: &[T],
+    ctx: &mut MergeContext<'_, '_>,
+) {
+    match ctx.selection() {
+        Selection::All => {}
+        // Only index-wise merging can honor a selection inside the vector
+        Selection::Partial
+            if ctx.vec_strategy() == VecMergeStrategy::MergeByIndex && ctx.should_recurse() => {}
+        _ => return,
+    }
+    match ctx.vec_strategy() {
+        VecMergeStrategy::Replace => {
+            if ctx.options.strategy == MergeStrategy::PreferRight {
+                left.clear();
+                left.extend_from_slice(right);
+            }
+        }
+        VecMergeStrategy::Append => left.extend_from_slice(right),
+        VecMergeStrategy::MergeByIndex if ctx.should_recurse() => {
+            for (i, value) in right.iter().enumerate() {
+                match left.get_mut(i) {
+                    Some(existing) => {
+                        ctx.with_segment(i, |ctx| existing.deep_merge_with(value, ctx))
+                    }
+                    // Stop at the first unselected element so indices keep their meaning
+                    None => match ctx.with_segment(i, |ctx| ctx.copy_selected(value)) {
+                        Some(value) => left.push(value),
+                        None => break,
+                    },
+                }
+            }
+        }
+        VecMergeStrategy::MergeByIndex => {
+            if ctx.options.strategy == MergeStrategy::PreferRight {
+                left.clear();
+                left.extend_from_slice(right);
+            }
+        }
+    }
+}
+
+impl<K, V> DeepMergeWith for HashMap<K, V>
+where
+    K: Clone + Eq + Hash + Display + 'static,
+    V: DeepMergeWith,
+{
+    fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>) {
+        match ctx.selection() {
+            Selection::All if ctx.resolve(self, other) => return,
+            Selection::All if !ctx.should_recurse() => return ctx.apply_strategy(self, other),
+            Selection::All => {}
+            Selection::Partial if ctx.should_recurse() => {}
+            _ => return,
+        }
+        for (key, value) in other {
+            ctx.with_segment(key, |ctx| match self.get_mut(key) {
+                Some(existing) => existing.deep_merge_with(value, ctx),
+                None => {
+                    if let Some(value) = ctx.copy_selected(value) {
+                        self.insert(key.clone(), value);
+                    }
+                }
+            });
+        }
+    }
+
+    fn empty_like(&self) -> Option<Self> {
+        Some(HashMap::new())
+    }
+}
+
+/// Objects merge key-by-key, `null` on the right never overwrites, and
+/// arrays follow the vector strategy.
+#[cfg(feature = "json")]
+impl DeepMergeWith for serde_json::Value {
+    fn deep_merge_with(&mut self, other: &Self, ctx: &mut MergeContext<'_, '_>) {
+        use serde_json::Value;
+        let selection = ctx.selection();
+        if other.is_null() || (selection == Selection::All && ctx.resolve(self, other)) {
+            return;
+        }
+        match (self, other) {
+            (Value::Object(left), Value::Object(right))
+                if ctx.should_recurse() && selection != Selection::None =>
+            {
+                for (key, value) in right {
+                    ctx.with_segment(key, |ctx| match left.get_mut(key) {
+                        Some(existing) => existing.deep_merge_with(value, ctx),
+                        None => {
+                            if let Some(value) = ctx.copy_selected(value) {
+                                left.insert(key.clone(), value);
+                            }
+                        }
+                    });
+                }
+            }
+            (Value::Array(left), Value::Array(right)) => merge_elements(left, right, ctx),
+            (slot, value) if selection == Selection::All => ctx.apply_strategy(slot, value),
+            _ => {}
+        }
+    }
+
+    fn empty_like(&self) -> Option<Self> {
+        use serde_json::Value;
+        match self {
+            Value::Object(_) => Some(Value::Object(Default::default())),
+            Value::Array(_) => Some(Value::Array(Vec::new())),
+            _ => None,
+        }
+    }
+}
+
+/// Merge two values using [`MergeOptions`]
+///
+/// Example:
+/// ```rust
+/// use std::collections::HashMap;
+/// use toolchest::deep::{merge_with, MergeOptions, MergeStrategy, Resolution};
+///
+/// let left = HashMap::from([("port".to_string(), 80), ("workers".to_string(), 4)]);
+/// let right = HashMap::from([("port".to_string(), 8080), ("workers".to_string(), 2)]);
+///
+/// let kept = merge_with(&left, &right, &MergeOptions::new().with_strategy(MergeStrategy::PreferLeft));
+/// assert_eq!(kept["port"], 80);
+///
+/// // Custom rule: take the larger worker count
+/// let opts = MergeOptions::new().on_conflict(|path, l, r| match path {
+///     "workers" => {
+///         let (l, r) = (l.downcast_ref::<i32>().unwrap(), r.downcast_ref::<i32>().unwrap());
+///         Resolution::Replace(Box::new(*l.max(r)))
+///     }
+///     _ => Resolution::Merge,
+/// });
+/// let merged = merge_with(&left, &right, &opts);
+/// assert_eq!((merged["port"], merged["workers"]), (8080, 4));
+/// ```
+pub fn merge_with<T: DeepMergeWith>(left: &T, right: &T, options: &MergeOptions<'_>) -> T {
+    let mut result = left.clone();
+    let mut ctx = MergeContext {
+        options,
+        path: Vec::new(),
+        copied: 0,
+    };
+    result.deep_merge_with(right, &mut ctx);
+    result
+}
+
+/// Merge only the paths of `right` matching one of `patterns`
+///
+/// Shorthand for [`merge_with`] with [`MergeOptions::with_only`]. Patterns
+/// use [`path_matches`](super::path_matches) syntax and cover whole subtrees.
+///
+/// Example:
+/// ```rust
+/// use std::collections::HashMap;
+/// use toolchest::deep::merge_only;
+///
+/// let section = |pairs: &[(&str, &str)]| {
+///     pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<HashMap<_, _>>()
+/// };
+/// let base = HashMap::from([
+///     ("server".to_string(), section(&[("host", "localhost"), ("port", "80")])),
+///     ("logging".to_string(), section(&[("level", "info")])),
+/// ]);
+/// let user = HashMap::from([
+///     ("server".to_string(), section(&[("host", "0.0.0.0"), ("port", "8080")])),
+///     ("logging".to_string(), section(&[("level", "debug"), ("file", "app.log")])),
+/// ]);
+///
+/// let merged = merge_only(&base, &user, &["server.port", "logging.*"]);
+/// assert_eq!(merged["server"]["host"], "localhost");
+/// assert_eq!(merged["server"]["port"], "8080");
+/// assert_eq!(merged["logging"], section(&[("level", "debug"), ("file", "app.log")]));
+/// ```
+pub fn merge_only<T: DeepMergeWith>(left: &T, right: &T, patterns: &[&str]) -> T {
+    merge_with(left, right, &MergeOptions::new().with_only(patterns))
+}
+
+/// Merge everything from `right` except paths matching one of `patterns`
+///
+/// Shorthand for [`merge_with`] with [`MergeOptions::with_except`]; useful
+/// for layering configuration while protecting certain keys from override.
+///
+/// Example:
+/// ```rust
+/// use std::collections::HashMap;
+/// use toolchest::deep::merge_except;
+///
+/// let base = HashMap::from([("port".to_string(), 80), ("workers".to_string(), 4)]);
+/// let user = HashMap::from([("port".to_string(), 8080), ("workers".to_string(), 16)]);
+/// let merged = merge_except(&base, &user, &["port"]);
+/// assert_eq!((merged["port"], merged["workers"]), (80, 16));
+/// ```
+pub fn merge_except<T: DeepMergeWith>(left: &T, right: &T, patterns: &[&str]) -> T {
+    merge_with(left, right, &MergeOptions::new().with_except(patterns))
+}
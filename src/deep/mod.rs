@@ -1,8 +1,13 @@
 //! Deep operations module.
 //!
-//! Helpers for deep cloning, deep equality, merging nested structures, and
-//! path-based get/set access. Optional JSON-path helpers are available behind
-//! the `json` feature.
+//! Helpers for deep cloning, deep equality, merging nested structures (with
+//! configurable strategies via [`merge_with()`], or restricted to glob-selected
+//! paths via [`merge_only`]/[`merge_except`]), path-based get/set/unset access
+//! with leaf enumeration ([`paths`]) and pattern matching ([`path_matches`]),
+//! flattening to dotted keys ([`flatten`]), layering config from environment
+//! variables ([`from_env`]), and stable structural hashing ([`fingerprint()`]).
+//! Optional JSON-path helpers, including `pick`/`omit` projections, are
+//! available behind the `json` feature.
 //!
 //! Examples:
 //! ```rust
@@ -15,15 +20,28 @@
 //! ```
 
 pub mod clone;
+pub mod env;
 pub mod equal;
+pub mod fingerprint;
+pub mod flatten;
 #[cfg(feature = "json")]
 pub mod json_path;
 pub mod merge;
+pub mod merge_with;
 pub mod path;
 
 pub use clone::{clone as deep_clone, DeepClone};
+pub use env::{from_env, from_env_with, from_vars, to_env_lines, to_env_lines_with};
 pub use equal::{deep_equal, deep_equal_slice};
+pub use fingerprint::{fingerprint, Fingerprint};
 #[cfg(feature = "json")]
-pub use json_path::{json_get, json_has, json_set};
-pub use merge::{merge, merge_all, DeepMerge};
-pub use path::{get, has, set, PathAccess};
+pub use flatten::{flatten_json, flatten_json_with, unflatten_json, unflatten_json_with};
+pub use flatten::{flatten_keys, flatten_keys_with, unflatten_keys, unflatten_keys_with, Nested};
+#[cfg(feature = "json")]
+pub use json_path::{json_get, json_has, json_set, omit, pick};
+pub use merge::{merge, merge_all, merge_vec, DeepMerge, VecMergeStrategy};
+pub use merge_with::{
+    merge_except, merge_only, merge_with, DeepMergeWith, MergeContext, MergeOptions, MergeStrategy,
+    Resolution,
+};
+pub use path::{get, has, path_matches, paths, set, unset, PathAccess};
@@ -1,5 +1,7 @@
 //! Path-based access to nested structures
 
+use crate::strings::glob::wildcard_match;
+
 /// Trait for types that support path-based access
 pub trait PathAccess {
     /// Associated value type accessed by path
@@ -13,6 +15,19 @@ pub trait PathAccess {
     fn has_path(&self, path: &str) -> bool {
         self.get_path(path).is_some()
     }
+    /// Remove and return the value at `path`.
+    ///
+    /// The default returns `None`: fixed struct fields can't be removed.
+    fn remove_path(&mut self, path: &str) -> Option<Self::Value> {
+        let _ = path;
+        None
+    }
+    /// Every leaf path, in traversal order.
+    ///
+    /// The default returns an empty list.
+    fn paths(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// Generic get function
@@ -29,3 +44,176 @@ pub fn set<T: PathAccess>(container: &mut T, path: &str, value: T::Value) -> boo
 pub fn has<T: PathAccess>(container: &T, path: &str) -> bool {
     container.has_path(path)
 }
+
+/// Generic remove function, returning the removed value
+pub fn unset<T: PathAccess>(container: &mut T, path: &str) -> Option<T::Value> {
+    container.remove_path(path)
+}
+
+/// Every leaf path of `container`
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::paths;
+/// use toolchest::impl_path_access;
+///
+/// struct Limits { cpu: u32, mem: u32 }
+/// struct Job { priority: u32, limits: Limits }
+/// impl_path_access!(Limits: u32 { cpu, mem });
+/// impl_path_access!(Job: u32 { priority; nested: limits });
+///
+/// let job = Job { priority: 1, limits: Limits { cpu: 2, mem: 512 } };
+/// assert_eq!(paths(&job), ["priority", "limits.cpu", "limits.mem"]);
+/// ```
+pub fn paths<T: PathAccess>(container: &T) -> Vec<String> {
+    container.paths()
+}
+
+/// True if dot-separated `path` matches the glob-style `pattern`
+///
+/// Pattern segments match one path segment each; within a segment `*` matches
+/// any run of characters and `?` a single character. A segment of `**`
+/// matches any number of path segments, including none.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::path_matches;
+/// assert!(path_matches("logging.*", "logging.level"));
+/// assert!(!path_matches("logging.*", "logging.file.path"));
+/// assert!(path_matches("logging.**", "logging.file.path"));
+/// assert!(path_matches("**.port", "server.http.port"));
+/// assert!(path_matches("server.port?", "server.port2"));
+/// ```
+pub fn path_matches(pattern: &str, path: &str) -> bool {
+    match_segments(&split_path(pattern), &split_path(path))
+}
+
+pub(crate) fn split_path(path: &str) -> Vec<&str> {
+    if path.is_empty() {
+        Vec::new()
+    } else {
+        path.split('.').collect()
+    }
+}
+
+pub(crate) fn match_segments<P: AsRef<str>, S: AsRef<str>>(pattern: &[P], path: &[S]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((seg, rest)) if seg.as_ref() == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((seg, rest)) => match path.split_first() {
+            Some((head, tail)) => {
+                wildcard_match(seg.as_ref(), head.as_ref()) && match_segments(rest, tail)
+            }
+            None => false,
+        },
+    }
+}
+
+/// True if `pattern` could match some strict descendant of `path`
+pub(crate) fn matches_below<P: AsRef<str>, S: AsRef<str>>(pattern: &[P], path: &[S]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (None, _) => false,
+        (Some(_), None) => true,
+        (Some((seg, rest)), Some((_, tail))) if seg.as_ref() == "**" => {
+            matches_below(rest, path) || matches_below(pattern, tail)
+        }
+        (Some((seg, rest)), Some((head, tail))) => {
+            wildcard_match(seg.as_ref(), head.as_ref()) && matches_below(rest, tail)
+        }
+    }
+}
+
+/// Implement [`PathAccess`] for a plain struct by listing its fields
+///
+/// Leaf fields must have the declared value type; fields listed after
+/// `nested:` must themselves implement `PathAccess` with the same value type
+/// and are addressed with dotted paths (`"server.port"`). Leaf fields can't
+/// be removed, so `remove_path` only reaches into nested containers.
+///
+/// Example:
+/// ```rust
+/// use toolchest::deep::{get, has, set};
+/// use toolchest::impl_path_access;
+///
+/// struct Server { port: u16, workers: u16 }
+/// struct Config { retries: u16, server: Server }
+///
+/// impl_path_access!(Server: u16 { port, workers });
+/// impl_path_access!(Config: u16 { retries; nested: server });
+///
+/// let mut cfg = Config { retries: 3, server: Server { port: 8080, workers: 4 } };
+/// assert_eq!(get(&cfg, "server.port"), Some(&8080));
+/// assert!(set(&mut cfg, "server.workers", 8));
+/// assert_eq!(cfg.server.workers, 8);
+/// assert!(!has(&cfg, "server.host"));
+/// ```
+#[macro_export]
+macro_rules! impl_path_access {
+    ($ty:ty : $value:ty {
+        $($field:ident),* $(,)?
+        $(; nested: $($nested:ident),+ $(,)?)?
+    }) => {
+        impl $crate::deep::PathAccess for $ty {
+            type Value = $value;
+
+            #[allow(unused_variables)]
+            fn get_path<'a>(&'a self, path: &str) -> Option<&'a Self::Value> {
+                $(
+                    if path == stringify!($field) {
+                        return Some(&self.$field);
+                    }
+                )*
+                let (head, rest) = path.split_once('.')?;
+                $($(
+                    if head == stringify!($nested) {
+                        return $crate::deep::PathAccess::get_path(&self.$nested, rest);
+                    }
+                )+)?
+                None
+            }
+
+            #[allow(unused_variables)]
+            fn set_path(&mut self, path: &str, value: Self::Value) -> bool {
+                $(
+                    if path == stringify!($field) {
+                        self.$field = value;
+                        return true;
+                    }
+                )*
+                let Some((head, rest)) = path.split_once('.') else {
+                    return false;
+                };
+                $($(
+                    if head == stringify!($nested) {
+                        return $crate::deep::PathAccess::set_path(&mut self.$nested, rest, value);
+                    }
+                )+)?
+                false
+            }
+
+            #[allow(unused_variables)]
+            fn remove_path(&mut self, path: &str) -> Option<Self::Value> {
+                let (head, rest) = path.split_once('.')?;
+                $($(
+                    if head == stringify!($nested) {
+                        return $crate::deep::PathAccess::remove_path(&mut self.$nested, rest);
+                    }
+                )+)?
+                None
+            }
+
+            fn paths(&self) -> Vec<String> {
+                #[allow(unused_mut)]
+                let mut out = vec![$(stringify!($field).to_string()),*];
+                $($(
+                    for p in $crate::deep::PathAccess::paths(&self.$nested) {
+                        out.push(format!("{}.{}", stringify!($nested), p));
+                    }
+                )+)?
+                out
+            }
+        }
+    };
+}
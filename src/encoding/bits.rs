@@ -0,0 +1,273 @@
+//! Bit-level helpers on integers and a growable [`BitSet`].
+//!
+//! Bit indices count from the least significant bit (`0`). Functions taking a
+//! bit index or range ignore bits at or beyond 64.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::encoding::bits::{get_bit, set_bits_range, extract_bits, BitSet};
+//!
+//! let flags = set_bits_range(0, 4..8);
+//! assert_eq!(flags, 0xF0);
+//! assert!(get_bit(flags, 5));
+//! assert_eq!(extract_bits(0xABCD, 4..12), 0xBC);
+//!
+//! let mut seen = BitSet::with_capacity(128);
+//! seen.set(3, true);
+//! seen.set(100, true);
+//! assert_eq!(seen.iter_ones().collect::<Vec<_>>(), [3, 100]);
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+/// Mask with the bits in `range` set (clamped to `0..64`)
+fn range_mask(range: Range<u32>) -> u64 {
+    let start = range.start.min(64);
+    let end = range.end.min(64);
+    if start >= end {
+        return 0;
+    }
+    let width = end - start;
+    let ones = if width == 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    };
+    ones << start
+}
+
+/// True if bit `i` of `x` is set.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::get_bit;
+/// assert!(get_bit(0b100, 2));
+/// assert!(!get_bit(0b100, 1));
+/// assert!(!get_bit(u64::MAX, 64));
+/// ```
+pub fn get_bit(x: u64, i: u32) -> bool {
+    i < 64 && x & (1 << i) != 0
+}
+
+/// `x` with bit `i` set to `on`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::set_bit;
+/// assert_eq!(set_bit(0, 3, true), 8);
+/// assert_eq!(set_bit(0xF, 0, false), 0xE);
+/// ```
+pub fn set_bit(x: u64, i: u32, on: bool) -> u64 {
+    let mask = range_mask(i..i.saturating_add(1));
+    if on {
+        x | mask
+    } else {
+        x & !mask
+    }
+}
+
+/// `x` with bit `i` flipped.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::toggle_bit;
+/// assert_eq!(toggle_bit(0b101, 1), 0b111);
+/// ```
+pub fn toggle_bit(x: u64, i: u32) -> u64 {
+    x ^ range_mask(i..i.saturating_add(1))
+}
+
+/// `x` with every bit in `range` set.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::set_bits_range;
+/// assert_eq!(set_bits_range(0, 0..4), 0xF);
+/// assert_eq!(set_bits_range(0, 60..70), 0xF000_0000_0000_0000);
+/// ```
+pub fn set_bits_range(x: u64, range: Range<u32>) -> u64 {
+    x | range_mask(range)
+}
+
+/// `x` with every bit in `range` cleared.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::clear_bits_range;
+/// assert_eq!(clear_bits_range(0xFF, 2..6), 0b1100_0011);
+/// ```
+pub fn clear_bits_range(x: u64, range: Range<u32>) -> u64 {
+    x & !range_mask(range)
+}
+
+/// The bits of `x` in `range`, shifted down to start at bit 0.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::extract_bits;
+/// // IPv4 header byte 0: version in the high nibble
+/// assert_eq!(extract_bits(0x45, 4..8), 4);
+/// ```
+pub fn extract_bits(x: u64, range: Range<u32>) -> u64 {
+    let start = range.start.min(64);
+    (x & range_mask(range)).checked_shr(start).unwrap_or(0)
+}
+
+/// Reverse the bit order of a `u32` (bit 0 becomes bit 31).
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::bits::reverse_bits_u32;
+/// assert_eq!(reverse_bits_u32(1), 0x8000_0000);
+/// assert_eq!(reverse_bits_u32(0b1011), 0xD000_0000);
+/// ```
+pub fn reverse_bits_u32(x: u32) -> u32 {
+    x.reverse_bits()
+}
+
+/// Growable set of small non-negative integers stored one bit each.
+///
+/// Reading past the end returns `false`; setting past the end grows the set.
+/// Equality ignores capacity, so sets with the same members compare equal.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::BitSet;
+/// let mut ports = BitSet::new();
+/// ports.set(443, true);
+/// assert!(ports.toggle(80));
+/// assert!(ports.get(80) && !ports.get(8080));
+/// assert_eq!(ports.count_ones(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty set with room for indices `0..bits` without reallocating
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits.div_ceil(64)],
+        }
+    }
+
+    /// Number of indices addressable without growing
+    pub fn capacity(&self) -> usize {
+        self.words.len() * 64
+    }
+
+    /// True if `i` is in the set
+    pub fn get(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    /// Add (`on = true`) or remove `i`, returning whether it was present before
+    pub fn set(&mut self, i: usize, on: bool) -> bool {
+        let was = self.get(i);
+        if on {
+            if i / 64 >= self.words.len() {
+                self.words.resize(i / 64 + 1, 0);
+            }
+            self.words[i / 64] |= 1 << (i % 64);
+        } else if was {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+        was
+    }
+
+    /// Flip `i`, returning its new state
+    pub fn toggle(&mut self, i: usize) -> bool {
+        let on = !self.get(i);
+        self.set(i, on);
+        on
+    }
+
+    /// Number of members
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// True if no index is set
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Members in ascending order
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(wi, &word)| {
+            let mut w = word;
+            std::iter::from_fn(move || {
+                if w == 0 {
+                    return None;
+                }
+                let bit = w.trailing_zeros() as usize;
+                w &= w - 1;
+                Some(wi * 64 + bit)
+            })
+        })
+    }
+
+    /// Remove every member, keeping the allocation
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Add every member of `other`
+    pub fn union_with(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    /// Keep only members also in `other`
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        for (i, a) in self.words.iter_mut().enumerate() {
+            *a &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    fn trimmed(&self) -> &[u64] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&w| w != 0)
+            .map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for i in iter {
+            set.set(i, true);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
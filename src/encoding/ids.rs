@@ -0,0 +1,205 @@
+//! Short public IDs: base62 integers and keyed ID obfuscation
+
+use std::fmt;
+
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+// Longest code an `IdObfuscator` pads to; 62^21 still fits in a u128
+const MAX_CODE_LEN: usize = 21;
+
+/// Base62 encode an integer with the alphabet `0-9A-Za-z`.
+///
+/// The output is URL-safe and has no padding; `0` encodes as `"0"`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::base62_encode;
+/// assert_eq!(base62_encode(0), "0");
+/// assert_eq!(base62_encode(61), "z");
+/// assert_eq!(base62_encode(62), "10");
+/// assert_eq!(base62_encode(u128::MAX).len(), 22);
+/// ```
+pub fn base62_encode(mut value: u128) -> String {
+    let mut digits = Vec::with_capacity(22);
+    loop {
+        digits.push(BASE62_ALPHABET[(value % 62) as usize]);
+        value /= 62;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().map(|&d| d as char).collect()
+}
+
+/// Decode a string produced by [`base62_encode`].
+///
+/// Returns `None` for an empty string, characters outside `0-9A-Za-z`, or a
+/// value that does not fit in a `u128`. Leading zeros are accepted.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{base62_decode, base62_encode};
+/// assert_eq!(base62_decode("10"), Some(62));
+/// assert_eq!(base62_decode(&base62_encode(123_456_789)), Some(123_456_789));
+/// assert_eq!(base62_decode("ab-c"), None);
+/// ```
+pub fn base62_decode(s: &str) -> Option<u128> {
+    if s.is_empty() {
+        return None;
+    }
+    s.bytes().try_fold(0u128, |acc, c| {
+        let digit = BASE62_ALPHABET.iter().position(|&a| a == c)?;
+        acc.checked_mul(62)?.checked_add(digit as u128)
+    })
+}
+
+fn mix64(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+// Feistel round function; halves are at most 63 bits wide
+fn round(half: u128, key: u64, mask: u128) -> u128 {
+    mix64(half as u64 ^ key) as u128 & mask
+}
+
+/// Reversible, keyed mapping from integer IDs to short base62 strings.
+///
+/// Useful for exposing database primary keys without revealing how many rows
+/// exist or letting clients guess neighbours. Codes are as short as the ID
+/// allows (at least [`with_min_length`](Self::with_min_length) characters),
+/// and consecutive IDs give unrelated-looking codes. Each length is a keyed
+/// permutation (a Feistel network with cycle-walking) over every code of that
+/// length, with a keyed alphabet shuffle on top.
+///
+/// This is obfuscation, not encryption: anyone with enough ID/code pairs
+/// can work out the key. Keep secrets out of the IDs themselves.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::IdObfuscator;
+/// let ids = IdObfuscator::new("my app key").with_min_length(6);
+/// let code = ids.encode(42);
+/// assert_eq!(code.len(), 6);
+/// assert_ne!(code, ids.encode(43));
+/// assert_eq!(ids.decode(&code), Some(42));
+/// assert_ne!(IdObfuscator::new("another key").with_min_length(6).encode(42), code);
+/// ```
+#[derive(Clone)]
+pub struct IdObfuscator {
+    alphabet: [u8; 62],
+    round_keys: [u64; 4],
+    min_length: usize,
+}
+
+impl IdObfuscator {
+    /// Create an obfuscator whose codes are determined by `key`.
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        let mut state = crate::hash::fnv1a(key.as_ref());
+        let mut next = || {
+            state = mix64(state);
+            state
+        };
+        let mut alphabet = *BASE62_ALPHABET;
+        for i in (1..alphabet.len()).rev() {
+            alphabet.swap(i, (next() % (i as u64 + 1)) as usize);
+        }
+        let round_keys = [next(), next(), next(), next()];
+        Self {
+            alphabet,
+            round_keys,
+            min_length: 1,
+        }
+    }
+
+    /// Pad codes to at least `len` characters (clamped to `1..=21`).
+    ///
+    /// Longer minimums make codes harder to enumerate; 6 or more is a
+    /// reasonable choice for public URLs.
+    pub fn with_min_length(mut self, len: usize) -> Self {
+        self.min_length = len.clamp(1, MAX_CODE_LEN);
+        self
+    }
+
+    /// Encode `id` as a code of at least the minimum length.
+    pub fn encode(&self, id: u64) -> String {
+        let len = self.code_len(id);
+        let domain = 62u128.pow(len as u32);
+        let mut value = self.permute(id as u128, domain, false);
+        let mut digits = vec![0u8; len];
+        for d in digits.iter_mut().rev() {
+            *d = self.alphabet[(value % 62) as usize];
+            value /= 62;
+        }
+        digits.iter().map(|&d| d as char).collect()
+    }
+
+    /// Decode a code produced by [`encode`](Self::encode) with the same key and
+    /// minimum length.
+    ///
+    /// Returns `None` for characters outside the alphabet, codes shorter than
+    /// the minimum, and codes [`encode`](Self::encode) would never produce
+    /// (longer than needed for the ID they map to, or out of `u64` range).
+    /// Other well-formed codes decode to some ID, so check that it exists.
+    pub fn decode(&self, code: &str) -> Option<u64> {
+        let len = code.len();
+        if len < self.min_length || len > MAX_CODE_LEN {
+            return None;
+        }
+        let mut value = 0u128;
+        for c in code.bytes() {
+            let digit = self.alphabet.iter().position(|&a| a == c)?;
+            value = value * 62 + digit as u128;
+        }
+        let id = self.permute(value, 62u128.pow(len as u32), true);
+        let id = u64::try_from(id).ok()?;
+        (self.code_len(id) == len).then_some(id)
+    }
+
+    fn code_len(&self, id: u64) -> usize {
+        let mut len = 1;
+        let mut rest = id / 62;
+        while rest > 0 {
+            len += 1;
+            rest /= 62;
+        }
+        len.max(self.min_length)
+    }
+
+    // Keyed bijection on `0..domain`: a balanced Feistel network over the
+    // smallest even bit width that covers the domain, re-applied until the
+    // result lands back inside it
+    fn permute(&self, mut value: u128, domain: u128, inverse: bool) -> u128 {
+        let bits = (128 - (domain - 1).leading_zeros()).max(2);
+        let half = bits.div_ceil(2);
+        let mask = (1u128 << half) - 1;
+        loop {
+            let (mut left, mut right) = (value >> half, value & mask);
+            if inverse {
+                for &key in self.round_keys.iter().rev() {
+                    (left, right) = (right ^ round(left, key, mask), left);
+                }
+            } else {
+                for &key in &self.round_keys {
+                    (left, right) = (right, left ^ round(right, key, mask));
+                }
+            }
+            value = (left << half) | right;
+            if value < domain {
+                return value;
+            }
+        }
+    }
+}
+
+impl fmt::Debug for IdObfuscator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The alphabet and round keys are derived from the secret key
+        f.debug_struct("IdObfuscator")
+            .field("min_length", &self.min_length)
+            .finish_non_exhaustive()
+    }
+}
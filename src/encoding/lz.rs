@@ -0,0 +1,164 @@
+//! Small LZ77-style byte compression.
+//!
+//! [`lz_compress`] replaces repeated byte sequences with back-references into
+//! the previous 32 KiB of output. It has no dependencies and favors simplicity
+//! over ratio: use it for embedding modest assets or squeezing repetitive
+//! logs, and reach for a real deflate/zstd implementation for anything large.
+//!
+//! Format:
+//! - the uncompressed length as a LEB128 varint, then
+//! - groups of up to eight tokens, each group led by a flag byte whose bits
+//!   (LSB first) mark the token as a literal byte (`0`) or a match (`1`);
+//! - a match is two varints: the distance back (`1..=32768`) and the length
+//!   minus 4.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::encoding::{lz_compress, lz_decompress};
+//!
+//! let log = "GET /health 200\n".repeat(100);
+//! let packed = lz_compress(log.as_bytes());
+//! assert!(packed.len() < log.len() / 10);
+//! assert_eq!(lz_decompress(&packed).unwrap(), log.as_bytes());
+//! ```
+
+use super::{varint_decode, varint_encode};
+
+const WINDOW: usize = 1 << 15;
+const MIN_MATCH: usize = 4;
+const MAX_MATCH: usize = 1 << 12;
+const HASH_BITS: u32 = 15;
+const MAX_CHAIN: usize = 64;
+
+fn hash4(bytes: &[u8]) -> usize {
+    let v = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    (v.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+}
+
+/// Compress `input` with a greedy LZ77 matcher.
+///
+/// The output is never more than about 1/8 larger than the input.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{lz_compress, lz_decompress};
+/// let data = b"abcabcabcabcabcabc";
+/// let packed = lz_compress(data);
+/// assert!(packed.len() < data.len());
+/// assert_eq!(lz_decompress(&packed).unwrap(), data);
+/// ```
+pub fn lz_compress(input: &[u8]) -> Vec<u8> {
+    let mut out = varint_encode(input.len() as u64);
+    out.reserve(input.len() / 2);
+    // Most recent position for each hash, and the previous position with the same hash
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; input.len()];
+    let insert = |pos: usize, head: &mut [usize], prev: &mut [usize]| {
+        if pos + MIN_MATCH <= input.len() {
+            let h = hash4(&input[pos..]);
+            prev[pos] = head[h];
+            head[h] = pos;
+        }
+    };
+
+    let mut flag_at = 0;
+    let mut tokens = 8;
+    let mut pos = 0;
+    while pos < input.len() {
+        if tokens == 8 {
+            flag_at = out.len();
+            out.push(0);
+            tokens = 0;
+        }
+        let (mut best_len, mut best_dist) = (0, 0);
+        if pos + MIN_MATCH <= input.len() {
+            let max_len = (input.len() - pos).min(MAX_MATCH);
+            let mut candidate = head[hash4(&input[pos..])];
+            let mut steps = 0;
+            while candidate != usize::MAX && pos - candidate <= WINDOW && steps < MAX_CHAIN {
+                let len = input[candidate..]
+                    .iter()
+                    .zip(&input[pos..pos + max_len])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if len > best_len {
+                    best_len = len;
+                    best_dist = pos - candidate;
+                    if len == max_len {
+                        break;
+                    }
+                }
+                candidate = prev[candidate];
+                steps += 1;
+            }
+        }
+        if best_len >= MIN_MATCH {
+            out[flag_at] |= 1 << tokens;
+            out.extend(varint_encode(best_dist as u64));
+            out.extend(varint_encode((best_len - MIN_MATCH) as u64));
+            for p in pos..pos + best_len {
+                insert(p, &mut head, &mut prev);
+            }
+            pos += best_len;
+        } else {
+            out.push(input[pos]);
+            insert(pos, &mut head, &mut prev);
+            pos += 1;
+        }
+        tokens += 1;
+    }
+    out
+}
+
+/// Decompress data produced by [`lz_compress`].
+///
+/// Returns `None` if the input is truncated, references data before the
+/// start of the output, contains a match longer than the compressor emits,
+/// records a length the input could not possibly expand to, or decodes to a
+/// different length than recorded.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{lz_compress, lz_decompress};
+/// assert_eq!(lz_decompress(&lz_compress(b"")).unwrap(), b"");
+/// assert_eq!(lz_decompress(&[5, 0, b'a']), None);
+/// ```
+pub fn lz_decompress(input: &[u8]) -> Option<Vec<u8>> {
+    let (len, mut pos) = varint_decode(input)?;
+    let len = usize::try_from(len).ok()?;
+    // Every input byte expands to at most `MAX_MATCH` output bytes
+    if len > input.len().saturating_mul(MAX_MATCH) {
+        return None;
+    }
+    // Don't trust the header for the allocation size
+    let mut out = Vec::with_capacity(len.min(input.len().saturating_mul(8)));
+    while out.len() < len {
+        let flags = *input.get(pos)?;
+        pos += 1;
+        for bit in 0..8 {
+            if out.len() >= len {
+                break;
+            }
+            if flags & (1 << bit) == 0 {
+                out.push(*input.get(pos)?);
+                pos += 1;
+                continue;
+            }
+            let (dist, used) = varint_decode(input.get(pos..)?)?;
+            pos += used;
+            let (extra, used) = varint_decode(input.get(pos..)?)?;
+            pos += used;
+            let dist = usize::try_from(dist).ok()?;
+            let n = usize::try_from(extra).ok()?.checked_add(MIN_MATCH)?;
+            if dist == 0 || dist > out.len() || n > MAX_MATCH || n > len - out.len() {
+                return None;
+            }
+            // Byte-by-byte so overlapping matches repeat the pattern
+            let start = out.len() - dist;
+            for i in 0..n {
+                out.push(out[start + i]);
+            }
+        }
+    }
+    (pos == input.len()).then_some(out)
+}
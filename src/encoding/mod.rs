@@ -1,7 +1,13 @@
 //! Encoding helpers.
 //!
 //! Lightweight string/byte encoding utilities including hex, ROT13, Caesar
-//! cipher, and Base32 (RFC 4648 without padding).
+//! cipher, Base32 (RFC 4648 without padding, and Crockford with optional check
+//! symbol), grouped/lenient hex for MACs and fingerprints, Base58 (Bitcoin alphabet),
+//! Ascii85, Morse code, LEB128 varints with ZigZag signed mapping,
+//! quoted-printable (RFC 2045), Punycode (RFC 3492), and Unicode normalization
+//! forms (behind the `unicode` feature). Bit-level helpers and a growable
+//! [`BitSet`] live in [`bits`]; a small LZ77-style codec lives in [`lz`];
+//! base62 integer IDs and the keyed [`IdObfuscator`] live in [`ids`].
 //!
 //! Examples:
 //! ```rust
@@ -15,8 +21,21 @@
 //!
 //! let b32 = base32_encode(b"hi");
 //! assert_eq!(base32_decode(&b32).unwrap(), b"hi");
+//!
+//! use toolchest::encoding::{varint_encode, varint_decode, zigzag_encode, zigzag_decode};
+//! let buf = varint_encode(zigzag_encode(-3));
+//! let (v, used) = varint_decode(&buf).unwrap();
+//! assert_eq!((zigzag_decode(v), used), (-3, 1));
 //! ```
 
+pub mod bits;
+pub mod ids;
+pub mod lz;
+
+pub use bits::BitSet;
+pub use ids::{base62_decode, base62_encode, IdObfuscator};
+pub use lz::{lz_compress, lz_decompress};
+
 /// Hex-encode bytes to lowercase string
 pub fn hex_encode(bytes: &[u8]) -> String {
     bytes.iter().map(|b| format!("{b:02x}")).collect()
@@ -42,6 +61,63 @@ pub fn hex_decode(s: &str) -> Option<Vec<u8>> {
         .collect()
 }
 
+/// Hex-encode bytes to uppercase string
+pub fn hex_encode_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02X}")).collect()
+}
+
+/// Uppercase hex with `sep` between every `group` bytes, as used for MAC
+/// addresses and key fingerprints. A `group` of 0 means no separators.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::hex_encode_grouped;
+/// assert_eq!(hex_encode_grouped(&[0xde, 0xad, 0xbe, 0xef], 1, ":"), "DE:AD:BE:EF");
+/// assert_eq!(hex_encode_grouped(&[0xde, 0xad, 0xbe, 0xef, 0x01], 2, " "), "DEAD BEEF 01");
+/// ```
+pub fn hex_encode_grouped(bytes: &[u8], group: usize, sep: &str) -> String {
+    if group == 0 {
+        return hex_encode_upper(bytes);
+    }
+    bytes
+        .chunks(group)
+        .map(hex_encode_upper)
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// Decode hex, skipping common separators (whitespace, `:`, `-`, `.`, `_`)
+/// and an optional `0x` prefix.
+///
+/// Returns `None` on any other character or an odd number of digits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::hex_decode_lenient;
+/// assert_eq!(hex_decode_lenient("DE:AD:be:ef"), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(hex_decode_lenient("0x0102 0304"), Some(vec![1, 2, 3, 4]));
+/// assert_eq!(hex_decode_lenient("DE:A"), None);
+/// ```
+pub fn hex_decode_lenient(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim_start();
+    let s = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    let mut digits = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            ':' | '-' | '.' | '_' => {}
+            c if c.is_whitespace() => {}
+            c => digits.push(c.to_digit(16)? as u8),
+        }
+    }
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    Some(digits.chunks(2).map(|p| (p[0] << 4) | p[1]).collect())
+}
+
 /// ROT13 transformation for ASCII letters.
 ///
 /// Non-ASCII letters are left unchanged.
@@ -148,3 +224,758 @@ pub fn base32_decode(s: &str) -> Option<Vec<u8>> {
     }
     Some(out)
 }
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CROCKFORD_CHECK: &[u8; 5] = b"*~$=U";
+
+fn crockford_value(c: char) -> Option<u8> {
+    let v = match c.to_ascii_uppercase() {
+        'O' => 0,
+        'I' | 'L' => 1,
+        c @ '0'..='9' => c as u8 - b'0',
+        c @ 'A'..='Z' => CROCKFORD.iter().position(|&a| a == c as u8)? as u8,
+        _ => return None,
+    };
+    Some(v)
+}
+
+/// Check symbol: the bytes read as a big-endian integer, modulo 37
+fn crockford_check_symbol(bytes: &[u8]) -> char {
+    let rem = bytes
+        .iter()
+        .fold(0u32, |acc, &b| (acc * 256 + b as u32) % 37) as usize;
+    if rem < 32 {
+        CROCKFORD[rem] as char
+    } else {
+        CROCKFORD_CHECK[rem - 32] as char
+    }
+}
+
+/// Crockford Base32 encode (no padding).
+///
+/// Uses the alphabet `0-9A-Z` without `I`, `L`, `O`, and `U`, so output is
+/// easy to read aloud and type back in.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{crockford_encode, crockford_decode};
+/// let key = crockford_encode(b"licence");
+/// assert_eq!(key, "DHMP6SBECDJG");
+/// assert_eq!(crockford_decode(&key).unwrap(), b"licence");
+/// ```
+pub fn crockford_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer: u64 = 0;
+    let mut bits: u8 = 0;
+    for &b in bytes {
+        buffer = (buffer << 8) | b as u64;
+        bits += 8;
+        while bits >= 5 {
+            out.push(CROCKFORD[((buffer >> (bits - 5)) & 0x1F) as usize] as char);
+            bits -= 5;
+        }
+    }
+    if bits > 0 {
+        out.push(CROCKFORD[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+    }
+    out
+}
+
+/// Crockford Base32 decode.
+///
+/// Case-insensitive; `I`/`L` read as `1` and `O` as `0`, and hyphens are
+/// skipped. Returns `None` on any other character.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::crockford_decode;
+/// assert_eq!(crockford_decode("dhmp-6sbe-cdjg").unwrap(), b"licence");
+/// assert_eq!(crockford_decode("DHMP6SBECDJU"), None);
+/// ```
+pub fn crockford_decode(s: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer: u64 = 0;
+    let mut bits: u8 = 0;
+    for c in s.chars().filter(|&c| c != '-') {
+        buffer = (buffer << 5) | crockford_value(c)? as u64;
+        bits += 5;
+        if bits >= 8 {
+            out.push((buffer >> (bits - 8)) as u8);
+            bits -= 8;
+        }
+    }
+    Some(out)
+}
+
+/// Crockford Base32 encode with a trailing check symbol.
+///
+/// The check symbol is the value mod 37, drawn from the Base32 alphabet plus
+/// `*~$=U`, and catches single-character typos and most transpositions.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{crockford_encode_check, crockford_decode_check};
+/// let key = crockford_encode_check(&[0x12, 0x34, 0x56]);
+/// assert_eq!(key, "28T5CJ");
+/// assert_eq!(crockford_decode_check(&key).unwrap(), vec![0x12, 0x34, 0x56]);
+/// assert_eq!(crockford_decode_check("28T6CJ"), None); // typo caught
+/// ```
+pub fn crockford_encode_check(bytes: &[u8]) -> String {
+    let mut out = crockford_encode(bytes);
+    out.push(crockford_check_symbol(bytes));
+    out
+}
+
+/// Decode Crockford Base32 produced by [`crockford_encode_check`].
+///
+/// Returns `None` if the input is malformed or the check symbol doesn't match.
+pub fn crockford_decode_check(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim_end_matches('-');
+    let check = s.chars().next_back()?;
+    let bytes = crockford_decode(&s[..s.len() - check.len_utf8()])?;
+    let expected = crockford_check_symbol(&bytes);
+    let matches = match check.to_ascii_uppercase() {
+        c @ ('*' | '~' | '$' | '=' | 'U') => c == expected,
+        c => crockford_value(c).map(|v| CROCKFORD[v as usize] as char) == Some(expected),
+    };
+    matches.then_some(bytes)
+}
+
+/// Encode an unsigned integer as an unsigned LEB128 varint.
+///
+/// Each output byte carries 7 bits of payload, least-significant group first,
+/// with the high bit set on every byte except the last. Values below 128
+/// encode to a single byte; `u64::MAX` encodes to 10 bytes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::varint_encode;
+/// assert_eq!(varint_encode(1), vec![0x01]);
+/// assert_eq!(varint_encode(300), vec![0xac, 0x02]);
+/// ```
+pub fn varint_encode(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(10);
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Decode an unsigned LEB128 varint from the start of `bytes`.
+///
+/// Returns the decoded value and the number of bytes consumed, so callers can
+/// continue reading from `&bytes[n..]`. Returns `None` if the input ends before
+/// the terminating byte or the encoded value does not fit in a `u64`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{varint_encode, varint_decode};
+/// let mut buf = varint_encode(300);
+/// buf.push(0xff); // trailing data is left untouched
+/// assert_eq!(varint_decode(&buf), Some((300, 2)));
+/// assert_eq!(varint_decode(&[0x80]), None);
+/// ```
+pub fn varint_decode(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for (i, &b) in bytes.iter().enumerate().take(10) {
+        let payload = (b & 0x7F) as u64;
+        // The tenth byte may only contribute the single remaining bit.
+        if i == 9 && payload > 1 {
+            return None;
+        }
+        value |= payload << (7 * i);
+        if b & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// ZigZag-encode a signed integer so small magnitudes map to small unsigned values.
+///
+/// `0, -1, 1, -2, 2, ...` map to `0, 1, 2, 3, 4, ...`, which pairs well with
+/// [`varint_encode`] for compact signed integers.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::zigzag_encode;
+/// assert_eq!(zigzag_encode(0), 0);
+/// assert_eq!(zigzag_encode(-1), 1);
+/// assert_eq!(zigzag_encode(1), 2);
+/// assert_eq!(zigzag_encode(i64::MIN), u64::MAX);
+/// ```
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Decode a ZigZag-encoded value back into a signed integer.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{zigzag_encode, zigzag_decode};
+/// assert_eq!(zigzag_decode(3), -2);
+/// assert_eq!(zigzag_decode(zigzag_encode(-12345)), -12345);
+/// ```
+pub fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58 encode using the Bitcoin alphabet.
+///
+/// Leading zero bytes are preserved as leading `'1'` characters. The alphabet
+/// omits `0`, `O`, `I`, and `l`, which makes the output easy to read aloud.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::base58_encode;
+/// assert_eq!(base58_encode(b"hello world"), "StV1DL6CwTryKyV");
+/// assert_eq!(base58_encode(&[0, 0, 1]), "112");
+/// ```
+pub fn base58_encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    // Base-58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &b in &bytes[zeros..] {
+        let mut carry = b as u32;
+        for d in digits.iter_mut() {
+            carry += (*d as u32) << 8;
+            *d = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.extend(std::iter::repeat('1').take(zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[d as usize] as char),
+    );
+    out
+}
+
+/// Base58 decode using the Bitcoin alphabet.
+///
+/// Returns `None` if the input contains characters outside the alphabet.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{base58_encode, base58_decode};
+/// assert_eq!(base58_decode("StV1DL6CwTryKyV").unwrap(), b"hello world");
+/// assert_eq!(base58_decode(&base58_encode(&[0, 255])).unwrap(), vec![0, 255]);
+/// assert_eq!(base58_decode("0OIl"), None);
+/// ```
+pub fn base58_decode(s: &str) -> Option<Vec<u8>> {
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+    // Bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+    for c in s.bytes().skip(zeros) {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for b in bytes.iter_mut() {
+            carry += (*b as u32) * 58;
+            *b = (carry & 0xFF) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+    let mut out = vec![0u8; zeros];
+    out.extend(bytes.iter().rev());
+    Some(out)
+}
+
+/// Ascii85 (Base85) encode, Adobe variant without `<~ ~>` delimiters.
+///
+/// Each 4-byte group becomes 5 characters in `'!'..='u'`; an all-zero group is
+/// shortened to `'z'`. A trailing partial group emits `n + 1` characters.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::ascii85_encode;
+/// assert_eq!(ascii85_encode(b"Man "), "9jqo^");
+/// assert_eq!(ascii85_encode(&[0, 0, 0, 0]), "z");
+/// ```
+pub fn ascii85_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 5 / 4 + 5);
+    for chunk in bytes.chunks(4) {
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(group);
+        if chunk.len() == 4 && value == 0 {
+            out.push('z');
+            continue;
+        }
+        let mut encoded = [0u8; 5];
+        for slot in encoded.iter_mut().rev() {
+            *slot = (value % 85) as u8 + b'!';
+            value /= 85;
+        }
+        out.extend(encoded[..chunk.len() + 1].iter().map(|&b| b as char));
+    }
+    out
+}
+
+/// Ascii85 (Base85) decode, Adobe variant.
+///
+/// Whitespace and optional `<~ ~>` delimiters are ignored and `'z'` expands to
+/// four zero bytes. Returns `None` on invalid characters, a misplaced `'z'`, a
+/// dangling single character, or a group that overflows 32 bits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::{ascii85_encode, ascii85_decode};
+/// assert_eq!(ascii85_decode("<~9jqo^~>").unwrap(), b"Man ");
+/// let data = b"any\x00\x00\x00\x00bytes";
+/// assert_eq!(ascii85_decode(&ascii85_encode(data)).unwrap(), data);
+/// ```
+pub fn ascii85_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim();
+    let s = s.strip_prefix("<~").unwrap_or(s);
+    let s = s.strip_suffix("~>").unwrap_or(s);
+    let mut out = Vec::with_capacity(s.len() * 4 / 5);
+    let mut group = [0u8; 5];
+    let mut n = 0usize;
+    fn flush(group: &[u8; 5], n: usize, out: &mut Vec<u8>) -> Option<()> {
+        let mut padded = [b'u' - b'!'; 5];
+        padded[..n].copy_from_slice(&group[..n]);
+        let value = padded
+            .iter()
+            .try_fold(0u32, |acc, &d| acc.checked_mul(85)?.checked_add(d as u32))?;
+        out.extend_from_slice(&value.to_be_bytes()[..n - 1]);
+        Some(())
+    }
+    for c in s.bytes() {
+        match c {
+            b'z' if n == 0 => out.extend_from_slice(&[0, 0, 0, 0]),
+            b'!'..=b'u' => {
+                group[n] = c - b'!';
+                n += 1;
+                if n == 5 {
+                    flush(&group, 5, &mut out)?;
+                    n = 0;
+                }
+            }
+            c if c.is_ascii_whitespace() => {}
+            _ => return None,
+        }
+    }
+    match n {
+        0 => {}
+        1 => return None,
+        _ => flush(&group, n, &mut out)?,
+    }
+    Some(out)
+}
+
+const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+/// Encode text as International Morse code.
+///
+/// Letters are separated by a space and words by `" / "`. Letters are
+/// case-insensitive. Returns `None` if the text contains a character with no
+/// Morse representation.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::morse_encode;
+/// assert_eq!(morse_encode("SOS").unwrap(), "... --- ...");
+/// assert_eq!(morse_encode("hi there").unwrap(), ".... .. / - .... . .-. .");
+/// assert_eq!(morse_encode("ß"), None);
+/// ```
+pub fn morse_encode(s: &str) -> Option<String> {
+    let words = s
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .map(|c| {
+                    let up = c.to_ascii_uppercase();
+                    MORSE_TABLE
+                        .iter()
+                        .find(|(k, _)| *k == up)
+                        .map(|(_, code)| *code)
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|codes| codes.join(" "))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(words.join(" / "))
+}
+
+/// Decode International Morse code into uppercase text.
+///
+/// Expects letters separated by whitespace and words separated by `/`.
+/// Returns `None` on an unknown code.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::morse_decode;
+/// assert_eq!(morse_decode("... --- ...").unwrap(), "SOS");
+/// assert_eq!(morse_decode(".... .. / - .... . .-. .").unwrap(), "HI THERE");
+/// assert_eq!(morse_decode("........"), None);
+/// ```
+pub fn morse_decode(s: &str) -> Option<String> {
+    let words = s
+        .split('/')
+        .map(|word| {
+            word.split_whitespace()
+                .map(|code| {
+                    MORSE_TABLE
+                        .iter()
+                        .find(|(_, c)| *c == code)
+                        .map(|(k, _)| *k)
+                })
+                .collect::<Option<String>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        words
+            .into_iter()
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Quoted-printable encode (RFC 2045).
+///
+/// Line breaks (`\n` or `\r\n`) in the input are kept as hard breaks; longer
+/// lines are split with `=\r\n` soft breaks so no line exceeds 76 characters.
+/// Trailing spaces/tabs before a line break are escaped.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::quoted_printable_encode;
+/// assert_eq!(quoted_printable_encode("caf\u{e9} = 1 ".as_bytes()), "caf=C3=A9 =3D 1=20");
+/// ```
+pub fn quoted_printable_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut line_len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\n' || (b == b'\r' && bytes.get(i + 1) == Some(&b'\n')) {
+            if b == b'\r' {
+                out.push('\r');
+                i += 1;
+            }
+            out.push('\n');
+            line_len = 0;
+            i += 1;
+            continue;
+        }
+        let at_eol = match bytes.get(i + 1) {
+            None | Some(b'\n') => true,
+            Some(b'\r') => bytes.get(i + 2) == Some(&b'\n'),
+            _ => false,
+        };
+        let literal =
+            (b'!'..=b'~').contains(&b) && b != b'=' || ((b == b' ' || b == b'\t') && !at_eol);
+        let width = if literal { 1 } else { 3 };
+        if line_len + width > 75 {
+            out.push_str("=\r\n");
+            line_len = 0;
+        }
+        if literal {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("={b:02X}"));
+        }
+        line_len += width;
+        i += 1;
+    }
+    out
+}
+
+/// Quoted-printable decode (RFC 2045).
+///
+/// Soft line breaks are removed and trailing whitespace on each line is
+/// ignored. Returns `None` for malformed `=` escapes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::quoted_printable_decode;
+/// assert_eq!(quoted_printable_decode("caf=C3=A9 =\r\nlatte").unwrap(), "caf\u{e9} latte".as_bytes());
+/// assert_eq!(quoted_printable_decode("bad=G1"), None);
+/// ```
+pub fn quoted_printable_decode(s: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len());
+    for segment in s.split_inclusive('\n') {
+        let (body, eol) = if let Some(b) = segment.strip_suffix("\r\n") {
+            (b, "\r\n")
+        } else if let Some(b) = segment.strip_suffix('\n') {
+            (b, "\n")
+        } else {
+            (segment, "")
+        };
+        let body = body.trim_end_matches([' ', '\t']);
+        let (body, eol) = match body.strip_suffix('=') {
+            Some(b) => (b, ""),
+            None => (body, eol),
+        };
+        let bytes = body.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'=' {
+                let hex = body.get(i + 1..i + 3)?;
+                if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                out.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 3;
+            } else {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+        out.extend_from_slice(eol.as_bytes());
+    }
+    Some(out)
+}
+
+const PUNY_BASE: u32 = 36;
+const PUNY_TMIN: u32 = 1;
+const PUNY_TMAX: u32 = 26;
+const PUNY_SKEW: u32 = 38;
+const PUNY_DAMP: u32 = 700;
+const PUNY_INITIAL_BIAS: u32 = 72;
+const PUNY_INITIAL_N: u32 = 128;
+
+fn puny_adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { PUNY_DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((PUNY_BASE - PUNY_TMIN) * PUNY_TMAX) / 2 {
+        delta /= PUNY_BASE - PUNY_TMIN;
+        k += PUNY_BASE;
+    }
+    k + (PUNY_BASE - PUNY_TMIN + 1) * delta / (delta + PUNY_SKEW)
+}
+
+fn puny_threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        PUNY_TMIN
+    } else if k >= bias + PUNY_TMAX {
+        PUNY_TMAX
+    } else {
+        k - bias
+    }
+}
+
+fn puny_digit(d: u32) -> char {
+    if d < 26 {
+        (b'a' + d as u8) as char
+    } else {
+        (b'0' + (d - 26) as u8) as char
+    }
+}
+
+/// Punycode encode a single label (RFC 3492), without the `xn--` prefix.
+///
+/// Returns `None` on arithmetic overflow (extremely long input).
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::punycode_encode;
+/// assert_eq!(punycode_encode("b\u{fc}cher").unwrap(), "bcher-kva");
+/// ```
+pub fn punycode_encode(input: &str) -> Option<String> {
+    let chars: Vec<u32> = input.chars().map(|c| c as u32).collect();
+    let mut out: String = input.chars().filter(char::is_ascii).collect();
+    let basic = out.len() as u32;
+    let mut handled = basic;
+    if basic > 0 {
+        out.push('-');
+    }
+    let (mut n, mut delta, mut bias) = (PUNY_INITIAL_N, 0u32, PUNY_INITIAL_BIAS);
+    while (handled as usize) < chars.len() {
+        let m = chars.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for &c in &chars {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = PUNY_BASE;
+                loop {
+                    let t = puny_threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    out.push(puny_digit(t + (q - t) % (PUNY_BASE - t)));
+                    q = (q - t) / (PUNY_BASE - t);
+                    k += PUNY_BASE;
+                }
+                out.push(puny_digit(q));
+                bias = puny_adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(out)
+}
+
+/// Punycode decode a single label (RFC 3492), without the `xn--` prefix.
+///
+/// Returns `None` for invalid digits, overflow, or invalid code points.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::punycode_decode;
+/// assert_eq!(punycode_decode("mnchen-3ya").unwrap(), "m\u{fc}nchen");
+/// ```
+pub fn punycode_decode(input: &str) -> Option<String> {
+    let (basic, rest) = match input.rfind('-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+    let mut out: Vec<char> = basic.chars().collect();
+    let (mut n, mut i, mut bias) = (PUNY_INITIAL_N, 0u32, PUNY_INITIAL_BIAS);
+    let mut digits = rest.bytes();
+    while digits.len() > 0 {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = PUNY_BASE;
+        loop {
+            let digit = match digits.next()? {
+                c @ b'0'..=b'9' => (c - b'0') as u32 + 26,
+                c @ b'a'..=b'z' => (c - b'a') as u32,
+                c @ b'A'..=b'Z' => (c - b'A') as u32,
+                _ => return None,
+            };
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = puny_threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(PUNY_BASE - t)?;
+            k += PUNY_BASE;
+        }
+        let len = out.len() as u32 + 1;
+        bias = puny_adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        out.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(out.into_iter().collect())
+}
+
+/// Unicode Normalization Form C (canonical composition).
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::normalize_nfc;
+/// assert_eq!(normalize_nfc("e\u{301}"), "\u{e9}");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn normalize_nfc(s: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfc(s).collect()
+}
+
+/// Unicode Normalization Form D (canonical decomposition).
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::normalize_nfd;
+/// assert_eq!(normalize_nfd("\u{e9}"), "e\u{301}");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn normalize_nfd(s: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfd(s).collect()
+}
+
+/// Unicode Normalization Form KC (compatibility composition).
+///
+/// Folds compatibility variants such as ligatures and fullwidth letters.
+///
+/// Example:
+/// ```rust
+/// use toolchest::encoding::normalize_nfkc;
+/// assert_eq!(normalize_nfkc("\u{fb01}le \u{ff21}"), "file A");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn normalize_nfkc(s: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfkc(s).collect()
+}
+
+/// Unicode Normalization Form KD (compatibility decomposition).
+#[cfg(feature = "unicode")]
+pub fn normalize_nfkd(s: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfkd(s).collect()
+}
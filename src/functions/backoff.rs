@@ -1,21 +1,24 @@
 //! Retry with exponential backoff.
 //!
 //! Provides [`retry_with_backoff`] which retries an operation up to a maximum
-//! number of attempts, sleeping between attempts with an exponentially
-//! increasing delay (doubling each time) starting from `base_delay`.
+//! number of attempts, sleeping between attempts according to a delay
+//! schedule. Any `IntoIterator<Item = Duration>` works as a schedule; the usual
+//! choice is [`BackoffIter`](crate::time::BackoffIter), which doubles from a
+//! base delay and supports caps and jitter.
 //!
 //! Notes:
 //! - The final error from the last attempt is returned on exhaustion.
+//! - Retrying stops early when the schedule runs out of delays.
 //! - Sleep uses `std::thread::sleep`, making this a blocking API.
-//! - Duration multiplication uses `saturating_mul(2)` to avoid overflow.
 //!
 //! Basic example:
 //! ```rust
 //! use toolchest::functions::retry_with_backoff;
+//! use toolchest::time::BackoffIter;
 //! use std::time::Duration;
 //!
 //! let mut tries = 0;
-//! let result: Result<&'static str, &'static str> = retry_with_backoff(3, Duration::from_millis(5), || {
+//! let result: Result<&'static str, &'static str> = retry_with_backoff(3, BackoffIter::new(Duration::from_millis(5)), || {
 //!     tries += 1;
 //!     if tries < 3 { Err("not yet") } else { Ok("ok") }
 //! });
@@ -25,31 +28,87 @@
 use std::thread;
 use std::time::Duration;
 
-/// Retry with exponential backoff starting at `base_delay`.
+use crate::time::Budget;
+
+/// Retry with delays taken from `schedule`.
 ///
 /// - `attempts`: maximum number of times to try `op` (must be ≥ 1).
-/// - `base_delay`: initial delay before the second attempt; doubles each retry.
+/// - `schedule`: delays to sleep between attempts, in order; e.g. a
+///   [`BackoffIter`](crate::time::BackoffIter) or a fixed `Vec<Duration>`.
 /// - `op`: operation returning `Result<T, E>`.
 ///
 /// Returns `Ok(T)` on the first successful attempt, or `Err(E)` from the last
-/// attempt when attempts are exhausted.
+/// attempt when attempts or delays are exhausted.
 ///
 /// Example that always fails and returns the last error:
 /// ```rust
 /// use toolchest::functions::retry_with_backoff;
 /// use std::time::Duration;
-/// let res: Result<(), &str> = retry_with_backoff(2, Duration::from_millis(1), || Err("oops"));
+/// let mut calls = 0;
+/// let res: Result<(), &str> = retry_with_backoff(5, [Duration::from_millis(1)], || {
+///     calls += 1;
+///     Err("oops")
+/// });
 /// assert_eq!(res.unwrap_err(), "oops");
+/// assert_eq!(calls, 2); // schedule had a single delay
 /// ```
-pub fn retry_with_backoff<F, T, E>(
+pub fn retry_with_backoff<S, F, T, E>(mut attempts: u32, schedule: S, mut op: F) -> Result<T, E>
+where
+    S: IntoIterator<Item = Duration>,
+    F: FnMut() -> Result<T, E>,
+{
+    let mut delays = schedule.into_iter();
+    loop {
+        match op() {
+            Ok(v) => return Ok(v),
+            Err(e) => {
+                attempts = attempts.saturating_sub(1);
+                if attempts == 0 {
+                    return Err(e);
+                }
+                match delays.next() {
+                    Some(delay) => thread::sleep(delay),
+                    None => return Err(e),
+                }
+            }
+        }
+    }
+}
+
+/// [`retry_with_backoff`] bounded by a [`Budget`].
+///
+/// The first attempt always runs. After a failure, retrying stops (returning
+/// that error) when attempts or delays run out, or when the next delay would
+/// not fit in the remaining budget.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::retry_with_backoff_budget;
+/// use toolchest::time::{Budget, BackoffIter};
+/// use std::time::Duration;
+///
+/// let budget = Budget::new(Duration::from_millis(30));
+/// let mut calls = 0;
+/// let res: Result<(), &str> = retry_with_backoff_budget(
+///     &budget,
+///     100,
+///     BackoffIter::new(Duration::from_millis(4)),
+///     || { calls += 1; Err("busy") },
+/// );
+/// assert!(res.is_err());
+/// assert!(calls <= 4); // 4 + 8 + 16 ms fits, 32 ms does not
+/// ```
+pub fn retry_with_backoff_budget<S, F, T, E>(
+    budget: &Budget,
     mut attempts: u32,
-    base_delay: Duration,
+    schedule: S,
     mut op: F,
 ) -> Result<T, E>
 where
+    S: IntoIterator<Item = Duration>,
     F: FnMut() -> Result<T, E>,
 {
-    let mut delay = base_delay;
+    let mut delays = schedule.into_iter();
     loop {
         match op() {
             Ok(v) => return Ok(v),
@@ -58,8 +117,10 @@ where
                 if attempts == 0 {
                     return Err(e);
                 }
-                thread::sleep(delay);
-                delay = delay.saturating_mul(2);
+                match delays.next() {
+                    Some(delay) if budget.allows(delay) => thread::sleep(delay),
+                    _ => return Err(e),
+                }
             }
         }
     }
@@ -0,0 +1,115 @@
+//! Cooperative cancellation token.
+//!
+//! A [`CancellationToken`] is a cheap, cloneable flag shared between the code
+//! that requests cancellation and the code that should stop. Workers poll
+//! [`CancellationToken::is_cancelled`], sleep with
+//! [`CancellationToken::wait_timeout`], or select on
+//! [`CancellationToken::cancelled_channel`].
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::functions::CancellationToken;
+//! use std::time::Duration;
+//!
+//! let token = CancellationToken::new();
+//! let worker = {
+//!     let token = token.clone();
+//!     std::thread::spawn(move || {
+//!         let mut ticks = 0;
+//!         while !token.wait_timeout(Duration::from_millis(1)) {
+//!             ticks += 1;
+//!         }
+//!         ticks
+//!     })
+//! };
+//! std::thread::sleep(Duration::from_millis(10));
+//! token.cancel();
+//! assert!(worker.join().unwrap() > 0);
+//! ```
+
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct Inner {
+    // (cancelled, senders waiting for the cancellation signal)
+    state: Mutex<(bool, Vec<mpsc::Sender<()>>)>,
+    cv: Condvar,
+}
+
+/// Shared cancellation flag; clones observe the same state.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not yet cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; idempotent
+    pub fn cancel(&self) {
+        let mut state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.0 {
+            return;
+        }
+        state.0 = true;
+        for tx in state.1.drain(..) {
+            let _ = tx.send(());
+        }
+        self.inner.cv.notify_all();
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called on any clone
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner()).0
+    }
+
+    /// Receiver that gets one `()` when the token is cancelled
+    ///
+    /// If the token is already cancelled the message is available immediately.
+    pub fn cancelled_channel(&self) -> mpsc::Receiver<()> {
+        let (tx, rx) = mpsc::channel();
+        let mut state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.0 {
+            let _ = tx.send(());
+        } else {
+            state.1.push(tx);
+        }
+        rx
+    }
+
+    /// Block for up to `dur` or until cancelled; returns `true` if cancelled
+    pub fn wait_timeout(&self, dur: Duration) -> bool {
+        let deadline = Instant::now().checked_add(dur);
+        let mut state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+        while !state.0 {
+            let Some(deadline) = deadline else {
+                state = self.inner.cv.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            };
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            state = self
+                .inner
+                .cv
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
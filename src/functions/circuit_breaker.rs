@@ -24,6 +24,8 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use std::{error::Error, fmt};
 
+use crate::time::{system_clock, Clock};
+
 /// Circuit breaker state.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BreakerState {
@@ -45,6 +47,7 @@ pub struct CircuitBreaker {
     threshold: u32,
     open_until: Arc<Mutex<Option<Instant>>>,
     cooldown: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 /// Error returned by `CircuitBreaker::call`.
@@ -76,9 +79,31 @@ impl CircuitBreaker {
             threshold,
             open_until: Arc::new(Mutex::new(None)),
             cooldown,
+            clock: system_clock(),
         }
     }
 
+    /// Read time from `clock` instead of the system clock.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::{BreakerState, CircuitBreaker};
+    /// use toolchest::time::MockClock;
+    /// use std::time::Duration;
+    ///
+    /// let clock = MockClock::new();
+    /// let cb = CircuitBreaker::new(1, Duration::from_secs(30)).with_clock(clock.clone());
+    /// let _ = cb.call::<_, (), &str>(|| Err("boom"));
+    /// assert!(cb.call::<_, (), &str>(|| Ok(())).is_err());
+    /// clock.advance(Duration::from_secs(30));
+    /// assert!(cb.call::<_, (), &str>(|| Ok(())).is_ok());
+    /// assert_eq!(cb.state(), BreakerState::Closed);
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     /// Get current state.
     pub fn state(&self) -> BreakerState {
         *self.state.lock().unwrap()
@@ -133,7 +158,7 @@ impl CircuitBreaker {
         let mut state = self.state.lock().unwrap();
         if *state == BreakerState::Open {
             if let Some(until) = *self.open_until.lock().unwrap() {
-                if Instant::now() >= until {
+                if self.clock.now() >= until {
                     *state = BreakerState::HalfOpen;
                 }
             }
@@ -153,7 +178,7 @@ impl CircuitBreaker {
         *f += 1;
         if *f >= self.threshold {
             *self.state.lock().unwrap() = BreakerState::Open;
-            *self.open_until.lock().unwrap() = Some(Instant::now() + self.cooldown);
+            *self.open_until.lock().unwrap() = Some(self.clock.now() + self.cooldown);
         }
     }
 }
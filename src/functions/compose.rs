@@ -1,7 +1,8 @@
 //! Function composition helpers.
 //!
 //! Utilities for composing and transforming functions and values:
-//! - [`compose`] to build `g ∘ f`.
+//! - [`compose`] to build `g ∘ f`, and [`compose3`]..[`compose5`] for longer chains.
+//! - [`Pipeline`] to chain any number of steps left to right with `.then`.
 //! - [`pipe`] to pass a value through two functions.
 //! - [`tap`] to perform a side-effect without changing the value.
 //! - [`identity`] returns its input unchanged.
@@ -60,6 +61,136 @@ where
     move |a| g(f(a))
 }
 
+/// Compose three functions `h ∘ g ∘ f` (applies `f`, then `g`, then `h`).
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::compose::compose3;
+/// let f = compose3(|x: i32| x * 10, |x: i32| x - 1, |x: i32| x + 5);
+/// assert_eq!(f(1), 50);
+/// ```
+pub fn compose3<A, B, C, D, F, G, H>(h: H, g: G, f: F) -> impl Fn(A) -> D
+where
+    F: Fn(A) -> B,
+    G: Fn(B) -> C,
+    H: Fn(C) -> D,
+{
+    move |a| h(g(f(a)))
+}
+
+/// Compose four functions, applied right to left.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::compose::compose4;
+/// let f = compose4(|x: i32| x * 2, |x: i32| x - 1, |x: i32| x * 10, |x: i32| x + 5);
+/// assert_eq!(f(1), 118); // ((1 + 5) * 10 - 1) * 2
+/// ```
+pub fn compose4<A, B, C, D, E, F1, F2, F3, F4>(f4: F4, f3: F3, f2: F2, f1: F1) -> impl Fn(A) -> E
+where
+    F1: Fn(A) -> B,
+    F2: Fn(B) -> C,
+    F3: Fn(C) -> D,
+    F4: Fn(D) -> E,
+{
+    move |a| f4(f3(f2(f1(a))))
+}
+
+/// Compose five functions, applied right to left.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::compose::compose5;
+/// let f = compose5(
+///     |x: i32| x.to_string(),
+///     |x: i32| x * 2,
+///     |x: i32| x - 1,
+///     |x: i32| x * 10,
+///     |x: i32| x + 5,
+/// );
+/// assert_eq!(f(1), "118");
+/// ```
+pub fn compose5<A, B, C, D, E, R, F1, F2, F3, F4, F5>(
+    f5: F5,
+    f4: F4,
+    f3: F3,
+    f2: F2,
+    f1: F1,
+) -> impl Fn(A) -> R
+where
+    F1: Fn(A) -> B,
+    F2: Fn(B) -> C,
+    F3: Fn(C) -> D,
+    F4: Fn(D) -> E,
+    F5: Fn(E) -> R,
+{
+    move |a| f5(f4(f3(f2(f1(a)))))
+}
+
+/// A chain of transformations applied left to right.
+///
+/// Start with [`Pipeline::new`] (the identity) and append steps with
+/// [`Pipeline::then`]; each step may change the value's type. A built
+/// pipeline can be [`run`](Pipeline::run) any number of times.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::Pipeline;
+/// let p = Pipeline::new()
+///     .then(|s: &str| s.trim().to_string())
+///     .then(|s| s.len())
+///     .then(|n| n * 2);
+/// assert_eq!(p.run("  abc "), 6);
+/// assert_eq!(p.run("x"), 2);
+/// ```
+pub struct Pipeline<A, B = A> {
+    f: Box<dyn Fn(A) -> B>,
+}
+
+impl<A: 'static> Pipeline<A> {
+    /// Empty pipeline that returns its input unchanged
+    pub fn new() -> Self {
+        Self {
+            f: Box::new(identity),
+        }
+    }
+}
+
+impl<A: 'static> Default for Pipeline<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: 'static, B: 'static> Pipeline<A, B> {
+    /// Append a step that runs on the current output
+    pub fn then<C, F>(self, next: F) -> Pipeline<A, C>
+    where
+        F: Fn(B) -> C + 'static,
+    {
+        let prev = self.f;
+        Pipeline {
+            f: Box::new(move |a| next(prev(a))),
+        }
+    }
+
+    /// Run the pipeline on `input`
+    pub fn run(&self, input: A) -> B {
+        (self.f)(input)
+    }
+
+    /// Convert into a plain closure
+    pub fn into_fn(self) -> impl Fn(A) -> B {
+        move |a| (self.f)(a)
+    }
+}
+
+impl<A, B> std::fmt::Debug for Pipeline<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline").finish_non_exhaustive()
+    }
+}
+
 /// Pipe a value through `f` then `g`.
 pub fn pipe<A, B, C, F, G>(a: A, f: F, g: G) -> C
 where
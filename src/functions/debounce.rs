@@ -7,6 +7,8 @@
 //! This module exposes:
 //! - [`Debounced`] — a handle that schedules execution based on calls.
 //! - [`debounce`] — constructor producing a [`Debounced`] instance.
+//! - [`DebouncedArg`] / [`debounce_arg`] — the same for a `Fn(T)`, passing the
+//!   argument of the last call in each burst.
 //!
 //! Behavior:
 //! - Each `call` schedules execution at `now + delay` and cancels any previously
@@ -40,6 +42,8 @@ use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use crate::time::{system_clock, Clock};
+
 /// A debounced function wrapper.
 ///
 /// Maintains a deadline that is pushed forward on each `call`. When the
@@ -58,6 +62,7 @@ where
     shutdown: Arc<AtomicBool>,
     // Join handle storage so we can terminate cleanly on drop
     worker: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl<F> Debounced<F>
@@ -89,12 +94,24 @@ where
         {
             let (lock, cvar) = &*self.deadline;
             let mut dl = lock.lock().unwrap();
-            *dl = Some(Instant::now() + self.delay);
+            *dl = Some(self.clock.now() + self.delay);
             cvar.notify_one();
         }
 
         // Start worker once
         if !self.started.swap(true, Ordering::SeqCst) {
+            // Let a manually advanced clock interrupt the worker's wait
+            let weak = Arc::downgrade(&self.deadline);
+            self.clock
+                .on_advance(Box::new(move || match weak.upgrade() {
+                    Some(deadline) => {
+                        let _guard = deadline.0.lock().unwrap_or_else(|e| e.into_inner());
+                        deadline.1.notify_all();
+                        true
+                    }
+                    None => false,
+                }));
+            let clock = Arc::clone(&self.clock);
             let func = Arc::clone(&self.func);
             let deadline = Arc::clone(&self.deadline);
             let shutdown = Arc::clone(&self.shutdown);
@@ -111,7 +128,7 @@ where
                 }
                 // Wait until the current deadline elapses, but extend if updated
                 while let Some(target) = *dl {
-                    let now = Instant::now();
+                    let now = clock.now();
                     if now >= target {
                         break;
                     }
@@ -136,6 +153,33 @@ where
             *worker_holder.lock().unwrap() = Some(handle);
         }
     }
+
+    /// True if a call is waiting for its quiet period to elapse.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::debounce;
+    /// use std::time::Duration;
+    /// let d = debounce(|| {}, Duration::from_millis(10));
+    /// assert!(!d.pending());
+    /// d.call();
+    /// assert!(d.pending());
+    /// std::thread::sleep(Duration::from_millis(40));
+    /// assert!(!d.pending());
+    /// ```
+    pub fn pending(&self) -> bool {
+        self.deadline.0.lock().unwrap().is_some()
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    ///
+    /// With a [`MockClock`](crate::time::MockClock), advancing the clock past
+    /// the quiet period wakes the worker immediately; the function still runs
+    /// on the worker thread.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
 }
 
 impl<F> Debounced<F>
@@ -200,5 +244,77 @@ where
         started: Arc::new(AtomicBool::new(false)),
         shutdown: Arc::new(AtomicBool::new(false)),
         worker: Arc::new(Mutex::new(None)),
+        clock: system_clock(),
+    }
+}
+
+/// A debounced `Fn(T)` that receives the argument of the last call in a burst.
+///
+/// Created by [`debounce_arg`]; timing and threading behave as for
+/// [`Debounced`].
+pub struct DebouncedArg<T: Send + 'static> {
+    latest: Arc<Mutex<Option<T>>>,
+    inner: Debounced<Box<dyn Fn() + Send>>,
+}
+
+impl<T: Send + 'static> DebouncedArg<T> {
+    /// Record `arg` as the latest argument and restart the quiet period.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::debounce_arg;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// let saved = Arc::new(Mutex::new(Vec::new()));
+    /// let s = Arc::clone(&saved);
+    /// let autosave = debounce_arg(move |text: String| s.lock().unwrap().push(text), Duration::from_millis(10));
+    /// autosave.call("h".into());
+    /// autosave.call("hi".into());
+    /// std::thread::sleep(Duration::from_millis(50));
+    /// assert_eq!(*saved.lock().unwrap(), ["hi"]);
+    /// ```
+    pub fn call(&self, arg: T) {
+        *self.latest.lock().unwrap() = Some(arg);
+        self.inner.call();
+    }
+
+    /// True if a call is waiting for its quiet period to elapse.
+    pub fn pending(&self) -> bool {
+        self.inner.pending()
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        Self {
+            latest: self.latest,
+            inner: self.inner.with_clock(clock),
+        }
+    }
+
+    /// Stop the background worker, dropping any pending argument unused.
+    pub fn stop(self) {
+        std::mem::drop(self);
+    }
+}
+
+/// Create a debounced version of a one-argument function.
+///
+/// Each burst of calls runs `func` once, with the argument of the last call.
+pub fn debounce_arg<T, F>(func: F, delay: Duration) -> DebouncedArg<T>
+where
+    T: Send + 'static,
+    F: Fn(T) + Send + 'static,
+{
+    let latest = Arc::new(Mutex::new(None));
+    let slot = Arc::clone(&latest);
+    let fire: Box<dyn Fn() + Send> = Box::new(move || {
+        let arg = slot.lock().unwrap().take();
+        if let Some(arg) = arg {
+            func(arg);
+        }
+    });
+    DebouncedArg {
+        latest,
+        inner: debounce(fire, delay),
     }
 }
@@ -0,0 +1,195 @@
+//! Cached and lazily computed values.
+//!
+//! Complements [`once`](fn@crate::functions::once), which only runs unit
+//! side-effects, with cells that hold the computed value:
+//! - [`Lazy`] — computed by a closure on first access, then cached forever.
+//! - [`OnceValue`] — set at most once, readable afterwards.
+//! - [`Expiring`] — recomputed by a closure after a time-to-live elapses.
+//!
+//! All three are thread-safe and can be shared behind an `Arc` or placed in a
+//! `static` (for `Lazy` with a `fn` initializer and `OnceValue`).
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::functions::lazy::{Lazy, OnceValue, Expiring};
+//! use std::time::Duration;
+//!
+//! let config = Lazy::new(|| vec!["a", "b"]);
+//! assert_eq!(config.len(), 2); // computed here
+//!
+//! let port = OnceValue::new();
+//! assert!(port.set(8080).is_ok());
+//! assert_eq!(port.set(9090), Err(9090));
+//! assert_eq!(port.get(), Some(&8080));
+//!
+//! let token = Expiring::new(Duration::from_secs(60), || "fresh".to_string());
+//! assert_eq!(token.get(), "fresh");
+//! ```
+
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// A value computed on first access and cached afterwards.
+///
+/// The initializer runs at most once, even under concurrent access.
+pub struct Lazy<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: Mutex<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Create a lazy value with the given initializer.
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            init: Mutex::new(Some(init)),
+        }
+    }
+
+    /// Get the value, computing it on first call.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::lazy::Lazy;
+    /// static ANSWER: Lazy<u32> = Lazy::new(|| 6 * 7);
+    /// assert_eq!(*ANSWER.get(), 42);
+    /// ```
+    pub fn get(&self) -> &T {
+        self.cell.get_or_init(|| {
+            let f = self
+                .init
+                .lock()
+                .unwrap()
+                .take()
+                .expect("Lazy initializer already taken");
+            f()
+        })
+    }
+
+    /// True once the value has been computed.
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy")
+            .field("value", &self.cell.get())
+            .finish()
+    }
+}
+
+/// A cell that can be written once and read many times.
+#[derive(Debug, Default)]
+pub struct OnceValue<T> {
+    cell: OnceLock<T>,
+}
+
+impl<T> OnceValue<T> {
+    /// Create an empty cell.
+    pub const fn new() -> Self {
+        Self {
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// Store `value` if the cell is empty; otherwise hand it back as `Err`.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        self.cell.set(value)
+    }
+
+    /// The stored value, if any.
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    /// Get the stored value, initializing it with `f` if empty.
+    pub fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        self.cell.get_or_init(f)
+    }
+
+    /// True if a value has been stored.
+    pub fn is_set(&self) -> bool {
+        self.cell.get().is_some()
+    }
+
+    /// Consume the cell and return the stored value, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+/// A cached value that is recomputed once its time-to-live elapses.
+///
+/// [`get`](Expiring::get) returns a clone of the cached value, refreshing it
+/// first if it is missing or older than the TTL. Refreshes happen on the
+/// calling thread while holding the internal lock, so concurrent callers wait
+/// for a single recomputation.
+pub struct Expiring<T, F> {
+    ttl: Duration,
+    compute: F,
+    slot: Mutex<Option<(T, Instant)>>,
+}
+
+impl<T: Clone, F: Fn() -> T> Expiring<T, F> {
+    /// Create an expiring value recomputed by `compute` every `ttl`.
+    ///
+    /// Nothing is computed until the first [`get`](Expiring::get).
+    pub fn new(ttl: Duration, compute: F) -> Self {
+        Self {
+            ttl,
+            compute,
+            slot: Mutex::new(None),
+        }
+    }
+
+    /// Current value, recomputing it if expired.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::lazy::Expiring;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::time::Duration;
+    /// let calls = AtomicU32::new(0);
+    /// let v = Expiring::new(Duration::from_millis(5), || calls.fetch_add(1, Ordering::SeqCst));
+    /// assert_eq!(v.get(), 0);
+    /// assert_eq!(v.get(), 0); // cached
+    /// std::thread::sleep(Duration::from_millis(10));
+    /// assert_eq!(v.get(), 1); // recomputed
+    /// ```
+    pub fn get(&self) -> T {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.as_ref() {
+            Some((v, at)) if at.elapsed() < self.ttl => v.clone(),
+            _ => {
+                let v = (self.compute)();
+                *slot = Some((v.clone(), Instant::now()));
+                v
+            }
+        }
+    }
+
+    /// True if a cached value exists and has not expired.
+    pub fn is_fresh(&self) -> bool {
+        self.slot
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|(_, at)| at.elapsed() < self.ttl)
+    }
+
+    /// Drop the cached value so the next `get` recomputes it.
+    pub fn invalidate(&self) {
+        *self.slot.lock().unwrap() = None;
+    }
+}
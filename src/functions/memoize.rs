@@ -2,6 +2,8 @@
 //!
 //! Provides [`memoize`] to wrap a pure function so that repeated calls with the
 //! same argument return a cached result instead of recomputing.
+//! [`memoize2`]/[`memoize3`] cover multi-argument functions, and
+//! [`memoize_recursive`] caches the recursive calls of DP-style functions too.
 //!
 //! Notes and caveats:
 //! - Inputs must implement `Eq + Hash + Clone`; outputs must implement `Clone`.
@@ -45,3 +47,75 @@ where
         res
     }
 }
+
+/// Memoize a two-argument function, keyed by the argument pair.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::memoize2;
+/// let pow = memoize2(|base: u64, exp: u32| base.pow(exp));
+/// assert_eq!(pow(2, 10), 1024);
+/// assert_eq!(pow(2, 10), 1024); // cached
+/// ```
+pub fn memoize2<A, B, R, F>(func: F) -> impl Fn(A, B) -> R
+where
+    A: Eq + Hash + Clone + 'static,
+    B: Eq + Hash + Clone + 'static,
+    R: Clone + 'static,
+    F: Fn(A, B) -> R + 'static,
+{
+    let cached = memoize(move |(a, b): (A, B)| func(a, b));
+    move |a, b| cached((a, b))
+}
+
+/// Memoize a three-argument function, keyed by the argument triple.
+pub fn memoize3<A, B, C, R, F>(func: F) -> impl Fn(A, B, C) -> R
+where
+    A: Eq + Hash + Clone + 'static,
+    B: Eq + Hash + Clone + 'static,
+    C: Eq + Hash + Clone + 'static,
+    R: Clone + 'static,
+    F: Fn(A, B, C) -> R + 'static,
+{
+    let cached = memoize(move |(a, b, c): (A, B, C)| func(a, b, c));
+    move |a, b, c| cached((a, b, c))
+}
+
+fn call_recursive<A, R, F>(func: &F, cache: &Mutex<HashMap<A, R>>, arg: A) -> R
+where
+    A: Eq + Hash + Clone,
+    R: Clone,
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    if let Some(v) = cache.lock().unwrap().get(&arg).cloned() {
+        return v;
+    }
+    // The lock is released while recursing so nested calls can use the cache
+    let rec = |a: A| call_recursive(func, cache, a);
+    let res = func(&rec, arg.clone());
+    cache.lock().unwrap().insert(arg, res.clone());
+    res
+}
+
+/// Memoize a recursive function.
+///
+/// `func` receives a handle to the memoized function as its first argument;
+/// calling it for sub-problems goes through the shared cache.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::memoize_recursive;
+/// let fib = memoize_recursive(|fib, n: u64| -> u64 {
+///     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+/// });
+/// assert_eq!(fib(90), 2_880_067_194_370_816_120);
+/// ```
+pub fn memoize_recursive<A, R, F>(func: F) -> impl Fn(A) -> R
+where
+    A: Eq + Hash + Clone + 'static,
+    R: Clone + 'static,
+    F: Fn(&dyn Fn(A) -> R, A) -> R + 'static,
+{
+    let cache: Arc<Mutex<HashMap<A, R>>> = Arc::new(Mutex::new(HashMap::new()));
+    move |arg: A| call_recursive(&func, &cache, arg)
+}
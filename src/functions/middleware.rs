@@ -0,0 +1,132 @@
+//! Aspect-style wrappers that instrument a function without changing it.
+//!
+//! Each combinator takes a single-argument function (use a tuple for more
+//! arguments, as with [`compose`](fn@super::compose)) and returns a new closure
+//! with the same signature. The [`Wrap`] extension trait offers the same
+//! wrappers as methods so they can be chained.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::functions::middleware::Wrap;
+//! use std::sync::Mutex;
+//!
+//! let log = Mutex::new(Vec::new());
+//! let square = (|x: i32| x * x).with_logging(|line| log.lock().unwrap().push(line));
+//! assert_eq!(square(4), 16);
+//! assert_eq!(log.lock().unwrap().as_slice(), ["4 -> 16"]);
+//! ```
+
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+/// Wrap `f` so each call reports `"{arg:?} -> {result:?}"` to `logger`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::with_logging;
+/// let lines = std::cell::RefCell::new(Vec::new());
+/// let upper = with_logging(|s: &str| s.to_uppercase(), |l| lines.borrow_mut().push(l));
+/// assert_eq!(upper("hi"), "HI");
+/// assert_eq!(lines.borrow()[0], r#""hi" -> "HI""#);
+/// ```
+pub fn with_logging<A, R, F, L>(f: F, logger: L) -> impl Fn(A) -> R
+where
+    A: Debug,
+    R: Debug,
+    F: Fn(A) -> R,
+    L: Fn(String),
+{
+    move |a| {
+        let arg = format!("{a:?}");
+        let result = f(a);
+        logger(format!("{arg} -> {result:?}"));
+        result
+    }
+}
+
+/// Wrap `f` so the elapsed time of each call is passed to `sink`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::with_timing;
+/// use std::time::Duration;
+/// let total = std::cell::Cell::new(Duration::ZERO);
+/// let slow = with_timing(|ms: u64| std::thread::sleep(Duration::from_millis(ms)), |d| {
+///     total.set(total.get() + d)
+/// });
+/// slow(5);
+/// assert!(total.get() >= Duration::from_millis(5));
+/// ```
+pub fn with_timing<A, R, F, S>(f: F, sink: S) -> impl Fn(A) -> R
+where
+    F: Fn(A) -> R,
+    S: Fn(Duration),
+{
+    move |a| {
+        let start = Instant::now();
+        let result = f(a);
+        sink(start.elapsed());
+        result
+    }
+}
+
+/// Wrap `f` with hooks that see the argument before and the result after each call.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::with_before_after;
+/// use std::cell::RefCell;
+/// let events = RefCell::new(Vec::new());
+/// let inc = with_before_after(
+///     |x: i32| x + 1,
+///     |x: &i32| events.borrow_mut().push(format!("in {x}")),
+///     |r: &i32| events.borrow_mut().push(format!("out {r}")),
+/// );
+/// assert_eq!(inc(1), 2);
+/// assert_eq!(*events.borrow(), ["in 1", "out 2"]);
+/// ```
+pub fn with_before_after<A, R, F, B, Af>(f: F, before: B, after: Af) -> impl Fn(A) -> R
+where
+    F: Fn(A) -> R,
+    B: Fn(&A),
+    Af: Fn(&R),
+{
+    move |a| {
+        before(&a);
+        let result = f(a);
+        after(&result);
+        result
+    }
+}
+
+/// Method-style access to the wrappers in this module for any `Fn(A) -> R`.
+pub trait Wrap<A, R>: Fn(A) -> R + Sized {
+    /// See [`with_logging`]
+    fn with_logging<L>(self, logger: L) -> impl Fn(A) -> R
+    where
+        A: Debug,
+        R: Debug,
+        L: Fn(String),
+    {
+        with_logging(self, logger)
+    }
+
+    /// See [`with_timing`]
+    fn with_timing<S>(self, sink: S) -> impl Fn(A) -> R
+    where
+        S: Fn(Duration),
+    {
+        with_timing(self, sink)
+    }
+
+    /// See [`with_before_after`]
+    fn with_before_after<B, Af>(self, before: B, after: Af) -> impl Fn(A) -> R
+    where
+        B: Fn(&A),
+        Af: Fn(&R),
+    {
+        with_before_after(self, before, after)
+    }
+}
+
+impl<A, R, F: Fn(A) -> R> Wrap<A, R> for F {}
@@ -1,23 +1,35 @@
 //! Function combinators module
 
 pub mod backoff;
+pub mod cancel;
 pub mod circuit_breaker;
 pub mod debounce;
+pub mod lazy;
 pub mod memoize;
+pub mod middleware;
 pub mod once;
 pub mod rate_limiter;
+pub mod resilience;
 pub mod retry;
+pub mod supervisor;
 pub mod throttle;
 pub mod timeout;
+pub mod worker_pool;
 
-pub use backoff::retry_with_backoff;
+pub use backoff::{retry_with_backoff, retry_with_backoff_budget};
+pub use cancel::CancellationToken;
 pub use circuit_breaker::{BreakerState, CircuitBreaker, CircuitBreakerError};
-pub use compose::{compose, pipe, tap};
-pub use debounce::{debounce, Debounced};
-pub use memoize::memoize;
-pub use once::once;
-pub use rate_limiter::RateLimiter;
-pub use retry::retry;
+pub use compose::{compose, pipe, tap, Pipeline};
+pub use debounce::{debounce, debounce_arg, Debounced, DebouncedArg};
+pub use lazy::{Expiring, Lazy, OnceValue};
+pub use memoize::{memoize, memoize2, memoize3, memoize_recursive};
+pub use middleware::{with_before_after, with_logging, with_timing, Wrap};
+pub use once::{once, once_value, CallCounter, OnceFn};
+pub use rate_limiter::{KeyedRateLimiter, RateLimiter};
+pub use resilience::{Resilience, ResilienceError};
+pub use retry::{retry, retry_with_budget};
+pub use supervisor::{supervise, RestartPolicy, SupervisorHandle, SupervisorStatus};
 pub use throttle::{throttle, Throttled};
-pub use timeout::with_timeout;
+pub use timeout::{with_timeout, with_timeout_cancellable};
+pub use worker_pool::{JobError, JobHandle, WorkerPool};
 pub mod compose;
@@ -1,7 +1,9 @@
 //! Once wrapper: ensure a function runs at most once.
 //!
 //! Provides [`once`] which wraps a `FnOnce` so that it is executed at most one
-//! time, even if the returned closure is called multiple times.
+//! time, even if the returned closure is called multiple times, and
+//! [`once_value`] which also keeps the result and returns it on every call.
+//! [`CallCounter`] counts how often wrapped closures run, for tests.
 //!
 //! Basic example:
 //! ```rust
@@ -17,7 +19,9 @@
 //! assert_eq!(counter.load(Ordering::SeqCst), 1);
 //! ```
 
-use std::sync::{Arc, Once as StdOnce};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Once as StdOnce, OnceLock};
 
 /// Wrap a function so it can run at most once.
 ///
@@ -38,3 +42,145 @@ where
         });
     }
 }
+
+/// A function that runs at most once and remembers its result.
+///
+/// Created by [`once_value`]. Thread-safe: concurrent first calls block until
+/// the single run completes.
+pub struct OnceFn<T, F> {
+    cell: OnceLock<T>,
+    func: Mutex<Option<F>>,
+}
+
+/// Wrap `f` so it runs on the first [`call`](OnceFn::call) only; every call
+/// returns the cached result.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::once_value;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// let runs = AtomicUsize::new(0);
+/// let init = once_value(|| {
+///     runs.fetch_add(1, Ordering::SeqCst);
+///     "ready".to_string()
+/// });
+/// assert!(!init.has_run());
+/// assert_eq!(init.call(), "ready");
+/// assert_eq!(init.call(), "ready");
+/// assert!(init.has_run());
+/// assert_eq!(runs.load(Ordering::SeqCst), 1);
+/// ```
+pub fn once_value<T, F>(f: F) -> OnceFn<T, F>
+where
+    F: FnOnce() -> T,
+{
+    OnceFn {
+        cell: OnceLock::new(),
+        func: Mutex::new(Some(f)),
+    }
+}
+
+impl<T, F: FnOnce() -> T> OnceFn<T, F> {
+    /// Run the function if it has not run yet, then return the cached result
+    pub fn call(&self) -> &T {
+        self.cell.get_or_init(|| {
+            let f = self
+                .func
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take()
+                .expect("OnceFn function already taken");
+            f()
+        })
+    }
+
+    /// The cached result, without running the function
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    /// Whether the function has completed
+    pub fn has_run(&self) -> bool {
+        self.cell.get().is_some()
+    }
+
+    /// Consume the wrapper and return the result, if it ran
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for OnceFn<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnceFn")
+            .field("value", &self.cell.get())
+            .finish()
+    }
+}
+
+/// Shared counter of how many times wrapped closures were called.
+///
+/// Clones share the same count, so keep one handle for assertions and move
+/// wrapped closures into the code under test. Multi-argument functions can be
+/// wrapped by taking a tuple.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::CallCounter;
+/// let counter = CallCounter::new();
+/// let double = counter.wrap(|x: i32| x * 2);
+/// let hello = counter.wrap0(|| "hi");
+/// assert_eq!(double(4), 8);
+/// assert_eq!(hello(), "hi");
+/// assert_eq!(counter.times_called(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CallCounter {
+    count: Arc<AtomicUsize>,
+}
+
+impl CallCounter {
+    /// Create a counter at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a one-argument function so each call is counted
+    pub fn wrap<A, R, F>(&self, f: F) -> impl Fn(A) -> R
+    where
+        F: Fn(A) -> R,
+    {
+        let count = Arc::clone(&self.count);
+        move |a| {
+            count.fetch_add(1, Ordering::SeqCst);
+            f(a)
+        }
+    }
+
+    /// Wrap a zero-argument function so each call is counted
+    pub fn wrap0<R, F>(&self, f: F) -> impl Fn() -> R
+    where
+        F: Fn() -> R,
+    {
+        let count = Arc::clone(&self.count);
+        move || {
+            count.fetch_add(1, Ordering::SeqCst);
+            f()
+        }
+    }
+
+    /// Record a call manually
+    pub fn record(&self) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Number of calls so far
+    pub fn times_called(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Reset the count to zero
+    pub fn reset(&self) {
+        self.count.store(0, Ordering::SeqCst);
+    }
+}
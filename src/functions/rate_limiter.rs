@@ -56,9 +56,25 @@
 //! // At most 1 immediate hit (capacity 1). After a short wait, more would be allowed.
 //! assert!(hits.load(Ordering::SeqCst) <= 1);
 //! ```
+//!
+//! Per-client limits with [`KeyedRateLimiter`]:
+//!
+//! ```rust
+//! use toolchest::functions::KeyedRateLimiter;
+//!
+//! let limiter = KeyedRateLimiter::new(1, 1).with_max_keys(10_000);
+//! assert!(limiter.allow(&"10.0.0.1"));
+//! assert!(!limiter.allow(&"10.0.0.1"));
+//! assert!(limiter.allow(&"10.0.0.2")); // independent bucket
+//! ```
 
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+use crate::time::{system_clock, Clock};
 
 /// Token-bucket rate limiter.
 ///
@@ -72,6 +88,7 @@ pub struct RateLimiter {
     tokens: Arc<Mutex<f64>>, // allow fractional refill
     refill_per_sec: f64,
     last_refill: Arc<Mutex<Instant>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl RateLimiter {
@@ -95,16 +112,26 @@ impl RateLimiter {
             tokens: Arc::new(Mutex::new(capacity as f64)),
             refill_per_sec: refill_per_second as f64,
             last_refill: Arc::new(Mutex::new(Instant::now())),
+            clock: system_clock(),
         }
     }
 
+    /// Read time from `clock` instead of the system clock (e.g. a
+    /// [`MockClock`](crate::time::MockClock) in tests).
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        *self.last_refill.lock().unwrap() = self.clock.now();
+        self
+    }
+
     fn refill(&self) {
         let mut last = self.last_refill.lock().unwrap();
-        let elapsed = last.elapsed().as_secs_f64();
+        let now = self.clock.now();
+        let elapsed = now.saturating_duration_since(*last).as_secs_f64();
         if elapsed > 0.0 {
             let mut tk = self.tokens.lock().unwrap();
             *tk = (*tk + elapsed * self.refill_per_sec).min(self.capacity as f64);
-            *last = Instant::now();
+            *last = now;
         }
     }
 
@@ -139,3 +166,161 @@ impl RateLimiter {
         }
     }
 }
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token-bucket rate limiter with an independent bucket per key.
+///
+/// Each key (user id, IP address, API category, ...) gets its own bucket with
+/// the same `capacity` and refill rate. Buckets idle for longer than the idle
+/// timeout are evicted: [`allow`](Self::allow) sweeps them out every few
+/// hundred calls, so memory tracks the number of recently active keys. The
+/// number of tracked keys can also be capped with `max_keys`; when full, idle
+/// buckets are dropped first and then the least recently used one. An
+/// evicted key simply starts again with a full bucket.
+pub struct KeyedRateLimiter<K> {
+    capacity: u32,
+    refill_per_sec: f64,
+    idle_timeout: Duration,
+    max_keys: usize,
+    buckets: Mutex<HashMap<K, Bucket>>,
+    calls: AtomicUsize,
+    clock: Arc<dyn Clock>,
+}
+
+// How many `allow` calls pass between idle sweeps
+const SWEEP_EVERY: usize = 256;
+
+impl<K: Eq + Hash + Clone> KeyedRateLimiter<K> {
+    /// Create a keyed limiter; every bucket starts full at `capacity`.
+    ///
+    /// The idle timeout defaults to the time a bucket needs to refill
+    /// completely (after which it is indistinguishable from a new one), and
+    /// the key count is only limited by those idle sweeps.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use toolchest::functions::KeyedRateLimiter;
+    /// let limiter = KeyedRateLimiter::new(5, 10);
+    /// assert!(limiter.allow(&"alice"));
+    /// assert_eq!(limiter.len(), 1);
+    /// ```
+    pub fn new(capacity: u32, refill_per_second: u32) -> Self {
+        let idle_timeout = if refill_per_second == 0 {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(capacity as f64 / refill_per_second as f64)
+        };
+        Self {
+            capacity,
+            refill_per_sec: refill_per_second as f64,
+            idle_timeout,
+            max_keys: usize::MAX,
+            buckets: Mutex::new(HashMap::new()),
+            calls: AtomicUsize::new(0),
+            clock: system_clock(),
+        }
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Evict buckets that have not been used for `timeout`.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Track at most `max_keys` buckets (minimum 1).
+    pub fn with_max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = max_keys.max(1);
+        self
+    }
+
+    /// Attempt to consume a single token from `key`'s bucket.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use toolchest::functions::KeyedRateLimiter;
+    /// let limiter = KeyedRateLimiter::new(2, 1);
+    /// assert!(limiter.allow(&1));
+    /// assert!(limiter.allow(&1));
+    /// assert!(!limiter.allow(&1));
+    /// assert!(limiter.allow(&2));
+    /// ```
+    pub fn allow(&self, key: &K) -> bool {
+        let now = self.clock.now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if self.calls.fetch_add(1, Ordering::Relaxed) % SWEEP_EVERY == SWEEP_EVERY - 1 {
+            self.evict_idle_locked(&mut buckets, now);
+        }
+        if !buckets.contains_key(key) {
+            if buckets.len() >= self.max_keys {
+                self.evict_idle_locked(&mut buckets, now);
+            }
+            if buckets.len() >= self.max_keys {
+                let oldest = buckets
+                    .iter()
+                    .min_by_key(|(_, b)| b.last_refill)
+                    .map(|(k, _)| k.clone());
+                if let Some(oldest) = oldest {
+                    buckets.remove(&oldest);
+                }
+            }
+            buckets.insert(
+                key.clone(),
+                Bucket {
+                    tokens: self.capacity as f64,
+                    last_refill: now,
+                },
+            );
+        }
+        let bucket = buckets.get_mut(key).expect("bucket inserted above");
+        let elapsed = now
+            .saturating_duration_since(bucket.last_refill)
+            .as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity as f64);
+        bucket.last_refill = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Drop buckets idle for longer than the idle timeout; returns how many.
+    pub fn evict_idle(&self) -> usize {
+        let mut buckets = self.buckets.lock().unwrap();
+        self.evict_idle_locked(&mut buckets, self.clock.now())
+    }
+
+    fn evict_idle_locked(&self, buckets: &mut HashMap<K, Bucket>, now: Instant) -> usize {
+        let before = buckets.len();
+        buckets.retain(|_, b| now.saturating_duration_since(b.last_refill) < self.idle_timeout);
+        before - buckets.len()
+    }
+
+    /// Forget `key`, giving it a full bucket on its next request.
+    pub fn reset(&self, key: &K) {
+        self.buckets.lock().unwrap().remove(key);
+    }
+
+    /// Number of keys currently tracked.
+    pub fn len(&self) -> usize {
+        self.buckets.lock().unwrap().len()
+    }
+
+    /// True when no keys are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,224 @@
+//! Retry, circuit breaking, rate limiting, and timeouts composed in one place.
+//!
+//! [`Resilience`] wraps an operation in the four primitives in a fixed order,
+//! from outermost to innermost:
+//!
+//! 1. retry (with a delay schedule) around each attempt
+//! 2. [`RateLimiter`] check
+//! 3. [`CircuitBreaker`] guard
+//! 4. per-attempt timeout (see [`with_timeout`])
+//!
+//! Only operation errors and timeouts are retried; both count as failures for
+//! the breaker. Rate-limit and open-circuit rejections are returned at once.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::functions::{Resilience, ResilienceError};
+//! use toolchest::time::BackoffIter;
+//! use std::sync::atomic::{AtomicU32, Ordering};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let policy = Resilience::new()
+//!     .with_retry(3, BackoffIter::new(Duration::from_millis(1)))
+//!     .with_timeout(Duration::from_secs(1));
+//!
+//! let tries = Arc::new(AtomicU32::new(0));
+//! let t = tries.clone();
+//! let res: Result<u32, ResilienceError<&str>> = policy.call(move || {
+//!     let n = t.fetch_add(1, Ordering::SeqCst) + 1;
+//!     if n < 3 { Err("flaky") } else { Ok(n) }
+//! });
+//! assert_eq!(res.unwrap(), 3);
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerError};
+use super::rate_limiter::RateLimiter;
+use super::timeout::with_timeout;
+
+type Schedule = Box<dyn Fn() -> Box<dyn Iterator<Item = Duration>> + Send + Sync>;
+
+/// Error returned by [`Resilience::call`], naming the layer that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResilienceError<E> {
+    /// The rate limiter had no token available
+    RateLimited,
+    /// The circuit breaker is open
+    CircuitOpen,
+    /// The last attempt exceeded the per-attempt timeout
+    Timeout,
+    /// The last attempt returned an error
+    Operation(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ResilienceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResilienceError::RateLimited => write!(f, "rate limited"),
+            ResilienceError::CircuitOpen => write!(f, "circuit open"),
+            ResilienceError::Timeout => write!(f, "timed out"),
+            ResilienceError::Operation(e) => write!(f, "operation error: {e}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for ResilienceError<E> {}
+
+/// Builder composing retry, circuit breaker, rate limiter, and timeout.
+///
+/// Every layer is optional; a fresh `Resilience` simply calls the operation
+/// once. The breaker and limiter are owned by the policy, so share one policy
+/// (e.g. behind an `Arc`) between callers that should share their state.
+pub struct Resilience {
+    attempts: u32,
+    schedule: Option<Schedule>,
+    breaker: Option<CircuitBreaker>,
+    limiter: Option<RateLimiter>,
+    timeout: Option<Duration>,
+}
+
+impl Default for Resilience {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Resilience {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resilience")
+            .field("attempts", &self.attempts)
+            .field("circuit_breaker", &self.breaker.as_ref().map(|b| b.state()))
+            .field("rate_limiter", &self.limiter.is_some())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl Resilience {
+    /// Policy with no layers: one attempt, no limits
+    pub fn new() -> Self {
+        Self {
+            attempts: 1,
+            schedule: None,
+            breaker: None,
+            limiter: None,
+            timeout: None,
+        }
+    }
+
+    /// Retry up to `attempts` times, sleeping for the next delay of
+    /// `schedule` between attempts (as in
+    /// [`retry_with_backoff`](super::retry_with_backoff)). The schedule is
+    /// cloned for every call.
+    pub fn with_retry<S>(mut self, attempts: u32, schedule: S) -> Self
+    where
+        S: IntoIterator<Item = Duration> + Clone + Send + Sync + 'static,
+        S::IntoIter: 'static,
+    {
+        self.attempts = attempts.max(1);
+        self.schedule = Some(Box::new(move || Box::new(schedule.clone().into_iter())));
+        self
+    }
+
+    /// Guard attempts with `breaker`
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    /// Require a token from `limiter` before each attempt
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Abandon an attempt after `timeout`; the attempt runs on a worker
+    /// thread that is left to finish in the background
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The circuit breaker, if configured
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.breaker.as_ref()
+    }
+
+    /// Run `op` through all configured layers.
+    ///
+    /// Example with a tripped breaker:
+    /// ```rust
+    /// use toolchest::functions::{CircuitBreaker, Resilience, ResilienceError};
+    /// use std::time::Duration;
+    ///
+    /// let policy = Resilience::new()
+    ///     .with_retry(5, vec![Duration::ZERO; 4])
+    ///     .with_circuit_breaker(CircuitBreaker::new(2, Duration::from_secs(60)));
+    /// let res: Result<(), _> = policy.call(|| Err("down"));
+    /// assert_eq!(res, Err(ResilienceError::CircuitOpen));
+    /// ```
+    pub fn call<F, T, E>(&self, op: F) -> Result<T, ResilienceError<E>>
+    where
+        F: Fn() -> Result<T, E> + Send + Sync + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let op = Arc::new(op);
+        let mut delays = self.schedule.as_ref().map(|s| s());
+        let mut attempts = self.attempts;
+        loop {
+            let err = match self.attempt(&op) {
+                Ok(v) => return Ok(v),
+                Err(e @ (ResilienceError::RateLimited | ResilienceError::CircuitOpen)) => {
+                    return Err(e)
+                }
+                Err(e) => e,
+            };
+            attempts -= 1;
+            if attempts == 0 {
+                return Err(err);
+            }
+            match delays.as_mut().and_then(|d| d.next()) {
+                Some(delay) => thread::sleep(delay),
+                None => return Err(err),
+            }
+        }
+    }
+
+    fn attempt<F, T, E>(&self, op: &Arc<F>) -> Result<T, ResilienceError<E>>
+    where
+        F: Fn() -> Result<T, E> + Send + Sync + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        if let Some(limiter) = &self.limiter {
+            if !limiter.allow() {
+                return Err(ResilienceError::RateLimited);
+            }
+        }
+        let run = || match self.timeout {
+            Some(dur) => {
+                let op = Arc::clone(op);
+                match with_timeout(dur, move || op()) {
+                    Some(Ok(v)) => Ok(v),
+                    Some(Err(e)) => Err(ResilienceError::Operation(e)),
+                    None => Err(ResilienceError::Timeout),
+                }
+            }
+            None => op().map_err(ResilienceError::Operation),
+        };
+        match &self.breaker {
+            Some(breaker) => breaker.call(run).map_err(|e| match e {
+                CircuitBreakerError::Open => ResilienceError::CircuitOpen,
+                CircuitBreakerError::OperationError(inner) => inner,
+            }),
+            None => run(),
+        }
+    }
+}
@@ -21,6 +21,8 @@
 use std::thread;
 use std::time::Duration;
 
+use crate::time::Budget;
+
 /// Retry an operation up to `attempts` with optional fixed delay.
 ///
 /// - `attempts`: maximum number of tries (must be ≥ 1)
@@ -55,3 +57,50 @@ where
         }
     }
 }
+
+/// [`retry`] bounded by a [`Budget`].
+///
+/// The first attempt always runs. After a failure, retrying stops (returning
+/// that error) once the budget is exhausted or when the delay would not fit in
+/// the remaining time.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::retry_with_budget;
+/// use toolchest::time::Budget;
+/// use std::time::Duration;
+///
+/// let budget = Budget::new(Duration::from_millis(20));
+/// let mut calls = 0;
+/// let res: Result<(), &str> = retry_with_budget(&budget, 1000, Some(Duration::from_millis(5)), || {
+///     calls += 1;
+///     Err("down")
+/// });
+/// assert_eq!(res.unwrap_err(), "down");
+/// assert!(calls < 1000);
+/// ```
+pub fn retry_with_budget<F, T, E>(
+    budget: &Budget,
+    mut attempts: u32,
+    delay: Option<Duration>,
+    mut op: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    loop {
+        match op() {
+            Ok(v) => return Ok(v),
+            Err(e) => {
+                attempts = attempts.saturating_sub(1);
+                let wait = delay.unwrap_or_default();
+                if attempts == 0 || !budget.allows(wait) {
+                    return Err(e);
+                }
+                if !wait.is_zero() {
+                    thread::sleep(wait);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Supervised long-running closures.
+//!
+//! [`supervise`] runs a closure on a background thread and restarts it when
+//! it panics or returns `Err`, sleeping between restarts according to a
+//! [`RestartPolicy`]. It is the daemon-style counterpart to
+//! [`retry_with_backoff`](super::retry_with_backoff): instead of producing a
+//! value, the closure is expected to run until told to stop.
+//!
+//! Notes:
+//! - The closure receives a [`CancellationToken`]; long-running loops should
+//!   poll it so [`SupervisorHandle::stop`] can return promptly.
+//! - Returning `Ok(())` means the work is done; the supervisor does not restart it.
+//! - Backoff sleeps are interrupted immediately by `stop`.
+//! - Panics are caught, but the panic hook still runs (and prints by default).
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::functions::{supervise, RestartPolicy, SupervisorStatus};
+//! use toolchest::time::BackoffIter;
+//! use std::time::Duration;
+//!
+//! let mut runs = 0;
+//! let policy = RestartPolicy::new(BackoffIter::new(Duration::from_millis(1)));
+//! let handle = supervise(
+//!     move |_token| {
+//!         runs += 1;
+//!         if runs < 3 { Err("flaky") } else { Ok(()) }
+//!     },
+//!     policy,
+//! );
+//! assert_eq!(handle.join(), SupervisorStatus::Finished);
+//! ```
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::CancellationToken;
+use crate::time::BackoffIter;
+
+/// When and how often [`supervise`] restarts a failed closure.
+#[derive(Clone, Debug)]
+pub struct RestartPolicy {
+    /// Maximum number of restarts before giving up (`None` = unlimited).
+    pub max_restarts: Option<u32>,
+    /// Delays slept before each restart; running out of delays also gives up.
+    pub backoff: BackoffIter,
+}
+
+impl RestartPolicy {
+    /// Unlimited restarts with delays taken from `backoff`.
+    pub fn new(backoff: BackoffIter) -> Self {
+        Self {
+            max_restarts: None,
+            backoff,
+        }
+    }
+
+    /// Give up after `n` restarts.
+    pub fn with_max_restarts(mut self, n: u32) -> Self {
+        self.max_restarts = Some(n);
+        self
+    }
+}
+
+impl Default for RestartPolicy {
+    /// Unlimited restarts, backing off from 100ms up to 30s.
+    fn default() -> Self {
+        Self::new(BackoffIter::new(Duration::from_millis(100)).with_max(Duration::from_secs(30)))
+    }
+}
+
+/// Lifecycle state of a supervised closure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupervisorStatus {
+    /// The closure is currently executing.
+    Running,
+    /// The closure failed and the supervisor is waiting to restart it.
+    Restarting,
+    /// The closure returned `Ok(())` without being asked to stop.
+    Finished,
+    /// The closure failed and the restart policy is exhausted.
+    GaveUp,
+    /// [`SupervisorHandle::stop`] was called (or the handle was dropped).
+    Stopped,
+}
+
+impl SupervisorStatus {
+    /// Whether the supervisor thread has exited.
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Finished | Self::GaveUp | Self::Stopped)
+    }
+}
+
+impl fmt::Display for SupervisorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Running => "running",
+            Self::Restarting => "restarting",
+            Self::Finished => "finished",
+            Self::GaveUp => "gave up",
+            Self::Stopped => "stopped",
+        };
+        f.write_str(s)
+    }
+}
+
+struct State {
+    status: SupervisorStatus,
+    restarts: u32,
+    last_error: Option<String>,
+}
+
+/// Handle to a supervised closure returned by [`supervise`].
+///
+/// Dropping the handle requests a stop without waiting for the thread.
+pub struct SupervisorHandle {
+    token: CancellationToken,
+    state: Arc<Mutex<State>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SupervisorHandle {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Current lifecycle state.
+    pub fn status(&self) -> SupervisorStatus {
+        self.lock().status
+    }
+
+    /// Number of times the closure has been restarted so far.
+    pub fn restarts(&self) -> u32 {
+        self.lock().restarts
+    }
+
+    /// Description of the most recent failure (`Err` debug output or panic message).
+    pub fn last_error(&self) -> Option<String> {
+        self.lock().last_error.clone()
+    }
+
+    /// Cancel the token, interrupt any backoff, and wait for the thread to exit.
+    ///
+    /// Returns the final status; idempotent.
+    pub fn stop(&mut self) -> SupervisorStatus {
+        self.token.cancel();
+        if let Some(t) = self.thread.take() {
+            let _ = t.join();
+        }
+        self.status()
+    }
+
+    /// Wait for the supervisor to finish on its own and return the final status.
+    pub fn join(mut self) -> SupervisorStatus {
+        if let Some(t) = self.thread.take() {
+            let _ = t.join();
+        }
+        self.status()
+    }
+}
+
+impl fmt::Debug for SupervisorHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("SupervisorHandle")
+            .field("status", &state.status)
+            .field("restarts", &state.restarts)
+            .field("last_error", &state.last_error)
+            .finish()
+    }
+}
+
+impl Drop for SupervisorHandle {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+// Message of a `panic!` payload, if it carried one
+pub(crate) fn panic_text(payload: &(dyn Any + Send)) -> Option<&str> {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        Some(s)
+    } else {
+        payload.downcast_ref::<String>().map(String::as_str)
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match panic_text(&*payload) {
+        Some(s) => format!("panicked: {s}"),
+        None => "panicked".to_string(),
+    }
+}
+
+/// Run `f` on a background thread, restarting it on panic or `Err`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::{supervise, RestartPolicy, SupervisorStatus};
+/// use toolchest::time::BackoffIter;
+/// use std::time::Duration;
+///
+/// let policy = RestartPolicy::new(BackoffIter::new(Duration::from_millis(1))).with_max_restarts(2);
+/// let handle = supervise(|_token| Err::<(), _>("boom"), policy);
+/// assert_eq!(handle.join(), SupervisorStatus::GaveUp);
+///
+/// let mut daemon = supervise(
+///     |token| {
+///         while !token.wait_timeout(Duration::from_millis(5)) {}
+///         Ok::<(), ()>(())
+///     },
+///     RestartPolicy::default(),
+/// );
+/// assert_eq!(daemon.stop(), SupervisorStatus::Stopped);
+/// ```
+pub fn supervise<F, E>(mut f: F, policy: RestartPolicy) -> SupervisorHandle
+where
+    F: FnMut(&CancellationToken) -> Result<(), E> + Send + 'static,
+    E: fmt::Debug,
+{
+    let token = CancellationToken::new();
+    let state = Arc::new(Mutex::new(State {
+        status: SupervisorStatus::Running,
+        restarts: 0,
+        last_error: None,
+    }));
+    let thread = {
+        let token = token.clone();
+        let shared = Arc::clone(&state);
+        let status_state = Arc::clone(&state);
+        let set = move |status: SupervisorStatus| {
+            status_state
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .status = status;
+        };
+        let RestartPolicy {
+            max_restarts,
+            mut backoff,
+        } = policy;
+        thread::spawn(move || loop {
+            if token.is_cancelled() {
+                return set(SupervisorStatus::Stopped);
+            }
+            set(SupervisorStatus::Running);
+            let error = match catch_unwind(AssertUnwindSafe(|| f(&token))) {
+                Ok(Ok(())) if token.is_cancelled() => return set(SupervisorStatus::Stopped),
+                Ok(Ok(())) => return set(SupervisorStatus::Finished),
+                Ok(Err(e)) => format!("{e:?}"),
+                Err(payload) => panic_message(payload),
+            };
+            let restarts = {
+                let mut s = shared.lock().unwrap_or_else(|e| e.into_inner());
+                s.last_error = Some(error);
+                s.restarts
+            };
+            if token.is_cancelled() {
+                return set(SupervisorStatus::Stopped);
+            }
+            if max_restarts.is_some_and(|max| restarts >= max) {
+                return set(SupervisorStatus::GaveUp);
+            }
+            let Some(delay) = backoff.next() else {
+                return set(SupervisorStatus::GaveUp);
+            };
+            set(SupervisorStatus::Restarting);
+            if token.wait_timeout(delay) {
+                return set(SupervisorStatus::Stopped);
+            }
+            shared.lock().unwrap_or_else(|e| e.into_inner()).restarts += 1;
+        })
+    };
+    SupervisorHandle {
+        token,
+        state,
+        thread: Some(thread),
+    }
+}
@@ -50,6 +50,8 @@
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use crate::time::{system_clock, Clock};
+
 /// A throttled function wrapper.
 ///
 /// Stores the function and timing state used to ensure calls do not execute
@@ -58,6 +60,7 @@ pub struct Throttled<F> {
     pub(crate) func: F,
     pub(crate) delay: Duration,
     pub(crate) last_call: Arc<Mutex<Option<Instant>>>,
+    pub(crate) clock: Arc<dyn Clock>,
 }
 
 impl<F> Throttled<F>
@@ -85,14 +88,15 @@ where
     pub fn call(&self) {
         let should_execute = {
             let mut last = self.last_call.lock().unwrap();
+            let now = self.clock.now();
             match *last {
                 None => {
-                    *last = Some(Instant::now());
+                    *last = Some(now);
                     true
                 }
                 Some(last_instant) => {
-                    if last_instant.elapsed() >= self.delay {
-                        *last = Some(Instant::now());
+                    if now.saturating_duration_since(last_instant) >= self.delay {
+                        *last = Some(now);
                         true
                     } else {
                         false
@@ -105,6 +109,50 @@ where
             (self.func)();
         }
     }
+
+    /// Time until the next `call` would execute; zero if it would run now.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::throttle;
+    /// use std::time::Duration;
+    /// let t = throttle(|| {}, Duration::from_secs(60));
+    /// assert_eq!(t.remaining(), Duration::ZERO);
+    /// t.call();
+    /// assert!(t.remaining() > Duration::from_secs(59));
+    /// ```
+    pub fn remaining(&self) -> Duration {
+        self.last_call
+            .lock()
+            .unwrap()
+            .map_or(Duration::ZERO, |last| {
+                let elapsed = self.clock.now().saturating_duration_since(last);
+                self.delay.saturating_sub(elapsed)
+            })
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::throttle;
+    /// use toolchest::time::MockClock;
+    /// use std::cell::Cell;
+    /// use std::time::Duration;
+    ///
+    /// let clock = MockClock::new();
+    /// let runs = Cell::new(0);
+    /// let t = throttle(|| runs.set(runs.get() + 1), Duration::from_secs(1)).with_clock(clock.clone());
+    /// t.call();
+    /// t.call();
+    /// clock.advance(Duration::from_secs(1));
+    /// t.call();
+    /// assert_eq!(runs.get(), 2);
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
 }
 
 /// Create a throttled wrapper around `func` with the provided `delay`.
@@ -130,5 +178,6 @@ where
         func,
         delay,
         last_call: Arc::new(Mutex::new(None)),
+        clock: system_clock(),
     }
 }
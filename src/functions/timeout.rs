@@ -9,8 +9,8 @@
 //!   cancelled on timeout; it simply continues running to completion in the
 //!   background.
 //! - `T` must be `Send + 'static` because it crosses the thread boundary.
-//! - This is a simple building block; for cancellation, consider additional
-//!   coordination.
+//! - [`with_timeout_cancellable`] passes the closure a [`CancellationToken`]
+//!   that is cancelled on timeout, so cooperative work can stop early.
 //!
 //! Basic example:
 //! ```rust
@@ -28,6 +28,7 @@
 //! assert_eq!(slow, None);
 //! ```
 
+use super::cancel::CancellationToken;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
@@ -48,3 +49,39 @@ where
     });
     rx.recv_timeout(dur).ok()
 }
+
+/// Run `f` with a timeout, cancelling its token when the timeout elapses.
+///
+/// `f` receives a [`CancellationToken`] it should poll (or wait on) to stop
+/// early; on timeout the token is cancelled and `None` is returned. The worker
+/// thread still runs until `f` returns, so `f` must cooperate to exit promptly.
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::with_timeout_cancellable;
+/// use std::time::Duration;
+/// let (tx, rx) = std::sync::mpsc::channel();
+/// let res = with_timeout_cancellable(Duration::from_millis(5), move |token| {
+///     while !token.wait_timeout(Duration::from_millis(1)) {}
+///     tx.send("stopped").unwrap();
+/// });
+/// assert_eq!(res, None);
+/// assert_eq!(rx.recv().unwrap(), "stopped");
+/// ```
+pub fn with_timeout_cancellable<T, F>(dur: Duration, f: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce(CancellationToken) -> T + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let worker_token = token.clone();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(f(worker_token));
+    });
+    let res = rx.recv_timeout(dur).ok();
+    if res.is_none() {
+        token.cancel();
+    }
+    res
+}
@@ -0,0 +1,324 @@
+//! Fixed-size thread pool fed by a channel.
+//!
+//! [`WorkerPool`] keeps `n` long-lived threads pulling boxed jobs from a
+//! shared queue. [`WorkerPool::submit`] returns a [`JobHandle`] for the
+//! job's result. It does not depend on any async runtime: call it from plain
+//! threads, or from async code that can afford to block on
+//! [`JobHandle::join`].
+//!
+//! Notes:
+//! - Each job runs under `catch_unwind`, so a panicking job reports
+//!   [`JobError::Panicked`] through its handle and the worker keeps going.
+//!   The panic hook still runs (and prints by default).
+//! - [`WorkerPool::shutdown`] stops accepting work, lets queued jobs finish
+//!   until a deadline, then drops whatever has not started.
+//! - Dropping the pool closes the queue without waiting; queued jobs still run.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::functions::WorkerPool;
+//! use std::time::Duration;
+//!
+//! let pool = WorkerPool::new(4);
+//! let handles: Vec<_> = (1..=5u64).map(|n| pool.submit(move || n * n)).collect();
+//! let squares: Vec<u64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+//! assert_eq!(squares, [1, 4, 9, 16, 25]);
+//! assert!(pool.shutdown(Duration::from_secs(1)));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use super::supervisor::panic_text;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Why a [`JobHandle`] has no value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JobError {
+    /// The job panicked; holds the panic message.
+    Panicked(String),
+    /// The job was dropped before it ran because the pool shut down.
+    Cancelled,
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobError::Panicked(msg) => write!(f, "job panicked: {msg}"),
+            JobError::Cancelled => write!(f, "job cancelled"),
+        }
+    }
+}
+
+impl Error for JobError {}
+
+struct Slot<T> {
+    result: Mutex<Option<Result<T, JobError>>>,
+    ready: Condvar,
+}
+
+// Fills the slot exactly once; a job dropped unrun reports `Cancelled`
+struct Completer<T>(Option<Arc<Slot<T>>>);
+
+impl<T> Completer<T> {
+    fn complete(mut self, result: Result<T, JobError>) {
+        if let Some(slot) = self.0.take() {
+            *slot.result.lock().unwrap_or_else(|e| e.into_inner()) = Some(result);
+            slot.ready.notify_all();
+        }
+    }
+}
+
+impl<T> Drop for Completer<T> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.0.take() {
+            *slot.result.lock().unwrap_or_else(|e| e.into_inner()) = Some(Err(JobError::Cancelled));
+            slot.ready.notify_all();
+        }
+    }
+}
+
+/// Handle to a job submitted with [`WorkerPool::submit`].
+///
+/// Dropping the handle does not cancel the job.
+pub struct JobHandle<T> {
+    slot: Arc<Slot<T>>,
+}
+
+impl<T> JobHandle<T> {
+    /// Whether the job has finished (or will never run).
+    pub fn is_finished(&self) -> bool {
+        self.slot
+            .result
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Block until the job finishes and return its result.
+    pub fn join(self) -> Result<T, JobError> {
+        let mut result = self.slot.result.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if let Some(r) = result.take() {
+                return r;
+            }
+            result = self
+                .slot
+                .ready
+                .wait(result)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+impl<T> fmt::Debug for JobHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+struct Shared {
+    queue: Mutex<mpsc::Receiver<Job>>,
+    // Set when shutdown times out: remaining queued jobs are dropped unrun
+    abandon: AtomicBool,
+    pending: AtomicUsize,
+    alive: Mutex<usize>,
+    exited: Condvar,
+}
+
+// Counts a worker as exited even if it somehow unwinds
+struct ExitGuard(Arc<Shared>);
+
+impl Drop for ExitGuard {
+    fn drop(&mut self) {
+        *self.0.alive.lock().unwrap_or_else(|e| e.into_inner()) -= 1;
+        self.0.exited.notify_all();
+    }
+}
+
+fn worker(shared: Arc<Shared>) {
+    let _guard = ExitGuard(Arc::clone(&shared));
+    loop {
+        let job = shared
+            .queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .recv();
+        let Ok(job) = job else {
+            return;
+        };
+        if !shared.abandon.load(Ordering::SeqCst) {
+            job();
+        }
+        shared.pending.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A fixed set of worker threads running submitted jobs in FIFO order.
+pub struct WorkerPool {
+    sender: Option<mpsc::Sender<Job>>,
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    /// Start `n_threads` workers (`0` uses [`std::thread::available_parallelism`]).
+    pub fn new(n_threads: usize) -> Self {
+        let n = if n_threads == 0 {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            n_threads
+        };
+        let (sender, receiver) = mpsc::channel();
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(receiver),
+            abandon: AtomicBool::new(false),
+            pending: AtomicUsize::new(0),
+            alive: Mutex::new(n),
+            exited: Condvar::new(),
+        });
+        let workers = (0..n)
+            .map(|i| {
+                let shared = Arc::clone(&shared);
+                thread::Builder::new()
+                    .name(format!("toolchest-worker-{i}"))
+                    .spawn(move || worker(shared))
+                    .expect("failed to spawn worker thread")
+            })
+            .collect();
+        Self {
+            sender: Some(sender),
+            shared,
+            workers,
+        }
+    }
+
+    /// Number of worker threads.
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Jobs submitted but not yet finished (queued or running).
+    pub fn pending(&self) -> usize {
+        self.shared.pending.load(Ordering::SeqCst)
+    }
+
+    /// Queue `job` and return a handle to its result.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::{JobError, WorkerPool};
+    /// let pool = WorkerPool::new(1);
+    /// let bad = pool.submit(|| -> u8 { panic!("boom") });
+    /// let good = pool.submit(|| 7);
+    /// assert_eq!(bad.join(), Err(JobError::Panicked("boom".into())));
+    /// assert_eq!(good.join(), Ok(7));
+    /// ```
+    pub fn submit<T, F>(&self, job: F) -> JobHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let slot = Arc::new(Slot {
+            result: Mutex::new(None),
+            ready: Condvar::new(),
+        });
+        let completer = Completer(Some(Arc::clone(&slot)));
+        let boxed: Job = Box::new(move || {
+            let result = catch_unwind(AssertUnwindSafe(job)).map_err(|payload| {
+                JobError::Panicked(panic_text(&*payload).unwrap_or("Box<dyn Any>").to_string())
+            });
+            completer.complete(result);
+        });
+        self.shared.pending.fetch_add(1, Ordering::SeqCst);
+        let sent = self
+            .sender
+            .as_ref()
+            .is_some_and(|tx| tx.send(boxed).is_ok());
+        if !sent {
+            // The rejected job was dropped, which already reported `Cancelled`
+            self.shared.pending.fetch_sub(1, Ordering::SeqCst);
+        }
+        JobHandle { slot }
+    }
+
+    /// Stop accepting jobs and wait up to `timeout` for queued jobs to finish.
+    ///
+    /// Returns `true` if every worker exited in time. Otherwise jobs that have
+    /// not started are dropped (their handles report [`JobError::Cancelled`]),
+    /// running jobs are left to finish on their detached threads, and `false`
+    /// is returned. A `timeout` too large to add to the current time (such as
+    /// `Duration::MAX`) waits without a deadline.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::functions::{JobError, WorkerPool};
+    /// use std::time::Duration;
+    /// let pool = WorkerPool::new(1);
+    /// let (started, wait) = std::sync::mpsc::channel();
+    /// let slow = pool.submit(move || {
+    ///     started.send(()).unwrap();
+    ///     std::thread::sleep(Duration::from_millis(200));
+    /// });
+    /// let queued = pool.submit(|| 1);
+    /// wait.recv().unwrap();
+    /// assert!(!pool.shutdown(Duration::from_millis(10)));
+    /// assert_eq!(queued.join(), Err(JobError::Cancelled));
+    /// assert_eq!(slow.join(), Ok(()));
+    /// ```
+    pub fn shutdown(mut self, timeout: Duration) -> bool {
+        self.sender = None;
+        let deadline = Instant::now().checked_add(timeout);
+        let mut alive = self.shared.alive.lock().unwrap_or_else(|e| e.into_inner());
+        while *alive > 0 {
+            let Some(deadline) = deadline else {
+                alive = self
+                    .shared
+                    .exited
+                    .wait(alive)
+                    .unwrap_or_else(|e| e.into_inner());
+                continue;
+            };
+            let now = Instant::now();
+            if now >= deadline {
+                self.shared.abandon.store(true, Ordering::SeqCst);
+                return false;
+            }
+            alive = self
+                .shared
+                .exited
+                .wait_timeout(alive, deadline - now)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+        drop(alive);
+        for w in self.workers.drain(..) {
+            let _ = w.join();
+        }
+        true
+    }
+}
+
+impl fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("threads", &self.threads())
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        // Closing the channel lets workers drain the queue and exit
+        self.sender = None;
+    }
+}
@@ -0,0 +1,456 @@
+//! Stable hashing of any `Serialize` value via a canonical encoding
+
+use std::fmt;
+
+use serde::ser::{self, Serialize};
+
+use super::stream::Fnv1a;
+
+/// Stable 64-bit hash of `value`'s serialized form.
+///
+/// The value is encoded the way `serde_json` would shape it (options as the
+/// inner value or null, enums externally tagged, structs as maps), except that
+/// map and struct entries are sorted by key. The result is FNV-1a over that
+/// canonical encoding, so it is the same across runs and platforms, ignores
+/// `HashMap` iteration order and struct field order, and needs no `Hash`
+/// impl. All integer types hash alike by value; `f32` hashes as `f64`.
+///
+/// Useful for cache keys and change detection. Not suitable where an
+/// adversary chooses the input.
+///
+/// # Panics
+///
+/// If `T`'s `Serialize` impl returns an error (derived impls never do).
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::hash_serialize;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// #[derive(serde::Serialize)]
+/// struct Job { name: String, retries: u8, tags: HashMap<String, i64> }
+///
+/// #[derive(serde::Serialize)]
+/// struct JobV2 { tags: BTreeMap<String, u32>, retries: u64, name: String }
+///
+/// let mut job = Job {
+///     name: "sync".into(),
+///     retries: 3,
+///     tags: HashMap::from([("a".into(), 1), ("b".into(), 2)]),
+/// };
+/// let v2 = JobV2 {
+///     tags: BTreeMap::from([("b".into(), 2), ("a".into(), 1)]),
+///     retries: 3,
+///     name: "sync".into(),
+/// };
+/// let key = hash_serialize(&job);
+/// assert_eq!(key, hash_serialize(&v2));
+/// job.retries += 1;
+/// assert_ne!(key, hash_serialize(&job));
+/// ```
+pub fn hash_serialize<T: Serialize + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1a::new();
+    match value.serialize(Encoder { out: &mut hasher }) {
+        Ok(()) => hasher.finish(),
+        Err(e) => panic!("hash_serialize: {e}"),
+    }
+}
+
+// Every encoded value is self-delimiting (tag byte, then fixed-size or
+// length-prefixed payload, or a sequence closed by END), so concatenations
+// are unambiguous.
+const NULL: u8 = b'n';
+const BOOL: u8 = b'b';
+const INT: u8 = b'i';
+const UINT: u8 = b'u';
+const FLOAT: u8 = b'f';
+const STR: u8 = b's';
+const BYTES: u8 = b'y';
+const SEQ: u8 = b'l';
+const MAP: u8 = b'm';
+const END: u8 = b'e';
+
+trait Sink {
+    fn put(&mut self, bytes: &[u8]);
+}
+
+impl Sink for Fnv1a {
+    fn put(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+impl Sink for Vec<u8> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+fn put_len(out: &mut impl Sink, tag: u8, bytes: &[u8]) {
+    out.put(&[tag]);
+    out.put(&(bytes.len() as u64).to_le_bytes());
+    out.put(bytes);
+}
+
+fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::new();
+    value.serialize(Encoder { out: &mut buf })?;
+    Ok(buf)
+}
+
+struct Encoder<'a, W> {
+    out: &'a mut W,
+}
+
+impl<'a, W: Sink> Encoder<'a, W> {
+    fn int(self, v: i128) -> Result<(), Error> {
+        self.out.put(&[INT]);
+        self.out.put(&v.to_le_bytes());
+        Ok(())
+    }
+
+    // `{variant: ...}` wrapper for externally tagged enum variants
+    fn open_variant(&mut self, variant: &str) {
+        self.out.put(&[MAP]);
+        put_len(self.out, STR, variant.as_bytes());
+    }
+}
+
+impl<'a, W: Sink> ser::Serializer for Encoder<'a, W> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SeqEncoder<'a, W>;
+    type SerializeTuple = SeqEncoder<'a, W>;
+    type SerializeTupleStruct = SeqEncoder<'a, W>;
+    type SerializeTupleVariant = SeqEncoder<'a, W>;
+    type SerializeMap = MapEncoder<'a, W>;
+    type SerializeStruct = MapEncoder<'a, W>;
+    type SerializeStructVariant = MapEncoder<'a, W>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.out.put(&[BOOL, v as u8]);
+        Ok(())
+    }
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        self.int(v)
+    }
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.int(v.into())
+    }
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        match i128::try_from(v) {
+            Ok(i) => self.int(i),
+            Err(_) => {
+                self.out.put(&[UINT]);
+                self.out.put(&v.to_le_bytes());
+                Ok(())
+            }
+        }
+    }
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.serialize_f64(v.into())
+    }
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.out.put(&[FLOAT]);
+        self.out.put(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        put_len(self.out, STR, v.as_bytes());
+        Ok(())
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        put_len(self.out, BYTES, v);
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.out.put(&[NULL]);
+        Ok(())
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.open_variant(variant);
+        value.serialize(Encoder {
+            out: &mut *self.out,
+        })?;
+        self.out.put(&[END]);
+        Ok(())
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqEncoder<'a, W>, Error> {
+        self.out.put(&[SEQ]);
+        Ok(SeqEncoder {
+            out: self.out,
+            variant: false,
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<SeqEncoder<'a, W>, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqEncoder<'a, W>, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SeqEncoder<'a, W>, Error> {
+        self.open_variant(variant);
+        self.out.put(&[SEQ]);
+        Ok(SeqEncoder {
+            out: self.out,
+            variant: true,
+        })
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapEncoder<'a, W>, Error> {
+        Ok(MapEncoder::new(self.out, false))
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<MapEncoder<'a, W>, Error> {
+        Ok(MapEncoder::new(self.out, false))
+    }
+    fn serialize_struct_variant(
+        mut self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<MapEncoder<'a, W>, Error> {
+        self.open_variant(variant);
+        Ok(MapEncoder::new(self.out, true))
+    }
+}
+
+struct SeqEncoder<'a, W> {
+    out: &'a mut W,
+    // Also close the `{variant: ...}` wrapper
+    variant: bool,
+}
+
+impl<W: Sink> SeqEncoder<'_, W> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(Encoder {
+            out: &mut *self.out,
+        })
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        self.out.put(&[END]);
+        if self.variant {
+            self.out.put(&[END]);
+        }
+        Ok(())
+    }
+}
+
+impl<W: Sink> ser::SerializeSeq for SeqEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<W: Sink> ser::SerializeTuple for SeqEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<W: Sink> ser::SerializeTupleStruct for SeqEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<W: Sink> ser::SerializeTupleVariant for SeqEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+// Entries are buffered as encoded bytes and written sorted by encoded key
+struct MapEncoder<'a, W> {
+    out: &'a mut W,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+    key: Option<Vec<u8>>,
+    variant: bool,
+}
+
+impl<'a, W: Sink> MapEncoder<'a, W> {
+    fn new(out: &'a mut W, variant: bool) -> Self {
+        Self {
+            out,
+            entries: Vec::new(),
+            key: None,
+            variant,
+        }
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        let mut k = Vec::new();
+        put_len(&mut k, STR, key.as_bytes());
+        self.entries.push((k, encode(value)?));
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<(), Error> {
+        self.entries.sort_unstable();
+        self.out.put(&[MAP]);
+        for (k, v) in &self.entries {
+            self.out.put(k);
+            self.out.put(v);
+        }
+        self.out.put(&[END]);
+        if self.variant {
+            self.out.put(&[END]);
+        }
+        Ok(())
+    }
+}
+
+impl<W: Sink> ser::SerializeMap for MapEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(encode(key)?);
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error("map value serialized before its key".into()))?;
+        self.entries.push((key, encode(value)?));
+        Ok(())
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<W: Sink> ser::SerializeStruct for MapEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<W: Sink> ser::SerializeStructVariant for MapEncoder<'_, W> {
+    type Ok = ();
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
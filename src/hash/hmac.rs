@@ -0,0 +1,86 @@
+//! HMAC message authentication (RFC 2104)
+
+use super::sha::{Digest, Sha1, Sha256};
+
+/// HMAC over any of the crate's block digests
+fn hmac<D: Digest>(key: &[u8], msg: &[u8]) -> D::Output {
+    let mut block = vec![0u8; D::BLOCK_SIZE];
+    if key.len() > D::BLOCK_SIZE {
+        let mut h = D::default();
+        h.update(key);
+        let digest = h.finish();
+        block[..digest.as_ref().len()].copy_from_slice(digest.as_ref());
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = D::default();
+    inner.update(&block.iter().map(|b| b ^ 0x36).collect::<Vec<_>>());
+    inner.update(msg);
+    let inner = inner.finish();
+
+    let mut outer = D::default();
+    outer.update(&block.iter().map(|b| b ^ 0x5c).collect::<Vec<_>>());
+    outer.update(inner.as_ref());
+    outer.finish()
+}
+
+/// HMAC-SHA256 tag for `msg` under `key`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::hmac_sha256;
+/// use toolchest::encoding::hex_encode;
+/// let tag = hmac_sha256(b"key", b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(
+///     hex_encode(&tag),
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+/// );
+/// ```
+pub fn hmac_sha256(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    hmac::<Sha256>(key, msg)
+}
+
+/// HMAC-SHA1 tag for `msg` under `key`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::hmac_sha1;
+/// use toolchest::encoding::hex_encode;
+/// let tag = hmac_sha1(b"key", b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hex_encode(&tag), "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9");
+/// ```
+pub fn hmac_sha1(key: &[u8], msg: &[u8]) -> [u8; 20] {
+    hmac::<Sha1>(key, msg)
+}
+
+/// Compare two byte strings in time independent of where they differ.
+///
+/// Use this to check MACs so timing does not leak how many leading bytes of
+/// a forged signature were correct. Lengths are not secret.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::constant_time_eq;
+/// assert!(constant_time_eq(b"abc", b"abc"));
+/// assert!(!constant_time_eq(b"abc", b"abd"));
+/// ```
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Verify an HMAC-SHA256 `tag` (e.g. a webhook signature) in constant time.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::{hmac_sha256, verify_hmac_sha256};
+/// let tag = hmac_sha256(b"secret", b"payload");
+/// assert!(verify_hmac_sha256(b"secret", b"payload", &tag));
+/// assert!(!verify_hmac_sha256(b"secret", b"tampered", &tag));
+/// ```
+pub fn verify_hmac_sha256(key: &[u8], msg: &[u8], tag: &[u8]) -> bool {
+    constant_time_eq(&hmac_sha256(key, msg), tag)
+}
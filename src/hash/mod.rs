@@ -2,7 +2,16 @@
 //!
 //! Convenient hashing utilities for quick IDs, bucket selection, and more.
 //! Includes simple algorithms like djb2 and FNV-1a, alongside MurmurHash3
-//! (x86 32-bit variant) and a `consistent_hash` helper for bucketing.
+//! (x86 32-bit and x64 128-bit variants), CRC-32, a `consistent_hash` helper
+//! for bucketing, and `combine`/`hash_many` for composite keys.
+//!
+//! With the `crypto` feature, dependency-free SHA-1/SHA-256 digests and HMAC
+//! (`hmac_sha256`, `hmac_sha1`, `verify_hmac_sha256`) are also available, e.g.
+//! for webhook signature checks.
+//!
+//! With the `serde` feature (also enabled by `json`), `hash_serialize` gives
+//! any `Serialize` type a stable hash of its canonical serialized form, with
+//! map keys sorted, for cache keys and change detection.
 //!
 //! Examples:
 //! ```rust
@@ -15,6 +24,21 @@
 //! assert!(bucket < 10);
 //! ```
 
+#[cfg(feature = "serde")]
+mod canonical;
+#[cfg(feature = "crypto")]
+mod hmac;
+#[cfg(feature = "crypto")]
+mod sha;
+pub(crate) mod stream;
+
+#[cfg(feature = "serde")]
+pub use canonical::hash_serialize;
+#[cfg(feature = "crypto")]
+pub use hmac::{constant_time_eq, hmac_sha1, hmac_sha256, verify_hmac_sha256};
+#[cfg(feature = "crypto")]
+pub use sha::{sha1, sha256};
+
 /// Convenience hash for strings using djb2.
 ///
 /// Example:
@@ -115,6 +139,114 @@ pub fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
     h
 }
 
+/// MurmurHash3 x64 128-bit.
+///
+/// The result is the reference digest read as a little-endian `u128`
+/// (`h1` in the low 64 bits), so `to_le_bytes()` yields the usual byte order.
+/// Same attribution as [`murmur3_32`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::murmur3_128;
+/// assert_eq!(murmur3_128(b"", 0), 0);
+/// assert_ne!(murmur3_128(b"key", 1), murmur3_128(b"key", 2));
+/// ```
+pub fn murmur3_128(bytes: &[u8], seed: u64) -> u128 {
+    const C1: u64 = 0x87c37b91114253d5;
+    const C2: u64 = 0x4cf5ad432745937f;
+    fn fmix64(mut k: u64) -> u64 {
+        k ^= k >> 33;
+        k = k.wrapping_mul(0xff51afd7ed558ccd);
+        k ^= k >> 33;
+        k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+        k ^= k >> 33;
+        k
+    }
+    let mix_k1 = |k: u64| k.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+    let mix_k2 = |k: u64| k.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+
+    let mut h1 = seed;
+    let mut h2 = seed;
+    let mut chunks = bytes.chunks_exact(16);
+    for chunk in &mut chunks {
+        let k1 = u64::from_le_bytes(chunk[..8].try_into().unwrap());
+        let k2 = u64::from_le_bytes(chunk[8..].try_into().unwrap());
+        h1 ^= mix_k1(k1);
+        h1 = h1.rotate_left(27).wrapping_add(h2);
+        h1 = h1.wrapping_mul(5).wrapping_add(0x52dce729);
+        h2 ^= mix_k2(k2);
+        h2 = h2.rotate_left(31).wrapping_add(h1);
+        h2 = h2.wrapping_mul(5).wrapping_add(0x38495ab5);
+    }
+    let rem = chunks.remainder();
+    if !rem.is_empty() {
+        let mut tail = [0u8; 16];
+        tail[..rem.len()].copy_from_slice(rem);
+        if rem.len() > 8 {
+            h2 ^= mix_k2(u64::from_le_bytes(tail[8..].try_into().unwrap()));
+        }
+        h1 ^= mix_k1(u64::from_le_bytes(tail[..8].try_into().unwrap()));
+    }
+    let len = bytes.len() as u64;
+    h1 ^= len;
+    h2 ^= len;
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    ((h2 as u128) << 64) | h1 as u128
+}
+
+/// Mix `value` into `seed` (boost `hash_combine`, 64-bit constant).
+///
+/// Order matters: `combine(a, b)` and `combine(b, a)` differ.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::{combine, fnv1a};
+/// let key = combine(fnv1a(b"tenant-7"), fnv1a(b"report.pdf"));
+/// assert_ne!(key, combine(fnv1a(b"report.pdf"), fnv1a(b"tenant-7")));
+/// ```
+pub fn combine(seed: u64, value: u64) -> u64 {
+    seed ^ value
+        .wrapping_add(0x9e3779b97f4a7c15)
+        .wrapping_add(seed << 6)
+        .wrapping_add(seed >> 2)
+}
+
+/// Hash several byte strings into one key without concatenating them.
+///
+/// Each part is hashed with [`fnv1a`] and folded with [`combine`], so part
+/// boundaries matter: `["ab", "c"]` and `["a", "bc"]` hash differently.
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::hash_many;
+/// let k = hash_many(&[b"user", b"42", b"avatar"]);
+/// assert_eq!(k, hash_many(&[b"user", b"42", b"avatar"]));
+/// assert_ne!(hash_many(&[b"ab", b"c"]), hash_many(&[b"a", b"bc"]));
+/// ```
+pub fn hash_many(parts: &[&[u8]]) -> u64 {
+    parts
+        .iter()
+        .fold(parts.len() as u64, |acc, part| combine(acc, fnv1a(part)))
+}
+
+/// CRC-32 checksum (IEEE polynomial, as used by zip, gzip and PNG).
+///
+/// Example:
+/// ```rust
+/// use toolchest::hash::crc32;
+/// assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+/// ```
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut c = stream::Crc32::new();
+    c.update(bytes);
+    c.finish()
+}
+
 /// Consistent hashing to bucket index `[0, buckets)`.
 ///
 /// Returns `0` when `buckets` is `0`.
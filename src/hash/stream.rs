@@ -0,0 +1,142 @@
+//! Incremental versions of the non-crypto hashes, for hashing streams in chunks
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32 (IEEE 802.3, as used by zip/gzip/PNG)
+pub(crate) struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    pub(crate) fn new() -> Self {
+        Self { crc: 0xFFFF_FFFF }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.crc = CRC32_TABLE[((self.crc ^ b as u32) & 0xFF) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        !self.crc
+    }
+}
+
+/// FNV-1a 64-bit, matching [`fnv1a`](super::fnv1a)
+pub(crate) struct Fnv1a {
+    h: u64,
+}
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Self {
+            h: 0xcbf29ce484222325,
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.h ^= b as u64;
+            self.h = self.h.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.h
+    }
+}
+
+/// MurmurHash3 x86 32-bit, matching [`murmur3_32`](super::murmur3_32)
+pub(crate) struct Murmur3_32 {
+    h: u32,
+    tail: [u8; 4],
+    tail_len: usize,
+    len: u64,
+}
+
+const C1: u32 = 0xcc9e2d51;
+const C2: u32 = 0x1b873593;
+
+fn mix_k(k: u32) -> u32 {
+    k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+}
+
+impl Murmur3_32 {
+    pub(crate) fn new(seed: u32) -> Self {
+        Self {
+            h: seed,
+            tail: [0; 4],
+            tail_len: 0,
+            len: 0,
+        }
+    }
+
+    fn block(&mut self, block: [u8; 4]) {
+        self.h ^= mix_k(u32::from_le_bytes(block));
+        self.h = self
+            .h
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64);
+    }
+
+    pub(crate) fn update(&mut self, mut bytes: &[u8]) {
+        self.len += bytes.len() as u64;
+        if self.tail_len > 0 {
+            let take = (4 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + take].copy_from_slice(&bytes[..take]);
+            self.tail_len += take;
+            bytes = &bytes[take..];
+            if self.tail_len < 4 {
+                return;
+            }
+            self.block(self.tail);
+            self.tail_len = 0;
+        }
+        let mut chunks = bytes.chunks_exact(4);
+        for c in &mut chunks {
+            self.block([c[0], c[1], c[2], c[3]]);
+        }
+        let rem = chunks.remainder();
+        self.tail[..rem.len()].copy_from_slice(rem);
+        self.tail_len = rem.len();
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        let mut h = self.h;
+        if self.tail_len > 0 {
+            let mut k = 0u32;
+            for (i, b) in self.tail[..self.tail_len].iter().enumerate() {
+                k ^= (*b as u32) << (8 * i);
+            }
+            h ^= mix_k(k);
+        }
+        // The reference implementation folds in the length as a 32-bit value
+        h ^= self.len as u32;
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x85ebca6b);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xc2b2ae35);
+        h ^= h >> 16;
+        h
+    }
+}
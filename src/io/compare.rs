@@ -0,0 +1,184 @@
+//! File checksums and file/directory comparison
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::hash::stream::{Crc32, Fnv1a, Murmur3_32};
+
+const CHUNK: usize = 64 * 1024;
+
+/// Hash algorithm for [`file_hash`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algo {
+    /// FNV-1a 64-bit (same as [`crate::hash::fnv1a`])
+    Fnv,
+    /// MurmurHash3 x86 32-bit with seed 0 (same as [`crate::hash::murmur3_32`])
+    Murmur3,
+    /// CRC-32 IEEE (same as [`crate::hash::crc32`])
+    Crc32,
+}
+
+// Feed `f` the file in fixed-size chunks
+fn for_each_chunk(path: &Path, mut f: impl FnMut(&[u8])) -> io::Result<()> {
+    let mut file = File::open(path)?;
+    let mut buf = vec![0u8; CHUNK];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            return Ok(());
+        }
+        f(&buf[..n]);
+    }
+}
+
+/// Hash a file's contents, streaming it in 64 KiB chunks.
+///
+/// 32-bit algorithms are widened to `u64`. Results match the in-memory
+/// functions in [`crate::hash`] for the same bytes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{file_hash, write_atomic, Algo};
+/// let path = std::path::PathBuf::from("target/tmp_file_hash.txt");
+/// write_atomic(&path, b"123456789").unwrap();
+/// assert_eq!(file_hash(&path, Algo::Crc32).unwrap(), 0xCBF4_3926);
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn file_hash<P: AsRef<Path>>(path: P, algo: Algo) -> io::Result<u64> {
+    let path = path.as_ref();
+    match algo {
+        Algo::Fnv => {
+            let mut h = Fnv1a::new();
+            for_each_chunk(path, |c| h.update(c))?;
+            Ok(h.finish())
+        }
+        Algo::Murmur3 => {
+            let mut h = Murmur3_32::new(0);
+            for_each_chunk(path, |c| h.update(c))?;
+            Ok(h.finish() as u64)
+        }
+        Algo::Crc32 => {
+            let mut h = Crc32::new();
+            for_each_chunk(path, |c| h.update(c))?;
+            Ok(h.finish() as u64)
+        }
+    }
+}
+
+// Fill `buf` as far as possible; returns bytes read (short only at EOF)
+fn read_full(file: &mut File, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match file.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// True if two files have identical contents.
+///
+/// Returns early when the sizes differ, otherwise compares chunk by chunk and
+/// stops at the first difference.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{files_identical, write_atomic};
+/// let a = std::path::PathBuf::from("target/tmp_ident_a.txt");
+/// let b = std::path::PathBuf::from("target/tmp_ident_b.txt");
+/// write_atomic(&a, b"same").unwrap();
+/// write_atomic(&b, b"same").unwrap();
+/// assert!(files_identical(&a, &b).unwrap());
+/// std::fs::remove_file(&a).ok();
+/// std::fs::remove_file(&b).ok();
+/// ```
+pub fn files_identical<P: AsRef<Path>, Q: AsRef<Path>>(a: P, b: Q) -> io::Result<bool> {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if fs::metadata(a)?.len() != fs::metadata(b)?.len() {
+        return Ok(false);
+    }
+    let (mut fa, mut fb) = (File::open(a)?, File::open(b)?);
+    let (mut ba, mut bb) = (vec![0u8; CHUNK], vec![0u8; CHUNK]);
+    loop {
+        let na = read_full(&mut fa, &mut ba)?;
+        let nb = read_full(&mut fb, &mut bb)?;
+        if na != nb || ba[..na] != bb[..nb] {
+            return Ok(false);
+        }
+        if na == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+/// Differences between two directory trees, as paths relative to the roots.
+///
+/// Each list is sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirDiff {
+    /// Files present only in the second tree
+    pub added: Vec<PathBuf>,
+    /// Files present only in the first tree
+    pub removed: Vec<PathBuf>,
+    /// Files present in both whose contents differ
+    pub changed: Vec<PathBuf>,
+}
+
+impl DirDiff {
+    /// True if the trees contain the same files with the same contents
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn relative_files(root: &Path) -> io::Result<BTreeSet<PathBuf>> {
+    let mut out = BTreeSet::new();
+    super::walk_files(root, &mut |path, meta| {
+        if meta.is_file() {
+            let rel = path.strip_prefix(root).map_err(io::Error::other)?;
+            out.insert(rel.to_path_buf());
+        }
+        Ok(())
+    })?;
+    Ok(out)
+}
+
+/// Compare two directory trees file by file.
+///
+/// Symlinks are not followed. Files present in both trees are compared with
+/// [`files_identical`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{dir_diff, ensure_dir, write_atomic};
+/// let a = std::path::PathBuf::from("target/tmp_dir_diff_doc/a");
+/// let b = std::path::PathBuf::from("target/tmp_dir_diff_doc/b");
+/// ensure_dir(&a).unwrap();
+/// ensure_dir(&b).unwrap();
+/// write_atomic(a.join("x.txt"), b"1").unwrap();
+/// write_atomic(b.join("x.txt"), b"2").unwrap();
+/// write_atomic(b.join("y.txt"), b"new").unwrap();
+/// let diff = dir_diff(&a, &b).unwrap();
+/// assert_eq!(diff.changed, vec![std::path::PathBuf::from("x.txt")]);
+/// assert_eq!(diff.added, vec![std::path::PathBuf::from("y.txt")]);
+/// std::fs::remove_dir_all("target/tmp_dir_diff_doc").ok();
+/// ```
+pub fn dir_diff<P: AsRef<Path>, Q: AsRef<Path>>(a: P, b: Q) -> io::Result<DirDiff> {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    let left = relative_files(a)?;
+    let right = relative_files(b)?;
+    let mut diff = DirDiff {
+        added: right.difference(&left).cloned().collect(),
+        removed: left.difference(&right).cloned().collect(),
+        changed: Vec::new(),
+    };
+    for rel in left.intersection(&right) {
+        if !files_identical(a.join(rel), b.join(rel))? {
+            diff.changed.push(rel.clone());
+        }
+    }
+    Ok(diff)
+}
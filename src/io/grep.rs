@@ -0,0 +1,196 @@
+//! Line-oriented content search over a directory tree
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::strings::glob::wildcard_prefix_match;
+
+/// Options for [`grep`].
+#[derive(Debug, Clone)]
+pub struct GrepOptions {
+    /// Match case-insensitively
+    pub case_insensitive: bool,
+    /// Treat `*` (any run of characters) and `?` (one character) as wildcards
+    pub wildcard: bool,
+    /// Skip files larger than this many bytes
+    pub max_file_size: Option<u64>,
+    /// Skip files whose first 8 KiB contain a NUL byte
+    pub skip_binary: bool,
+    /// Only search files whose names contain this (case-insensitive), like [`find_files`](super::find_files)
+    pub file_name_contains: Option<String>,
+    /// Stop after this many matches in total
+    pub max_matches: Option<usize>,
+}
+
+impl Default for GrepOptions {
+    fn default() -> Self {
+        Self {
+            case_insensitive: false,
+            wildcard: false,
+            max_file_size: Some(10 * 1024 * 1024),
+            skip_binary: true,
+            file_name_contains: None,
+            max_matches: None,
+        }
+    }
+}
+
+/// Search files below `root` for lines containing `pattern`.
+///
+/// Returns `(path, line_no, line)` triples with 1-based line numbers, ordered
+/// by path then line. Files are streamed line by line; invalid UTF-8 is decoded
+/// lossily and line endings are stripped. `root` may also be a single file.
+/// Symlinks are not followed.
+///
+/// Only a missing or unreadable `root` is an error. Like `grep -r`, files and
+/// subdirectories that cannot be read (permissions, deleted mid-walk, I/O
+/// errors) are skipped, keeping every match found elsewhere.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{ensure_dir, grep, write_atomic, GrepOptions};
+/// let dir = std::path::PathBuf::from("target/tmp_grep_doc");
+/// ensure_dir(&dir).unwrap();
+/// write_atomic(dir.join("a.txt"), b"alpha\nbeta\ngamma\n").unwrap();
+/// let opts = GrepOptions { wildcard: true, ..Default::default() };
+/// let hits = grep(&dir, "?eta", &opts).unwrap();
+/// assert_eq!(hits.len(), 1);
+/// assert_eq!((hits[0].1, hits[0].2.as_str()), (2, "beta"));
+/// std::fs::remove_dir_all(&dir).ok();
+/// ```
+pub fn grep<P: AsRef<Path>>(
+    root: P,
+    pattern: &str,
+    opts: &GrepOptions,
+) -> io::Result<Vec<(PathBuf, usize, String)>> {
+    let root = root.as_ref();
+    let mut files = Vec::new();
+    let meta = fs::symlink_metadata(root)?;
+    if meta.is_file() {
+        files.push((root.to_path_buf(), meta.len()));
+    } else {
+        collect_files(fs::read_dir(root)?, &mut files);
+    }
+    files.sort();
+
+    let name_filter = opts.file_name_contains.as_ref().map(|s| s.to_lowercase());
+    let matcher = Matcher::new(pattern, opts);
+    let limit = opts.max_matches.unwrap_or(usize::MAX);
+    let mut out = Vec::new();
+    let mut scratch = Vec::new();
+    for (path, len) in files {
+        if out.len() >= limit {
+            break;
+        }
+        if opts.max_file_size.is_some_and(|max| len > max) {
+            continue;
+        }
+        if let Some(filter) = &name_filter {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if !name.to_lowercase().contains(filter.as_str()) {
+                continue;
+            }
+        }
+        // An unreadable file is skipped; matches already found in it are kept
+        let _ = search_file(&path, &matcher, opts, limit, &mut out, &mut scratch);
+    }
+    Ok(out)
+}
+
+// Recursively gather regular files, skipping entries that cannot be read
+fn collect_files(entries: fs::ReadDir, files: &mut Vec<(PathBuf, u64)>) {
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if meta.is_dir() {
+            if let Ok(sub) = fs::read_dir(&path) {
+                collect_files(sub, files);
+            }
+        } else if meta.is_file() {
+            files.push((path, meta.len()));
+        }
+    }
+}
+
+fn search_file(
+    path: &Path,
+    matcher: &Matcher,
+    opts: &GrepOptions,
+    limit: usize,
+    out: &mut Vec<(PathBuf, usize, String)>,
+    scratch: &mut Vec<char>,
+) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    if opts.skip_binary && looks_binary(&mut reader)? {
+        return Ok(());
+    }
+    let mut buf = Vec::new();
+    let mut line_no = 0;
+    while out.len() < limit {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+        line_no += 1;
+        while matches!(buf.last(), Some(b'\n' | b'\r')) {
+            buf.pop();
+        }
+        let line = String::from_utf8_lossy(&buf);
+        if matcher.is_match(&line, scratch) {
+            out.push((path.to_path_buf(), line_no, line.into_owned()));
+        }
+    }
+    Ok(())
+}
+
+fn looks_binary<R: Read>(reader: &mut BufReader<R>) -> io::Result<bool> {
+    // Peek at the buffered prefix without consuming it
+    let head = reader.fill_buf()?;
+    Ok(head.iter().take(8192).any(|&b| b == 0))
+}
+
+struct Matcher {
+    literal: String,
+    pattern: Vec<char>,
+    case_insensitive: bool,
+    wildcard: bool,
+}
+
+impl Matcher {
+    fn new(pattern: &str, opts: &GrepOptions) -> Self {
+        let literal = if opts.case_insensitive {
+            pattern.to_lowercase()
+        } else {
+            pattern.to_string()
+        };
+        Self {
+            pattern: literal.chars().collect(),
+            literal,
+            case_insensitive: opts.case_insensitive,
+            wildcard: opts.wildcard,
+        }
+    }
+
+    // `scratch` is reused across lines so matching does not allocate per line
+    fn is_match(&self, line: &str, scratch: &mut Vec<char>) -> bool {
+        if !self.case_insensitive && !self.wildcard {
+            return line.contains(self.literal.as_str());
+        }
+        scratch.clear();
+        if self.case_insensitive {
+            scratch.extend(line.chars().flat_map(char::to_lowercase));
+        } else {
+            scratch.extend(line.chars());
+        }
+        if !self.wildcard {
+            return self.pattern.is_empty()
+                || scratch
+                    .windows(self.pattern.len())
+                    .any(|w| w == self.pattern);
+        }
+        (0..=scratch.len()).any(|start| wildcard_prefix_match(&self.pattern, &scratch[start..]))
+    }
+}
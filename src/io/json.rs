@@ -0,0 +1,98 @@
+//! JSON file loading and saving (behind the `json` feature)
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::write_atomic;
+use crate::deep::DeepMerge;
+
+/// Output layout for [`save_json_atomic`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonFormat {
+    /// Indented, human-readable output with a trailing newline
+    #[default]
+    Pretty,
+    /// Single-line output
+    Compact,
+}
+
+/// Read and deserialize a JSON file.
+///
+/// Parse and type errors are reported as `io::ErrorKind::InvalidData`
+/// (`UnexpectedEof` for truncated input).
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{load_json, write_atomic};
+/// let path = std::path::PathBuf::from("target/tmp_load_json.json");
+/// write_atomic(&path, br#"{"port": 8080}"#).unwrap();
+/// let v: serde_json::Value = load_json(&path).unwrap();
+/// assert_eq!(v["port"], 8080);
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn load_json<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> io::Result<T> {
+    let bytes = fs::read(path)?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Serialize `value` as JSON and write it atomically (see [`write_atomic`]).
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{load_json, save_json_atomic, JsonFormat};
+/// let path = std::path::PathBuf::from("target/tmp_save_json.json");
+/// save_json_atomic(&path, &vec![1, 2, 3], JsonFormat::Compact).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "[1,2,3]");
+/// let back: Vec<i32> = load_json(&path).unwrap();
+/// assert_eq!(back, vec![1, 2, 3]);
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn save_json_atomic<T: Serialize + ?Sized, P: AsRef<Path>>(
+    path: P,
+    value: &T,
+    format: JsonFormat,
+) -> io::Result<()> {
+    let data = match format {
+        JsonFormat::Pretty => {
+            let mut data = serde_json::to_vec_pretty(value)?;
+            data.push(b'\n');
+            data
+        }
+        JsonFormat::Compact => serde_json::to_vec(value)?,
+    };
+    write_atomic(path, &data)
+}
+
+/// Load several JSON files and deep-merge them in order before deserializing.
+///
+/// Later files override earlier ones key by key; nested objects merge
+/// recursively and `null` never overwrites (see
+/// [`DeepMerge`](crate::deep::DeepMerge) for `serde_json::Value`). Every file
+/// must exist and parse.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{load_json_merged, write_atomic};
+/// let base = std::path::PathBuf::from("target/tmp_json_base.json");
+/// let local = std::path::PathBuf::from("target/tmp_json_local.json");
+/// write_atomic(&base, br#"{"server": {"host": "0.0.0.0", "port": 80}}"#).unwrap();
+/// write_atomic(&local, br#"{"server": {"port": 8080}}"#).unwrap();
+/// let cfg: serde_json::Value = load_json_merged(&[&base, &local]).unwrap();
+/// assert_eq!(cfg["server"]["host"], "0.0.0.0");
+/// assert_eq!(cfg["server"]["port"], 8080);
+/// std::fs::remove_file(&base).ok();
+/// std::fs::remove_file(&local).ok();
+/// ```
+pub fn load_json_merged<T: DeserializeOwned, P: AsRef<Path>>(paths: &[P]) -> io::Result<T> {
+    let mut merged = Value::Null;
+    for path in paths {
+        let layer: Value = load_json(path)?;
+        merged.deep_merge(&layer);
+    }
+    Ok(serde_json::from_value(merged)?)
+}
@@ -0,0 +1,236 @@
+//! Tiny durable key-value store for CLI tool state (behind the `fs` feature)
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{with_extension_appended, write_atomic, FileLock};
+use crate::strings::escape::{escape_json_string, unescape_json_string};
+
+/// String map persisted to a single file with [`write_atomic`].
+///
+/// The file holds one `key = "value"` line per entry (TOML-style basic
+/// strings; keys are quoted unless they are bare `[A-Za-z0-9_-]+`), sorted by
+/// key. Blank lines and `#` comments are ignored when loading. While a store
+/// is open it holds an exclusive [`FileLock`] on a sibling `<file>.lock`, so
+/// two processes cannot clobber each other's writes.
+///
+/// By default every [`set`](KvStore::set)/[`remove`](KvStore::remove) is
+/// written through; with [`with_write_behind`](KvStore::with_write_behind)
+/// changes stay in memory until [`flush`](KvStore::flush) or drop.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::KvStore;
+/// let path = std::path::PathBuf::from("target/tmp_kv_doc.toml");
+/// {
+///     let mut store = KvStore::open(&path).unwrap();
+///     store.set("last_run", "2024-05-01").unwrap();
+///     store.set("user name", "Ada").unwrap();
+/// }
+/// let store = KvStore::open(&path).unwrap();
+/// assert_eq!(store.get("user name"), Some("Ada"));
+/// assert_eq!(store.keys(), ["last_run", "user name"]);
+/// # drop(store);
+/// # std::fs::remove_file(&path).ok();
+/// # std::fs::remove_file("target/tmp_kv_doc.toml.lock").ok();
+/// ```
+#[derive(Debug)]
+pub struct KvStore {
+    path: PathBuf,
+    entries: HashMap<String, String>,
+    write_behind: bool,
+    dirty: bool,
+    _lock: FileLock,
+}
+
+impl KvStore {
+    /// Open (or create on first write) the store at `path`, blocking until
+    /// its lock is available.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let lock = FileLock::lock_exclusive(with_extension_appended(path, "lock"))?;
+        Self::load(path, lock)
+    }
+
+    /// Open the store without blocking; `Ok(None)` if another handle holds it.
+    pub fn try_open<P: AsRef<Path>>(path: P) -> io::Result<Option<Self>> {
+        let path = path.as_ref();
+        match FileLock::try_lock(with_extension_appended(path, "lock"))? {
+            Some(lock) => Self::load(path, lock).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn load(path: &Path, lock: FileLock) -> io::Result<Self> {
+        let entries = match fs::read_to_string(path) {
+            Ok(text) => parse(&text)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+            write_behind: false,
+            dirty: false,
+            _lock: lock,
+        })
+    }
+
+    /// Keep changes in memory until [`flush`](KvStore::flush) (or drop).
+    pub fn with_write_behind(mut self, enabled: bool) -> Self {
+        self.write_behind = enabled;
+        self
+    }
+
+    /// Path of the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Value stored under `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Store `value` under `key`, returning the previous value.
+    pub fn set(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> io::Result<Option<String>> {
+        let old = self.entries.insert(key.into(), value.into());
+        self.changed()?;
+        Ok(old)
+    }
+
+    /// Remove `key`, returning its value; the file is untouched if it was absent.
+    pub fn remove(&mut self, key: &str) -> io::Result<Option<String>> {
+        let old = self.entries.remove(key);
+        if old.is_some() {
+            self.changed()?;
+        }
+        Ok(old)
+    }
+
+    /// All keys in sorted order.
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.entries.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if the store has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// True if there are changes not yet written to disk.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Write pending changes to disk (a no-op when nothing changed).
+    pub fn flush(&mut self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        write_atomic(&self.path, render(&self.entries).as_bytes())?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    fn changed(&mut self) -> io::Result<()> {
+        self.dirty = true;
+        if self.write_behind {
+            Ok(())
+        } else {
+            self.flush()
+        }
+    }
+}
+
+impl Drop for KvStore {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+fn render(entries: &HashMap<String, String>) -> String {
+    let mut pairs: Vec<_> = entries.iter().collect();
+    pairs.sort_unstable();
+    let mut out = String::new();
+    for (key, value) in pairs {
+        if is_bare_key(key) {
+            out.push_str(key);
+        } else {
+            out.push('"');
+            out.push_str(&escape_json_string(key));
+            out.push('"');
+        }
+        out.push_str(" = \"");
+        out.push_str(&escape_json_string(value));
+        out.push_str("\"\n");
+    }
+    out
+}
+
+/// Split a leading quoted string off `s` (which starts after the opening quote).
+fn quoted(s: &str) -> Option<(String, &str)> {
+    let mut escaped = false;
+    for (i, ch) in s.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some((unescape_json_string(&s[..i])?, &s[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_line(line: &str) -> Option<(String, String)> {
+    let (key, rest) = match line.strip_prefix('"') {
+        Some(s) => quoted(s)?,
+        None => {
+            let end = line.find(|c: char| c == '=' || c.is_whitespace())?;
+            let key = &line[..end];
+            is_bare_key(key).then(|| (key.to_string(), &line[end..]))?
+        }
+    };
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let (value, tail) = quoted(rest.strip_prefix('"')?)?;
+    let tail = tail.trim_start();
+    (tail.is_empty() || tail.starts_with('#')).then_some((key, value))
+}
+
+fn parse(text: &str) -> io::Result<HashMap<String, String>> {
+    let mut entries = HashMap::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = parse_line(line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed key-value entry on line {}", idx + 1),
+            )
+        })?;
+        entries.insert(key, value);
+    }
+    Ok(entries)
+}
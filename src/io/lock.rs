@@ -0,0 +1,106 @@
+//! Advisory file locks (behind the `fs` feature)
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs4::fs_std::FileExt;
+
+/// RAII guard for an advisory lock on a file.
+///
+/// Uses `flock` on Unix and `LockFileEx` on Windows. The lock is released when
+/// the guard is dropped (or via [`FileLock::unlock`]). Locks are advisory:
+/// they only coordinate processes that also take them. The file is created if
+/// it does not exist and is never truncated.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::FileLock;
+/// let path = std::path::PathBuf::from("target/tmp_file_lock.lock");
+/// let guard = FileLock::lock_exclusive(&path).unwrap();
+/// assert!(FileLock::try_lock(&path).unwrap().is_none());
+/// drop(guard);
+/// assert!(FileLock::try_lock(&path).unwrap().is_some());
+/// std::fs::remove_file(&path).ok();
+/// ```
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+    path: PathBuf,
+}
+
+fn open_lock_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+impl FileLock {
+    /// Block until an exclusive lock on `path` is acquired.
+    pub fn lock_exclusive<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = open_lock_file(path)?;
+        FileExt::lock_exclusive(&file)?;
+        Ok(Self::guard(file, path))
+    }
+
+    /// Block until a shared lock on `path` is acquired.
+    ///
+    /// Any number of shared locks may be held at once, but none while an
+    /// exclusive lock is held.
+    pub fn lock_shared<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = open_lock_file(path)?;
+        FileExt::lock_shared(&file)?;
+        Ok(Self::guard(file, path))
+    }
+
+    /// Try to take an exclusive lock without blocking.
+    ///
+    /// Returns `Ok(None)` if another lock is currently held.
+    pub fn try_lock<P: AsRef<Path>>(path: P) -> io::Result<Option<Self>> {
+        let path = path.as_ref();
+        let file = open_lock_file(path)?;
+        Ok(FileExt::try_lock_exclusive(&file)?.then(|| Self::guard(file, path)))
+    }
+
+    /// Try to take a shared lock without blocking.
+    ///
+    /// Returns `Ok(None)` if an exclusive lock is currently held.
+    pub fn try_lock_shared<P: AsRef<Path>>(path: P) -> io::Result<Option<Self>> {
+        let path = path.as_ref();
+        let file = open_lock_file(path)?;
+        Ok(FileExt::try_lock_shared(&file)?.then(|| Self::guard(file, path)))
+    }
+
+    fn guard(file: File, path: &Path) -> Self {
+        Self {
+            file,
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// The locked file, e.g. to read or write shared state through it.
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    /// Path of the locked file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Release the lock, reporting any error (dropping releases silently).
+    pub fn unlock(self) -> io::Result<()> {
+        FileExt::unlock(&self.file)
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(&self.file);
+    }
+}
@@ -1,8 +1,15 @@
 //! IO helpers (std-only).
 //!
-//! Utilities for simple file and directory operations, including reading
-//! lines, atomic writes, ensuring directories exist, copying directory trees,
-//! and optional recursive file search (behind the `fs` feature).
+//! Utilities for simple file and directory operations, including reading lines,
+//! atomic writes, line-ending/BOM handling and lossy decoding
+//! ([`read_to_string_lossy`]), ensuring directories exist, copying directory
+//! trees, chunked copies and reads with progress callbacks
+//! ([`copy_with_progress`], [`read_with_progress`]), measuring and pruning
+//! directory trees, line-oriented content search ([`grep`]), streaming
+//! checksums and file/tree comparison ([`file_hash`], [`dir_diff`]), sandboxed
+//! resolution of untrusted paths ([`resolve_within`]), optional recursive file
+//! search, advisory file locks, and a small durable key-value store (behind the
+//! `fs` feature), and JSON config loading (behind the `json` feature).
 //!
 //! Examples:
 //! ```rust
@@ -23,6 +30,35 @@
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+mod compare;
+mod grep;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "fs")]
+mod kv;
+#[cfg(feature = "fs")]
+mod lock;
+mod progress;
+mod safe_path;
+mod text;
+pub use compare::{dir_diff, file_hash, files_identical, Algo, DirDiff};
+pub use grep::{grep, GrepOptions};
+#[cfg(feature = "json")]
+pub use json::{load_json, load_json_merged, save_json_atomic, JsonFormat};
+#[cfg(feature = "fs")]
+pub use kv::KvStore;
+#[cfg(feature = "fs")]
+pub use lock::FileLock;
+pub use progress::{
+    copy_dir_with_progress, copy_stream_with_progress, copy_with_progress, copy_with_progress_buf,
+    read_with_progress, read_with_progress_buf,
+};
+pub use safe_path::{file_extension_lower, is_hidden, resolve_within, with_extension_appended};
+pub use text::{
+    detect_line_ending, normalize_line_endings, read_to_string_lossy, strip_bom, LineEnding,
+};
 
 /// Read a text file as lines into `Vec<String>`.
 ///
@@ -115,6 +151,140 @@ pub fn copy_dir<P: AsRef<Path>>(src: P, dst: P) -> io::Result<()> {
     rec(src.as_ref(), dst.as_ref())
 }
 
+// Visit every non-directory entry below `dir` without following symlinks.
+fn walk_files(
+    dir: &Path,
+    f: &mut dyn FnMut(&Path, &fs::Metadata) -> io::Result<()>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let meta = fs::symlink_metadata(&path)?;
+        if meta.is_dir() {
+            walk_files(&path, f)?;
+        } else {
+            f(&path, &meta)?;
+        }
+    }
+    Ok(())
+}
+
+/// Total size in bytes of all files below `path`.
+///
+/// Symlinks are counted by their own size and are not followed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{dir_size, ensure_dir, write_atomic};
+/// let dir = std::path::PathBuf::from("target/tmp_dir_size");
+/// ensure_dir(dir.join("sub")).unwrap();
+/// write_atomic(dir.join("a.txt"), b"abc").unwrap();
+/// write_atomic(dir.join("sub/b.txt"), b"de").unwrap();
+/// assert_eq!(dir_size(&dir).unwrap(), 5);
+/// std::fs::remove_dir_all(&dir).ok();
+/// ```
+pub fn dir_size<P: AsRef<Path>>(path: P) -> io::Result<u64> {
+    let mut total = 0u64;
+    walk_files(path.as_ref(), &mut |_, meta| {
+        total += meta.len();
+        Ok(())
+    })?;
+    Ok(total)
+}
+
+/// Number of files (non-directories) below `path`, recursively.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{count_files, ensure_dir, write_atomic};
+/// let dir = std::path::PathBuf::from("target/tmp_count_files");
+/// ensure_dir(dir.join("sub")).unwrap();
+/// write_atomic(dir.join("a.txt"), b"").unwrap();
+/// write_atomic(dir.join("sub/b.txt"), b"").unwrap();
+/// assert_eq!(count_files(&dir).unwrap(), 2);
+/// std::fs::remove_dir_all(&dir).ok();
+/// ```
+pub fn count_files<P: AsRef<Path>>(path: P) -> io::Result<usize> {
+    let mut count = 0usize;
+    walk_files(path.as_ref(), &mut |_, _| {
+        count += 1;
+        Ok(())
+    })?;
+    Ok(count)
+}
+
+/// Remove empty directories below `path`, bottom-up.
+///
+/// Directories that only contain empty directories are removed as well. The
+/// root `path` itself is kept. Returns the number of directories removed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{ensure_dir, remove_empty_dirs};
+/// let dir = std::path::PathBuf::from("target/tmp_remove_empty");
+/// ensure_dir(dir.join("a/b/c")).unwrap();
+/// assert_eq!(remove_empty_dirs(&dir).unwrap(), 3);
+/// assert!(dir.exists());
+/// std::fs::remove_dir_all(&dir).ok();
+/// ```
+pub fn remove_empty_dirs<P: AsRef<Path>>(path: P) -> io::Result<usize> {
+    // Returns (removed count, whether `dir` is now empty)
+    fn rec(dir: &Path) -> io::Result<(usize, bool)> {
+        let mut removed = 0usize;
+        let mut empty = true;
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if fs::symlink_metadata(&path)?.is_dir() {
+                let (n, child_empty) = rec(&path)?;
+                removed += n;
+                if child_empty {
+                    fs::remove_dir(&path)?;
+                    removed += 1;
+                } else {
+                    empty = false;
+                }
+            } else {
+                empty = false;
+            }
+        }
+        Ok((removed, empty))
+    }
+    Ok(rec(path.as_ref())?.0)
+}
+
+/// Delete files below `path` whose modification time is older than `older_than`.
+///
+/// Directories are left in place; combine with [`remove_empty_dirs`] to prune
+/// them. Returns the number of files deleted.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{clean_dir, ensure_dir, write_atomic};
+/// use std::time::Duration;
+/// let dir = std::path::PathBuf::from("target/tmp_clean_dir");
+/// ensure_dir(&dir).unwrap();
+/// write_atomic(dir.join("fresh.log"), b"x").unwrap();
+/// // Nothing is a day old yet
+/// assert_eq!(clean_dir(&dir, Duration::from_secs(86_400)).unwrap(), 0);
+/// std::fs::remove_dir_all(&dir).ok();
+/// ```
+pub fn clean_dir<P: AsRef<Path>>(path: P, older_than: Duration) -> io::Result<usize> {
+    let now = SystemTime::now();
+    let mut deleted = 0usize;
+    walk_files(path.as_ref(), &mut |file, meta| {
+        let age = now
+            .duration_since(meta.modified()?)
+            .unwrap_or(Duration::ZERO);
+        if age > older_than {
+            fs::remove_file(file)?;
+            deleted += 1;
+        }
+        Ok(())
+    })?;
+    Ok(deleted)
+}
+
 #[cfg(feature = "fs")]
 /// Recursively find files whose names contain `pattern` (case-insensitive)
 pub fn find_files<P: AsRef<Path>>(root: P, pattern: &str) -> io::Result<Vec<PathBuf>> {
@@ -135,8 +305,5 @@ pub fn find_files<P: AsRef<Path>>(root: P, pattern: &str) -> io::Result<Vec<Path
 #[cfg(not(feature = "fs"))]
 /// Find files under root matching pattern (stub)
 pub fn find_files<P: AsRef<Path>>(_root: P, _pattern: &str) -> io::Result<Vec<PathBuf>> {
-    Err(io::Error::new(
-        io::ErrorKind::Other,
-        "find_files requires the 'fs' feature",
-    ))
+    Err(io::Error::other("find_files requires the 'fs' feature"))
 }
@@ -0,0 +1,258 @@
+//! Chunked copying and reading with progress callbacks
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+const CHUNK: usize = 64 * 1024;
+
+/// Copy `reader` into `writer` in chunks of `buffer_size` bytes, reporting
+/// progress as `progress(bytes_copied, total)`.
+///
+/// `progress` is called once with `0` before the first read and again after
+/// every chunk is written; `total` is passed through unchanged (use `None`
+/// when the length is unknown). A `buffer_size` of zero is treated as one.
+/// Interrupted reads are retried. Returns the number of bytes copied.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::copy_stream_with_progress;
+/// let data = vec![7u8; 10_000];
+/// let mut out = Vec::new();
+/// let mut calls = Vec::new();
+/// let n = copy_stream_with_progress(&mut &data[..], &mut out, Some(10_000), 4096, |done, total| {
+///     calls.push((done, total));
+/// })
+/// .unwrap();
+/// assert_eq!(n, 10_000);
+/// assert_eq!(out, data);
+/// assert_eq!(calls.first(), Some(&(0, Some(10_000))));
+/// assert_eq!(calls.last(), Some(&(10_000, Some(10_000))));
+/// assert_eq!(calls.len(), 4);
+/// ```
+pub fn copy_stream_with_progress<R, W, F>(
+    reader: &mut R,
+    writer: &mut W,
+    total: Option<u64>,
+    buffer_size: usize,
+    mut progress: F,
+) -> io::Result<u64>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+    F: FnMut(u64, Option<u64>),
+{
+    let mut buf = vec![0u8; buffer_size.max(1)];
+    let mut copied = 0u64;
+    progress(0, total);
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => return Ok(copied),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..n])?;
+        copied += n as u64;
+        progress(copied, total);
+    }
+}
+
+/// Copy the file `src` to `dst` in 64 KiB chunks, calling
+/// `progress(bytes_copied, total_bytes)` as it goes.
+///
+/// Like [`std::fs::copy`], `dst` is created or truncated, its permissions are
+/// set to match `src`, and the number of bytes copied is returned.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{copy_with_progress, write_atomic};
+/// let src = std::path::PathBuf::from("target/tmp_progress_src.bin");
+/// let dst = std::path::PathBuf::from("target/tmp_progress_dst.bin");
+/// write_atomic(&src, &[1u8; 200_000]).unwrap();
+/// let mut last = (0, 0);
+/// copy_with_progress(&src, &dst, |done, total| last = (done, total)).unwrap();
+/// assert_eq!(last, (200_000, 200_000));
+/// assert_eq!(std::fs::read(&dst).unwrap().len(), 200_000);
+/// std::fs::remove_file(&src).ok();
+/// std::fs::remove_file(&dst).ok();
+/// ```
+pub fn copy_with_progress<P, Q, F>(src: P, dst: Q, progress: F) -> io::Result<u64>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    copy_with_progress_buf(src, dst, CHUNK, progress)
+}
+
+/// [`copy_with_progress`] in chunks of `buffer_size` bytes instead of 64 KiB.
+///
+/// Smaller buffers report progress more often; a `buffer_size` of zero is
+/// treated as one.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{copy_with_progress_buf, write_atomic};
+/// let src = std::path::PathBuf::from("target/tmp_progress_buf_src.bin");
+/// let dst = std::path::PathBuf::from("target/tmp_progress_buf_dst.bin");
+/// write_atomic(&src, &[1u8; 1000]).unwrap();
+/// let mut calls = 0;
+/// copy_with_progress_buf(&src, &dst, 100, |_, _| calls += 1).unwrap();
+/// assert_eq!(calls, 11);
+/// std::fs::remove_file(&src).ok();
+/// std::fs::remove_file(&dst).ok();
+/// ```
+pub fn copy_with_progress_buf<P, Q, F>(
+    src: P,
+    dst: Q,
+    buffer_size: usize,
+    mut progress: F,
+) -> io::Result<u64>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let mut reader = File::open(src.as_ref())?;
+    let meta = reader.metadata()?;
+    let mut writer = File::create(dst.as_ref())?;
+    let n = copy_stream_with_progress(
+        &mut reader,
+        &mut writer,
+        Some(meta.len()),
+        buffer_size,
+        |done, _| progress(done, meta.len()),
+    )?;
+    writer.flush()?;
+    fs::set_permissions(dst.as_ref(), meta.permissions())?;
+    Ok(n)
+}
+
+/// Read a whole file in 64 KiB chunks, calling
+/// `progress(bytes_read, total_bytes)` as it goes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{read_with_progress, write_atomic};
+/// let path = std::path::PathBuf::from("target/tmp_read_progress.txt");
+/// write_atomic(&path, b"hello").unwrap();
+/// let mut seen = Vec::new();
+/// let bytes = read_with_progress(&path, |done, total| seen.push((done, total))).unwrap();
+/// assert_eq!(bytes, b"hello");
+/// assert_eq!(seen, [(0, 5), (5, 5)]);
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn read_with_progress<P, F>(path: P, progress: F) -> io::Result<Vec<u8>>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    read_with_progress_buf(path, CHUNK, progress)
+}
+
+/// [`read_with_progress`] in chunks of `buffer_size` bytes instead of 64 KiB.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{read_with_progress_buf, write_atomic};
+/// let path = std::path::PathBuf::from("target/tmp_read_progress_buf.txt");
+/// write_atomic(&path, b"hello").unwrap();
+/// let mut seen = Vec::new();
+/// let bytes = read_with_progress_buf(&path, 2, |done, _| seen.push(done)).unwrap();
+/// assert_eq!(bytes, b"hello");
+/// assert_eq!(seen, [0, 2, 4, 5]);
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn read_with_progress_buf<P, F>(
+    path: P,
+    buffer_size: usize,
+    mut progress: F,
+) -> io::Result<Vec<u8>>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    let mut reader = File::open(path)?;
+    let total = reader.metadata()?.len();
+    let mut out = Vec::with_capacity(usize::try_from(total).unwrap_or(0));
+    copy_stream_with_progress(
+        &mut reader,
+        &mut out,
+        Some(total),
+        buffer_size,
+        |done, _| progress(done, total),
+    )?;
+    Ok(out)
+}
+
+// Sum of file sizes `copy_dir` would copy (symlinks followed, like `fs::copy`)
+fn tree_size(dir: &Path) -> io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            total += tree_size(&entry.path())?;
+        } else {
+            total += fs::metadata(entry.path())?.len();
+        }
+    }
+    Ok(total)
+}
+
+/// Recursively copy a directory tree like [`copy_dir`](super::copy_dir),
+/// calling `progress(bytes_copied, total_bytes)` with running totals across
+/// all files.
+///
+/// The total is measured up front, so it can be off if the tree changes
+/// while copying.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{copy_dir_with_progress, ensure_dir, write_atomic};
+/// use std::path::PathBuf;
+/// let src = PathBuf::from("target/tmp_progress_tree_src");
+/// let dst = PathBuf::from("target/tmp_progress_tree_dst");
+/// ensure_dir(src.join("sub")).unwrap();
+/// write_atomic(src.join("a.txt"), b"abc").unwrap();
+/// write_atomic(src.join("sub/b.txt"), b"defg").unwrap();
+/// let mut last = (0, 0);
+/// copy_dir_with_progress(&src, &dst, |done, total| last = (done, total)).unwrap();
+/// assert_eq!(last, (7, 7));
+/// assert!(dst.join("sub/b.txt").exists());
+/// std::fs::remove_dir_all(&src).ok();
+/// std::fs::remove_dir_all(&dst).ok();
+/// ```
+pub fn copy_dir_with_progress<P, F>(src: P, dst: P, mut progress: F) -> io::Result<u64>
+where
+    P: AsRef<Path>,
+    F: FnMut(u64, u64),
+{
+    fn rec(
+        src: &Path,
+        dst: &Path,
+        copied: &mut u64,
+        progress: &mut dyn FnMut(u64),
+    ) -> io::Result<()> {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            let sp = entry.path();
+            let dp = dst.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                rec(&sp, &dp, copied, progress)?;
+            } else {
+                let base = *copied;
+                *copied += copy_with_progress(&sp, &dp, |done, _| progress(base + done))?;
+            }
+        }
+        Ok(())
+    }
+    let total = tree_size(src.as_ref())?;
+    let mut copied = 0;
+    progress(0, total);
+    rec(src.as_ref(), dst.as_ref(), &mut copied, &mut |done| {
+        progress(done, total)
+    })?;
+    Ok(copied)
+}
@@ -0,0 +1,125 @@
+//! Path handling for untrusted input: sandboxed resolution and small helpers
+
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+fn escape_error(user_path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("path escapes root: {}", user_path.display()),
+    )
+}
+
+/// Resolve `user_path` relative to `root`, refusing anything that lands outside.
+///
+/// `root` must exist and is canonicalized. `user_path` must be relative; it is
+/// walked component by component, following symlinks for the parts that
+/// exist, so both `..` traversal and symlinks pointing out of `root` are
+/// rejected with `PermissionDenied`. Absolute paths fail with `InvalidInput`.
+/// The target itself doesn't need to exist, which makes this usable for files
+/// about to be created.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{ensure_dir, resolve_within};
+/// let root = std::path::PathBuf::from("target/tmp_resolve_within");
+/// ensure_dir(&root).unwrap();
+/// let inside = resolve_within(&root, "uploads/../avatar.png").unwrap();
+/// assert!(inside.ends_with("tmp_resolve_within/avatar.png"));
+/// assert!(resolve_within(&root, "../../etc/passwd").is_err());
+/// assert!(resolve_within(&root, "/etc/passwd").is_err());
+/// ```
+pub fn resolve_within<R: AsRef<Path>, P: AsRef<Path>>(
+    root: R,
+    user_path: P,
+) -> io::Result<PathBuf> {
+    let root = fs::canonicalize(root)?;
+    let user_path = user_path.as_ref();
+    let mut resolved = root.clone();
+    for component in user_path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if resolved == root {
+                    return Err(escape_error(user_path));
+                }
+                resolved.pop();
+            }
+            Component::Normal(part) => {
+                resolved.push(part);
+                // Follow symlinks for parts that exist so links can't leave root
+                if fs::symlink_metadata(&resolved).is_ok() {
+                    resolved = fs::canonicalize(&resolved)?;
+                    if !resolved.starts_with(&root) {
+                        return Err(escape_error(user_path));
+                    }
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("expected a relative path: {}", user_path.display()),
+                ));
+            }
+        }
+    }
+    Ok(resolved)
+}
+
+/// True if the file name starts with `.` (Unix convention; `.` and `..` excluded).
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::is_hidden;
+/// assert!(is_hidden("config/.env"));
+/// assert!(!is_hidden("config/app.toml"));
+/// assert!(!is_hidden(".."));
+/// ```
+pub fn is_hidden<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with('.') && n != "." && n != "..")
+}
+
+/// Lowercased extension, if any.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::file_extension_lower;
+/// assert_eq!(file_extension_lower("photo.JPG").as_deref(), Some("jpg"));
+/// assert_eq!(file_extension_lower(".bashrc"), None);
+/// assert_eq!(file_extension_lower("Makefile"), None);
+/// ```
+pub fn file_extension_lower<P: AsRef<Path>>(path: P) -> Option<String> {
+    path.as_ref()
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+}
+
+/// Append `.ext` to the file name, keeping any existing extension.
+///
+/// Paths without a file name (e.g. ending in `..`) are returned unchanged.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::with_extension_appended;
+/// use std::path::PathBuf;
+/// assert_eq!(with_extension_appended("logs/app.log", "gz"), PathBuf::from("logs/app.log.gz"));
+/// assert_eq!(with_extension_appended("data", ".bak"), PathBuf::from("data.bak"));
+/// ```
+pub fn with_extension_appended<P: AsRef<Path>>(path: P, ext: &str) -> PathBuf {
+    let path = path.as_ref();
+    let Some(name) = path.file_name() else {
+        return path.to_path_buf();
+    };
+    let ext = ext.trim_start_matches('.');
+    let mut name = OsString::from(name);
+    if !ext.is_empty() {
+        name.push(".");
+        name.push(ext);
+    }
+    path.with_file_name(name)
+}
@@ -0,0 +1,165 @@
+//! Line endings, byte-order marks, and forgiving text decoding
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::write_atomic;
+
+/// Line terminator style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\n` (Unix)
+    Lf,
+    /// `\r\n` (Windows)
+    CrLf,
+}
+
+impl LineEnding {
+    /// The terminator itself
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+
+    /// The platform's native terminator
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            LineEnding::CrLf
+        } else {
+            LineEnding::Lf
+        }
+    }
+}
+
+/// Count `(lf, crlf, lone_cr)` terminators
+fn count_endings(bytes: &[u8]) -> (usize, usize, usize) {
+    let (mut lf, mut crlf, mut cr) = (0, 0, 0);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                crlf += 1;
+                i += 1;
+            }
+            b'\r' => cr += 1,
+            b'\n' => lf += 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    (lf, crlf, cr)
+}
+
+fn convert_endings(bytes: &[u8], ending: LineEnding) -> Vec<u8> {
+    let eol = ending.as_str().as_bytes();
+    let mut out = Vec::with_capacity(bytes.len() + bytes.len() / 16);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' => {
+                if bytes.get(i + 1) == Some(&b'\n') {
+                    i += 1;
+                }
+                out.extend_from_slice(eol);
+            }
+            b'\n' => out.extend_from_slice(eol),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Detect the dominant line ending of a file.
+///
+/// Returns `Ok(None)` if the file has no line breaks. When styles are mixed
+/// the more frequent one wins, with ties going to [`LineEnding::Lf`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{detect_line_ending, LineEnding};
+/// let path = std::path::PathBuf::from("target/tmp_detect_eol.txt");
+/// std::fs::write(&path, "a\r\nb\r\nc\n").unwrap();
+/// assert_eq!(detect_line_ending(&path).unwrap(), Some(LineEnding::CrLf));
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn detect_line_ending<P: AsRef<Path>>(path: P) -> io::Result<Option<LineEnding>> {
+    let (lf, crlf, _) = count_endings(&fs::read(path)?);
+    Ok(match (lf, crlf) {
+        (0, 0) => None,
+        (lf, crlf) if crlf > lf => Some(LineEnding::CrLf),
+        _ => Some(LineEnding::Lf),
+    })
+}
+
+/// Rewrite a file so every line break (`\n`, `\r\n`, or a lone `\r`) uses `ending`.
+///
+/// Works on raw bytes, so any ASCII-compatible encoding is preserved. The file
+/// is replaced with [`write_atomic`] and only when something changes; the
+/// return value says whether it did.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::{normalize_line_endings, LineEnding};
+/// let path = std::path::PathBuf::from("target/tmp_normalize_eol.txt");
+/// std::fs::write(&path, "a\r\nb\rc\n").unwrap();
+/// assert!(normalize_line_endings(&path, LineEnding::Lf).unwrap());
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "a\nb\nc\n");
+/// assert!(!normalize_line_endings(&path, LineEnding::Lf).unwrap());
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn normalize_line_endings<P: AsRef<Path>>(path: P, ending: LineEnding) -> io::Result<bool> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)?;
+    let converted = convert_endings(&bytes, ending);
+    if converted == bytes {
+        return Ok(false);
+    }
+    write_atomic(path, &converted)?;
+    Ok(true)
+}
+
+/// Strip a leading byte-order mark (`U+FEFF`) if present.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::strip_bom;
+/// assert_eq!(strip_bom("\u{FEFF}id,name"), "id,name");
+/// assert_eq!(strip_bom("id,name"), "id,name");
+/// ```
+pub fn strip_bom(s: &str) -> &str {
+    s.strip_prefix('\u{FEFF}').unwrap_or(s)
+}
+
+/// Read a text file without failing on bad encoding.
+///
+/// A UTF-8 BOM is removed; UTF-16 files with a BOM are decoded as UTF-16; any
+/// invalid sequences become `U+FFFD`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::io::read_to_string_lossy;
+/// let path = std::path::PathBuf::from("target/tmp_lossy.txt");
+/// std::fs::write(&path, b"\xEF\xBB\xBFcaf\xE9").unwrap();
+/// assert_eq!(read_to_string_lossy(&path).unwrap(), "caf\u{FFFD}");
+/// std::fs::remove_file(&path).ok();
+/// ```
+pub fn read_to_string_lossy<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let bytes = fs::read(path)?;
+    let utf16 = |rest: &[u8], from: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = rest
+            .chunks(2)
+            .map(|c| from([c[0], *c.get(1).unwrap_or(&0)]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+    Ok(match bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8_lossy(&bytes).into_owned(),
+    })
+}
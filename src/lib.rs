@@ -40,6 +40,8 @@ pub mod types;
 #[cfg(feature = "std")]
 pub mod collections;
 #[cfg(feature = "std")]
+pub mod concurrency;
+#[cfg(feature = "std")]
 pub mod encoding;
 #[cfg(feature = "std")]
 pub mod hash;
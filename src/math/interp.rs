@@ -0,0 +1,272 @@
+//! Interpolation helpers: smoothstep, range remapping, Bézier and
+//! Catmull-Rom curves, and easing functions.
+//!
+//! Curve functions are generic over anything that can be added and scaled by
+//! an `f64`, so they work for plain `f64` as well as
+//! [`Vec2`](crate::math::linalg::Vec2)/[`Vec3`](crate::math::linalg::Vec3).
+//!
+//! Example:
+//! ```rust
+//! use toolchest::math::interp::{cubic_bezier, inverse_lerp, smoothstep};
+//! use toolchest::math::linalg::Vec2;
+//!
+//! assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+//! assert_eq!(inverse_lerp(10.0, 20.0, 15.0), 0.5);
+//! let mid = cubic_bezier(Vec2::new(0.0, 0.0), Vec2::new(0.0, 1.0), Vec2::new(1.0, 1.0), Vec2::new(1.0, 0.0), 0.5);
+//! assert_eq!(mid, Vec2::new(0.5, 0.75));
+//! ```
+
+use std::ops::{Add, Mul};
+
+/// Position of `v` between `a` and `b` (0 at `a`, 1 at `b`; not clamped).
+///
+/// Returns 0 when `a == b`.
+pub fn inverse_lerp(a: f64, b: f64, v: f64) -> f64 {
+    if a == b {
+        0.0
+    } else {
+        (v - a) / (b - a)
+    }
+}
+
+/// Map `x` from `[in_min, in_max]` to `[out_min, out_max]`, clamping to the
+/// output range.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::interp::remap_clamped;
+/// assert_eq!(remap_clamped(150.0, 0.0, 100.0, 0.0, 1.0), 1.0);
+/// assert_eq!(remap_clamped(25.0, 0.0, 100.0, 10.0, 0.0), 7.5);
+/// ```
+pub fn remap_clamped(x: f64, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> f64 {
+    let t = inverse_lerp(in_min, in_max, x).clamp(0.0, 1.0);
+    out_min + (out_max - out_min) * t
+}
+
+/// Hermite smoothstep: 0 below `edge0`, 1 above `edge1`, smooth in between.
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = inverse_lerp(edge0, edge1, x).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Ken Perlin's smootherstep (zero first and second derivatives at the edges).
+pub fn smootherstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = inverse_lerp(edge0, edge1, x).clamp(0.0, 1.0);
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Quadratic Bézier curve at `t` in `[0, 1]`.
+pub fn quadratic_bezier<T>(p0: T, p1: T, p2: T, t: f64) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    let u = 1.0 - t;
+    p0 * (u * u) + p1 * (2.0 * u * t) + p2 * (t * t)
+}
+
+/// Cubic Bézier curve at `t` in `[0, 1]`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::interp::cubic_bezier;
+/// assert_eq!(cubic_bezier(0.0, 0.0, 1.0, 1.0, 0.5), 0.5);
+/// assert_eq!(cubic_bezier(0.0, 0.0, 1.0, 1.0, 1.0), 1.0);
+/// ```
+pub fn cubic_bezier<T>(p0: T, p1: T, p2: T, p3: T, t: f64) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
+
+/// Uniform Catmull-Rom segment between `p1` and `p2` at `t` in `[0, 1]`,
+/// using `p0` and `p3` as neighbouring control points.
+pub fn catmull_rom_segment<T>(p0: T, p1: T, p2: T, p3: T, t: f64) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (-0.5 * t3 + t2 - 0.5 * t)
+        + p1 * (1.5 * t3 - 2.5 * t2 + 1.0)
+        + p2 * (-1.5 * t3 + 2.0 * t2 + 0.5 * t)
+        + p3 * (0.5 * t3 - 0.5 * t2)
+}
+
+/// Point on a Catmull-Rom spline passing through every point of `points`.
+///
+/// `t` runs from 0 (first point) to 1 (last point) and is clamped; each
+/// segment gets an equal share. End points are duplicated as their own
+/// neighbours. Returns `None` for an empty slice.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::interp::catmull_rom;
+/// let ys = [0.0, 1.0, 0.0, 1.0];
+/// assert_eq!(catmull_rom(&ys, 0.0), Some(0.0));
+/// assert_eq!(catmull_rom(&ys, 1.0 / 3.0), Some(1.0)); // passes through points
+/// assert_eq!(catmull_rom::<f64>(&[], 0.5), None);
+/// ```
+pub fn catmull_rom<T>(points: &[T], t: f64) -> Option<T>
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T>,
+{
+    let last = points.len().checked_sub(1)?;
+    if last == 0 {
+        return Some(points[0]);
+    }
+    let pos = t.clamp(0.0, 1.0) * last as f64;
+    let seg = (pos.floor() as usize).min(last - 1);
+    let local = pos - seg as f64;
+    let at = |i: isize| points[i.clamp(0, last as isize) as usize];
+    let i = seg as isize;
+    Some(catmull_rom_segment(
+        at(i - 1),
+        at(i),
+        at(i + 1),
+        at(i + 2),
+        local,
+    ))
+}
+
+/// Easing curves mapping progress `t` in `[0, 1]` to eased progress.
+///
+/// All functions return 0 at `t = 0` and 1 at `t = 1`; `back` variants
+/// overshoot in between. Inputs are not clamped.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::interp::easing;
+/// assert_eq!(easing::ease_in_out_quad(0.5), 0.5);
+/// assert!(easing::ease_in_quad(0.25) < 0.25);
+/// assert!(easing::ease_out_quad(0.25) > 0.25);
+/// ```
+pub mod easing {
+    use std::f64::consts::PI;
+
+    /// No easing
+    pub fn linear(t: f64) -> f64 {
+        t
+    }
+
+    /// Quadratic, accelerating
+    pub fn ease_in_quad(t: f64) -> f64 {
+        t * t
+    }
+
+    /// Quadratic, decelerating
+    pub fn ease_out_quad(t: f64) -> f64 {
+        1.0 - (1.0 - t) * (1.0 - t)
+    }
+
+    /// Quadratic, accelerating then decelerating
+    pub fn ease_in_out_quad(t: f64) -> f64 {
+        if t < 0.5 {
+            2.0 * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+        }
+    }
+
+    /// Cubic, accelerating
+    pub fn ease_in_cubic(t: f64) -> f64 {
+        t * t * t
+    }
+
+    /// Cubic, decelerating
+    pub fn ease_out_cubic(t: f64) -> f64 {
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    /// Cubic, accelerating then decelerating
+    pub fn ease_in_out_cubic(t: f64) -> f64 {
+        if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        }
+    }
+
+    /// Sinusoidal, accelerating
+    pub fn ease_in_sine(t: f64) -> f64 {
+        1.0 - (t * PI / 2.0).cos()
+    }
+
+    /// Sinusoidal, decelerating
+    pub fn ease_out_sine(t: f64) -> f64 {
+        (t * PI / 2.0).sin()
+    }
+
+    /// Sinusoidal, accelerating then decelerating
+    pub fn ease_in_out_sine(t: f64) -> f64 {
+        -((PI * t).cos() - 1.0) / 2.0
+    }
+
+    /// Exponential, accelerating
+    pub fn ease_in_expo(t: f64) -> f64 {
+        if t <= 0.0 {
+            0.0
+        } else {
+            2f64.powf(10.0 * t - 10.0)
+        }
+    }
+
+    /// Exponential, decelerating
+    pub fn ease_out_expo(t: f64) -> f64 {
+        if t >= 1.0 {
+            1.0
+        } else {
+            1.0 - 2f64.powf(-10.0 * t)
+        }
+    }
+
+    /// Exponential, accelerating then decelerating
+    pub fn ease_in_out_expo(t: f64) -> f64 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else if t < 0.5 {
+            2f64.powf(20.0 * t - 10.0) / 2.0
+        } else {
+            (2.0 - 2f64.powf(-20.0 * t + 10.0)) / 2.0
+        }
+    }
+
+    const BACK: f64 = 1.70158;
+
+    /// Pulls back slightly before accelerating
+    pub fn ease_in_back(t: f64) -> f64 {
+        (BACK + 1.0) * t * t * t - BACK * t * t
+    }
+
+    /// Overshoots the target before settling
+    pub fn ease_out_back(t: f64) -> f64 {
+        let u = t - 1.0;
+        1.0 + (BACK + 1.0) * u * u * u + BACK * u * u
+    }
+
+    /// Bounces to rest at the end
+    pub fn ease_out_bounce(t: f64) -> f64 {
+        const N: f64 = 7.5625;
+        const D: f64 = 2.75;
+        if t < 1.0 / D {
+            N * t * t
+        } else if t < 2.0 / D {
+            let t = t - 1.5 / D;
+            N * t * t + 0.75
+        } else if t < 2.5 / D {
+            let t = t - 2.25 / D;
+            N * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / D;
+            N * t * t + 0.984375
+        }
+    }
+
+    /// Bounces away from the start
+    pub fn ease_in_bounce(t: f64) -> f64 {
+        1.0 - ease_out_bounce(1.0 - t)
+    }
+}
@@ -0,0 +1,351 @@
+//! Small fixed-size vectors, matrices, and 2D geometry helpers
+//!
+//! Matrices are row-major and multiply column vectors (`m * v`). `Mat3` doubles
+//! as a 2D affine transform.
+//!
+//! ```rust
+//! use toolchest::math::linalg::{Mat2, Mat3, Vec2};
+//! let m = Mat2::new([[2.0, 1.0], [1.0, 1.0]]);
+//! assert_eq!(m.determinant(), 1.0);
+//! assert_eq!(m * m.inverse().unwrap(), Mat2::IDENTITY);
+//! let t = Mat3::translation(1.0, 2.0) * Mat3::scale(2.0, 2.0);
+//! assert_eq!(t.transform_point(Vec2::new(1.0, 1.0)), Vec2::new(3.0, 4.0));
+//! ```
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// 2D vector
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec2 {
+    /// X component
+    pub x: f64,
+    /// Y component
+    pub y: f64,
+}
+
+/// 3D vector
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec3 {
+    /// X component
+    pub x: f64,
+    /// Y component
+    pub y: f64,
+    /// Z component
+    pub z: f64,
+}
+
+macro_rules! impl_vec_ops {
+    ($t:ident { $($f:ident),+ }) => {
+        impl Add for $t {
+            type Output = $t;
+            fn add(self, o: $t) -> $t { $t { $($f: self.$f + o.$f),+ } }
+        }
+        impl Sub for $t {
+            type Output = $t;
+            fn sub(self, o: $t) -> $t { $t { $($f: self.$f - o.$f),+ } }
+        }
+        impl Mul<f64> for $t {
+            type Output = $t;
+            fn mul(self, s: f64) -> $t { $t { $($f: self.$f * s),+ } }
+        }
+        impl Div<f64> for $t {
+            type Output = $t;
+            fn div(self, s: f64) -> $t { $t { $($f: self.$f / s),+ } }
+        }
+        impl Neg for $t {
+            type Output = $t;
+            fn neg(self) -> $t { $t { $($f: -self.$f),+ } }
+        }
+        impl AddAssign for $t {
+            fn add_assign(&mut self, o: $t) { $(self.$f += o.$f;)+ }
+        }
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, o: $t) { $(self.$f -= o.$f;)+ }
+        }
+        impl $t {
+            /// Dot product
+            pub fn dot(self, o: $t) -> f64 { 0.0 $(+ self.$f * o.$f)+ }
+            /// Euclidean length
+            pub fn length(self) -> f64 { self.dot(self).sqrt() }
+            /// Squared length (avoids the square root)
+            pub fn length_squared(self) -> f64 { self.dot(self) }
+            /// Distance to another point
+            pub fn distance(self, o: $t) -> f64 { (self - o).length() }
+            /// Unit vector in the same direction; `None` for the zero vector
+            pub fn normalized(self) -> Option<$t> {
+                let len = self.length();
+                (len > 0.0).then(|| self / len)
+            }
+            /// Linear interpolation towards `o` (`t = 0` is `self`, `t = 1` is `o`)
+            pub fn lerp(self, o: $t, t: f64) -> $t { self + (o - self) * t }
+        }
+    };
+}
+
+impl_vec_ops!(Vec2 { x, y });
+impl_vec_ops!(Vec3 { x, y, z });
+
+impl Vec2 {
+    /// The zero vector
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    /// Create a vector
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// 2D cross product (z component of the 3D cross product)
+    pub fn cross(self, o: Vec2) -> f64 {
+        self.x * o.y - self.y * o.x
+    }
+
+    /// Vector rotated 90° counter-clockwise
+    pub fn perp(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+impl Vec3 {
+    /// The zero vector
+    pub const ZERO: Vec3 = Vec3 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Create a vector
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Cross product
+    pub fn cross(self, o: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * o.z - self.z * o.y,
+            self.z * o.x - self.x * o.z,
+            self.x * o.y - self.y * o.x,
+        )
+    }
+}
+
+/// 2x2 matrix (row-major)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat2 {
+    /// Rows of the matrix
+    pub m: [[f64; 2]; 2],
+}
+
+/// 3x3 matrix (row-major)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3 {
+    /// Rows of the matrix
+    pub m: [[f64; 3]; 3],
+}
+
+macro_rules! impl_mat_common {
+    ($t:ident, $n:literal) => {
+        impl $t {
+            /// Create from rows
+            pub const fn new(m: [[f64; $n]; $n]) -> Self {
+                Self { m }
+            }
+
+            /// Transposed matrix
+            pub fn transpose(&self) -> Self {
+                let mut out = [[0.0; $n]; $n];
+                for (i, row) in self.m.iter().enumerate() {
+                    for (j, v) in row.iter().enumerate() {
+                        out[j][i] = *v;
+                    }
+                }
+                Self { m: out }
+            }
+        }
+
+        impl Default for $t {
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl Mul for $t {
+            type Output = $t;
+            fn mul(self, o: $t) -> $t {
+                let mut out = [[0.0; $n]; $n];
+                for (i, row) in out.iter_mut().enumerate() {
+                    for (j, cell) in row.iter_mut().enumerate() {
+                        *cell = (0..$n).map(|k| self.m[i][k] * o.m[k][j]).sum();
+                    }
+                }
+                $t { m: out }
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = $t;
+            fn mul(self, s: f64) -> $t {
+                $t {
+                    m: self.m.map(|row| row.map(|v| v * s)),
+                }
+            }
+        }
+    };
+}
+
+impl_mat_common!(Mat2, 2);
+impl_mat_common!(Mat3, 3);
+
+impl Mat2 {
+    /// Identity matrix
+    pub const IDENTITY: Mat2 = Mat2 {
+        m: [[1.0, 0.0], [0.0, 1.0]],
+    };
+
+    /// Counter-clockwise rotation by `angle` radians
+    pub fn rotation(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new([[c, -s], [s, c]])
+    }
+
+    /// Determinant
+    pub fn determinant(&self) -> f64 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    /// Inverse; `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let [[a, b], [c, d]] = self.m;
+        Some(Self::new([[d, -b], [-c, a]]) * (1.0 / det))
+    }
+}
+
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+    fn mul(self, v: Vec2) -> Vec2 {
+        Vec2::new(
+            self.m[0][0] * v.x + self.m[0][1] * v.y,
+            self.m[1][0] * v.x + self.m[1][1] * v.y,
+        )
+    }
+}
+
+impl Mat3 {
+    /// Identity matrix
+    pub const IDENTITY: Mat3 = Mat3 {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    /// 2D affine translation
+    pub fn translation(tx: f64, ty: f64) -> Self {
+        Self::new([[1.0, 0.0, tx], [0.0, 1.0, ty], [0.0, 0.0, 1.0]])
+    }
+
+    /// 2D affine counter-clockwise rotation by `angle` radians
+    pub fn rotation(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new([[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// 2D affine scale
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::new([[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Determinant
+    pub fn determinant(&self) -> f64 {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Inverse via the adjugate; `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let m = &self.m;
+        let cof = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let adj = [
+            [cof(1, 2, 1, 2), -cof(0, 2, 1, 2), cof(0, 1, 1, 2)],
+            [-cof(1, 2, 0, 2), cof(0, 2, 0, 2), -cof(0, 1, 0, 2)],
+            [cof(1, 2, 0, 1), -cof(0, 2, 0, 1), cof(0, 1, 0, 1)],
+        ];
+        Some(Self::new(adj) * (1.0 / det))
+    }
+
+    /// Apply as a 2D affine transform to a point (w = 1)
+    pub fn transform_point(&self, p: Vec2) -> Vec2 {
+        let v = *self * Vec3::new(p.x, p.y, 1.0);
+        Vec2::new(v.x, v.y)
+    }
+
+    /// Apply as a 2D affine transform to a direction (w = 0, ignores translation)
+    pub fn transform_vector(&self, v: Vec2) -> Vec2 {
+        let r = *self * Vec3::new(v.x, v.y, 0.0);
+        Vec2::new(r.x, r.y)
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    fn mul(self, v: Vec3) -> Vec3 {
+        let row = |r: [f64; 3]| r[0] * v.x + r[1] * v.y + r[2] * v.z;
+        Vec3::new(row(self.m[0]), row(self.m[1]), row(self.m[2]))
+    }
+}
+
+/// True if `p` lies inside `polygon` (even-odd rule; boundary points may go either way)
+pub fn point_in_polygon(p: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let n = polygon.len();
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + n - 1) % n]);
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Intersection point of segments `a1-a2` and `b1-b2`, if they cross
+///
+/// Collinear overlapping segments return `None`.
+pub fn segment_intersection(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> Option<Vec2> {
+    let r = a2 - a1;
+    let s = b2 - b1;
+    let denom = r.cross(s);
+    if denom == 0.0 {
+        return None;
+    }
+    let t = (b1 - a1).cross(s) / denom;
+    let u = (b1 - a1).cross(r) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| a1 + r * t)
+}
+
+/// Axis-aligned bounding box `(min, max)` of the points; `None` if empty
+pub fn bounding_box(points: &[Vec2]) -> Option<(Vec2, Vec2)> {
+    let first = *points.first()?;
+    Some(points.iter().fold((first, first), |(lo, hi), p| {
+        (
+            Vec2::new(lo.x.min(p.x), lo.y.min(p.y)),
+            Vec2::new(hi.x.max(p.x), hi.y.max(p.y)),
+        )
+    }))
+}
+
+/// Signed polygon area (shoelace); positive for counter-clockwise vertices
+pub fn polygon_area(polygon: &[Vec2]) -> f64 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| polygon[i].cross(polygon[(i + 1) % n]))
+        .sum::<f64>()
+        / 2.0
+}
@@ -1,6 +1,10 @@
 //! Math utilities module.
 //!
-//! Numeric helpers covering rounding, clamping, statistics, and more.
+//! Numeric helpers covering rounding, clamping, statistics (including
+//! [`quantiles`] and [`histogram`] for latency reporting, and the one-pass
+//! [`Running`] accumulator for streams), overflow-aware
+//! integer arithmetic ([`safe`]), small vectors/matrices and 2D geometry
+//! ([`linalg`]), interpolation curves and easing ([`interp`]), and more.
 //!
 //! Examples:
 //! ```rust
@@ -13,10 +17,16 @@
 //! assert_eq!(median(&mut vals), 2.0);
 //! ```
 
+pub mod interp;
+pub mod linalg;
 pub mod numeric;
 pub mod rounding;
+pub mod safe;
 pub mod statistics;
 
 pub use numeric::{approx_eq, gcd_u64, lcm_u64, signum_zero, sum_i64_saturating};
 pub use rounding::{ceil, clamp, floor, in_range, round};
-pub use statistics::{max_by, mean, median, min_by, percentile, std_dev, sum, variance};
+pub use statistics::{
+    bucketize, histogram, max_by, mean, median, min_by, percentile, quantiles, std_dev, sum,
+    variance, Running,
+};
@@ -44,6 +44,8 @@ pub fn lcm_u64(a: u64, b: u64) -> u64 {
 }
 
 /// Linear interpolation between a and b by t in \[0,1\]
+///
+/// See [`interp`](crate::math::interp) for smoothstep, curves, and easing.
 pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
     a + (b - a) * t
 }
@@ -0,0 +1,241 @@
+//! Overflow-aware integer arithmetic generic over all primitive integers
+
+/// Primitive integer operations used by the generic helpers in this module
+///
+/// Implemented for every built-in signed and unsigned integer type. For
+/// unsigned types `wrapping_abs` is the identity.
+pub trait SafeInt: Copy + PartialOrd {
+    /// Checked addition
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked subtraction
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked multiplication
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked division (`None` on divide-by-zero or overflow)
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked exponentiation
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    /// Saturating addition
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Saturating subtraction
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Saturating multiplication
+    fn saturating_mul(self, rhs: Self) -> Self;
+    /// Saturating exponentiation
+    fn saturating_pow(self, exp: u32) -> Self;
+    /// Wrapping addition
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping subtraction
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Wrapping multiplication
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Wrapping absolute value (`MIN` stays `MIN` for signed types)
+    fn wrapping_abs(self) -> Self;
+}
+
+macro_rules! impl_safe_int {
+    (@common) => {
+        fn checked_add(self, rhs: Self) -> Option<Self> { self.checked_add(rhs) }
+        fn checked_sub(self, rhs: Self) -> Option<Self> { self.checked_sub(rhs) }
+        fn checked_mul(self, rhs: Self) -> Option<Self> { self.checked_mul(rhs) }
+        fn checked_div(self, rhs: Self) -> Option<Self> { self.checked_div(rhs) }
+        fn checked_pow(self, exp: u32) -> Option<Self> { self.checked_pow(exp) }
+        fn saturating_add(self, rhs: Self) -> Self { self.saturating_add(rhs) }
+        fn saturating_sub(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
+        fn saturating_mul(self, rhs: Self) -> Self { self.saturating_mul(rhs) }
+        fn saturating_pow(self, exp: u32) -> Self { self.saturating_pow(exp) }
+        fn wrapping_add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+        fn wrapping_sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+        fn wrapping_mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+    };
+    (signed: $($t:ty),*) => {
+        $(
+            impl SafeInt for $t {
+                impl_safe_int!(@common);
+                fn wrapping_abs(self) -> Self { self.wrapping_abs() }
+            }
+        )*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(
+            impl SafeInt for $t {
+                impl_safe_int!(@common);
+                fn wrapping_abs(self) -> Self { self }
+            }
+        )*
+    };
+}
+
+impl_safe_int!(signed: i8, i16, i32, i64, i128, isize);
+impl_safe_int!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Add, returning `None` on overflow
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::checked_add;
+/// assert_eq!(checked_add(250u8, 5), Some(255));
+/// assert_eq!(checked_add(250u8, 6), None);
+/// ```
+pub fn checked_add<T: SafeInt>(a: T, b: T) -> Option<T> {
+    a.checked_add(b)
+}
+
+/// Subtract, returning `None` on overflow
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::checked_sub;
+/// assert_eq!(checked_sub(0u32, 1), None);
+/// assert_eq!(checked_sub(-5i8, 3), Some(-8));
+/// ```
+pub fn checked_sub<T: SafeInt>(a: T, b: T) -> Option<T> {
+    a.checked_sub(b)
+}
+
+/// Multiply, returning `None` on overflow
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::checked_mul;
+/// assert_eq!(checked_mul(i64::MAX, 2), None);
+/// assert_eq!(checked_mul(6u16, 7), Some(42));
+/// ```
+pub fn checked_mul<T: SafeInt>(a: T, b: T) -> Option<T> {
+    a.checked_mul(b)
+}
+
+/// Divide, returning `None` on divide-by-zero or overflow (`MIN / -1`)
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::checked_div;
+/// assert_eq!(checked_div(7u8, 0), None);
+/// assert_eq!(checked_div(i32::MIN, -1), None);
+/// ```
+pub fn checked_div<T: SafeInt>(a: T, b: T) -> Option<T> {
+    a.checked_div(b)
+}
+
+/// Raise to a power, returning `None` on overflow
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::checked_pow;
+/// assert_eq!(checked_pow(2u8, 7), Some(128));
+/// assert_eq!(checked_pow(2u8, 8), None);
+/// ```
+pub fn checked_pow<T: SafeInt>(base: T, exp: u32) -> Option<T> {
+    base.checked_pow(exp)
+}
+
+/// Add, clamping at the numeric bounds
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::saturating_add;
+/// assert_eq!(saturating_add(i8::MAX, 1), i8::MAX);
+/// ```
+pub fn saturating_add<T: SafeInt>(a: T, b: T) -> T {
+    a.saturating_add(b)
+}
+
+/// Subtract, clamping at the numeric bounds
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::saturating_sub;
+/// assert_eq!(saturating_sub(3u32, 10), 0);
+/// ```
+pub fn saturating_sub<T: SafeInt>(a: T, b: T) -> T {
+    a.saturating_sub(b)
+}
+
+/// Multiply, clamping at the numeric bounds
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::saturating_mul;
+/// assert_eq!(saturating_mul(-100i8, 2), i8::MIN);
+/// ```
+pub fn saturating_mul<T: SafeInt>(a: T, b: T) -> T {
+    a.saturating_mul(b)
+}
+
+/// Raise to a power, clamping at the numeric bounds
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::saturating_pow;
+/// assert_eq!(saturating_pow(10u16, 5), u16::MAX);
+/// assert_eq!(saturating_pow(-2i8, 7), i8::MIN);
+/// ```
+pub fn saturating_pow<T: SafeInt>(base: T, exp: u32) -> T {
+    base.saturating_pow(exp)
+}
+
+/// Add with two's-complement wrap-around
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::wrapping_add;
+/// assert_eq!(wrapping_add(255u8, 2), 1);
+/// ```
+pub fn wrapping_add<T: SafeInt>(a: T, b: T) -> T {
+    a.wrapping_add(b)
+}
+
+/// Subtract with two's-complement wrap-around
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::wrapping_sub;
+/// assert_eq!(wrapping_sub(0u8, 1), 255);
+/// ```
+pub fn wrapping_sub<T: SafeInt>(a: T, b: T) -> T {
+    a.wrapping_sub(b)
+}
+
+/// Multiply with two's-complement wrap-around
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::wrapping_mul;
+/// assert_eq!(wrapping_mul(16u8, 17), 16);
+/// ```
+pub fn wrapping_mul<T: SafeInt>(a: T, b: T) -> T {
+    a.wrapping_mul(b)
+}
+
+/// Absolute value that wraps instead of panicking on `MIN`
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::wrapping_abs;
+/// assert_eq!(wrapping_abs(-5i32), 5);
+/// assert_eq!(wrapping_abs(i8::MIN), i8::MIN);
+/// assert_eq!(wrapping_abs(7u8), 7);
+/// ```
+pub fn wrapping_abs<T: SafeInt>(x: T) -> T {
+    x.wrapping_abs()
+}
+
+/// Mean of `i64` values without intermediate overflow
+///
+/// Accumulates in `i128`, so sums that would overflow `i64` (and panic or wrap
+/// with a naive `sum`) are handled exactly. Returns `0.0` for an empty slice,
+/// matching [`mean`](crate::math::mean).
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::safe::mean_i64_no_overflow;
+/// assert_eq!(mean_i64_no_overflow(&[i64::MAX, i64::MAX]), i64::MAX as f64);
+/// assert_eq!(mean_i64_no_overflow(&[1, 2, 3, 4]), 2.5);
+/// ```
+pub fn mean_i64_no_overflow(values: &[i64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let total: i128 = values.iter().map(|&v| v as i128).sum();
+    total as f64 / values.len() as f64
+}
@@ -80,3 +80,228 @@ pub fn percentile(values: &mut [f64], p: f64) -> f64 {
     let idx = pos.round() as usize;
     values[idx]
 }
+
+/// Several percentiles from one sort; each `q` is a fraction in `0.0..=1.0`.
+///
+/// Uses the same nearest-rank method as [`percentile`]. NaN values are
+/// ignored; with no other values every quantile is `0.0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::statistics::quantiles;
+/// let latencies: Vec<f64> = (1..=100).map(f64::from).collect();
+/// assert_eq!(quantiles(&latencies, &[0.5, 0.9, 0.99]), [51.0, 90.0, 99.0]);
+/// ```
+pub fn quantiles(values: &[f64], qs: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+    if sorted.is_empty() {
+        return vec![0.0; qs.len()];
+    }
+    sorted.sort_by(f64::total_cmp);
+    let last = (sorted.len() - 1) as f64;
+    qs.iter()
+        .map(|q| sorted[(q.clamp(0.0, 1.0) * last).round() as usize])
+        .collect()
+}
+
+/// Count values into `n_bins` equal-width bins spanning their min..max.
+///
+/// Bins are half-open except the last, which includes the maximum. NaN and
+/// infinite values are skipped. Returns an empty vector when `n_bins == 0` or
+/// there are no finite values; if all values are equal, a single bin `x..x`
+/// holds them.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::statistics::histogram;
+/// let h = histogram(&[1.0, 2.0, 2.5, 4.0, 5.0], 2);
+/// assert_eq!(h, vec![(1.0..3.0, 3), (3.0..5.0, 2)]);
+/// ```
+pub fn histogram(values: &[f64], n_bins: usize) -> Vec<(core::ops::Range<f64>, usize)> {
+    let finite = || values.iter().copied().filter(|v| v.is_finite());
+    let Some(min) = finite().reduce(f64::min) else {
+        return Vec::new();
+    };
+    let max = finite().fold(min, f64::max);
+    if n_bins == 0 {
+        return Vec::new();
+    }
+    if min == max {
+        return vec![(min..max, finite().count())];
+    }
+    let width = (max - min) / n_bins as f64;
+    let mut counts = vec![0usize; n_bins];
+    for v in finite() {
+        let idx = (((v - min) / width) as usize).min(n_bins - 1);
+        counts[idx] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let start = min + width * i as f64;
+            let end = if i + 1 == n_bins {
+                max
+            } else {
+                min + width * (i + 1) as f64
+            };
+            (start..end, c)
+        })
+        .collect()
+}
+
+/// Index of the bucket `value` falls into, given ascending `boundaries`.
+///
+/// Bucket `i` covers `boundaries[i - 1] <= value < boundaries[i]`, so the
+/// result is in `0..=boundaries.len()`. NaN falls into bucket `0`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::statistics::bucketize;
+/// let bounds = [10.0, 50.0, 100.0]; // ms
+/// assert_eq!(bucketize(5.0, &bounds), 0);
+/// assert_eq!(bucketize(10.0, &bounds), 1);
+/// assert_eq!(bucketize(75.0, &bounds), 2);
+/// assert_eq!(bucketize(250.0, &bounds), 3);
+/// ```
+pub fn bucketize(value: f64, boundaries: &[f64]) -> usize {
+    boundaries.partition_point(|&b| b <= value)
+}
+
+/// One-pass accumulator for count, mean, variance, min, and max.
+///
+/// Uses Welford's algorithm, so it stays numerically stable on long streams
+/// without keeping the values. Accumulators from separate chunks or threads
+/// can be combined with [`Running::merge`]. Like the slice functions,
+/// [`mean`](Running::mean) and [`variance`](Running::variance) return `0.0`
+/// when nothing has been pushed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::math::statistics::Running;
+/// let mut stats = Running::new();
+/// for x in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     stats.push(x);
+/// }
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), 5.0);
+/// assert_eq!(stats.variance(), 4.0);
+/// assert_eq!(stats.std_dev(), 2.0);
+/// assert_eq!((stats.min(), stats.max()), (Some(2.0), Some(9.0)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Running {
+    count: u64,
+    mean: f64,
+    // Sum of squared deviations from the mean
+    m2: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Running {
+    /// Empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one observation.
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = Some(self.min.map_or(x, |m| m.min(x)));
+        self.max = Some(self.max.map_or(x, |m| m.max(x)));
+    }
+
+    /// Number of observations pushed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Arithmetic mean.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Population variance, matching [`variance`].
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.m2 / self.count as f64
+    }
+
+    /// Sample variance (divides by `n - 1`); `0.0` for fewer than two values.
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        self.m2 / (self.count - 1) as f64
+    }
+
+    /// Population standard deviation, matching [`std_dev`].
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Smallest observation, or `None` if empty.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Largest observation, or `None` if empty.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Fold in the observations summarized by `other`, as if they had been
+    /// pushed into `self`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::math::statistics::Running;
+    /// let mut left: Running = [1.0, 2.0, 3.0].into_iter().collect();
+    /// let right: Running = [10.0, 20.0].into_iter().collect();
+    /// left.merge(&right);
+    /// let all: Running = [1.0, 2.0, 3.0, 10.0, 20.0].into_iter().collect();
+    /// assert_eq!(left.count(), 5);
+    /// assert!((left.mean() - all.mean()).abs() < 1e-12);
+    /// assert!((left.variance() - all.variance()).abs() < 1e-9);
+    /// assert_eq!(left.max(), Some(20.0));
+    /// ```
+    pub fn merge(&mut self, other: &Running) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+        self.min = self.min.zip(other.min).map(|(a, b)| a.min(b));
+        self.max = self.max.zip(other.max).map(|(a, b)| a.max(b));
+    }
+}
+
+impl Extend<f64> for Running {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for Running {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut stats = Running::new();
+        stats.extend(iter);
+        stats
+    }
+}
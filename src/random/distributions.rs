@@ -0,0 +1,172 @@
+//! Sampling from common continuous and discrete distributions
+
+use super::Rng;
+
+/// Natural log of `k!` (exact sum for small `k`, Stirling series beyond)
+fn ln_factorial(k: f64) -> f64 {
+    if k < 10.0 {
+        let mut acc = 0.0;
+        let mut i = 2.0;
+        while i <= k {
+            acc += f64::ln(i);
+            i += 1.0;
+        }
+        return acc;
+    }
+    let n = k + 1.0;
+    (n - 0.5) * n.ln() - n + 0.5 * (2.0 * std::f64::consts::PI).ln() + 1.0 / (12.0 * n)
+        - 1.0 / (360.0 * n * n * n)
+}
+
+impl Rng {
+    /// Sample from a normal distribution (Box–Muller)
+    ///
+    /// Panics if `std_dev` is negative or not finite.
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        assert!(
+            std_dev.is_finite() && std_dev >= 0.0,
+            "normal: std_dev must be finite and non-negative"
+        );
+        // 1 - u keeps the log argument in (0, 1]
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        mean + std_dev * z
+    }
+
+    /// Sample from an exponential distribution with rate `lambda`
+    ///
+    /// Panics if `lambda` is not positive and finite.
+    pub fn exponential(&mut self, lambda: f64) -> f64 {
+        assert!(
+            lambda.is_finite() && lambda > 0.0,
+            "exponential: lambda must be positive"
+        );
+        -(1.0 - self.next_f64()).ln() / lambda
+    }
+
+    /// Sample from a Poisson distribution with mean `lambda`
+    ///
+    /// Uses Knuth's multiplication method for small `lambda` and Hörmann's
+    /// transformed rejection (PTRS) otherwise. Panics if `lambda` is negative
+    /// or not finite.
+    pub fn poisson(&mut self, lambda: f64) -> u64 {
+        assert!(
+            lambda.is_finite() && lambda >= 0.0,
+            "poisson: lambda must be finite and non-negative"
+        );
+        if lambda == 0.0 {
+            return 0;
+        }
+        if lambda < 30.0 {
+            let limit = (-lambda).exp();
+            let mut k = 0;
+            let mut p = self.next_f64();
+            while p > limit {
+                k += 1;
+                p *= self.next_f64();
+            }
+            return k;
+        }
+        let slam = lambda.sqrt();
+        let loglam = lambda.ln();
+        let b = 0.931 + 2.53 * slam;
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let vr = 0.9277 - 3.6224 / (b - 2.0);
+        loop {
+            let u = self.next_f64() - 0.5;
+            let v = 1.0 - self.next_f64();
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+            if us >= 0.07 && v <= vr {
+                return k as u64;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+            if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln()
+                <= -lambda + k * loglam - ln_factorial(k)
+            {
+                return k as u64;
+            }
+        }
+    }
+
+    /// Sample from a triangular distribution on `[min, max]` peaking at `mode`
+    ///
+    /// Panics unless `min <= mode <= max`.
+    pub fn triangular(&mut self, min: f64, mode: f64, max: f64) -> f64 {
+        assert!(
+            min <= mode && mode <= max,
+            "triangular: requires min <= mode <= max"
+        );
+        let span = max - min;
+        if span == 0.0 {
+            return min;
+        }
+        let u = self.next_f64();
+        let split = (mode - min) / span;
+        if u < split {
+            min + (u * span * (mode - min)).sqrt()
+        } else {
+            max - ((1.0 - u) * span * (max - mode)).sqrt()
+        }
+    }
+}
+
+/// Normally distributed sample with the given mean and standard deviation.
+///
+/// Panics if `std_dev` is negative or not finite. Use [`Rng::normal`] for a
+/// reproducible, seeded stream.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::normal;
+/// let x = normal(10.0, 0.0);
+/// assert_eq!(x, 10.0);
+/// ```
+pub fn normal(mean: f64, std_dev: f64) -> f64 {
+    Rng::new().normal(mean, std_dev)
+}
+
+/// Exponentially distributed sample with rate `lambda` (mean `1 / lambda`).
+///
+/// Panics if `lambda` is not positive and finite.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::exponential;
+/// assert!(exponential(2.0) >= 0.0);
+/// ```
+pub fn exponential(lambda: f64) -> f64 {
+    Rng::new().exponential(lambda)
+}
+
+/// Poisson distributed count with mean `lambda`.
+///
+/// Panics if `lambda` is negative or not finite.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::poisson;
+/// assert_eq!(poisson(0.0), 0);
+/// let _arrivals = poisson(4.5);
+/// ```
+pub fn poisson(lambda: f64) -> u64 {
+    Rng::new().poisson(lambda)
+}
+
+/// Triangular distributed sample on `[min, max]` with peak at `mode`.
+///
+/// Panics unless `min <= mode <= max`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::triangular;
+/// let x = triangular(1.0, 2.0, 5.0);
+/// assert!((1.0..=5.0).contains(&x));
+/// ```
+pub fn triangular(min: f64, mode: f64, max: f64) -> f64 {
+    Rng::new().triangular(min, mode, max)
+}
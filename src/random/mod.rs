@@ -1,8 +1,10 @@
 //! Random utilities (non-cryptographic).
 //!
-//! Pseudo-random helpers for quick sampling, choices, and UUID-like IDs. These
-//! are not cryptographically secure and should not be used for security-
-//! sensitive purposes.
+//! Pseudo-random helpers for quick sampling, choices, random strings,
+//! durations/timestamps/dates, and UUID-like IDs. These are not
+//! cryptographically secure and should not be used for security-sensitive
+//! purposes. With the `crypto` feature, [`secure_bytes`] and [`secure_token`]
+//! draw from the OS entropy source, and [`uuid_v4`] uses it too.
 //!
 //! Examples:
 //! ```rust
@@ -20,9 +22,33 @@
 //! let bytes = random_bytes(4);
 //! assert_eq!(bytes.len(), 4);
 //! ```
+//!
+//! Distributions sampled from a seedable [`Rng`]:
+//! ```rust
+//! use toolchest::random::{normal, Rng};
+//!
+//! let _ = normal(0.0, 1.0);
+//! let mut rng = Rng::seeded(7);
+//! let a = rng.poisson(3.0);
+//! assert_eq!(a, Rng::seeded(7).poisson(3.0));
+//! ```
 
 use std::time::Instant;
 
+mod distributions;
+mod rng;
+#[cfg(feature = "crypto")]
+mod secure;
+mod temporal;
+mod text;
+
+pub use distributions::{exponential, normal, poisson, triangular};
+pub use rng::Rng;
+#[cfg(feature = "crypto")]
+pub use secure::{secure_bytes, secure_token};
+pub use temporal::{random_date, random_duration, random_instant_within, random_unix_timestamp};
+pub use text::{random_alphanumeric, random_hex, random_passphrase, random_string_with};
+
 fn next_u64(state: &mut u128) -> u64 {
     *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
     (*state >> 32) as u64
@@ -117,7 +143,10 @@ pub fn weighted_choice<'a, T>(v: &'a [T], weights: &[f64]) -> Option<&'a T> {
     }
     v.last()
 }
-/// Generate a random UUID v4 (non-crypto).
+/// Generate a random UUID v4.
+///
+/// Uses OS entropy when the `crypto` feature is enabled, making the IDs
+/// unpredictable; otherwise a time-seeded [`Rng`] is used.
 ///
 /// Example:
 /// ```rust
@@ -126,16 +155,35 @@ pub fn weighted_choice<'a, T>(v: &'a [T], weights: &[f64]) -> Option<&'a T> {
 /// assert_eq!(id.len(), 36);
 /// ```
 pub fn uuid_v4() -> String {
-    let mut s = Instant::now().elapsed().as_nanos();
     let mut bytes = [0u8; 16];
-    for b in &mut bytes {
-        *b = (next_u64(&mut s) & 0xFF) as u8;
+    #[cfg(feature = "crypto")]
+    bytes.copy_from_slice(&secure_bytes(16));
+    #[cfg(not(feature = "crypto"))]
+    {
+        let mut rng = Rng::new();
+        bytes[..8].copy_from_slice(&rng.next_u64().to_le_bytes());
+        bytes[8..].copy_from_slice(&rng.next_u64().to_le_bytes());
     }
     bytes[6] = (bytes[6] & 0x0F) | 0x40; // version 4
     bytes[8] = (bytes[8] & 0x3F) | 0x80; // variant
     format!("{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
         bytes[0],bytes[1],bytes[2],bytes[3],bytes[4],bytes[5],bytes[6],bytes[7],bytes[8],bytes[9],bytes[10],bytes[11],bytes[12],bytes[13],bytes[14],bytes[15])
 }
+/// Uniformly random permutation of `0..n`.
+///
+/// Use [`Rng::permutation`] for a reproducible, seeded version.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_permutation;
+/// let mut p = random_permutation(5);
+/// assert_eq!(p.len(), 5);
+/// p.sort();
+/// assert_eq!(p, [0, 1, 2, 3, 4]);
+/// ```
+pub fn random_permutation(n: usize) -> Vec<usize> {
+    Rng::new().permutation(n)
+}
 /// Generate `n` random bytes (non-crypto).
 ///
 /// Example:
@@ -0,0 +1,117 @@
+//! Random durations, instants, timestamps, and dates for test data and jitter
+
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use super::Rng;
+use crate::time::Date;
+
+impl Rng {
+    /// Uniform `u128` in `[0, span)`; `span` must be non-zero
+    fn below_u128(&mut self, span: u128) -> u128 {
+        let x = if span <= u64::MAX as u128 {
+            self.next_u64() as u128
+        } else {
+            (self.next_u64() as u128) << 64 | self.next_u64() as u128
+        };
+        x % span
+    }
+
+    /// Uniform duration in `[min, max)` at nanosecond resolution
+    ///
+    /// Returns `min` when `max <= min`.
+    pub fn duration(&mut self, min: Duration, max: Duration) -> Duration {
+        let Some(span) = max.checked_sub(min).filter(|d| !d.is_zero()) else {
+            return min;
+        };
+        let offset = self.below_u128(span.as_nanos());
+        min + Duration::new(
+            (offset / 1_000_000_000) as u64,
+            (offset % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Instant uniformly within `[base, base + window)`
+    ///
+    /// A drawn offset too large to add to `base` (possible for huge windows
+    /// such as [`Duration::MAX`]) yields `base` instead of panicking.
+    pub fn instant_within(&mut self, base: Instant, window: Duration) -> Instant {
+        base.checked_add(self.duration(Duration::ZERO, window))
+            .unwrap_or(base)
+    }
+
+    /// Unix timestamp (seconds) uniformly within `range`; panics if it is empty
+    pub fn unix_timestamp(&mut self, range: Range<i64>) -> i64 {
+        self.range(range.start, range.end)
+    }
+
+    /// Date uniformly within `[start, end)`; panics if `end <= start`
+    pub fn date_between(&mut self, start: Date, end: Date) -> Date {
+        start.add_days(self.range(0, start.days_until(end)))
+    }
+}
+
+/// Random duration in `[min, max)`, or `min` when `max <= min`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::{random_duration, Rng};
+/// use std::time::Duration;
+/// let d = random_duration(Duration::from_millis(100), Duration::from_millis(200));
+/// assert!(d >= Duration::from_millis(100) && d < Duration::from_millis(200));
+/// // Deterministic variant for tests
+/// let (lo, hi) = (Duration::ZERO, Duration::from_secs(1));
+/// assert_eq!(Rng::seeded(3).duration(lo, hi), Rng::seeded(3).duration(lo, hi));
+/// ```
+pub fn random_duration(min: Duration, max: Duration) -> Duration {
+    Rng::new().duration(min, max)
+}
+
+/// Random instant between now and `now + window`.
+///
+/// Like [`Rng::instant_within`], returns now if the drawn offset overflows
+/// [`Instant`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_instant_within;
+/// use std::time::{Duration, Instant};
+/// let before = Instant::now();
+/// let t = random_instant_within(Duration::from_secs(60));
+/// assert!(t >= before && t < Instant::now() + Duration::from_secs(60));
+/// ```
+pub fn random_instant_within(window: Duration) -> Instant {
+    Rng::new().instant_within(Instant::now(), window)
+}
+
+/// Random Unix timestamp in seconds within `range`.
+///
+/// Panics if `range` is empty.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_unix_timestamp;
+/// // Somewhere in 2024
+/// let ts = random_unix_timestamp(1_704_067_200..1_735_689_600);
+/// assert!((1_704_067_200..1_735_689_600).contains(&ts));
+/// ```
+pub fn random_unix_timestamp(range: Range<i64>) -> i64 {
+    Rng::new().unix_timestamp(range)
+}
+
+/// Random calendar date in `[start, end)`.
+///
+/// Panics if `end <= start`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_date;
+/// use toolchest::time::Date;
+/// let start = Date::new(2024, 1, 1).unwrap();
+/// let end = Date::new(2025, 1, 1).unwrap();
+/// let d = random_date(start, end);
+/// assert!(d >= start && d < end);
+/// ```
+pub fn random_date(start: Date, end: Date) -> Date {
+    Rng::new().date_between(start, end)
+}
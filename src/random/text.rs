@@ -0,0 +1,97 @@
+//! Random strings from configurable character sets and word lists
+
+use super::Rng;
+
+const ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const HEX: &str = "0123456789abcdef";
+
+impl Rng {
+    /// String of `len` characters drawn uniformly from `charset`
+    ///
+    /// Panics if `charset` is empty.
+    pub fn string_with(&mut self, len: usize, charset: &str) -> String {
+        let chars: Vec<char> = charset.chars().collect();
+        assert!(!chars.is_empty(), "string_with: charset must not be empty");
+        (0..len)
+            .map(|_| chars[self.range(0, chars.len() as i64) as usize])
+            .collect()
+    }
+
+    /// Lowercase hex string of `len` characters
+    pub fn hex(&mut self, len: usize) -> String {
+        self.string_with(len, HEX)
+    }
+
+    /// ASCII letters and digits, `len` characters
+    pub fn alphanumeric(&mut self, len: usize) -> String {
+        self.string_with(len, ALPHANUMERIC)
+    }
+
+    /// `words` entries from `wordlist` joined with `-`
+    ///
+    /// Panics if `wordlist` is empty.
+    pub fn passphrase(&mut self, words: usize, wordlist: &[&str]) -> String {
+        assert!(
+            !wordlist.is_empty(),
+            "passphrase: wordlist must not be empty"
+        );
+        (0..words)
+            .map(|_| wordlist[self.range(0, wordlist.len() as i64) as usize])
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// Random string of `len` characters from `charset` (any Unicode characters).
+///
+/// Panics if `charset` is empty.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::{random_string_with, Rng};
+/// let pin = random_string_with(6, "0123456789");
+/// assert!(pin.len() == 6 && pin.chars().all(|c| c.is_ascii_digit()));
+/// // Deterministic variant for tests
+/// assert_eq!(Rng::seeded(1).string_with(4, "ab"), Rng::seeded(1).string_with(4, "ab"));
+/// ```
+pub fn random_string_with(len: usize, charset: &str) -> String {
+    Rng::new().string_with(len, charset)
+}
+
+/// Random lowercase hex string of `len` characters.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_hex;
+/// let h = random_hex(8);
+/// assert!(h.len() == 8 && h.chars().all(|c| c.is_ascii_hexdigit()));
+/// ```
+pub fn random_hex(len: usize) -> String {
+    Rng::new().hex(len)
+}
+
+/// Random string of `len` ASCII letters and digits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_alphanumeric;
+/// assert!(random_alphanumeric(12).chars().all(|c| c.is_ascii_alphanumeric()));
+/// ```
+pub fn random_alphanumeric(len: usize) -> String {
+    Rng::new().alphanumeric(len)
+}
+
+/// Random passphrase of `words` words from `wordlist`, joined with `-`.
+///
+/// Not suitable for real credentials unless the RNG is replaced with a secure
+/// source; panics if `wordlist` is empty.
+///
+/// Example:
+/// ```rust
+/// use toolchest::random::random_passphrase;
+/// let p = random_passphrase(3, &["correct", "horse", "battery", "staple"]);
+/// assert_eq!(p.split('-').count(), 3);
+/// ```
+pub fn random_passphrase(words: usize, wordlist: &[&str]) -> String {
+    Rng::new().passphrase(words, wordlist)
+}
@@ -0,0 +1,167 @@
+//! ANSI escape sequence handling for colored terminal output.
+//!
+//! Measures and truncates strings while ignoring CSI (`ESC [ ... m` colors,
+//! cursor movement), OSC (`ESC ] ... BEL`, e.g. hyperlinks), and other `ESC`
+//! sequences, so styled text can be aligned like plain text. Widths use
+//! [`display_width`].
+//!
+//! Example:
+//! ```rust
+//! use toolchest::strings::ansi::{strip_ansi, visible_width, pad_end_ansi};
+//! let red = "\x1b[31merror\x1b[0m";
+//! assert_eq!(strip_ansi(red), "error");
+//! assert_eq!(visible_width(red), 5);
+//! assert_eq!(strip_ansi(&pad_end_ansi(red, 8, ' ')), "error   ");
+//! ```
+
+use super::wrap::{char_width, display_width};
+
+// Byte length of the escape sequence starting at `start` (which holds ESC)
+fn escape_len(bytes: &[u8], start: usize) -> usize {
+    let mut j = start + 1;
+    let Some(&kind) = bytes.get(j) else {
+        return 1;
+    };
+    match kind {
+        b'[' => {
+            j += 1;
+            while j < bytes.len() && (0x20..=0x3F).contains(&bytes[j]) {
+                j += 1;
+            }
+            if j < bytes.len() && (0x40..=0x7E).contains(&bytes[j]) {
+                j += 1;
+            }
+        }
+        // OSC, DCS, SOS, PM, APC: run until BEL or ST (ESC \)
+        b']' | b'P' | b'X' | b'^' | b'_' => {
+            j += 1;
+            while j < bytes.len() {
+                if bytes[j] == 0x07 {
+                    return j + 1 - start;
+                }
+                if bytes[j] == 0x1b && bytes.get(j + 1) == Some(&b'\\') {
+                    return j + 2 - start;
+                }
+                j += 1;
+            }
+        }
+        _ => {
+            while j < bytes.len() && (0x20..=0x2F).contains(&bytes[j]) {
+                j += 1;
+            }
+            if j < bytes.len() && (0x30..=0x7E).contains(&bytes[j]) {
+                j += 1;
+            }
+        }
+    }
+    j - start
+}
+
+// Split into (is_escape, piece) runs
+fn segments(s: &str) -> Vec<(bool, &str)> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::new();
+    let (mut i, mut text_start) = (0, 0);
+    while i < bytes.len() {
+        if bytes[i] == 0x1b {
+            if text_start < i {
+                out.push((false, &s[text_start..i]));
+            }
+            let len = escape_len(bytes, i);
+            out.push((true, &s[i..i + len]));
+            i += len;
+            text_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    if text_start < s.len() {
+        out.push((false, &s[text_start..]));
+    }
+    out
+}
+
+/// Remove all ANSI escape sequences.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::ansi::strip_ansi;
+/// assert_eq!(strip_ansi("\x1b[1;32mok\x1b[0m done"), "ok done");
+/// assert_eq!(strip_ansi("\x1b]8;;https://x.y\x07link\x1b]8;;\x07"), "link");
+/// ```
+pub fn strip_ansi(s: &str) -> String {
+    segments(s)
+        .into_iter()
+        .filter(|(esc, _)| !esc)
+        .map(|(_, t)| t)
+        .collect()
+}
+
+/// Display width of `s` ignoring escape sequences.
+pub fn visible_width(s: &str) -> usize {
+    segments(s)
+        .into_iter()
+        .filter(|(esc, _)| !esc)
+        .map(|(_, t)| display_width(t))
+        .sum()
+}
+
+/// Truncate to `width` visible columns, ending with `"..."` when cut.
+///
+/// Escape sequences are preserved (they take no width); if any were present a
+/// reset (`ESC [0m`) is appended to a cut string so styles don't leak.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::ansi::{truncate_ansi, strip_ansi, visible_width};
+/// let s = "\x1b[31mhello world\x1b[0m";
+/// let t = truncate_ansi(s, 8);
+/// assert_eq!(strip_ansi(&t), "hello...");
+/// assert!(t.starts_with("\x1b[31m") && t.ends_with("\x1b[0m"));
+/// assert_eq!(truncate_ansi(s, 11), s);
+/// ```
+pub fn truncate_ansi(s: &str, width: usize) -> String {
+    if visible_width(s) <= width {
+        return s.to_string();
+    }
+    let (ellipsis, budget) = if width >= 3 {
+        ("...", width - 3)
+    } else {
+        ("", width)
+    };
+    let mut out = String::with_capacity(s.len());
+    let mut used = 0;
+    let mut styled = false;
+    'outer: for (esc, piece) in segments(s) {
+        if esc {
+            styled = true;
+            out.push_str(piece);
+            continue;
+        }
+        for ch in piece.chars() {
+            let w = char_width(ch);
+            if used + w > budget {
+                break 'outer;
+            }
+            used += w;
+            out.push(ch);
+        }
+    }
+    out.push_str(ellipsis);
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Pad the end with `pad` until the visible width reaches `width`.
+pub fn pad_end_ansi(s: &str, width: usize, pad: char) -> String {
+    let n = width.saturating_sub(visible_width(s));
+    format!("{s}{}", pad.to_string().repeat(n))
+}
+
+/// Pad the start with `pad` until the visible width reaches `width`.
+pub fn pad_start_ansi(s: &str, width: usize, pad: char) -> String {
+    let n = width.saturating_sub(visible_width(s));
+    format!("{}{s}", pad.to_string().repeat(n))
+}
@@ -1,100 +1,435 @@
 //! Case conversion utilities
 
+/// Identifier casing styles for [`convert_case`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `Train-Case`
+    Train,
+    /// `dot.case`
+    Dot,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+}
+
+/// Options for acronym-aware case conversion.
+///
+/// `acronyms` are kept intact when splitting (so `"IPv4Address"` splits into
+/// `IPv4` + `Address` when `"IPv4"` is listed) and are written exactly as
+/// declared in capitalized positions of camel, Pascal and Train case.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::case::{convert_case, Case, CaseOptions};
+/// let opts = CaseOptions::with_acronyms(&["HTTP", "IPv4"]);
+/// assert_eq!(convert_case("http_client", Case::Pascal, &opts), "HTTPClient");
+/// assert_eq!(convert_case("parseIPv4Address", Case::Snake, &opts), "parse_ipv4_address");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaseOptions {
+    /// Known acronyms, with their preferred spelling
+    pub acronyms: Vec<String>,
+}
+
+impl CaseOptions {
+    /// Options declaring the given acronyms
+    pub fn with_acronyms(acronyms: &[&str]) -> Self {
+        Self {
+            acronyms: acronyms.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+/// Split an identifier into words.
+///
+/// Non-alphanumeric characters separate words, as do lower-to-upper and
+/// digit-to-upper transitions. A run of capitals followed by a lowercase
+/// letter ends one letter early, so acronyms split cleanly. Digits stay with
+/// the word they follow.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::case::split_words;
+/// assert_eq!(split_words("XMLHttpRequest"), ["XML", "Http", "Request"]);
+/// assert_eq!(split_words("v2Response"), ["v2", "Response"]);
+/// assert_eq!(split_words("--user_ID.count"), ["user", "ID", "count"]);
+/// ```
+pub fn split_words(s: &str) -> Vec<String> {
+    split_words_with(s, &CaseOptions::default())
+}
+
+/// [`split_words`] that keeps declared acronyms in one piece.
+pub fn split_words_with(s: &str, opts: &CaseOptions) -> Vec<String> {
+    let mut acronyms: Vec<Vec<char>> = opts
+        .acronyms
+        .iter()
+        .filter(|a| !a.is_empty())
+        .map(|a| a.chars().collect())
+        .collect();
+    // Prefer the longest match
+    acronyms.sort_by_key(|a| std::cmp::Reverse(a.len()));
+
+    let mut words = Vec::new();
+    for segment in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            if let Some(a) = acronyms.iter().find(|a| chars[start..].starts_with(a)) {
+                words.push(a.iter().collect());
+                start += a.len();
+                continue;
+            }
+            let mut end = start + 1;
+            while end < chars.len() && !is_word_boundary(&chars, end) {
+                end += 1;
+            }
+            words.push(chars[start..end].iter().collect());
+            start = end;
+        }
+    }
+    words
+}
+
+/// Whether a new word starts at `chars[i]`
+fn is_word_boundary(chars: &[char], i: usize) -> bool {
+    let (prev, cur) = (chars[i - 1], chars[i]);
+    if !cur.is_uppercase() {
+        return false;
+    }
+    if prev.is_lowercase() || prev.is_numeric() {
+        return true;
+    }
+    // "XMLHttp": the last capital of a run starts the next word
+    prev.is_uppercase() && chars.get(i + 1).is_some_and(|n| n.is_lowercase())
+}
+
+fn capitalized(word: &str, opts: &CaseOptions) -> String {
+    if let Some(a) = opts.acronyms.iter().find(|a| a.eq_ignore_ascii_case(word)) {
+        return a.clone();
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Convert an identifier to `case`, honouring declared acronyms.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::case::{convert_case, Case, CaseOptions};
+/// let opts = CaseOptions::default();
+/// assert_eq!(convert_case("XMLHttpRequest", Case::Camel, &opts), "xmlHttpRequest");
+/// assert_eq!(convert_case("XMLHttpRequest", Case::Train, &opts), "Xml-Http-Request");
+/// ```
+pub fn convert_case(s: &str, case: Case, opts: &CaseOptions) -> String {
+    let words = split_words_with(s, opts);
+    let joined = |sep: &str, f: &dyn Fn(&str) -> String| {
+        words.iter().map(|w| f(w)).collect::<Vec<_>>().join(sep)
+    };
+    match case {
+        Case::Snake => joined("_", &|w| w.to_lowercase()),
+        Case::ScreamingSnake => joined("_", &|w| w.to_uppercase()),
+        Case::Kebab => joined("-", &|w| w.to_lowercase()),
+        Case::Dot => joined(".", &|w| w.to_lowercase()),
+        Case::Train => joined("-", &|w| capitalized(w, opts)),
+        Case::Pascal => joined("", &|w| capitalized(w, opts)),
+        Case::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalized(w, opts)
+                }
+            })
+            .collect(),
+    }
+}
+
 /// Convert a string to snake_case
 ///
+/// Words are split with [`split_words`], so acronyms and digits are handled.
+///
 /// # Examples
 /// ```
 /// use toolchest::strings::to_snake_case;
 /// assert_eq!(to_snake_case("HelloWorld"), "hello_world");
 /// assert_eq!(to_snake_case("hello-world"), "hello_world");
 /// assert_eq!(to_snake_case("HELLO_WORLD"), "hello_world");
+/// assert_eq!(to_snake_case("XMLHttpRequest"), "xml_http_request");
 /// ```
 #[inline]
 pub fn to_snake_case(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() + s.len() / 4);
-    let mut prev_is_upper = false;
-    let mut first = true;
-    let mut last_was_sep = false;
-
-    for ch in s.chars() {
-        if ch.is_ascii_uppercase() {
-            if !first && !prev_is_upper && !last_was_sep {
-                result.push('_');
-            }
-            result.push(ch.to_ascii_lowercase());
-            prev_is_upper = true;
-            last_was_sep = false;
-        } else if ch == '-' || ch == ' ' || ch == '_' {
-            if !result.is_empty() && !result.ends_with('_') {
-                result.push('_');
-            }
-            prev_is_upper = false;
-            last_was_sep = true;
-        } else {
-            result.push(ch.to_ascii_lowercase());
-            prev_is_upper = false;
-            last_was_sep = false;
-        }
-        first = false;
-    }
-
-    result
+    convert_case(s, Case::Snake, &CaseOptions::default())
 }
 
 /// Convert to camelCase
 #[inline]
 pub fn to_camel_case(s: &str) -> String {
-    let snake = to_snake_case(s);
-    let mut result = String::with_capacity(snake.len());
-    let mut capitalize_next = false;
-
-    for (i, ch) in snake.chars().enumerate() {
-        if ch == '_' {
-            capitalize_next = true;
-        } else if capitalize_next || i == 0 {
-            result.push(ch.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(ch);
-        }
-    }
-
-    // First character should be lowercase for camelCase
-    if let Some(first) = result.chars().next() {
-        result = format!("{}{}", first.to_lowercase(), &result[1..]);
-    }
-
-    result
+    convert_case(s, Case::Camel, &CaseOptions::default())
 }
 
-/// Convert to PascalCase  
+/// Convert to PascalCase
 #[inline]
 pub fn to_pascal_case(s: &str) -> String {
-    let mut camel = to_camel_case(s);
-    if let Some(first) = camel.chars().next() {
-        camel = format!("{}{}", first.to_uppercase(), &camel[1..]);
-    }
-    camel
+    convert_case(s, Case::Pascal, &CaseOptions::default())
 }
 
 /// Convert to kebab-case
 #[inline]
 pub fn to_kebab_case(s: &str) -> String {
-    to_snake_case(s).replace('_', "-")
+    convert_case(s, Case::Kebab, &CaseOptions::default())
 }
 
-/// Convert to Title Case
+/// Convert to SCREAMING_SNAKE_CASE
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::to_screaming_snake_case;
+/// assert_eq!(to_screaming_snake_case("maxRetryCount"), "MAX_RETRY_COUNT");
+/// ```
+pub fn to_screaming_snake_case(s: &str) -> String {
+    convert_case(s, Case::ScreamingSnake, &CaseOptions::default())
+}
+
+/// Convert to Train-Case (as in HTTP header names)
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::to_train_case;
+/// assert_eq!(to_train_case("content_type"), "Content-Type");
+/// ```
+pub fn to_train_case(s: &str) -> String {
+    convert_case(s, Case::Train, &CaseOptions::default())
+}
+
+/// Convert to dot.case
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::to_dot_case;
+/// assert_eq!(to_dot_case("ServerPort"), "server.port");
+/// ```
+pub fn to_dot_case(s: &str) -> String {
+    convert_case(s, Case::Dot, &CaseOptions::default())
+}
+
+/// Convert to Title Case, capitalizing every word
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::to_title_case;
+/// assert_eq!(to_title_case("the lord of the rings"), "The Lord Of The Rings");
+/// ```
 pub fn to_title_case(s: &str) -> String {
-    s.split_whitespace()
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => {
-                    first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
-                }
-            }
-        })
-        .collect::<Vec<_>>()
-        .join(" ")
+    to_title_case_with(s, &TitleCaseOptions::default())
+}
+
+/// Which words [`to_title_case_with`] leaves lowercase
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleStyle {
+    /// Capitalize every word
+    #[default]
+    EveryWord,
+    /// AP style: articles, and conjunctions and prepositions of up to three
+    /// letters, stay lowercase
+    Ap,
+    /// Chicago style: articles, prepositions of any length, and
+    /// `and`/`but`/`for`/`or`/`nor` stay lowercase
+    Chicago,
+}
+
+/// Options for [`to_title_case_with`].
+///
+/// `exceptions` are written exactly as given wherever they appear (matched
+/// case-insensitively), even at the start or end of the title: list brand
+/// names and acronyms such as `"iPhone"` or `"NASA"` here.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::case::{to_title_case_with, TitleCaseOptions, TitleStyle};
+/// let opts = TitleCaseOptions::new(TitleStyle::Chicago).with_exceptions(&["NASA"]);
+/// assert_eq!(to_title_case_with("nasa at the edge of space", &opts), "NASA at the Edge of Space");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TitleCaseOptions {
+    /// Small-word rules to apply
+    pub style: TitleStyle,
+    /// Words with a fixed spelling
+    pub exceptions: Vec<String>,
+}
+
+impl TitleCaseOptions {
+    /// Options using `style` and no exceptions
+    pub fn new(style: TitleStyle) -> Self {
+        Self {
+            style,
+            exceptions: Vec::new(),
+        }
+    }
+
+    /// Add words with a fixed spelling
+    pub fn with_exceptions(mut self, words: &[&str]) -> Self {
+        self.exceptions.extend(words.iter().map(|w| w.to_string()));
+        self
+    }
+}
+
+const ARTICLES: &[&str] = &["a", "an", "the"];
+const CONJUNCTIONS: &[&str] = &["and", "but", "for", "nor", "or", "so", "yet"];
+const PREPOSITIONS: &[&str] = &[
+    "about",
+    "above",
+    "across",
+    "after",
+    "against",
+    "along",
+    "among",
+    "around",
+    "as",
+    "at",
+    "before",
+    "behind",
+    "below",
+    "beneath",
+    "beside",
+    "between",
+    "beyond",
+    "by",
+    "down",
+    "during",
+    "except",
+    "for",
+    "from",
+    "in",
+    "inside",
+    "into",
+    "like",
+    "near",
+    "of",
+    "off",
+    "on",
+    "onto",
+    "out",
+    "outside",
+    "over",
+    "past",
+    "per",
+    "since",
+    "through",
+    "throughout",
+    "till",
+    "to",
+    "toward",
+    "towards",
+    "under",
+    "underneath",
+    "until",
+    "up",
+    "upon",
+    "via",
+    "with",
+    "within",
+    "without",
+];
+
+fn is_small_word(word: &str, style: TitleStyle) -> bool {
+    match style {
+        TitleStyle::EveryWord => false,
+        TitleStyle::Ap => {
+            ARTICLES.contains(&word)
+                || (word.len() <= 3
+                    && (CONJUNCTIONS.contains(&word) || PREPOSITIONS.contains(&word)))
+        }
+        TitleStyle::Chicago => {
+            ARTICLES.contains(&word)
+                || PREPOSITIONS.contains(&word)
+                || ["and", "but", "for", "or", "nor"].contains(&word)
+        }
+    }
+}
+
+// Uppercase the first character as-is (punctuation included), lowercase the rest
+fn capitalize_first_char(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+    }
+}
+
+// Uppercase the first letter (skipping leading punctuation), lowercase the rest
+fn capitalize_first_letter(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    let mut done = false;
+    for c in word.chars() {
+        if !done && c.is_alphanumeric() {
+            out.extend(c.to_uppercase());
+            done = true;
+        } else {
+            out.extend(c.to_lowercase());
+        }
+    }
+    out
+}
+
+/// Convert to Title Case with small-word rules and fixed-spelling exceptions.
+///
+/// Words are separated by whitespace (runs collapse to one space). Small
+/// words are still capitalized as the first or last word and after a word
+/// ending in `:`, `.`, `!`, `?`, or `—`. Surrounding punctuation is ignored
+/// when matching words, so `"(of)"` counts as `of`, and the AP and Chicago
+/// styles capitalize the first letter after it (`"(foo)"` becomes `"(Foo)"`).
+/// [`TitleStyle::EveryWord`] keeps [`to_title_case`]'s rule of uppercasing
+/// each word's first character, so `"(foo)"` stays as is.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::case::{to_title_case_with, TitleCaseOptions, TitleStyle};
+/// let ap = TitleCaseOptions::new(TitleStyle::Ap);
+/// assert_eq!(to_title_case_with("the lord of the rings", &ap), "The Lord of the Rings");
+/// assert_eq!(to_title_case_with("a tale of two cities: the sequel", &ap), "A Tale of Two Cities: The Sequel");
+/// // AP capitalizes prepositions of four or more letters; Chicago does not
+/// assert_eq!(to_title_case_with("life without walls", &ap), "Life Without Walls");
+/// let chicago = TitleCaseOptions::new(TitleStyle::Chicago);
+/// assert_eq!(to_title_case_with("life without walls", &chicago), "Life without Walls");
+/// assert_eq!(to_title_case_with("what it's made of", &chicago), "What It's Made Of");
+/// ```
+pub fn to_title_case_with(s: &str, opts: &TitleCaseOptions) -> String {
+    let words: Vec<&str> = s.split_whitespace().collect();
+    let mut out = Vec::with_capacity(words.len());
+    for (i, word) in words.iter().enumerate() {
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if let Some(e) = opts
+            .exceptions
+            .iter()
+            .find(|e| !core.is_empty() && e.to_lowercase() == core.to_lowercase())
+        {
+            out.push(word.replacen(core, e, 1));
+            continue;
+        }
+        let boundary =
+            i == 0 || i + 1 == words.len() || words[i - 1].ends_with([':', '.', '!', '?', '—']);
+        if opts.style == TitleStyle::EveryWord {
+            out.push(capitalize_first_char(word));
+        } else if !boundary && is_small_word(&core.to_lowercase(), opts.style) {
+            out.push(word.to_lowercase());
+        } else {
+            out.push(capitalize_first_letter(word));
+        }
+    }
+    out.join(" ")
 }
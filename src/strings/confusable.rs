@@ -0,0 +1,116 @@
+//! Homoglyph ("confusable") detection for spoofing checks.
+//!
+//! Strings are reduced to a *skeleton* in the spirit of Unicode TS #39: common
+//! Cyrillic and Greek lookalikes map to their Latin counterparts, `0`/`O`,
+//! `1`/`l`/`I` and `rn`/`m` are folded together, and invisible format
+//! characters are dropped. With the `unicode` feature the input is first
+//! NFKD-normalized, which also folds fullwidth letters and ligatures. Two
+//! strings are confusable when their skeletons match.
+//!
+//! The comparison is case-sensitive; lowercase both sides first for
+//! case-insensitive identifiers such as usernames.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::strings::confusable::{confusable_skeleton, is_confusable};
+//! assert!(is_confusable("paypal", "p\u{430}yp\u{430}l")); // Cyrillic 'а'
+//! assert!(is_confusable("admin", "adrnin"));
+//! assert!(!is_confusable("admin", "bdmin"));
+//! assert_eq!(confusable_skeleton("G00GLE"), "GOOGLE");
+//! ```
+
+const HOMOGLYPHS: &[(char, &str)] = &[
+    // Cyrillic lowercase
+    ('\u{430}', "a"),
+    ('\u{435}', "e"),
+    ('\u{43e}', "o"),
+    ('\u{440}', "p"),
+    ('\u{441}', "c"),
+    ('\u{443}', "y"),
+    ('\u{445}', "x"),
+    ('\u{456}', "i"),
+    ('\u{458}', "j"),
+    ('\u{455}', "s"),
+    ('\u{501}', "d"),
+    ('\u{4bb}', "h"),
+    ('\u{51b}', "q"),
+    ('\u{51d}', "w"),
+    // Cyrillic uppercase
+    ('\u{410}', "A"),
+    ('\u{412}', "B"),
+    ('\u{415}', "E"),
+    ('\u{41a}', "K"),
+    ('\u{41c}', "M"),
+    ('\u{41d}', "H"),
+    ('\u{41e}', "O"),
+    ('\u{420}', "P"),
+    ('\u{421}', "C"),
+    ('\u{422}', "T"),
+    ('\u{425}', "X"),
+    ('\u{423}', "Y"),
+    ('\u{406}', "l"),
+    ('\u{408}', "J"),
+    ('\u{405}', "S"),
+    ('\u{4c0}', "l"),
+    // Greek
+    ('\u{3b1}', "a"),
+    ('\u{3bf}', "o"),
+    ('\u{3bd}', "v"),
+    ('\u{3c1}', "p"),
+    ('\u{3b9}', "i"),
+    ('\u{3ba}', "k"),
+    ('\u{391}', "A"),
+    ('\u{392}', "B"),
+    ('\u{395}', "E"),
+    ('\u{396}', "Z"),
+    ('\u{397}', "H"),
+    ('\u{399}', "l"),
+    ('\u{39a}', "K"),
+    ('\u{39c}', "M"),
+    ('\u{39d}', "N"),
+    ('\u{39f}', "O"),
+    ('\u{3a1}', "P"),
+    ('\u{3a4}', "T"),
+    ('\u{3a5}', "Y"),
+    ('\u{3a7}', "X"),
+    // Latin lookalikes
+    ('0', "O"),
+    ('1', "l"),
+    ('I', "l"),
+    ('|', "l"),
+    ('\u{131}', "i"),
+    ('\u{261}', "g"),
+    ('m', "rn"),
+    ('w', "vv"),
+];
+
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{ad}' | '\u{200b}'..='\u{200f}' | '\u{2060}'..='\u{2064}' | '\u{feff}'
+    )
+}
+
+/// Reduce a string to its confusable skeleton (see the module docs).
+pub fn confusable_skeleton(s: &str) -> String {
+    #[cfg(feature = "unicode")]
+    let normalized = crate::encoding::normalize_nfkd(s);
+    #[cfg(not(feature = "unicode"))]
+    let normalized = s;
+    let mut out = String::with_capacity(normalized.len());
+    for c in normalized.chars().filter(|c| !is_invisible(*c)) {
+        match HOMOGLYPHS.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => out.push_str(to),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// True if `a` and `b` look alike (their skeletons are equal).
+///
+/// Identical strings are trivially confusable; check `a != b` as well when
+/// looking for spoofing attempts.
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    confusable_skeleton(a) == confusable_skeleton(b)
+}
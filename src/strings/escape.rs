@@ -1,4 +1,4 @@
-//! Escaping utilities (HTML, regex)
+//! Escaping utilities (HTML, regex, JSON, CSV, XML, SQL)
 
 /// Escape HTML special characters
 pub fn escape_html(input: &str) -> String {
@@ -30,3 +30,198 @@ pub fn escape_regex(input: &str) -> String {
     }
     out
 }
+
+/// Escape a string for inclusion inside a JSON string literal (without quotes)
+///
+/// Escapes `"`, `\`, and control characters; non-ASCII text is kept as-is.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::escape_json_string;
+/// assert_eq!(escape_json_string("say \"hi\"\n"), r#"say \"hi\"\n"#);
+/// assert_eq!(escape_json_string("\u{1}"), r"\u0001");
+/// ```
+pub fn escape_json_string(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Reverse [`escape_json_string`], decoding all JSON escape sequences
+///
+/// Handles `\uXXXX` including surrogate pairs. Returns `None` on a malformed
+/// or truncated escape sequence.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::unescape_json_string;
+/// assert_eq!(unescape_json_string(r#"a\"b\né😀"#).unwrap(), "a\"b\né😀");
+/// assert_eq!(unescape_json_string(r"\x"), None);
+/// ```
+pub fn unescape_json_string(input: &str) -> Option<String> {
+    fn hex4(chars: &mut std::str::Chars<'_>) -> Option<u32> {
+        let mut v = 0u32;
+        for _ in 0..4 {
+            v = v * 16 + chars.next()?.to_digit(16)?;
+        }
+        Some(v)
+    }
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next()? {
+            '"' => out.push('"'),
+            '\\' => out.push('\\'),
+            '/' => out.push('/'),
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            'b' => out.push('\u{8}'),
+            'f' => out.push('\u{c}'),
+            'u' => {
+                let hi = hex4(&mut chars)?;
+                let code = if (0xD800..0xDC00).contains(&hi) {
+                    if chars.next()? != '\\' || chars.next()? != 'u' {
+                        return None;
+                    }
+                    let lo = hex4(&mut chars)?;
+                    if !(0xDC00..0xE000).contains(&lo) {
+                        return None;
+                    }
+                    0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00)
+                } else {
+                    hi
+                };
+                out.push(char::from_u32(code)?);
+            }
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+/// Escape a single CSV field per RFC 4180
+///
+/// Fields containing a comma, quote, CR, or LF are wrapped in double quotes
+/// with inner quotes doubled; other fields are returned unchanged.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::escape_csv_field;
+/// assert_eq!(escape_csv_field("plain"), "plain");
+/// assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+/// assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+/// ```
+pub fn escape_csv_field(input: &str) -> String {
+    if input.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", input.replace('"', "\"\""))
+    } else {
+        input.to_string()
+    }
+}
+
+/// Escape XML special characters for text and attribute values
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::escape_xml;
+/// assert_eq!(escape_xml("<a href='x'>&</a>"), "&lt;a href=&apos;x&apos;&gt;&amp;&lt;/a&gt;");
+/// ```
+pub fn escape_xml(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Escape a value for use inside a single-quoted SQL string literal
+///
+/// Doubles single quotes (`'` becomes `''`). This is a convenience for
+/// generating scripts and fixtures; prefer parameterized queries when talking
+/// to a database.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::escape_sql_string;
+/// assert_eq!(escape_sql_string("O'Brien"), "O''Brien");
+/// ```
+pub fn escape_sql_string(input: &str) -> String {
+    input.replace('\'', "''")
+}
+
+/// Decode HTML entities
+///
+/// Supports the common named entities (`&amp;`, `&lt;`, `&gt;`, `&quot;`,
+/// `&apos;`, `&nbsp;`) and decimal/hex numeric references (`&#39;`, `&#x27;`).
+/// Unknown or malformed entities are left untouched.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::escape::{escape_html, unescape_html};
+/// assert_eq!(unescape_html("&lt;b&gt; &#39;x&#x27; &copy;"), "<b> 'x' &copy;");
+/// assert_eq!(unescape_html(&escape_html("<\"&'>")), "<\"&'>");
+/// ```
+pub fn unescape_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 12).and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => {
+                    let num = entity.strip_prefix('#')?;
+                    let code = match num.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => num.parse().ok()?,
+                    };
+                    char::from_u32(code)
+                }
+            };
+            ch.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
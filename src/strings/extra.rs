@@ -1,20 +1,176 @@
-//! Additional string utilities: slugify, pluralize, singularize, levenshtein
+//! Additional string utilities: slugify, transliteration, pluralize, singularize, levenshtein,
+//! run-length encoding
+
+pub use super::inflect::{pluralize, singularize};
+
+/// Options for [`slugify_with`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlugOptions {
+    /// Character placed between words (default `'-'`)
+    pub separator: char,
+    /// Maximum slug length in bytes; cuts at the last separator that fits when possible
+    pub max_length: Option<usize>,
+    /// Lowercase the output (default `true`)
+    pub lowercase: bool,
+    /// Words dropped from the slug, compared case-insensitively
+    pub stop_words: Vec<String>,
+    /// Transliterate `ä`/`ö`/`ü` as `ae`/`oe`/`ue` (German style) instead of `a`/`o`/`u`
+    pub expand_umlauts: bool,
+}
+
+impl Default for SlugOptions {
+    fn default() -> Self {
+        Self {
+            separator: '-',
+            max_length: None,
+            lowercase: true,
+            stop_words: Vec::new(),
+            expand_umlauts: false,
+        }
+    }
+}
 
-/// Create a URL-friendly slug from a string
-pub fn slugify(input: &str) -> String {
+fn transliterate_char(ch: char, expand_umlauts: bool) -> Option<&'static str> {
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+    Some(match lower {
+        'ä' if expand_umlauts => "ae",
+        'ö' if expand_umlauts => "oe",
+        'ü' if expand_umlauts => "ue",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' | 'ĉ' | 'ċ' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
+}
+
+/// Replace common accented Latin letters with ASCII equivalents
+///
+/// Case is preserved (`É` becomes `E`, `Æ` becomes `AE`); characters without a
+/// mapping are kept unchanged.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::extra::transliterate;
+/// assert_eq!(transliterate("Crème Brûlée"), "Creme Brulee");
+/// assert_eq!(transliterate("Straße Łódź"), "Strasse Lodz");
+/// ```
+pub fn transliterate(input: &str) -> String {
+    transliterate_with(input, false)
+}
+
+fn transliterate_with(input: &str, expand_umlauts: bool) -> String {
     let mut out = String::with_capacity(input.len());
-    let mut last_dash = false;
     for ch in input.chars() {
-        if ch.is_ascii_alphanumeric() {
-            out.push(ch.to_ascii_lowercase());
-            last_dash = false;
-        } else if (ch.is_whitespace() || ch == '-' || ch == '_') && !last_dash && !out.is_empty() {
-            out.push('-');
-            last_dash = true;
+        match transliterate_char(ch, expand_umlauts) {
+            Some(rep) if ch.is_uppercase() => out.push_str(&rep.to_ascii_uppercase()),
+            Some(rep) => out.push_str(rep),
+            None => out.push(ch),
         }
     }
-    if out.ends_with('-') {
-        out.pop();
+    out
+}
+
+/// Create a URL-friendly slug from a string
+///
+/// Accented letters are transliterated to ASCII first; see [`slugify_with`]
+/// for custom separators, length limits, and stop words.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::slugify;
+/// assert_eq!(slugify("Crème Brûlée, 2nd edition"), "creme-brulee-2nd-edition");
+/// ```
+pub fn slugify(input: &str) -> String {
+    slugify_with(input, &SlugOptions::default())
+}
+
+/// Create a slug using [`SlugOptions`]
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::{slugify_with, SlugOptions};
+/// let opts = SlugOptions {
+///     separator: '_',
+///     max_length: Some(16),
+///     stop_words: vec!["the".into(), "of".into()],
+///     expand_umlauts: true,
+///     ..SlugOptions::default()
+/// };
+/// assert_eq!(slugify_with("The Tale of Müller Straße", &opts), "tale_mueller");
+/// ```
+pub fn slugify_with(input: &str, options: &SlugOptions) -> String {
+    let text = transliterate_with(input, options.expand_umlauts);
+    let sep = options.separator;
+    let mut words: Vec<String> = Vec::new();
+    // Words are split on whitespace, '-' and '_'; other symbols are dropped
+    for raw in text.split(|c: char| c.is_whitespace() || c == '-' || c == '_') {
+        let word: String = raw
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| {
+                if options.lowercase {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        if word.is_empty()
+            || options
+                .stop_words
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(&word))
+        {
+            continue;
+        }
+        words.push(word);
+    }
+    let mut out = String::with_capacity(text.len());
+    for word in words {
+        if !out.is_empty() {
+            out.push(sep);
+        }
+        out.push_str(&word);
+    }
+    if let Some(max) = options.max_length {
+        if out.len() > max {
+            // Output is ASCII apart from the separator, so find a char boundary
+            let mut cut = max;
+            while !out.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            let head = &out[..cut];
+            let next_is_sep = out[cut..].starts_with(sep);
+            let end = if next_is_sep {
+                cut
+            } else {
+                head.rfind(sep).unwrap_or(cut)
+            };
+            out.truncate(end);
+            while out.ends_with(sep) {
+                out.pop();
+            }
+        }
     }
     out
 }
@@ -89,38 +245,6 @@ pub fn ensure_suffix(s: &str, suffix: &str) -> String {
     }
 }
 
-/// Very simple pluralize for common English nouns
-pub fn pluralize(word: &str) -> String {
-    if word.ends_with("y") && !matches!(word.chars().nth_back(1), Some('a' | 'e' | 'i' | 'o' | 'u'))
-    {
-        let mut s = word.to_string();
-        s.pop();
-        s.push_str("ies");
-        s
-    } else if word.ends_with('s')
-        || word.ends_with("x")
-        || word.ends_with("ch")
-        || word.ends_with("sh")
-    {
-        format!("{word}es")
-    } else {
-        format!("{word}s")
-    }
-}
-
-/// Very simple singularize matching the above pluralize
-pub fn singularize(word: &str) -> String {
-    if let Some(base) = word.strip_suffix("ies") {
-        format!("{base}y")
-    } else if let Some(base) = word.strip_suffix("es") {
-        base.to_string()
-    } else if let Some(base) = word.strip_suffix('s') {
-        base.to_string()
-    } else {
-        word.to_string()
-    }
-}
-
 /// Levenshtein distance between two strings
 pub fn levenshtein_distance(a: &str, b: &str) -> usize {
     let (a_len, b_len) = (a.chars().count(), b.chars().count());
@@ -217,29 +341,20 @@ pub fn find_all_indices(haystack: &str, needle: &str) -> Vec<usize> {
     haystack.match_indices(needle).map(|(i, _)| i).collect()
 }
 
-/// Word wrap text at width, breaking on whitespace
+/// Word wrap text at width, breaking on whitespace only
+///
+/// Widths are display columns; see [`wrap_opts`](super::wrap_opts) for long-word
+/// breaking and indentation.
 pub fn wrap(text: &str, width: usize) -> String {
     if width == 0 {
         return text.to_string();
     }
-    let mut out = String::new();
-    let mut line_len = 0usize;
-    for word in text.split_whitespace() {
-        let wlen = word.len();
-        if line_len == 0 {
-            out.push_str(word);
-            line_len = wlen;
-        } else if line_len + 1 + wlen <= width {
-            out.push(' ');
-            out.push_str(word);
-            line_len += 1 + wlen;
-        } else {
-            out.push('\n');
-            out.push_str(word);
-            line_len = wlen;
-        }
-    }
-    out
+    let opts = super::WrapOptions {
+        break_long_words: false,
+        preserve_newlines: false,
+        ..super::WrapOptions::new(width)
+    };
+    super::wrap_opts(text, &opts)
 }
 
 /// Add indentation with given prefix to each non-empty line
@@ -276,6 +391,105 @@ pub fn dedent(text: &str, n: usize) -> String {
         .join("\n")
 }
 
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Remove the whitespace prefix shared by every non-blank line
+///
+/// Like Python's `textwrap.dedent`: tabs and spaces are compared literally,
+/// whitespace-only lines are emptied and ignored when finding the prefix, and
+/// line endings are preserved.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::extra::dedent_auto;
+/// let text = "    fn main() {\n        run();\n\n    }\n";
+/// assert_eq!(dedent_auto(text), "fn main() {\n    run();\n\n}\n");
+/// ```
+pub fn dedent_auto(text: &str) -> String {
+    let mut common: Option<&str> = None;
+    for line in text.lines().filter(|l| !is_blank(l)) {
+        let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        common = Some(match common {
+            None => indent,
+            Some(prev) => {
+                let shared = prev
+                    .bytes()
+                    .zip(indent.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                &prev[..shared]
+            }
+        });
+    }
+    let common = common.unwrap_or("");
+    text.split_inclusive('\n')
+        .map(|line| {
+            if is_blank(line) {
+                &line[line.trim_end_matches(['\n', '\r']).len()..]
+            } else {
+                line.strip_prefix(common).unwrap_or(line)
+            }
+        })
+        .collect()
+}
+
+/// Strip leading whitespace followed by `margin` from each line (Kotlin's `trimMargin`)
+///
+/// Lines without the margin are kept as-is; a blank first or last line is
+/// dropped so the text can start on the line after an opening quote.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::extra::trim_margin;
+/// let sql = "
+///     |SELECT *
+///     |  FROM users
+///     ";
+/// assert_eq!(trim_margin(sql, '|'), "SELECT *\n  FROM users");
+/// ```
+pub fn trim_margin(text: &str, margin: char) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.last().is_some_and(|l| is_blank(l)) {
+        lines.pop();
+    }
+    if lines.first().is_some_and(|l| is_blank(l)) {
+        lines.remove(0);
+    }
+    lines
+        .iter()
+        .map(|l| l.trim_start().strip_prefix(margin).unwrap_or(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collapse runs of blank lines to at most `max_consecutive`
+///
+/// Whitespace-only lines count as blank and are kept verbatim; with `0`
+/// every blank line is removed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::extra::squeeze_blank_lines;
+/// assert_eq!(squeeze_blank_lines("a\n\n\n\nb\n", 1), "a\n\nb\n");
+/// assert_eq!(squeeze_blank_lines("a\n\nb", 0), "a\nb");
+/// ```
+pub fn squeeze_blank_lines(text: &str, max_consecutive: usize) -> String {
+    let mut run = 0;
+    text.split_inclusive('\n')
+        .filter(|line| {
+            if is_blank(line) {
+                run += 1;
+                run <= max_consecutive
+            } else {
+                run = 0;
+                true
+            }
+        })
+        .collect()
+}
+
 /// Check if string looks like an email
 pub fn is_email(s: &str) -> bool {
     s.contains('@')
@@ -283,9 +497,11 @@ pub fn is_email(s: &str) -> bool {
         && s.split('@').nth(1).is_some_and(|d| d.contains('.'))
 }
 
-/// Check if string looks like a URL (very basic)
+/// Check if string is an http(s) URL with a valid host and port
+///
+/// See [`validate_url`](crate::validation::validate_url) for configurable rules.
 pub fn is_url(s: &str) -> bool {
-    s.starts_with("http://") || s.starts_with("https://")
+    crate::validation::validate_url(s, &crate::validation::UrlRules::default()).is_ok()
 }
 
 /// Check if string is UUID v4 format
@@ -395,20 +611,12 @@ pub fn escape_shell(s: &str) -> String {
     format!("'{}'", s.replace("'", "'\\''"))
 }
 
-/// Random ASCII string from given charset
+/// Random alphanumeric ASCII string
+///
+/// Alias for [`random_alphanumeric`](crate::random::random_alphanumeric); see
+/// [`random_string_with`](crate::random::random_string_with) for custom charsets.
 pub fn random_string(len: usize) -> String {
-    let charset = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
-    let mut state = std::time::Instant::now().elapsed().as_nanos();
-    let mut next_u64 = || {
-        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
-        (state >> 32) as u64
-    };
-    let mut out = String::with_capacity(len);
-    for _ in 0..len {
-        let idx = (next_u64() as usize) % charset.len();
-        out.push(charset[idx] as char);
-    }
-    out
+    crate::random::random_alphanumeric(len)
 }
 
 /// Mask part of a string, leaving prefix and suffix visible
@@ -423,16 +631,94 @@ pub fn mask(s: &str, prefix: usize, suffix: usize, mask_char: char) -> String {
     out
 }
 
-/// Truncate the middle with ellipsis if longer than max_len
+/// Truncate the middle with ellipsis if longer than `max_len` characters
+///
+/// Shorthand for [`truncate_opts`](super::manipulation::truncate_opts) with
+/// [`EllipsisPosition::Middle`](super::manipulation::EllipsisPosition::Middle).
+/// Strings are returned unchanged when `max_len < 3`.
 pub fn ellipsis_middle(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len || max_len < 3 {
+    use super::manipulation::{truncate_opts, EllipsisPosition, TruncateOptions};
+    if max_len < 3 {
         return s.to_string();
     }
-    let side = (max_len - 3) / 2;
-    format!("{}...{}", &s[..side], &s[s.len() - side..])
+    let opts = TruncateOptions {
+        position: EllipsisPosition::Middle,
+        ..TruncateOptions::new(max_len)
+    };
+    truncate_opts(s, &opts)
 }
 
 /// Collapse consecutive whitespace to single spaces and trim ends
 pub fn normalize_whitespace(s: &str) -> String {
     s.split_whitespace().collect::<Vec<_>>().join(" ")
 }
+
+/// Run-length encode: each run becomes its count (omitted for 1) and the char
+///
+/// Digits and `\` in the input are escaped with `\` so the output always
+/// decodes unambiguously with [`rle_decode`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::{rle_decode, rle_encode};
+/// assert_eq!(rle_encode("aaabccdddd"), "3ab2c4d");
+/// assert_eq!(rle_encode("x111"), "x3\\1");
+/// assert_eq!(rle_decode(&rle_encode("x111")).unwrap(), "x111");
+/// ```
+pub fn rle_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut run = 1usize;
+        while chars.next_if_eq(&c).is_some() {
+            run += 1;
+        }
+        if run > 1 {
+            out.push_str(&run.to_string());
+        }
+        if c.is_ascii_digit() || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Decode the output of [`rle_encode`]
+///
+/// Returns `None` for malformed input: a count without a character, a zero
+/// count, a count that overflows `usize`, or a trailing `\`. A run too large
+/// to allocate also yields `None` instead of aborting the process.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::rle_decode;
+/// assert_eq!(rle_decode("3ab2c").unwrap(), "aaabcc");
+/// assert_eq!(rle_decode("12"), None);
+/// ```
+pub fn rle_decode(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    let mut count: Option<usize> = None;
+    while let Some(c) = chars.next() {
+        if let Some(d) = c.to_digit(10) {
+            count = Some(
+                count
+                    .unwrap_or(0)
+                    .checked_mul(10)?
+                    .checked_add(d as usize)?,
+            );
+            continue;
+        }
+        let c = if c == '\\' { chars.next()? } else { c };
+        match count.take() {
+            Some(0) => return None,
+            Some(n) => {
+                out.try_reserve(n.checked_mul(c.len_utf8())?).ok()?;
+                out.extend(std::iter::repeat(c).take(n));
+            }
+            None => out.push(c),
+        }
+    }
+    count.is_none().then_some(out)
+}
@@ -0,0 +1,94 @@
+//! Substring extraction around delimiters without manual index math
+
+/// Text between the first `start` and the next `end` after it.
+///
+/// Returns `None` if either delimiter is missing.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::between;
+/// assert_eq!(between("key=[value] rest", "[", "]"), Some("value"));
+/// assert_eq!(between("<a><b>", "<", ">"), Some("a"));
+/// assert_eq!(between("no brackets", "[", "]"), None);
+/// ```
+pub fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let len = s[from..].find(end)?;
+    Some(&s[from..from + len])
+}
+
+/// Every non-overlapping `start`…`end` span, scanning left to right.
+///
+/// An unterminated trailing `start` is ignored, and an empty `start` matches
+/// nothing.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::between_all;
+/// assert_eq!(between_all("{a} and {b} and {c", "{", "}"), ["a", "b"]);
+/// assert!(between_all("plain", "{", "}").is_empty());
+/// ```
+pub fn between_all<'a>(s: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut out = Vec::new();
+    if start.is_empty() {
+        return out;
+    }
+    let mut rest = s;
+    while let Some(pos) = rest.find(start) {
+        let inner = &rest[pos + start.len()..];
+        let Some(len) = inner.find(end) else {
+            break;
+        };
+        out.push(&inner[..len]);
+        rest = &inner[len + end.len()..];
+    }
+    out
+}
+
+/// Text before the first `delim`, or `None` if it does not occur.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::before;
+/// assert_eq!(before("user@example.com", "@"), Some("user"));
+/// assert_eq!(before("a.b.c", "."), Some("a"));
+/// assert_eq!(before("abc", "/"), None);
+/// ```
+pub fn before<'a>(s: &'a str, delim: &str) -> Option<&'a str> {
+    s.split_once(delim).map(|(head, _)| head)
+}
+
+/// Text after the first `delim`, or `None` if it does not occur.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::after;
+/// assert_eq!(after("user@example.com", "@"), Some("example.com"));
+/// assert_eq!(after("a.b.c", "."), Some("b.c"));
+/// ```
+pub fn after<'a>(s: &'a str, delim: &str) -> Option<&'a str> {
+    s.split_once(delim).map(|(_, tail)| tail)
+}
+
+/// Text before the last `delim`, or `None` if it does not occur.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::before_last;
+/// assert_eq!(before_last("archive.tar.gz", "."), Some("archive.tar"));
+/// ```
+pub fn before_last<'a>(s: &'a str, delim: &str) -> Option<&'a str> {
+    s.rsplit_once(delim).map(|(head, _)| head)
+}
+
+/// Text after the last `delim`, or `None` if it does not occur.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::after_last;
+/// assert_eq!(after_last("archive.tar.gz", "."), Some("gz"));
+/// assert_eq!(after_last("/usr/local/bin", "/"), Some("bin"));
+/// ```
+pub fn after_last<'a>(s: &'a str, delim: &str) -> Option<&'a str> {
+    s.rsplit_once(delim).map(|(_, tail)| tail)
+}
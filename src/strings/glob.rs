@@ -0,0 +1,48 @@
+//! `*`/`?` wildcard matching shared by grep and deep path patterns
+
+/// True if `pattern` matches all of `text` (`*` any run, `?` one char)
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    if !pattern.contains(['*', '?']) {
+        return pattern == text;
+    }
+    let pat: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    match_chars(&pat, &text, true)
+}
+
+/// True if `pat` matches some prefix of `text`
+pub(crate) fn wildcard_prefix_match(pat: &[char], text: &[char]) -> bool {
+    match_chars(pat, text, false)
+}
+
+// Greedy matcher that backtracks only to the most recent `*`
+fn match_chars(pat: &[char], text: &[char], whole: bool) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    loop {
+        if p == pat.len() && (!whole || t == text.len()) {
+            return true;
+        }
+        match pat.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+                continue;
+            }
+            Some(&c) if t < text.len() && (c == '?' || c == text[t]) => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((bp, bt)) if bt < text.len() => {
+                backtrack = Some((bp, bt + 1));
+                p = bp + 1;
+                t = bt + 1;
+            }
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! English noun inflection with irregular and uncountable word tables
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::sync::{OnceLock, RwLock};
+
+const IRREGULARS: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("tooth", "teeth"),
+    ("foot", "feet"),
+    ("mouse", "mice"),
+    ("goose", "geese"),
+    ("ox", "oxen"),
+    ("leaf", "leaves"),
+    ("loaf", "loaves"),
+    ("knife", "knives"),
+    ("wife", "wives"),
+    ("life", "lives"),
+    ("wolf", "wolves"),
+    ("half", "halves"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("calf", "calves"),
+    ("elf", "elves"),
+    ("cactus", "cacti"),
+    ("focus", "foci"),
+    ("fungus", "fungi"),
+    ("nucleus", "nuclei"),
+    ("radius", "radii"),
+    ("stimulus", "stimuli"),
+    ("analysis", "analyses"),
+    ("basis", "bases"),
+    ("crisis", "crises"),
+    ("thesis", "theses"),
+    ("criterion", "criteria"),
+    ("phenomenon", "phenomena"),
+    ("index", "indices"),
+    ("matrix", "matrices"),
+    ("vertex", "vertices"),
+    ("appendix", "appendices"),
+    ("potato", "potatoes"),
+    ("tomato", "tomatoes"),
+    ("hero", "heroes"),
+    ("echo", "echoes"),
+    ("quiz", "quizzes"),
+    ("die", "dice"),
+    ("bus", "buses"),
+    ("gas", "gases"),
+    ("lens", "lenses"),
+];
+
+const UNCOUNTABLES: &[&str] = &[
+    "aircraft",
+    "data",
+    "deer",
+    "equipment",
+    "feedback",
+    "fish",
+    "information",
+    "metadata",
+    "money",
+    "moose",
+    "news",
+    "rice",
+    "series",
+    "sheep",
+    "software",
+    "species",
+];
+
+struct Rules {
+    plurals: HashMap<String, String>,
+    singulars: HashMap<String, String>,
+    uncountable: HashSet<String>,
+}
+
+fn rules() -> &'static RwLock<Rules> {
+    static RULES: OnceLock<RwLock<Rules>> = OnceLock::new();
+    RULES.get_or_init(|| {
+        let mut r = Rules {
+            plurals: HashMap::new(),
+            singulars: HashMap::new(),
+            uncountable: UNCOUNTABLES.iter().map(|w| w.to_string()).collect(),
+        };
+        for (one, many) in IRREGULARS {
+            r.plurals.insert(one.to_string(), many.to_string());
+            r.singulars.insert(many.to_string(), one.to_string());
+        }
+        RwLock::new(r)
+    })
+}
+
+/// Register an irregular singular/plural pair used by [`pluralize`] and
+/// [`singularize`] (case-insensitive; overrides built-in entries)
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::{pluralize, register_irregular, singularize};
+/// register_irregular("octopus", "octopodes");
+/// assert_eq!(pluralize("octopus"), "octopodes");
+/// assert_eq!(singularize("Octopodes"), "Octopus");
+/// ```
+pub fn register_irregular(singular: &str, plural: &str) {
+    let (one, many) = (singular.to_lowercase(), plural.to_lowercase());
+    let mut r = rules().write().unwrap_or_else(|e| e.into_inner());
+    r.uncountable.remove(&one);
+    r.plurals.insert(one.clone(), many.clone());
+    r.singulars.insert(many, one);
+}
+
+/// Register a word whose plural and singular forms are identical
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::{pluralize, register_uncountable};
+/// register_uncountable("bison");
+/// assert_eq!(pluralize("bison"), "bison");
+/// ```
+pub fn register_uncountable(word: &str) {
+    let mut r = rules().write().unwrap_or_else(|e| e.into_inner());
+    r.uncountable.insert(word.to_lowercase());
+}
+
+/// Re-apply the casing of `original` (lower, UPPER, or Capitalized) to `word`
+fn match_case(original: &str, word: &str) -> String {
+    let has_lower = original.chars().any(char::is_lowercase);
+    if !has_lower && original.chars().any(char::is_uppercase) {
+        return word.to_uppercase();
+    }
+    match original.chars().next() {
+        Some(first) if first.is_uppercase() => {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |c| {
+                c.to_uppercase().chain(chars).collect::<String>()
+            })
+        }
+        _ => word.to_string(),
+    }
+}
+
+fn is_vowel(c: Option<char>) -> bool {
+    matches!(c, Some('a' | 'e' | 'i' | 'o' | 'u'))
+}
+
+/// Pluralize an English noun
+///
+/// Handles irregular (`person` → `people`) and uncountable (`sheep`) words,
+/// including ones added with [`register_irregular`]/[`register_uncountable`],
+/// and preserves the input's casing.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::pluralize;
+/// assert_eq!(pluralize("box"), "boxes");
+/// assert_eq!(pluralize("city"), "cities");
+/// assert_eq!(pluralize("Person"), "People");
+/// assert_eq!(pluralize("leaf"), "leaves");
+/// assert_eq!(pluralize("sheep"), "sheep");
+/// ```
+pub fn pluralize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    {
+        let r = rules().read().unwrap_or_else(|e| e.into_inner());
+        if r.uncountable.contains(&lower) || r.singulars.contains_key(&lower) {
+            return word.to_string();
+        }
+        if let Some(plural) = r.plurals.get(&lower) {
+            return match_case(word, plural);
+        }
+    }
+    let suffix = if lower.ends_with('y') && !is_vowel(lower.chars().nth_back(1)) {
+        let stem = &word[..word.len() - 1];
+        return format!("{stem}{}", match_case(&word[stem.len()..], "ies"));
+    } else if lower.ends_with('s')
+        || lower.ends_with('x')
+        || lower.ends_with('z')
+        || lower.ends_with("ch")
+        || lower.ends_with("sh")
+    {
+        "es"
+    } else {
+        "s"
+    };
+    let upper = !word.chars().any(char::is_lowercase) && word.chars().any(char::is_uppercase);
+    if upper {
+        format!("{word}{}", suffix.to_uppercase())
+    } else {
+        format!("{word}{suffix}")
+    }
+}
+
+/// Singularize an English noun (inverse of [`pluralize`])
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::singularize;
+/// assert_eq!(singularize("boxes"), "box");
+/// assert_eq!(singularize("files"), "file");
+/// assert_eq!(singularize("children"), "child");
+/// assert_eq!(singularize("glass"), "glass");
+/// ```
+pub fn singularize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    {
+        let r = rules().read().unwrap_or_else(|e| e.into_inner());
+        if r.uncountable.contains(&lower) || r.plurals.contains_key(&lower) {
+            return word.to_string();
+        }
+        if let Some(single) = r.singulars.get(&lower) {
+            return match_case(word, single);
+        }
+    }
+    let cut = |n: usize| word[..word.len() - n].to_string();
+    if lower.ends_with("ies") && lower.len() > 3 {
+        let stem = &word[..word.len() - 3];
+        let y = if word.ends_with("IES") { "Y" } else { "y" };
+        format!("{stem}{y}")
+    } else if ["sses", "xes", "zes", "ches", "shes"]
+        .iter()
+        .any(|s| lower.ends_with(s))
+    {
+        cut(2)
+    } else if lower.ends_with('s') && !lower.ends_with("ss") && !lower.ends_with("us") {
+        cut(1)
+    } else {
+        word.to_string()
+    }
+}
+
+/// Format a count with the word inflected to match: `"1 box"`, `"3 boxes"`
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::pluralize_n;
+/// assert_eq!(pluralize_n("box", 1), "1 box");
+/// assert_eq!(pluralize_n("box", 0), "0 boxes");
+/// assert_eq!(pluralize_n("child", 3usize), "3 children");
+/// ```
+pub fn pluralize_n<N: Display + PartialEq + From<u8>>(word: &str, count: N) -> String {
+    if count == N::from(1) {
+        format!("{count} {word}")
+    } else {
+        format!("{count} {}", pluralize(word))
+    }
+}
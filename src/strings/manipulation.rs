@@ -39,6 +39,173 @@ pub fn truncate_with(s: &str, max_len: usize, suffix: &str) -> String {
     }
 }
 
+/// Where [`truncate_opts`] places the ellipsis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EllipsisPosition {
+    /// Keep the end: `...world`
+    Start,
+    /// Keep both ends: `hel...rld`
+    Middle,
+    /// Keep the start: `hello...`
+    #[default]
+    End,
+}
+
+/// What [`truncate_opts`] counts towards the limit
+///
+/// Non-exhaustive because `Graphemes` only exists with the `unicode` feature;
+/// downstream `match`es need a wildcard arm so enabling it stays additive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TruncateUnit {
+    /// Unicode scalar values (`char`s)
+    #[default]
+    Chars,
+    /// Extended grapheme clusters (user-perceived characters)
+    #[cfg(feature = "unicode")]
+    Graphemes,
+    /// Terminal display columns (see [`char_width`](super::wrap::char_width))
+    Width,
+}
+
+/// Options for [`truncate_opts`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// Maximum length of the result (including the ellipsis), in `unit`s
+    pub max: usize,
+    /// Unit of measurement
+    pub unit: TruncateUnit,
+    /// Where the text is cut
+    pub position: EllipsisPosition,
+    /// Marker inserted at the cut; dropped if it alone would exceed `max`
+    pub ellipsis: String,
+}
+
+impl TruncateOptions {
+    /// Truncate to `max` chars at the end with `"..."`
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            unit: TruncateUnit::Chars,
+            position: EllipsisPosition::End,
+            ellipsis: "...".to_string(),
+        }
+    }
+}
+
+// Split into (piece, cost) pairs according to the unit
+fn measure_units(s: &str, unit: TruncateUnit) -> Vec<(&str, usize)> {
+    match unit {
+        TruncateUnit::Chars => s
+            .char_indices()
+            .map(|(i, c)| (&s[i..i + c.len_utf8()], 1))
+            .collect(),
+        #[cfg(feature = "unicode")]
+        TruncateUnit::Graphemes => unicode_segmentation::UnicodeSegmentation::graphemes(s, true)
+            .map(|g| (g, 1))
+            .collect(),
+        TruncateUnit::Width => s
+            .char_indices()
+            .map(|(i, c)| (&s[i..i + c.len_utf8()], super::wrap::char_width(c)))
+            .collect(),
+    }
+}
+
+// Number of leading units whose total cost fits in `budget`
+fn fitting<'a>(units: impl Iterator<Item = &'a (&'a str, usize)>, budget: usize) -> usize {
+    let mut used = 0;
+    units
+        .take_while(|(_, cost)| {
+            used += cost;
+            used <= budget
+        })
+        .count()
+}
+
+/// Truncate by chars, graphemes or display width, with the ellipsis at the start, middle or end.
+///
+/// Never splits a character (or grapheme with [`TruncateUnit::Graphemes`]).
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::manipulation::{truncate_opts, EllipsisPosition, TruncateOptions};
+/// let opts = TruncateOptions { position: EllipsisPosition::Middle, ..TruncateOptions::new(8) };
+/// assert_eq!(truncate_opts("héllo wörld", &opts), "hél...ld");
+/// let opts = TruncateOptions { position: EllipsisPosition::Start, ellipsis: "…".into(), ..TruncateOptions::new(5) };
+/// assert_eq!(truncate_opts("héllo wörld", &opts), "…örld");
+/// ```
+pub fn truncate_opts(s: &str, opts: &TruncateOptions) -> String {
+    let units = measure_units(s, opts.unit);
+    let total: usize = units.iter().map(|(_, c)| c).sum();
+    if total <= opts.max {
+        return s.to_string();
+    }
+    let ell_cost: usize = measure_units(&opts.ellipsis, opts.unit)
+        .iter()
+        .map(|(_, c)| c)
+        .sum();
+    let ellipsis = if ell_cost <= opts.max {
+        opts.ellipsis.as_str()
+    } else {
+        ""
+    };
+    let budget = opts.max - if ellipsis.is_empty() { 0 } else { ell_cost };
+    let join = |range: &[(&str, usize)]| range.iter().map(|(p, _)| *p).collect::<String>();
+    match opts.position {
+        EllipsisPosition::End => {
+            let n = fitting(units.iter(), budget);
+            join(&units[..n]) + ellipsis
+        }
+        EllipsisPosition::Start => {
+            let n = fitting(units.iter().rev(), budget);
+            format!("{ellipsis}{}", join(&units[units.len() - n..]))
+        }
+        EllipsisPosition::Middle => {
+            let head = fitting(units.iter(), budget.div_ceil(2));
+            let head_cost: usize = units[..head].iter().map(|(_, c)| c).sum();
+            let tail = fitting(units[head..].iter().rev(), budget - head_cost);
+            format!(
+                "{}{ellipsis}{}",
+                join(&units[..head]),
+                join(&units[units.len() - tail..])
+            )
+        }
+    }
+}
+
+/// Truncate to at most `max_chars` characters, ending with `"..."` when cut.
+///
+/// Unlike [`truncate`], lengths are counted in `char`s rather than bytes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::truncate_chars;
+/// assert_eq!(truncate_chars("héllo wörld", 8), "héllo...");
+/// assert_eq!(truncate_chars("héllo", 5), "héllo");
+/// ```
+pub fn truncate_chars(s: &str, max_chars: usize) -> String {
+    truncate_opts(s, &TruncateOptions::new(max_chars))
+}
+
+/// Truncate to at most `max_graphemes` user-perceived characters, ending with `"..."` when cut.
+///
+/// Combining marks and emoji sequences are never split.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::truncate_graphemes;
+/// let s = "e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}"; // five "é" built from combining marks
+/// assert_eq!(truncate_graphemes(s, 4), "e\u{301}...");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn truncate_graphemes(s: &str, max_graphemes: usize) -> String {
+    let opts = TruncateOptions {
+        unit: TruncateUnit::Graphemes,
+        ..TruncateOptions::new(max_graphemes)
+    };
+    truncate_opts(s, &opts)
+}
+
 /// Pad string start to reach target length
 pub fn pad_start(s: &str, target_len: usize, pad_char: char) -> String {
     let current_len = s.chars().count();
@@ -5,9 +5,17 @@
 //!
 //! Highlights:
 //! - Casing: [`to_snake_case`], [`to_camel_case`], [`to_kebab_case`], [`to_title_case`]
-//! - Manipulation: [`capitalize`], [`uncapitalize`], [`pad_start`], [`pad_end`], [`trim`], [`truncate`]
+//!   (with AP/Chicago small-word rules via [`to_title_case_with`]),
+//!   acronym-aware [`convert_case`]
+//! - Manipulation: [`capitalize`], [`uncapitalize`], [`pad_start`], [`pad_end`], [`trim`], [`truncate`],
+//!   [`truncate_chars`], [`truncate_opts`]
 //! - Paths/URLs: [`join_paths`], [`normalize_path`], [`url_encode`], [`url_decode`]
-//! - Extras: [`slugify`], inflection helpers
+//! - Extras: [`slugify`], inflection helpers, homoglyph checks ([`is_confusable`]),
+//!   phonetic codes ([`soundex`], [`metaphone`])
+//! - Ordering: [`natural_cmp`], [`natural_sort`]
+//! - Layout: [`wrap_opts`], [`display_width`], ANSI-aware measuring in [`ansi`]
+//! - Extraction: [`between`], [`between_all`], [`before`], [`after`], [`before_last`], [`after_last`]
+//! - Numbers: [`format_int_grouped`], [`format_float`], [`ordinal`]
 //!
 //! Examples:
 //! ```rust
@@ -18,18 +26,46 @@
 //! assert_eq!(url_encode("a b"), "a%20b");
 //! ```
 
+pub mod ansi;
 pub mod case;
+pub mod confusable;
 pub mod escape;
 pub mod extra;
+pub mod extract;
+pub(crate) mod glob;
+pub mod inflect;
 pub mod manipulation;
+pub mod natural;
+pub mod number;
 pub mod path;
+pub mod phonetic;
 pub mod url;
 pub mod words;
+pub mod wrap;
 
-pub use case::{to_camel_case, to_kebab_case, to_pascal_case, to_snake_case, to_title_case};
-pub use extra::{levenshtein_distance, pluralize, singularize, slugify};
+pub use case::{
+    convert_case, to_camel_case, to_dot_case, to_kebab_case, to_pascal_case,
+    to_screaming_snake_case, to_snake_case, to_title_case, to_title_case_with, to_train_case, Case,
+    CaseOptions, TitleCaseOptions, TitleStyle,
+};
+pub use confusable::{confusable_skeleton, is_confusable};
+pub use extra::{
+    levenshtein_distance, rle_decode, rle_encode, slugify, slugify_with, transliterate, SlugOptions,
+};
+pub use extract::{after, after_last, before, before_last, between, between_all};
+pub use inflect::{pluralize, pluralize_n, register_irregular, register_uncountable, singularize};
+#[cfg(feature = "unicode")]
+pub use manipulation::truncate_graphemes;
 pub use manipulation::{
-    capitalize, pad_end, pad_start, trim, truncate, truncate_with, uncapitalize,
+    capitalize, pad_end, pad_start, trim, truncate, truncate_chars, truncate_opts, truncate_with,
+    uncapitalize, EllipsisPosition, TruncateOptions, TruncateUnit,
+};
+pub use natural::{natural_cmp, natural_sort};
+pub use number::{
+    format_bytes, format_float, format_int_grouped, format_int_grouped_with, ordinal,
+    ordinal_suffix,
 };
 pub use path::{join_paths, normalize_path};
+pub use phonetic::{metaphone, soundex};
 pub use url::{url_decode, url_encode};
+pub use wrap::{char_width, display_width, wrap_opts, WrapOptions};
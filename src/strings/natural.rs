@@ -0,0 +1,73 @@
+//! Natural ("human") ordering of strings containing numbers
+
+use std::cmp::Ordering;
+
+/// Compare two strings treating runs of ASCII digits as numbers
+///
+/// `"file2"` orders before `"file10"`. Numeric runs compare by value, then by
+/// number of leading zeros; strings that are otherwise equal fall back to a
+/// plain comparison so the ordering stays total.
+///
+/// Example:
+/// ```rust
+/// use std::cmp::Ordering;
+/// use toolchest::strings::natural_cmp;
+/// assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+/// assert_eq!(natural_cmp("v1.10", "v1.9"), Ordering::Greater);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (ab, bb) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    while i < ab.len() && j < bb.len() {
+        if ab[i].is_ascii_digit() && bb[j].is_ascii_digit() {
+            let (si, sj) = (i, j);
+            while i < ab.len() && ab[i].is_ascii_digit() {
+                i += 1;
+            }
+            while j < bb.len() && bb[j].is_ascii_digit() {
+                j += 1;
+            }
+            let na = trim_zeros(&ab[si..i]);
+            let nb = trim_zeros(&bb[sj..j]);
+            let ord = na
+                .len()
+                .cmp(&nb.len())
+                .then_with(|| na.cmp(nb))
+                .then_with(|| (i - si).cmp(&(j - sj)));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        } else {
+            // Compare one full character from each side
+            let ca = a[i..].chars().next().unwrap_or_default();
+            let cb = b[j..].chars().next().unwrap_or_default();
+            if ca != cb {
+                return ca.cmp(&cb);
+            }
+            i += ca.len_utf8();
+            j += cb.len_utf8();
+        }
+    }
+    (ab.len() - i).cmp(&(bb.len() - j)).then_with(|| a.cmp(b))
+}
+
+fn trim_zeros(digits: &[u8]) -> &[u8] {
+    let start = digits
+        .iter()
+        .position(|&d| d != b'0')
+        .unwrap_or(digits.len());
+    &digits[start..]
+}
+
+/// Sort strings in natural order (see [`natural_cmp`])
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::natural_sort;
+/// let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+/// natural_sort(&mut files);
+/// assert_eq!(files, ["file1.txt", "file2.txt", "file10.txt"]);
+/// ```
+pub fn natural_sort<S: AsRef<str>>(items: &mut [S]) {
+    items.sort_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+}
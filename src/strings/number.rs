@@ -0,0 +1,131 @@
+//! Number display helpers: digit grouping, ordinals, and byte sizes
+
+/// Group the digits of an integer in threes with `,`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::format_int_grouped;
+/// assert_eq!(format_int_grouped(1234567), "1,234,567");
+/// assert_eq!(format_int_grouped(-1000), "-1,000");
+/// assert_eq!(format_int_grouped(999), "999");
+/// ```
+pub fn format_int_grouped(n: impl Into<i128>) -> String {
+    format_int_grouped_with(n, ",")
+}
+
+/// Group the digits of an integer in threes with a custom separator.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::format_int_grouped_with;
+/// assert_eq!(format_int_grouped_with(1234567u32, "."), "1.234.567");
+/// assert_eq!(format_int_grouped_with(u64::MAX, "_"), "18_446_744_073_709_551_615");
+/// ```
+pub fn format_int_grouped_with(n: impl Into<i128>, sep: &str) -> String {
+    let n = n.into();
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * sep.len() + 1);
+    if n < 0 {
+        out.push('-');
+    }
+    group_digits(&digits, sep, &mut out);
+    out
+}
+
+/// Format a float with `decimals` places and the integer part grouped by `sep`.
+///
+/// Non-finite values are formatted as Rust prints them (`NaN`, `inf`, `-inf`).
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::format_float;
+/// assert_eq!(format_float(1234567.891, 2, ","), "1,234,567.89");
+/// assert_eq!(format_float(-9876.6, 0, " "), "-9 877");
+/// assert_eq!(format_float(0.5, 3, ","), "0.500");
+/// ```
+pub fn format_float(x: f64, decimals: usize, sep: &str) -> String {
+    if !x.is_finite() {
+        return x.to_string();
+    }
+    let raw = format!("{:.*}", decimals, x);
+    let (sign, unsigned) = match raw.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", raw.as_str()),
+    };
+    let (int_part, frac_part) = match unsigned.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (unsigned, None),
+    };
+    let mut out = String::with_capacity(raw.len() + int_part.len() / 3 * sep.len());
+    out.push_str(sign);
+    group_digits(int_part, sep, &mut out);
+    if let Some(f) = frac_part {
+        out.push('.');
+        out.push_str(f);
+    }
+    out
+}
+
+/// English ordinal: `1st`, `2nd`, `3rd`, `4th`, `11th`, `21st`, ...
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::ordinal;
+/// assert_eq!(ordinal(3), "3rd");
+/// assert_eq!(ordinal(12), "12th");
+/// assert_eq!(ordinal(102), "102nd");
+/// ```
+pub fn ordinal(n: i64) -> String {
+    format!("{}{}", n, ordinal_suffix(n))
+}
+
+/// Suffix used by [`ordinal`]: `"st"`, `"nd"`, `"rd"`, or `"th"`.
+pub fn ordinal_suffix(n: i64) -> &'static str {
+    let n = n.unsigned_abs();
+    if (11..=13).contains(&(n % 100)) {
+        return "th";
+    }
+    match n % 10 {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    }
+}
+
+/// Human-readable byte size using binary (IEC) units: `B`, `KiB`, `MiB`, ... `EiB`.
+///
+/// Sizes below 1 KiB are printed exactly; larger sizes get one decimal place.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::format_bytes;
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(1536), "1.5 KiB");
+/// assert_eq!(format_bytes(1024 * 1024 - 1), "1.0 MiB");
+/// assert_eq!(format_bytes(u64::MAX), "16.0 EiB");
+/// ```
+pub fn format_bytes(n: u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    if n < 1024 {
+        return format!("{n} B");
+    }
+    let mut value = n as f64 / 1024.0;
+    let mut unit = 0;
+    // Bump the unit when rounding to one decimal would print "1024.0"
+    while value >= 1023.95 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn group_digits(digits: &str, sep: &str, out: &mut String) {
+    let lead = digits.len() % 3;
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (i + 3 - lead) % 3 == 0 {
+            out.push_str(sep);
+        }
+        out.push(c);
+    }
+}
@@ -0,0 +1,209 @@
+//! Phonetic codes for fuzzy matching of English names.
+//!
+//! Names that sound alike map to the same code, which catches spelling
+//! variants that edit distance alone misses. Input is transliterated to ASCII
+//! first and everything except letters is ignored.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::strings::phonetic::{metaphone, soundex};
+//! use toolchest::strings::levenshtein_distance;
+//!
+//! assert_eq!(soundex("Robert"), soundex("Rupert"));
+//! assert_eq!(metaphone("Knight"), metaphone("Night"));
+//! // Combine with edit distance for contact dedup
+//! let similar = |a: &str, b: &str| metaphone(a) == metaphone(b) && levenshtein_distance(a, b) <= 2;
+//! assert!(similar("Catherine", "Katherine"));
+//! assert!(!similar("Catherine", "Kathleen"));
+//! ```
+
+use super::extra::transliterate;
+
+fn letters(name: &str) -> Vec<u8> {
+    transliterate(name)
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|b| b.to_ascii_uppercase())
+        .collect()
+}
+
+fn soundex_digit(b: u8) -> Option<u8> {
+    Some(match b {
+        b'B' | b'F' | b'P' | b'V' => b'1',
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => b'2',
+        b'D' | b'T' => b'3',
+        b'L' => b'4',
+        b'M' | b'N' => b'5',
+        b'R' => b'6',
+        _ => return None,
+    })
+}
+
+/// American Soundex code: a letter followed by three digits.
+///
+/// Vowels separate repeated codes but `H` and `W` do not, as in the
+/// standard (e.g. `Ashcraft` is `A261`). Returns an empty string if `name`
+/// contains no letters.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::phonetic::soundex;
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Tymczak"), "T522");
+/// assert_eq!(soundex("Lee"), "L000");
+/// ```
+pub fn soundex(name: &str) -> String {
+    let letters = letters(name);
+    let Some(&first) = letters.first() else {
+        return String::new();
+    };
+    let mut code = vec![first];
+    let mut last = soundex_digit(first);
+    for &b in &letters[1..] {
+        let digit = soundex_digit(b);
+        match digit {
+            Some(d) if digit != last => {
+                code.push(d);
+                if code.len() == 4 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+        // H and W are transparent; vowels reset the run
+        if b != b'H' && b != b'W' {
+            last = digit;
+        }
+    }
+    code.resize(4, b'0');
+    String::from_utf8(code).unwrap_or_default()
+}
+
+fn is_vowel(b: Option<&u8>) -> bool {
+    matches!(b, Some(b'A' | b'E' | b'I' | b'O' | b'U'))
+}
+
+fn is_front_vowel(b: Option<&u8>) -> bool {
+    matches!(b, Some(b'E' | b'I' | b'Y'))
+}
+
+/// Metaphone code (Lawrence Philips' original algorithm).
+///
+/// Codes use the consonant letters plus `0` for "th" and `X` for "sh"/"ch".
+/// Unlike Soundex the code is not truncated. Returns an empty string if
+/// `name` contains no letters.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::phonetic::metaphone;
+/// assert_eq!(metaphone("Smith"), "SM0");
+/// assert_eq!(metaphone("Phone"), "FN");
+/// assert_eq!(metaphone("Wright"), "RT");
+/// ```
+pub fn metaphone(name: &str) -> String {
+    let mut w = letters(name);
+    match w.as_slice() {
+        [b'A', b'E', ..] | [b'G' | b'K' | b'P', b'N', ..] | [b'W', b'R', ..] => {
+            w.remove(0);
+        }
+        [b'X', ..] => w[0] = b'S',
+        [b'W', b'H', ..] => {
+            w.remove(1);
+        }
+        _ => {}
+    }
+    let at = |i: usize| w.get(i);
+    let starts = |i: usize, s: &[u8]| w.get(i..).is_some_and(|rest| rest.starts_with(s));
+    let mut code = String::new();
+    let mut i = 0;
+    while i < w.len() {
+        let c = w[i];
+        let prev = i.checked_sub(1).and_then(at);
+        let next = at(i + 1);
+        let is_last = i + 1 == w.len();
+        if prev == Some(&c) && c != b'C' {
+            i += 1;
+            continue;
+        }
+        match c {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if i == 0 {
+                    code.push(c as char);
+                }
+            }
+            b'B' => {
+                if !(is_last && prev == Some(&b'M')) {
+                    code.push('B');
+                }
+            }
+            b'C' => {
+                if prev == Some(&b'S') && is_front_vowel(next) {
+                    // SCE, SCI, SCY: silent
+                } else if starts(i, b"CIA") {
+                    code.push('X');
+                } else if is_front_vowel(next) {
+                    code.push('S');
+                } else if next == Some(&b'H') {
+                    code.push(if prev == Some(&b'S') { 'K' } else { 'X' });
+                } else {
+                    code.push('K');
+                }
+            }
+            b'D' => {
+                if next == Some(&b'G') && is_front_vowel(at(i + 2)) {
+                    code.push('J');
+                    i += 2;
+                } else {
+                    code.push('T');
+                }
+            }
+            b'G' => {
+                let silent = (next == Some(&b'H') && !is_vowel(at(i + 2)))
+                    || (i > 0 && (w[i + 1..] == *b"N" || w[i + 1..] == *b"NED"));
+                if !silent {
+                    let soft = is_front_vowel(next) && prev != Some(&b'G');
+                    code.push(if soft { 'J' } else { 'K' });
+                }
+            }
+            b'H' => {
+                let after_modifier = matches!(prev, Some(b'C' | b'S' | b'P' | b'T' | b'G'));
+                if !is_last && !after_modifier && is_vowel(next) {
+                    code.push('H');
+                }
+            }
+            b'K' => {
+                if prev != Some(&b'C') {
+                    code.push('K');
+                }
+            }
+            b'P' => code.push(if next == Some(&b'H') { 'F' } else { 'P' }),
+            b'Q' => code.push('K'),
+            b'S' => {
+                let sh = next == Some(&b'H') || starts(i, b"SIO") || starts(i, b"SIA");
+                code.push(if sh { 'X' } else { 'S' });
+            }
+            b'T' => {
+                if starts(i, b"TIA") || starts(i, b"TIO") {
+                    code.push('X');
+                } else if starts(i, b"TCH") {
+                    // silent before CH
+                } else if next == Some(&b'H') {
+                    code.push('0');
+                } else {
+                    code.push('T');
+                }
+            }
+            b'V' => code.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(next) {
+                    code.push(c as char);
+                }
+            }
+            b'X' => code.push_str("KS"),
+            b'Z' => code.push('S'),
+            _ => code.push(c as char),
+        }
+        i += 1;
+    }
+    code
+}
@@ -0,0 +1,209 @@
+//! Word wrapping measured in display columns
+
+/// Options for [`wrap_opts`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// Maximum line width in display columns (default 80)
+    pub width: usize,
+    /// Split words longer than the available width (default `true`)
+    pub break_long_words: bool,
+    /// Mark split words with a trailing `-` (default `false`)
+    pub hyphenate: bool,
+    /// Wrap each input line separately, keeping blank lines; otherwise all
+    /// whitespace including newlines is collapsed (default `true`)
+    pub preserve_newlines: bool,
+    /// Repeat each input line's leading whitespace on its continuation lines
+    /// (default `false`)
+    pub preserve_indent: bool,
+    /// Prefix for the first output line of each paragraph
+    pub initial_indent: String,
+    /// Prefix for continuation lines
+    pub subsequent_indent: String,
+}
+
+impl Default for WrapOptions {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            break_long_words: true,
+            hyphenate: false,
+            preserve_newlines: true,
+            preserve_indent: false,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+        }
+    }
+}
+
+impl WrapOptions {
+    /// Default options with the given width
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            ..Self::default()
+        }
+    }
+}
+
+/// Display width of a character in terminal columns
+///
+/// With the `unicode` feature this follows Unicode East Asian Width (wide CJK
+/// characters take two columns, combining marks zero); otherwise every
+/// character counts as one column.
+pub fn char_width(ch: char) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0)
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        let _ = ch;
+        1
+    }
+}
+
+/// Display width of a string in terminal columns (see [`char_width`])
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::display_width;
+/// assert_eq!(display_width("héllo"), 5);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+struct LineBuilder<'a> {
+    opts: &'a WrapOptions,
+    lines: Vec<String>,
+    current: String,
+    current_width: usize,
+    has_word: bool,
+    continuation: String,
+}
+
+impl LineBuilder<'_> {
+    fn start(&mut self, prefix: &str) {
+        self.current = prefix.to_string();
+        self.current_width = display_width(prefix);
+        self.has_word = false;
+    }
+
+    fn flush(&mut self) {
+        let line = std::mem::take(&mut self.current);
+        self.lines.push(line.trim_end().to_string());
+        let next = self.continuation.clone();
+        self.start(&next);
+    }
+
+    fn push_word(&mut self, word: &str) {
+        let width = self.opts.width;
+        let word_width = display_width(word);
+        if self.has_word {
+            if self.current_width + 1 + word_width <= width {
+                self.current.push(' ');
+                self.current.push_str(word);
+                self.current_width += 1 + word_width;
+                return;
+            }
+            // A word too long for any line starts on this one if there is room
+            let fresh_width = display_width(&self.continuation);
+            let min_room = if self.opts.hyphenate { 3 } else { 2 };
+            if self.opts.break_long_words
+                && fresh_width + word_width > width
+                && self.current_width + min_room <= width
+            {
+                self.current.push(' ');
+                self.current_width += 1;
+            } else {
+                self.flush();
+            }
+        }
+        if self.current_width + word_width <= width || !self.opts.break_long_words {
+            self.current.push_str(word);
+            self.current_width += word_width;
+            self.has_word = true;
+            return;
+        }
+        let mut rest = word;
+        while !rest.is_empty() {
+            let rest_width = display_width(rest);
+            if self.current_width + rest_width <= width {
+                self.current.push_str(rest);
+                self.current_width += rest_width;
+                self.has_word = true;
+                return;
+            }
+            let available = width.saturating_sub(self.current_width);
+            let hyphen = self.opts.hyphenate && available >= 2;
+            let budget = if hyphen { available - 1 } else { available };
+            let mut taken = 0;
+            let mut cut = 0;
+            for (idx, ch) in rest.char_indices() {
+                let w = char_width(ch);
+                if taken + w > budget {
+                    break;
+                }
+                taken += w;
+                cut = idx + ch.len_utf8();
+            }
+            if cut == 0 {
+                // Always make progress, even if one character overflows the line
+                cut = rest.chars().next().map_or(0, char::len_utf8);
+            }
+            self.current.push_str(&rest[..cut]);
+            if hyphen && cut < rest.len() {
+                self.current.push('-');
+            }
+            rest = &rest[cut..];
+            self.has_word = true;
+            if !rest.is_empty() {
+                self.flush();
+            }
+        }
+    }
+}
+
+/// Word wrap text with configurable long-word breaking and indentation
+///
+/// Widths are display columns (see [`display_width`]), not bytes.
+///
+/// Example:
+/// ```rust
+/// use toolchest::strings::{wrap_opts, WrapOptions};
+/// let opts = WrapOptions { hyphenate: true, subsequent_indent: "  ".into(), ..WrapOptions::new(10) };
+/// assert_eq!(wrap_opts("see supercalifragilistic now", &opts), "see super-\n  califra-\n  gilistic\n  now");
+/// ```
+pub fn wrap_opts(text: &str, opts: &WrapOptions) -> String {
+    let mut builder = LineBuilder {
+        opts,
+        lines: Vec::new(),
+        current: String::new(),
+        current_width: 0,
+        has_word: false,
+        continuation: String::new(),
+    };
+    let paragraphs: Vec<&str> = if opts.preserve_newlines {
+        text.lines().collect()
+    } else {
+        vec![text]
+    };
+    for paragraph in paragraphs {
+        if paragraph.trim().is_empty() {
+            builder.lines.push(String::new());
+            continue;
+        }
+        let leading = if opts.preserve_indent {
+            &paragraph[..paragraph.len() - paragraph.trim_start().len()]
+        } else {
+            ""
+        };
+        builder.continuation = format!("{}{}", opts.subsequent_indent, leading);
+        builder.start(&format!("{}{}", opts.initial_indent, leading));
+        for word in paragraph.split_whitespace() {
+            builder.push_word(word);
+        }
+        builder.flush();
+    }
+    builder.lines.join("\n")
+}
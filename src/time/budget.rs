@@ -0,0 +1,109 @@
+//! Deadline budgets that can be split across sub-operations
+
+use std::time::{Duration, Instant};
+
+/// A time budget counting down from creation.
+///
+/// Hand a `Budget` to work with a deadline; carve out [`child`](Budget::child)
+/// budgets for sub-steps so one slow step can't consume the whole allowance.
+/// A child never outlives its parent. A total too large to represent as an
+/// [`Instant`] (such as [`Duration::MAX`]) never runs out.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::Budget;
+/// use std::time::Duration;
+///
+/// let request = Budget::new(Duration::from_secs(2));
+/// let lookup = request.child(0.25);
+/// assert!(lookup.total() <= Duration::from_millis(500));
+/// assert!(!lookup.expired());
+/// assert!(lookup.deadline() <= request.deadline());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    total: Duration,
+    // `None` when the deadline is past what `Instant` can represent
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    /// Start a budget of `total` from now
+    pub fn new(total: Duration) -> Self {
+        Self {
+            total,
+            deadline: Instant::now().checked_add(total),
+        }
+    }
+
+    /// Budget ending at `deadline`
+    pub fn until(deadline: Instant) -> Self {
+        Self {
+            total: deadline.saturating_duration_since(Instant::now()),
+            deadline: Some(deadline),
+        }
+    }
+
+    /// Duration the budget was created with
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Instant at which the budget runs out, or `None` if it never does
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Time left; zero once expired, [`Duration::MAX`] if it never runs out
+    pub fn remaining(&self) -> Duration {
+        match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => Duration::MAX,
+        }
+    }
+
+    /// Whether the deadline has passed
+    pub fn expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Whether `d` still fits in the remaining time
+    pub fn allows(&self, d: Duration) -> bool {
+        self.deadline.is_none() || d < self.remaining()
+    }
+
+    /// Budget for a sub-operation: `fraction` (clamped to `0.0..=1.0`) of the
+    /// time remaining now. NaN is treated as zero.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::Budget;
+    /// use std::time::Duration;
+    /// let b = Budget::new(Duration::from_secs(10));
+    /// assert!(b.child(0.0).expired());
+    /// assert!(b.child(2.0).deadline() <= b.deadline());
+    /// ```
+    pub fn child(&self, fraction: f64) -> Budget {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let now = Instant::now();
+        let left = match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(now),
+            None => Duration::MAX,
+        };
+        let share =
+            Duration::try_from_secs_f64(left.as_secs_f64() * fraction).unwrap_or(Duration::MAX);
+        let deadline = match (now.checked_add(share), self.deadline) {
+            (Some(own), Some(parent)) => Some(own.min(parent)),
+            (own, parent) => own.or(parent),
+        };
+        Budget {
+            total: share,
+            deadline,
+        }
+    }
+}
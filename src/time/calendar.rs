@@ -0,0 +1,156 @@
+//! Calendar dates, weekdays, and business-day arithmetic
+
+use std::fmt;
+
+use super::civil::{civil_from_days, days_from_civil, days_in_month};
+
+/// Day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday
+    Mon,
+    /// Tuesday
+    Tue,
+    /// Wednesday
+    Wed,
+    /// Thursday
+    Thu,
+    /// Friday
+    Fri,
+    /// Saturday
+    Sat,
+    /// Sunday
+    Sun,
+}
+
+/// A proleptic Gregorian calendar date, ordered chronologically.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{Date, Weekday};
+/// let d = Date::new(2024, 2, 29).unwrap();
+/// assert_eq!(d.weekday(), Weekday::Thu);
+/// assert_eq!(d.add_days(1).to_string(), "2024-03-01");
+/// assert!(Date::new(2023, 2, 29).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Date from its parts; `None` if it doesn't exist
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year as i64, month))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month, 1-12
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Day of month, 1-31
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    fn days_since_epoch(&self) -> i64 {
+        days_from_civil(self.year as i64, self.month, self.day)
+    }
+
+    fn from_days(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+
+    /// Day of the week
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        const ORDER: [Weekday; 7] = [
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+        ];
+        ORDER[self.days_since_epoch().rem_euclid(7) as usize]
+    }
+
+    /// Date `n` calendar days later (earlier when negative)
+    pub fn add_days(&self, n: i64) -> Self {
+        Self::from_days(self.days_since_epoch() + n)
+    }
+
+    /// Calendar days from `self` to `other` (negative if `other` is earlier)
+    pub fn days_until(&self, other: Date) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// True on Saturday and Sunday
+pub fn is_weekend(date: Date) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// True on weekdays that aren't listed in `holidays`
+pub fn is_business_day(date: Date, holidays: &[Date]) -> bool {
+    !is_weekend(date) && !holidays.contains(&date)
+}
+
+/// First business day strictly after `date`, skipping weekends and `holidays`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{next_business_day, Date};
+/// let fri = Date::new(2024, 12, 20).unwrap();
+/// let christmas = [Date::new(2024, 12, 25).unwrap()];
+/// assert_eq!(next_business_day(fri, &[]), Date::new(2024, 12, 23).unwrap());
+/// let tue = Date::new(2024, 12, 24).unwrap();
+/// assert_eq!(next_business_day(tue, &christmas), Date::new(2024, 12, 26).unwrap());
+/// ```
+pub fn next_business_day(date: Date, holidays: &[Date]) -> Date {
+    add_business_days(date, 1, holidays)
+}
+
+/// Move `n` business days from `date` (backwards when negative), skipping
+/// weekends and `holidays`. `n == 0` returns `date` unchanged.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{add_business_days, Date};
+/// let wed = Date::new(2024, 5, 1).unwrap();
+/// assert_eq!(add_business_days(wed, 5, &[]), Date::new(2024, 5, 8).unwrap());
+/// assert_eq!(add_business_days(wed, -3, &[]), Date::new(2024, 4, 26).unwrap());
+/// ```
+pub fn add_business_days(date: Date, n: i64, holidays: &[Date]) -> Date {
+    let step = n.signum();
+    let mut remaining = n.unsigned_abs();
+    let mut current = date;
+    while remaining > 0 {
+        current = current.add_days(step);
+        if is_business_day(current, holidays) {
+            remaining -= 1;
+        }
+    }
+    current
+}
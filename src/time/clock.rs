@@ -0,0 +1,115 @@
+//! Colon-separated clock durations such as `01:02:03.250`
+
+use std::time::Duration;
+
+/// Options for [`format_clock_with`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClockOptions {
+    /// Split whole days off as a `Nd ` prefix instead of counting hours past 24
+    pub days: bool,
+    /// Append milliseconds as `.mmm`
+    pub millis: bool,
+}
+
+/// Format a duration as `HH:MM:SS`; hours grow past 24 as needed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::format_clock;
+/// use std::time::Duration;
+/// assert_eq!(format_clock(Duration::from_secs(3723)), "01:02:03");
+/// assert_eq!(format_clock(Duration::from_secs(100 * 3600)), "100:00:00");
+/// ```
+pub fn format_clock(d: Duration) -> String {
+    format_clock_with(d, &ClockOptions::default())
+}
+
+/// Format a duration as `[Nd ]HH:MM:SS[.mmm]`.
+///
+/// The day prefix is only written when at least one full day has elapsed.
+/// Milliseconds are truncated, not rounded.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{format_clock_with, ClockOptions};
+/// use std::time::Duration;
+/// let opts = ClockOptions { days: true, millis: true };
+/// assert_eq!(format_clock_with(Duration::from_millis(93_784_250), &opts), "1d 02:03:04.250");
+/// assert_eq!(format_clock_with(Duration::from_millis(1_500), &opts), "00:00:01.500");
+/// ```
+pub fn format_clock_with(d: Duration, opts: &ClockOptions) -> String {
+    let secs = d.as_secs();
+    let (days, hours) = if opts.days {
+        (secs / 86_400, secs % 86_400 / 3600)
+    } else {
+        (0, secs / 3600)
+    };
+    let mut out = String::new();
+    if days > 0 {
+        out.push_str(&format!("{days}d "));
+    }
+    out.push_str(&format!(
+        "{hours:02}:{:02}:{:02}",
+        secs % 3600 / 60,
+        secs % 60
+    ));
+    if opts.millis {
+        out.push_str(&format!(".{:03}", d.subsec_millis()));
+    }
+    out
+}
+
+/// Parse `[Nd ]H:MM:SS[.fff]` or `M:SS[.fff]` into a `Duration`.
+///
+/// The leading field may be any size; later fields must be one or two digits
+/// below 60. The fraction takes 1–9 digits. Returns `None` on anything else.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::parse_clock;
+/// use std::time::Duration;
+/// assert_eq!(parse_clock("01:02:03"), Some(Duration::from_secs(3723)));
+/// assert_eq!(parse_clock("4:05.25"), Some(Duration::from_millis(245_250)));
+/// assert_eq!(parse_clock("1d 00:00:01"), Some(Duration::from_secs(86_401)));
+/// assert_eq!(parse_clock("01:60:00"), None);
+/// ```
+pub fn parse_clock(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let (days, clock) = match s.split_once('d') {
+        Some((d, rest)) => (digits(d)?, rest.trim_start()),
+        None => (0, s),
+    };
+    let (clock, nanos) = match clock.split_once('.') {
+        Some((c, frac)) if (1..=9).contains(&frac.len()) => {
+            let scale = 10u64.pow(9 - frac.len() as u32);
+            (c, digits(frac)? * scale)
+        }
+        Some(_) => return None,
+        None => (clock, 0),
+    };
+    let fields: Vec<&str> = clock.split(':').collect();
+    let (first, rest) = match fields.len() {
+        2 | 3 => fields.split_first()?,
+        _ => return None,
+    };
+    let mut total = digits(first)?;
+    for field in rest {
+        let v = digits(field).filter(|&v| field.len() <= 2 && v < 60)?;
+        total = total.checked_mul(60)?.checked_add(v)?;
+    }
+    if days > 0 {
+        // With a day prefix the leading field is hours of that day
+        if fields.len() != 3 || total >= 86_400 {
+            return None;
+        }
+        total = days.checked_mul(86_400)?.checked_add(total)?;
+    }
+    Some(Duration::new(total, nanos as u32))
+}
+
+fn digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
@@ -0,0 +1,258 @@
+//! Map whose entries expire after a per-entry time-to-live
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
+
+use super::monotonic::{system_clock, Clock};
+use super::timer::{Timer, TimerHandle};
+
+struct Entry<V> {
+    value: V,
+    expires: Option<Instant>,
+}
+
+impl<V> Entry<V> {
+    fn live(&self, now: Instant) -> bool {
+        self.expires.map_or(true, |at| now < at)
+    }
+}
+
+// Shared with the background sweeper, which reads the clock from here so a
+// later `with_clock` applies to it too
+struct State<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<K, V> State<K, V> {
+    fn sweep(&mut self) -> usize {
+        let now = self.clock.now();
+        let before = self.entries.len();
+        self.entries.retain(|_, e| e.live(now));
+        before - self.entries.len()
+    }
+}
+
+type Shared<K, V> = Mutex<State<K, V>>;
+
+/// Thread-safe map whose entries vanish once their TTL elapses.
+///
+/// Expired entries are never returned; they are dropped lazily when touched,
+/// by [`sweep`](ExpiringMap::sweep), or periodically by a background sweeper
+/// started with [`with_sweeper`](ExpiringMap::with_sweeper) (which runs on the
+/// shared [`Timer`] thread and stops when the map is dropped). Deadlines use
+/// the monotonic clock, or the [`Clock`] given to
+/// [`with_clock`](ExpiringMap::with_clock), which the sweeper follows too.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::ExpiringMap;
+/// use std::time::Duration;
+///
+/// let nonces = ExpiringMap::new();
+/// nonces.insert_ttl("n-1", (), Duration::from_millis(20));
+/// assert!(nonces.contains_key("n-1"));
+/// std::thread::sleep(Duration::from_millis(30));
+/// assert!(!nonces.contains_key("n-1"));
+/// ```
+pub struct ExpiringMap<K, V> {
+    state: Arc<Shared<K, V>>,
+    sweeper: Option<TimerHandle>,
+}
+
+impl<K: Eq + Hash, V> Default for ExpiringMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V> ExpiringMap<K, V> {
+    /// Create an empty map without a background sweeper
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                entries: HashMap::new(),
+                clock: system_clock(),
+            })),
+            sweeper: None,
+        }
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        self.lock().clock = Arc::new(clock);
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<K, V>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Insert `value` expiring `ttl` from now, returning the previous live value
+    ///
+    /// A `ttl` too large to represent (such as `Duration::MAX`) never expires.
+    pub fn insert_ttl(&self, key: K, value: V, ttl: Duration) -> Option<V> {
+        let mut state = self.lock();
+        let now = state.clock.now();
+        let entry = Entry {
+            value,
+            expires: now.checked_add(ttl),
+        };
+        let old = state.entries.insert(key, entry)?;
+        old.live(now).then_some(old.value)
+    }
+
+    /// Insert `value` without an expiry, returning the previous live value
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        let mut state = self.lock();
+        let entry = Entry {
+            value,
+            expires: None,
+        };
+        let old = state.entries.insert(key, entry)?;
+        old.live(state.clock.now()).then_some(old.value)
+    }
+
+    /// Clone of the value for `key`, if present and not expired
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        V: Clone,
+    {
+        self.with_live(key, |v| v.clone())
+    }
+
+    /// True if `key` has a live entry
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.with_live(key, |_| ()).is_some()
+    }
+
+    /// Time left before `key` expires; `None` if absent, expired, or without TTL
+    pub fn ttl_remaining<Q>(&self, key: &Q) -> Option<Duration>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let state = self.lock();
+        let now = state.clock.now();
+        let entry = state.entries.get(key).filter(|e| e.live(now))?;
+        entry.expires.map(|at| at - now)
+    }
+
+    fn with_live<Q, R>(&self, key: &Q, f: impl FnOnce(&V) -> R) -> Option<R>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut state = self.lock();
+        let now = state.clock.now();
+        match state.entries.get(key) {
+            Some(entry) if entry.live(now) => Some(f(&entry.value)),
+            Some(_) => {
+                state.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Remove `key`, returning its value if it was still live
+    pub fn remove<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut state = self.lock();
+        let entry = state.entries.remove(key)?;
+        entry.live(state.clock.now()).then_some(entry.value)
+    }
+
+    /// Drop all expired entries, returning how many were removed
+    pub fn sweep(&self) -> usize {
+        self.lock().sweep()
+    }
+
+    /// Number of live entries (sweeps expired ones first)
+    pub fn len(&self) -> usize {
+        let mut state = self.lock();
+        state.sweep();
+        state.entries.len()
+    }
+
+    /// True if there are no live entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every entry
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+}
+
+impl<K, V> ExpiringMap<K, V>
+where
+    K: Eq + Hash + Send + 'static,
+    V: Send + 'static,
+{
+    /// Sweep expired entries every `period` on the shared timer thread.
+    ///
+    /// The sweeper holds only a weak reference and is cancelled when the map
+    /// is dropped.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::ExpiringMap;
+    /// use std::time::Duration;
+    /// let sessions = ExpiringMap::new().with_sweeper(Duration::from_millis(5));
+    /// sessions.insert_ttl(1, "alice", Duration::from_millis(1));
+    /// std::thread::sleep(Duration::from_millis(40));
+    /// assert!(sessions.is_empty());
+    /// ```
+    pub fn with_sweeper(mut self, period: Duration) -> Self {
+        if let Some(old) = self.sweeper.take() {
+            old.cancel();
+        }
+        let weak: Weak<Shared<K, V>> = Arc::downgrade(&self.state);
+        self.sweeper = Some(Timer::every(period, move || {
+            if let Some(state) = weak.upgrade() {
+                state.lock().unwrap_or_else(|e| e.into_inner()).sweep();
+            }
+        }));
+        self
+    }
+}
+
+impl<K, V> Drop for ExpiringMap<K, V> {
+    fn drop(&mut self) {
+        if let Some(handle) = &self.sweeper {
+            handle.cancel();
+        }
+    }
+}
+
+impl<K, V> fmt::Debug for ExpiringMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpiringMap")
+            .field(
+                "entries",
+                &self
+                    .state
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .entries
+                    .len(),
+            )
+            .field("sweeper", &self.sweeper.is_some())
+            .finish()
+    }
+}
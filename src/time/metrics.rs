@@ -0,0 +1,148 @@
+//! Lightweight latency metrics.
+//!
+//! [`Timings`] is a thread-safe registry that accumulates durations per label
+//! and reports count/min/max/mean/p95 summaries. Pair it with the
+//! [`timed!`](crate::timed) macro or [`Timings::time`] to measure closures.
+//!
+//! Samples are kept in memory per label so percentiles are exact; call
+//! [`Timings::reset`] periodically in long-running services.
+//!
+//! Basic example:
+//! ```rust
+//! use toolchest::time::metrics::Timings;
+//! use std::time::Duration;
+//!
+//! let timings = Timings::new();
+//! timings.record("db_query", Duration::from_millis(10));
+//! timings.record("db_query", Duration::from_millis(30));
+//! let stats = timings.stats("db_query").unwrap();
+//! assert_eq!(stats.count, 2);
+//! assert_eq!(stats.min, Duration::from_millis(10));
+//! assert_eq!(stats.mean, Duration::from_millis(20));
+//!
+//! let v = toolchest::timed!(timings, "compute", 2 + 2);
+//! assert_eq!(v, 4);
+//! assert_eq!(timings.stats("compute").unwrap().count, 1);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Summary statistics for a single label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimingStats {
+    /// Number of recorded samples
+    pub count: usize,
+    /// Fastest sample
+    pub min: Duration,
+    /// Slowest sample
+    pub max: Duration,
+    /// Arithmetic mean of all samples
+    pub mean: Duration,
+    /// 95th percentile (nearest-rank, as in [`crate::math::percentile`])
+    pub p95: Duration,
+}
+
+/// Thread-safe registry of durations keyed by label.
+#[derive(Debug, Default)]
+pub struct Timings {
+    samples: Mutex<HashMap<String, Vec<Duration>>>,
+}
+
+impl Timings {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a duration under `label`.
+    pub fn record(&self, label: &str, dur: Duration) {
+        self.samples
+            .lock()
+            .unwrap()
+            .entry(label.to_string())
+            .or_default()
+            .push(dur);
+    }
+
+    /// Run `f`, record how long it took under `label`, and return its result.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::metrics::Timings;
+    /// let t = Timings::new();
+    /// assert_eq!(t.time("add", || 1 + 1), 2);
+    /// assert_eq!(t.stats("add").unwrap().count, 1);
+    /// ```
+    pub fn time<T, F: FnOnce() -> T>(&self, label: &str, f: F) -> T {
+        let (v, took) = super::elapsed(f);
+        self.record(label, took);
+        v
+    }
+
+    /// Summary for `label`, or `None` if nothing was recorded.
+    pub fn stats(&self, label: &str) -> Option<TimingStats> {
+        self.samples
+            .lock()
+            .unwrap()
+            .get(label)
+            .and_then(|s| summarize(s))
+    }
+
+    /// Summaries for every recorded label.
+    pub fn snapshot(&self) -> HashMap<String, TimingStats> {
+        self.samples
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|(k, s)| summarize(s).map(|st| (k.clone(), st)))
+            .collect()
+    }
+
+    /// Labels that have at least one sample, sorted.
+    pub fn labels(&self) -> Vec<String> {
+        let mut out: Vec<String> = self.samples.lock().unwrap().keys().cloned().collect();
+        out.sort();
+        out
+    }
+
+    /// Drop all recorded samples.
+    pub fn reset(&self) {
+        self.samples.lock().unwrap().clear();
+    }
+}
+
+fn summarize(samples: &[Duration]) -> Option<TimingStats> {
+    let min = *samples.iter().min()?;
+    let max = *samples.iter().max()?;
+    let total: Duration = samples.iter().sum();
+    let mut secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+    let p95 = crate::math::percentile(&mut secs, 95.0);
+    Some(TimingStats {
+        count: samples.len(),
+        min,
+        max,
+        mean: total / samples.len() as u32,
+        p95: Duration::from_secs_f64(p95),
+    })
+}
+
+/// Evaluate an expression and record its duration in a [`Timings`] registry.
+///
+/// Expands to [`Timings::time`] with the expression wrapped in a closure, so
+/// the value of the expression is returned unchanged.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::metrics::Timings;
+/// let timings = Timings::new();
+/// let n = toolchest::timed!(timings, "sum", (1..=10).sum::<i32>());
+/// assert_eq!(n, 55);
+/// ```
+#[macro_export]
+macro_rules! timed {
+    ($timings:expr, $label:expr, $body:expr) => {
+        $timings.time($label, || $body)
+    };
+}
@@ -1,7 +1,12 @@
 //! Time utilities.
 //!
-//! Helpers for durations, timing, simple cron-like checks, and backoff
-//! iteration.
+//! Helpers for durations (including `HH:MM:SS` clock strings), timing, simple
+//! cron-like checks, backoff iteration, RFC 3339 timestamps, background
+//! [`Timer`]s, deadline [`Budget`]s, calendar [`Date`]s with business-day
+//! arithmetic, TTL maps ([`ExpiringMap`]), and sliding-window rates
+//! ([`RateMeter`], [`Throughput`]). Per-label latency summaries live in
+//! [`metrics`]. Time-dependent types read an injectable [`Clock`], so tests
+//! can drive them with a [`MockClock`] instead of sleeping.
 //!
 //! Examples:
 //! ```rust
@@ -21,13 +26,44 @@
 //! let mut iter = BackoffIter::new(Duration::from_millis(1));
 //! assert_eq!(iter.next().unwrap(), Duration::from_millis(1));
 //! assert_eq!(iter.next().unwrap(), Duration::from_millis(2));
+//! let capped = BackoffIter::new(Duration::from_millis(1)).with_max(Duration::from_millis(3));
+//! assert_eq!(capped.take(4).last(), Some(Duration::from_millis(3)));
 //!
 //! let dl = Instant::now();
 //! assert!(deadline(dl) || !deadline(dl));
 //! ```
 
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use crate::random::Rng;
+
+mod budget;
+mod calendar;
+pub(crate) mod civil;
+mod clock;
+mod expiring;
+pub mod metrics;
+mod monotonic;
+mod rate;
+mod rfc3339;
+mod timer;
+
+pub use budget::Budget;
+pub use calendar::{
+    add_business_days, is_business_day, is_weekend, next_business_day, Date, Weekday,
+};
+pub use clock::{format_clock, format_clock_with, parse_clock, ClockOptions};
+pub use expiring::ExpiringMap;
+pub(crate) use monotonic::system_clock;
+pub use monotonic::{Clock, MockClock, SystemClock};
+pub use rate::{RateMeter, Throughput};
+pub use rfc3339::{
+    format_rfc3339, format_rfc3339_with_offset, from_unix_timestamp, parse_rfc3339, unix_timestamp,
+    unix_timestamp_millis,
+};
+pub use timer::{Timer, TimerHandle};
+
 /// Human-readable duration like "1h2m3s".
 ///
 /// Example:
@@ -85,17 +121,34 @@ pub fn parse_duration(s: &str) -> Option<Duration> {
 /// Simple stopwatch.
 pub struct Stopwatch {
     start: Instant,
+    clock: Arc<dyn Clock>,
 }
 impl Stopwatch {
     /// Start a new stopwatch.
     pub fn start_new() -> Self {
+        Self::start_with(system_clock())
+    }
+    /// Start a new stopwatch reading time from `clock`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::{MockClock, Stopwatch};
+    /// use std::time::Duration;
+    /// let clock = MockClock::new();
+    /// let sw = Stopwatch::start_with(clock.clone());
+    /// clock.advance(Duration::from_secs(3));
+    /// assert_eq!(sw.elapsed(), Duration::from_secs(3));
+    /// ```
+    pub fn start_with(clock: impl Clock + 'static) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(clock);
         Self {
-            start: Instant::now(),
+            start: clock.now(),
+            clock,
         }
     }
     /// Elapsed time since start.
     pub fn elapsed(&self) -> Duration {
-        self.start.elapsed()
+        self.clock.now().saturating_duration_since(self.start)
     }
 }
 
@@ -126,21 +179,141 @@ pub fn deadline(d: Instant) -> bool {
     Instant::now() >= d
 }
 
+/// Randomization applied to each [`BackoffIter`] delay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JitterMode {
+    /// Use the computed delay as-is
+    #[default]
+    None,
+    /// Uniform in `[0, delay]`
+    Full,
+    /// `delay / 2` plus uniform in `[0, delay / 2]`
+    Equal,
+}
+
 /// Iterator yielding exponentially increasing delays.
+///
+/// By default delays double forever. Builder methods add a growth factor, an
+/// upper bound, jitter, and a limit on the number of delays produced.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{BackoffIter, JitterMode};
+/// use std::time::Duration;
+///
+/// let delays: Vec<_> = BackoffIter::new(Duration::from_millis(100))
+///     .with_factor(3.0)
+///     .with_max(Duration::from_millis(500))
+///     .take_attempts(4)
+///     .collect();
+/// assert_eq!(delays, [100, 300, 500, 500].map(Duration::from_millis));
+///
+/// let jittered = BackoffIter::new(Duration::from_millis(100)).with_jitter(JitterMode::Equal);
+/// for d in jittered.take(5) {
+///     assert!(d >= Duration::from_millis(50));
+/// }
+/// ```
+#[derive(Clone, Debug)]
 pub struct BackoffIter {
     cur: Duration,
+    factor: f64,
+    max: Option<Duration>,
+    jitter: JitterMode,
+    remaining: Option<usize>,
+    rng: Rng,
 }
 impl BackoffIter {
     /// Create a backoff iterator starting at base.
     pub fn new(base: Duration) -> Self {
-        Self { cur: base }
+        Self {
+            cur: base,
+            factor: 2.0,
+            max: None,
+            jitter: JitterMode::None,
+            remaining: None,
+            rng: Rng::new(),
+        }
+    }
+    /// Multiply the delay by `factor` after each step (default `2.0`).
+    ///
+    /// Non-finite or negative factors are treated as `1.0`.
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = if factor.is_finite() && factor >= 0.0 {
+            factor
+        } else {
+            1.0
+        };
+        self
+    }
+    /// Cap every yielded delay (before jitter) at `max`.
+    pub fn with_max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+    /// Randomize yielded delays with the given [`JitterMode`].
+    pub fn with_jitter(mut self, jitter: JitterMode) -> Self {
+        self.jitter = jitter;
+        self
+    }
+    /// Draw jitter from an [`Rng`] seeded with `seed`, for reproducible delays.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::{BackoffIter, JitterMode};
+    /// use std::time::Duration;
+    /// let delays = |seed| {
+    ///     BackoffIter::new(Duration::from_millis(100))
+    ///         .with_jitter(JitterMode::Full)
+    ///         .with_seed(seed)
+    ///         .take(5)
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(delays(7), delays(7));
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Rng::seeded(seed);
+        self
+    }
+    /// Stop after yielding `n` delays.
+    pub fn take_attempts(mut self, n: usize) -> Self {
+        self.remaining = Some(n);
+        self
+    }
+
+    fn grow(&self, d: Duration) -> Duration {
+        if self.factor.fract() == 0.0 && self.factor <= u32::MAX as f64 {
+            return d.saturating_mul(self.factor as u32);
+        }
+        let nanos = d.as_nanos() as f64 * self.factor;
+        if nanos >= u64::MAX as f64 {
+            Duration::from_nanos(u64::MAX)
+        } else {
+            Duration::from_nanos(nanos as u64)
+        }
     }
 }
 impl Iterator for BackoffIter {
     type Item = Duration;
     fn next(&mut self) -> Option<Self::Item> {
-        let out = self.cur;
-        self.cur = self.cur.saturating_mul(2);
+        if let Some(n) = self.remaining.as_mut() {
+            if *n == 0 {
+                return None;
+            }
+            *n -= 1;
+        }
+        let base = match self.max {
+            Some(max) => self.cur.min(max),
+            None => self.cur,
+        };
+        let out = match self.jitter {
+            JitterMode::None => base,
+            JitterMode::Full => base.mul_f64(self.rng.next_f64()),
+            JitterMode::Equal => base / 2 + (base / 2).mul_f64(self.rng.next_f64()),
+        };
+        // Stop growing once past the cap so the state cannot overflow
+        if self.max.map_or(true, |max| self.cur < max) {
+            self.cur = self.grow(self.cur);
+        }
         Some(out)
     }
 }
@@ -0,0 +1,142 @@
+//! Injectable monotonic clocks for deterministic tests
+
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+type Waker = Box<dyn Fn() -> bool + Send + Sync>;
+
+/// A source of monotonic [`Instant`]s.
+///
+/// Time-dependent types such as [`RateLimiter`](crate::functions::RateLimiter),
+/// [`CircuitBreaker`](crate::functions::CircuitBreaker), and
+/// [`Stopwatch`](super::Stopwatch) read the time through a `Clock`
+/// ([`SystemClock`] by default), so tests can substitute a [`MockClock`] and
+/// move time forward without sleeping.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// The current instant
+    fn now(&self) -> Instant;
+
+    /// Ask to be woken whenever this clock jumps forward without real time
+    /// passing.
+    ///
+    /// Background workers waiting on a condition variable (like
+    /// [`Debounced`](crate::functions::Debounced)) register here so a manual
+    /// clock can interrupt their sleep. `wake` returns `false` once it is no
+    /// longer needed. The default does nothing: real clocks never jump.
+    fn on_advance(&self, wake: Box<dyn Fn() -> bool + Send + Sync>) {
+        let _ = wake;
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+    fn on_advance(&self, wake: Box<dyn Fn() -> bool + Send + Sync>) {
+        (**self).on_advance(wake)
+    }
+}
+
+/// The real monotonic clock, [`Instant::now`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+// Shared default so types holding a clock don't allocate one each
+pub(crate) fn system_clock() -> Arc<dyn Clock> {
+    static SYSTEM: OnceLock<Arc<dyn Clock>> = OnceLock::new();
+    Arc::clone(SYSTEM.get_or_init(|| Arc::new(SystemClock)))
+}
+
+/// A manually driven clock for tests.
+///
+/// Clones share the same time, so keep one handle in the test and give
+/// another to the code under test. Time only moves when you call
+/// [`advance`](Self::advance).
+///
+/// Example:
+/// ```rust
+/// use toolchest::functions::RateLimiter;
+/// use toolchest::time::MockClock;
+/// use std::time::Duration;
+///
+/// let clock = MockClock::new();
+/// let limiter = RateLimiter::new(1, 2).with_clock(clock.clone());
+/// assert!(limiter.allow());
+/// assert!(!limiter.allow());
+/// clock.advance(Duration::from_millis(500));
+/// assert!(limiter.allow());
+/// ```
+#[derive(Clone)]
+pub struct MockClock {
+    inner: Arc<MockState>,
+}
+
+struct MockState {
+    now: Mutex<Instant>,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl MockClock {
+    /// A clock frozen at the current real instant
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// A clock frozen at `start`
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            inner: Arc::new(MockState {
+                now: Mutex::new(start),
+                wakers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Move time forward by `d` and wake any registered waiters
+    pub fn advance(&self, d: Duration) {
+        {
+            let mut now = self.inner.now.lock().unwrap_or_else(|e| e.into_inner());
+            *now += d;
+        }
+        self.inner
+            .wakers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|wake| wake());
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.inner.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn on_advance(&self, wake: Box<dyn Fn() -> bool + Send + Sync>) {
+        self.inner
+            .wakers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(wake);
+    }
+}
+
+impl fmt::Debug for MockClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClock")
+            .field("now", &self.now())
+            .finish()
+    }
+}
@@ -0,0 +1,224 @@
+//! Sliding-window rate measurement (events/sec, bytes/sec)
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::{system_clock, Clock};
+
+// Events are coalesced into this many buckets per window to bound memory
+const BUCKETS_PER_WINDOW: u32 = 20;
+
+#[derive(Debug)]
+struct Window {
+    window: Duration,
+    bucket: Duration,
+    started: Instant,
+    buckets: VecDeque<(Instant, u64)>,
+    total: u64,
+    clock: Arc<dyn Clock>,
+}
+
+impl Window {
+    fn new(window: Duration) -> Self {
+        assert!(!window.is_zero(), "rate window must be non-zero");
+        let clock = system_clock();
+        Self {
+            window,
+            bucket: window / BUCKETS_PER_WINDOW,
+            started: clock.now(),
+            clock,
+            buckets: VecDeque::new(),
+            total: 0,
+        }
+    }
+
+    fn prune(&mut self, now: Instant) {
+        while let Some(&(start, _)) = self.buckets.front() {
+            if now.saturating_duration_since(start) > self.window {
+                self.buckets.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn add(&mut self, now: Instant, n: u64) {
+        self.total = self.total.saturating_add(n);
+        match self.buckets.back_mut() {
+            Some((start, count)) if now.saturating_duration_since(*start) < self.bucket => {
+                *count = count.saturating_add(n);
+            }
+            _ => self.buckets.push_back((now, n)),
+        }
+        self.prune(now);
+    }
+
+    fn per_second(&mut self, now: Instant) -> f64 {
+        self.prune(now);
+        // Young meters average over their lifetime rather than the full window
+        let span = now.saturating_duration_since(self.started).min(self.window);
+        if span.is_zero() {
+            return 0.0;
+        }
+        let sum: u64 = self.buckets.iter().map(|(_, c)| c).sum();
+        sum as f64 / span.as_secs_f64()
+    }
+
+    fn reset(&mut self) {
+        self.started = self.clock.now();
+        self.buckets.clear();
+        self.total = 0;
+    }
+}
+
+/// Events per second over a sliding window.
+///
+/// Thread-safe; share it by reference or in an `Arc`. Events are grouped into
+/// small time buckets, so memory stays constant however fast you tick.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::RateMeter;
+/// use std::time::Duration;
+/// let meter = RateMeter::new(Duration::from_secs(1));
+/// for _ in 0..10 {
+///     meter.tick();
+/// }
+/// assert_eq!(meter.total(), 10);
+/// assert!(meter.rate() > 0.0);
+/// ```
+#[derive(Debug)]
+pub struct RateMeter {
+    inner: Mutex<Window>,
+}
+
+impl RateMeter {
+    /// Create a meter averaging over `window`; panics if `window` is zero
+    pub fn new(window: Duration) -> Self {
+        Self {
+            inner: Mutex::new(Window::new(window)),
+        }
+    }
+
+    /// Read time from `clock` instead of the system clock; restarts the meter
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::time::{MockClock, RateMeter};
+    /// use std::time::Duration;
+    /// let clock = MockClock::new();
+    /// let meter = RateMeter::new(Duration::from_secs(10)).with_clock(clock.clone());
+    /// meter.tick_n(50);
+    /// clock.advance(Duration::from_secs(5));
+    /// assert_eq!(meter.rate(), 10.0);
+    /// ```
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        {
+            let mut inner = self.lock();
+            inner.clock = Arc::new(clock);
+            inner.reset();
+        }
+        self
+    }
+
+    /// Record one event
+    pub fn tick(&self) {
+        self.tick_n(1);
+    }
+
+    /// Record `n` events at once
+    pub fn tick_n(&self, n: u64) {
+        let mut inner = self.lock();
+        let now = inner.clock.now();
+        inner.add(now, n);
+    }
+
+    /// Events per second over the window (or since creation, if shorter)
+    pub fn rate(&self) -> f64 {
+        let mut inner = self.lock();
+        let now = inner.clock.now();
+        inner.per_second(now)
+    }
+
+    /// Events recorded since creation or the last [`reset`](Self::reset)
+    pub fn total(&self) -> u64 {
+        self.lock().total
+    }
+
+    /// Clear all recorded events and restart the clock
+    pub fn reset(&self) {
+        self.lock().reset();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Window> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Bytes per second over a sliding window.
+///
+/// A [`RateMeter`] counting bytes, for download/copy progress reporting.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::Throughput;
+/// use std::time::Duration;
+/// let t = Throughput::new(Duration::from_secs(5));
+/// t.record(4096);
+/// t.record(4096);
+/// assert_eq!(t.total_bytes(), 8192);
+/// let _bps = t.bytes_per_sec();
+/// ```
+#[derive(Debug)]
+pub struct Throughput {
+    meter: RateMeter,
+}
+
+impl Throughput {
+    /// Create a throughput meter averaging over `window`; panics if `window` is zero
+    pub fn new(window: Duration) -> Self {
+        Self {
+            meter: RateMeter::new(window),
+        }
+    }
+
+    /// Read time from `clock` instead of the system clock; restarts the meter
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        Self {
+            meter: self.meter.with_clock(clock),
+        }
+    }
+
+    /// Record `bytes` transferred
+    pub fn record(&self, bytes: u64) {
+        self.meter.tick_n(bytes);
+    }
+
+    /// Bytes per second over the window
+    pub fn bytes_per_sec(&self) -> f64 {
+        self.meter.rate()
+    }
+
+    /// Total bytes recorded
+    pub fn total_bytes(&self) -> u64 {
+        self.meter.total()
+    }
+
+    /// Estimated time to transfer `remaining` bytes at the current rate
+    ///
+    /// `None` while the rate is zero, or if the estimate does not fit in a
+    /// `Duration`.
+    pub fn eta(&self, remaining: u64) -> Option<Duration> {
+        let rate = self.bytes_per_sec();
+        if rate <= 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(remaining as f64 / rate).ok()
+    }
+
+    /// Clear all recorded bytes and restart the clock
+    pub fn reset(&self) {
+        self.meter.reset();
+    }
+}
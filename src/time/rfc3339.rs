@@ -0,0 +1,225 @@
+//! RFC 3339 timestamps and Unix time helpers
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::civil::{civil_from_days, days_from_civil, days_in_month};
+
+/// Signed (seconds, nanoseconds) offset of `t` from the Unix epoch
+fn split_epoch(t: SystemTime) -> (i64, u32) {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+        Err(e) => {
+            let d = e.duration();
+            let (secs, nanos) = (d.as_secs() as i64, d.subsec_nanos());
+            if nanos == 0 {
+                (-secs, 0)
+            } else {
+                (-secs - 1, 1_000_000_000 - nanos)
+            }
+        }
+    }
+}
+
+fn from_epoch(secs: i64, nanos: u32) -> Option<SystemTime> {
+    if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+            .checked_add(Duration::from_nanos(nanos as u64))
+    }
+}
+
+/// Format as an RFC 3339 UTC timestamp, e.g. `2024-03-01T12:30:05.250Z`.
+///
+/// Fractional seconds are omitted when zero and otherwise printed with 3, 6,
+/// or 9 digits, whichever is exact.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::format_rfc3339;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// let t = UNIX_EPOCH + Duration::from_millis(1_700_000_000_250);
+/// assert_eq!(format_rfc3339(t), "2023-11-14T22:13:20.250Z");
+/// ```
+pub fn format_rfc3339(t: SystemTime) -> String {
+    format_rfc3339_with_offset(t, 0)
+}
+
+/// Format as an RFC 3339 timestamp in a fixed UTC offset (in minutes).
+///
+/// An offset of zero is written as `Z`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::format_rfc3339_with_offset;
+/// use std::time::UNIX_EPOCH;
+/// assert_eq!(format_rfc3339_with_offset(UNIX_EPOCH, -330), "1969-12-31T18:30:00-05:30");
+/// ```
+pub fn format_rfc3339_with_offset(t: SystemTime, offset_minutes: i32) -> String {
+    let (secs, nanos) = split_epoch(t);
+    let local = secs + offset_minutes as i64 * 60;
+    let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+    let sod = local.rem_euclid(86_400);
+    let mut out = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        sod / 3600,
+        sod % 3600 / 60,
+        sod % 60
+    );
+    if nanos != 0 {
+        if nanos % 1_000_000 == 0 {
+            out.push_str(&format!(".{:03}", nanos / 1_000_000));
+        } else if nanos % 1_000 == 0 {
+            out.push_str(&format!(".{:06}", nanos / 1_000));
+        } else {
+            out.push_str(&format!(".{:09}", nanos));
+        }
+    }
+    if offset_minutes == 0 {
+        out.push('Z');
+    } else {
+        let sign = if offset_minutes < 0 { '-' } else { '+' };
+        let abs = offset_minutes.unsigned_abs();
+        out.push_str(&format!("{}{:02}:{:02}", sign, abs / 60, abs % 60));
+    }
+    out
+}
+
+fn digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parse an RFC 3339 timestamp such as `2024-03-01T12:30:05.25+02:00`.
+///
+/// Accepts `T`, `t`, or a space between date and time, `Z`/`z` or a
+/// `±HH:MM` offset, and any number of fractional digits (truncated to
+/// nanoseconds). Returns `None` for malformed or out-of-range input.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{format_rfc3339, parse_rfc3339};
+/// let t = parse_rfc3339("2024-03-01T14:30:05.5+02:00").unwrap();
+/// assert_eq!(format_rfc3339(t), "2024-03-01T12:30:05.500Z");
+/// assert!(parse_rfc3339("2023-02-29T00:00:00Z").is_none());
+/// ```
+pub fn parse_rfc3339(s: &str) -> Option<SystemTime> {
+    if !s.is_ascii() || s.len() < 20 {
+        return None;
+    }
+    let b = s.as_bytes();
+    if b[4] != b'-' || b[7] != b'-' || !matches!(b[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    if b[13] != b':' || b[16] != b':' {
+        return None;
+    }
+    let year = digits(&s[0..4])? as i64;
+    let month = digits(&s[5..7])?;
+    let day = digits(&s[8..10])?;
+    let hour = digits(&s[11..13])?;
+    let minute = digits(&s[14..16])?;
+    let second = digits(&s[17..19])?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    // 60 is allowed for leap seconds and rolls into the next minute
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0u32;
+    if let Some(frac) = rest.strip_prefix('.') {
+        let len = frac.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        for (i, d) in frac.bytes().take(len).enumerate() {
+            if i < 9 {
+                nanos = nanos * 10 + (d - b'0') as u32;
+            }
+        }
+        nanos *= 10u32.pow(9 - len.min(9) as u32);
+        rest = &frac[len..];
+    }
+
+    let offset_secs = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let ob = rest.as_bytes();
+            if ob.len() != 6 || ob[3] != b':' {
+                return None;
+            }
+            let oh = digits(&rest[1..3])?;
+            let om = digits(&rest[4..6])?;
+            if oh > 23 || om > 59 {
+                return None;
+            }
+            let total = (oh * 3600 + om * 60) as i64;
+            match ob[0] {
+                b'+' => total,
+                b'-' => -total,
+                _ => return None,
+            }
+        }
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400
+        + (hour * 3600 + minute * 60 + second) as i64
+        - offset_secs;
+    from_epoch(secs, nanos)
+}
+
+/// Seconds since the Unix epoch (0 if the clock is before 1970).
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::unix_timestamp;
+/// assert!(unix_timestamp() > 1_600_000_000);
+/// ```
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Milliseconds since the Unix epoch (0 if the clock is before 1970).
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{unix_timestamp, unix_timestamp_millis};
+/// assert!(unix_timestamp_millis() / 1000 >= unix_timestamp() - 1);
+/// ```
+pub fn unix_timestamp_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Convert signed seconds since the Unix epoch into a `SystemTime`.
+///
+/// Returns `None` if the platform's `SystemTime` cannot represent the result,
+/// which can happen near `i64::MAX` or `i64::MIN` seconds.
+///
+/// Example:
+/// ```rust
+/// use toolchest::time::{format_rfc3339, from_unix_timestamp};
+/// assert_eq!(format_rfc3339(from_unix_timestamp(-1).unwrap()), "1969-12-31T23:59:59Z");
+/// ```
+pub fn from_unix_timestamp(secs: i64) -> Option<SystemTime> {
+    let offset = Duration::from_secs(secs.unsigned_abs());
+    if secs >= 0 {
+        UNIX_EPOCH.checked_add(offset)
+    } else {
+        UNIX_EPOCH.checked_sub(offset)
+    }
+}
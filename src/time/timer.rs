@@ -0,0 +1,211 @@
+//! One-shot and periodic timers on a shared background thread
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+enum Task {
+    Once(Box<dyn FnOnce() + Send>),
+    Repeat(Box<dyn FnMut() + Send>, Duration),
+}
+
+struct Job {
+    deadline: Instant,
+    seq: u64,
+    cancelled: Arc<AtomicBool>,
+    task: Task,
+}
+
+// BinaryHeap is a max-heap; order so the earliest deadline is on top
+impl Ord for Job {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other
+            .deadline
+            .cmp(&self.deadline)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for Job {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Job {
+    fn eq(&self, other: &Self) -> bool {
+        self.seq == other.seq
+    }
+}
+
+impl Eq for Job {}
+
+struct Scheduler {
+    queue: Mutex<BinaryHeap<Job>>,
+    wakeup: Condvar,
+    seq: AtomicU64,
+}
+
+impl Scheduler {
+    fn global() -> &'static Scheduler {
+        static SCHEDULER: OnceLock<Scheduler> = OnceLock::new();
+        static WORKER: OnceLock<()> = OnceLock::new();
+        let scheduler = SCHEDULER.get_or_init(|| Scheduler {
+            queue: Mutex::new(BinaryHeap::new()),
+            wakeup: Condvar::new(),
+            seq: AtomicU64::new(0),
+        });
+        WORKER.get_or_init(|| {
+            thread::Builder::new()
+                .name("toolchest-timer".into())
+                .spawn(move || scheduler.run())
+                .expect("failed to spawn timer thread");
+        });
+        scheduler
+    }
+
+    fn schedule(&self, delay: Duration, task: Task) -> TimerHandle {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        // A deadline past what `Instant` can represent never fires
+        if let Some(deadline) = Instant::now().checked_add(delay) {
+            let job = Job {
+                deadline,
+                seq: self.seq.fetch_add(1, Ordering::Relaxed),
+                cancelled: Arc::clone(&cancelled),
+                task,
+            };
+            self.push(job);
+        }
+        TimerHandle { cancelled }
+    }
+
+    fn push(&self, job: Job) {
+        self.queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(job);
+        self.wakeup.notify_one();
+    }
+
+    fn next_due(&self) -> Job {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            let now = Instant::now();
+            let wait = match queue.peek() {
+                None => None,
+                Some(job) if job.cancelled.load(Ordering::Acquire) => {
+                    queue.pop();
+                    continue;
+                }
+                Some(job) if job.deadline <= now => {
+                    return queue.pop().expect("peeked job");
+                }
+                Some(job) => Some(job.deadline - now),
+            };
+            queue = match wait {
+                None => self.wakeup.wait(queue).unwrap_or_else(|e| e.into_inner()),
+                Some(d) => {
+                    self.wakeup
+                        .wait_timeout(queue, d)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+
+    fn run(&self) {
+        loop {
+            let mut job = self.next_due();
+            match job.task {
+                Task::Once(f) => {
+                    let _ = catch_unwind(AssertUnwindSafe(f));
+                }
+                Task::Repeat(ref mut f, period) => {
+                    let _ = catch_unwind(AssertUnwindSafe(&mut *f));
+                    if !job.cancelled.load(Ordering::Acquire) {
+                        // Fixed rate, but skip missed ticks instead of bursting
+                        let now = Instant::now();
+                        let next = job
+                            .deadline
+                            .checked_add(period)
+                            .filter(|&next| next >= now)
+                            .or_else(|| now.checked_add(period));
+                        if let Some(deadline) = next {
+                            job.deadline = deadline;
+                            self.push(job);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Handle to a scheduled timer; use it to cancel.
+///
+/// Dropping the handle does not cancel the timer.
+#[derive(Clone, Debug)]
+pub struct TimerHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TimerHandle {
+    /// Stop the timer; a callback already running finishes normally.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// True once [`cancel`](Self::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Schedules callbacks on one shared background thread.
+///
+/// All timers in the process share a single lazily started worker thread, so
+/// callbacks should be short; hand heavy work off to another thread. A
+/// panicking callback is caught and does not stop other timers (a periodic
+/// timer keeps firing).
+///
+/// Example:
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use toolchest::time::Timer;
+///
+/// let ticks = Arc::new(AtomicUsize::new(0));
+/// let t = Arc::clone(&ticks);
+/// let heartbeat = Timer::every(Duration::from_millis(5), move || {
+///     t.fetch_add(1, Ordering::SeqCst);
+/// });
+/// std::thread::sleep(Duration::from_millis(60));
+/// heartbeat.cancel();
+/// assert!(ticks.load(Ordering::SeqCst) >= 2);
+/// ```
+#[derive(Debug)]
+pub struct Timer;
+
+impl Timer {
+    /// Run `callback` once after `delay`.
+    ///
+    /// A `delay` too large to represent as an [`Instant`] never fires.
+    pub fn after<F: FnOnce() + Send + 'static>(delay: Duration, callback: F) -> TimerHandle {
+        Scheduler::global().schedule(delay, Task::Once(Box::new(callback)))
+    }
+
+    /// Run `callback` every `period` (first run after one period) until cancelled.
+    ///
+    /// Runs at a fixed rate; ticks missed because the worker was busy are
+    /// skipped rather than replayed. A zero period is treated as 1ms.
+    pub fn every<F: FnMut() + Send + 'static>(period: Duration, callback: F) -> TimerHandle {
+        let period = period.max(Duration::from_millis(1));
+        Scheduler::global().schedule(period, Task::Repeat(Box::new(callback), period))
+    }
+}
@@ -0,0 +1,91 @@
+//! Explicit numeric casts that never truncate silently.
+//!
+//! Each helper spells out what happens when the value doesn't fit: clamp to
+//! the target range, return `None`, or follow a [`CastPolicy`] for floats.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::types::cast::{to_u32_clamped, to_i32_checked, f64_to_i64, CastPolicy};
+//! assert_eq!(to_u32_clamped(-5), 0);
+//! assert_eq!(to_u32_clamped(1 << 40), u32::MAX);
+//! assert_eq!(to_i32_checked(1 << 40), None);
+//! assert_eq!(f64_to_i64(2.5, CastPolicy::Round), Some(3));
+//! assert_eq!(f64_to_i64(2.5, CastPolicy::Fail), None);
+//! ```
+
+/// How [`f64_to_i64`] handles fractional values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastPolicy {
+    /// Round to nearest, ties away from zero
+    Round,
+    /// Drop the fractional part (round towards zero)
+    Trunc,
+    /// Reject values with a fractional part
+    Fail,
+}
+
+/// Convert between integer types, `None` if the value doesn't fit.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::cast::checked_cast;
+/// assert_eq!(checked_cast::<i64, u8>(255), Some(255u8));
+/// assert_eq!(checked_cast::<i64, u8>(256), None);
+/// ```
+pub fn checked_cast<T, U: TryFrom<T>>(value: T) -> Option<U> {
+    U::try_from(value).ok()
+}
+
+/// `i64` to `u32`, clamping into `0..=u32::MAX`.
+pub fn to_u32_clamped(value: i64) -> u32 {
+    value.clamp(0, u32::MAX as i64) as u32
+}
+
+/// `i64` to `i32`, clamping into `i32::MIN..=i32::MAX`.
+pub fn to_i32_clamped(value: i64) -> i32 {
+    value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// `i64` to `i32`, `None` if out of range.
+pub fn to_i32_checked(value: i64) -> Option<i32> {
+    i32::try_from(value).ok()
+}
+
+/// `usize` to `u32`, saturating at `u32::MAX`.
+pub fn usize_to_u32_saturating(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+/// `f64` to `i64` following `policy`.
+///
+/// Returns `None` for NaN, infinities, values outside the `i64` range (after
+/// rounding), and — with [`CastPolicy::Fail`] — non-integral values.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::cast::{f64_to_i64, CastPolicy};
+/// assert_eq!(f64_to_i64(-2.7, CastPolicy::Trunc), Some(-2));
+/// assert_eq!(f64_to_i64(-2.5, CastPolicy::Round), Some(-3));
+/// assert_eq!(f64_to_i64(4.0, CastPolicy::Fail), Some(4));
+/// assert_eq!(f64_to_i64(f64::NAN, CastPolicy::Round), None);
+/// assert_eq!(f64_to_i64(1e19, CastPolicy::Trunc), None);
+/// ```
+pub fn f64_to_i64(value: f64, policy: CastPolicy) -> Option<i64> {
+    let whole = match policy {
+        CastPolicy::Round => value.round(),
+        CastPolicy::Trunc => value.trunc(),
+        CastPolicy::Fail => {
+            if value.fract() != 0.0 {
+                return None;
+            }
+            value
+        }
+    };
+    // 2^63 is exactly representable; i64::MAX is not
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if whole.is_finite() && (-LIMIT..LIMIT).contains(&whole) {
+        Some(whole as i64)
+    } else {
+        None
+    }
+}
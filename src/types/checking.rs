@@ -1,5 +1,9 @@
 //! Type checking utilities
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
 /// Trait for checking if a value is empty.
 pub trait IsEmpty {
     /// Returns true if the value is considered empty.
@@ -30,9 +34,77 @@ impl<T> IsEmpty for &[T] {
     }
 }
 
-impl<K, V> IsEmpty for std::collections::HashMap<K, V> {
+impl<T, const N: usize> IsEmpty for [T; N] {
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<K, V> IsEmpty for HashMap<K, V> {
+    fn is_empty(&self) -> bool {
+        HashMap::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for HashSet<T> {
+    fn is_empty(&self) -> bool {
+        HashSet::is_empty(self)
+    }
+}
+
+impl<K, V> IsEmpty for BTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for BTreeSet<T> {
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        VecDeque::is_empty(self)
+    }
+}
+
+impl IsEmpty for Cow<'_, str> {
+    fn is_empty(&self) -> bool {
+        <str>::is_empty(self)
+    }
+}
+
+impl IsEmpty for Path {
     fn is_empty(&self) -> bool {
-        std::collections::HashMap::is_empty(self)
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for &Path {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl IsEmpty for PathBuf {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// `None` is empty; `Some(_)` is not, regardless of the inner value.
+impl<T> IsEmpty for Option<T> {
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+}
+
+/// `Err(_)` is empty; `Ok(_)` is not, regardless of the inner value.
+impl<T, E> IsEmpty for Result<T, E> {
+    fn is_empty(&self) -> bool {
+        self.is_err()
     }
 }
 
@@ -47,3 +119,31 @@ impl<K, V> IsEmpty for std::collections::HashMap<K, V> {
 pub fn is_empty<T: IsEmpty + ?Sized>(value: &T) -> bool {
     value.is_empty()
 }
+
+/// Check if an iterable yields no items.
+///
+/// Iterators cannot get a blanket [`IsEmpty`] impl (it would overlap with the
+/// collection impls), so this consumes at most one item instead.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::is_empty_iter;
+/// assert!(is_empty_iter(std::iter::empty::<u8>()));
+/// assert!(!is_empty_iter((0..10).filter(|x| x % 7 == 6)));
+/// ```
+pub fn is_empty_iter<I: IntoIterator>(iter: I) -> bool {
+    iter.into_iter().next().is_none()
+}
+
+/// Check if a string is empty or contains only whitespace.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::is_blank;
+/// assert!(is_blank(" \t\n"));
+/// assert!(is_blank(""));
+/// assert!(!is_blank(" x "));
+/// ```
+pub fn is_blank(s: &str) -> bool {
+    s.trim().is_empty()
+}
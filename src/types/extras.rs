@@ -36,6 +36,90 @@ where
     }
 }
 
+/// `Some(v)` or a lazily computed fallback
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::some_or_else_lazy;
+/// assert_eq!(some_or_else_lazy(Some(1), || unreachable!()), 1);
+/// assert_eq!(some_or_else_lazy(None, || 2 + 2), 4);
+/// ```
+pub fn some_or_else_lazy<T, F: FnOnce() -> T>(opt: Option<T>, f: F) -> T {
+    opt.unwrap_or_else(f)
+}
+
+/// Turn a `Result` into an `Option`, printing the error to stderr as
+/// `"{context}: {error}"`
+///
+/// Use [`result_to_option_logging`] to send the error somewhere else.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::ok_or_log;
+/// let parsed = ok_or_log("12".parse::<u8>(), "port");
+/// assert_eq!(parsed, Some(12));
+/// assert_eq!(ok_or_log("x".parse::<u8>(), "port"), None); // prints "port: invalid digit..."
+/// ```
+pub fn ok_or_log<T, E: std::fmt::Display>(res: Result<T, E>, context: &str) -> Option<T> {
+    result_to_option_logging(res, |e| eprintln!("{context}: {e}"))
+}
+
+/// Turn a `Result` into an `Option`, handing any error to `log` first
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::result_to_option_logging;
+/// let mut errors = Vec::new();
+/// let rows: Vec<u32> = ["1", "x", "3"]
+///     .iter()
+///     .filter_map(|s| result_to_option_logging(s.parse::<u32>(), |e| errors.push(e.to_string())))
+///     .collect();
+/// assert_eq!(rows, [1, 3]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn result_to_option_logging<T, E, F: FnOnce(&E)>(res: Result<T, E>, log: F) -> Option<T> {
+    match res {
+        Ok(v) => Some(v),
+        Err(e) => {
+            log(&e);
+            None
+        }
+    }
+}
+
+/// All `Ok` values, or the first `Err`
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::transpose_vec;
+/// assert_eq!(transpose_vec(vec![Ok::<_, &str>(1), Ok(2)]), Ok(vec![1, 2]));
+/// assert_eq!(transpose_vec(vec![Ok(1), Err("a"), Err("b")]), Err("a"));
+/// ```
+pub fn transpose_vec<T, E>(results: Vec<Result<T, E>>) -> Result<Vec<T>, E> {
+    results.into_iter().collect()
+}
+
+/// Split results into `(oks, errs)`, keeping order within each side
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::partition_results;
+/// let (ok, err) = partition_results(vec![Ok(1), Err("x"), Ok(3)]);
+/// assert_eq!(ok, [1, 3]);
+/// assert_eq!(err, ["x"]);
+/// ```
+pub fn partition_results<T, E>(results: Vec<Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for r in results {
+        match r {
+            Ok(v) => oks.push(v),
+            Err(e) => errs.push(e),
+        }
+    }
+    (oks, errs)
+}
+
 /// A non-empty vector wrapper
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NonEmptyVec<T> {
@@ -1,6 +1,8 @@
 //! Type utilities module.
 //!
-//! Helpers for type checking, conversions, and ergonomic wrappers.
+//! Helpers for type checking, conversions, explicit numeric casts
+//! ([`cast`]), unit newtypes ([`units`]), semantic versions ([`SemVer`]), and
+//! ergonomic wrappers.
 //!
 //! Examples:
 //! ```rust
@@ -14,10 +16,22 @@
 //! assert_eq!(nev.len(), 3);
 //! ```
 
+pub mod cast;
 pub mod checking;
 pub mod conversion;
 pub mod extras;
+#[cfg(feature = "std")]
+pub mod semver;
+#[cfg(feature = "std")]
+pub mod units;
 
-pub use checking::{is_empty, IsEmpty};
+pub use checking::{is_blank, is_empty, is_empty_iter, IsEmpty};
 pub use conversion::{default_to, parse_or, parse_or_default, to_string_safe};
-pub use extras::{map_ok_or, map_some_or, NonEmptyVec};
+pub use extras::{
+    map_ok_or, map_some_or, ok_or_log, partition_results, result_to_option_logging,
+    some_or_else_lazy, transpose_vec, NonEmptyVec,
+};
+#[cfg(feature = "std")]
+pub use semver::SemVer;
+#[cfg(feature = "std")]
+pub use units::{Bytes, Percent, Ratio};
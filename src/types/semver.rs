@@ -0,0 +1,387 @@
+//! Semantic versions ([SemVer 2.0.0](https://semver.org)) and simple
+//! Cargo-style version requirements.
+//!
+//! [`SemVer`] parses and orders versions like `1.4.0-rc.1+build.7`.
+//! [`SemVer::matches_requirement`] understands the comparators most tools
+//! need: caret (`^1.2`, also the meaning of a bare `1.2`), tilde (`~1.2.3`),
+//! exact (`=1.2`), `>`, `>=`, `<`, `<=`, wildcards (`*`, `1.x`), and
+//! comma-separated combinations such as `>=1.2, <1.5`.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::types::SemVer;
+//!
+//! let v: SemVer = "1.4.2".parse().unwrap();
+//! assert!(v > SemVer::new(1, 3, 9));
+//! assert!(v.matches_requirement("^1.2"));
+//! assert!(!v.matches_requirement("~1.2"));
+//! assert!(v.matches_requirement(">=1.2, <1.5"));
+//! assert!("1.4.2-rc.1".parse::<SemVer>().unwrap() < v);
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::validation::ValidationError;
+
+/// A semantic version: `MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD]`.
+///
+/// Ordering follows SemVer precedence (a pre-release sorts before its
+/// release, identifiers compare numerically or lexically), with build
+/// metadata compared last only as a tie-break so that `Ord` agrees with
+/// `Eq`. Use [`cmp_precedence`](Self::cmp_precedence) to ignore build
+/// metadata entirely.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SemVer {
+    /// Incremented for incompatible changes
+    pub major: u64,
+    /// Incremented for backwards-compatible features
+    pub minor: u64,
+    /// Incremented for backwards-compatible fixes
+    pub patch: u64,
+    /// Dot-separated pre-release identifiers (`"rc.1"`); empty for a release
+    pub pre: String,
+    /// Dot-separated build metadata (`"build.7"`); empty when absent
+    pub build: String,
+}
+
+impl SemVer {
+    /// A release version with no pre-release or build metadata.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre: String::new(),
+            build: String::new(),
+        }
+    }
+
+    /// Parse a strict SemVer 2.0.0 string (no leading `v`, no leading zeros).
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::types::SemVer;
+    /// use toolchest::validation::ValidationError;
+    /// let v = SemVer::parse("2.0.0-beta.2+sha.5114f85").unwrap();
+    /// assert_eq!((v.major, v.pre.as_str(), v.build.as_str()), (2, "beta.2", "sha.5114f85"));
+    /// assert_eq!(
+    ///     SemVer::parse("1.02.0"),
+    ///     Err(ValidationError::InvalidFormat("numeric identifier has a leading zero"))
+    /// );
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ValidationError> {
+        if s.is_empty() {
+            return Err(ValidationError::Empty);
+        }
+        if let Some((position, ch)) = s
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+')))
+        {
+            return Err(ValidationError::InvalidCharacter { ch, position });
+        }
+        let (rest, build) = match s.split_once('+') {
+            Some((rest, build)) => (rest, Some(build)),
+            None => (s, None),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (rest, None),
+        };
+        let parts: Vec<&str> = core.split('.').collect();
+        if parts.len() != 3 {
+            return Err(ValidationError::InvalidFormat("expected MAJOR.MINOR.PATCH"));
+        }
+        let mut nums = [0u64; 3];
+        for (n, part) in nums.iter_mut().zip(&parts) {
+            *n = parse_number(part)?;
+        }
+        if let Some(pre) = pre {
+            check_identifiers(pre, true)?;
+        }
+        if let Some(build) = build {
+            check_identifiers(build, false)?;
+        }
+        Ok(Self {
+            major: nums[0],
+            minor: nums[1],
+            patch: nums[2],
+            pre: pre.unwrap_or_default().to_string(),
+            build: build.unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Whether this is a pre-release (`1.0.0-alpha`).
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    /// Compare by SemVer precedence, ignoring build metadata.
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| cmp_pre(&self.pre, &other.pre))
+    }
+
+    /// Whether this version satisfies a Cargo-style requirement.
+    ///
+    /// Comma-separated comparators must all match. As in Cargo, a
+    /// pre-release version only matches when some comparator names a
+    /// pre-release of the same `MAJOR.MINOR.PATCH`. A malformed requirement
+    /// matches nothing.
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::types::SemVer;
+    /// let v = SemVer::new(0, 2, 5);
+    /// assert!(v.matches_requirement("^0.2"));
+    /// assert!(!v.matches_requirement("^0.1"));
+    /// assert!(v.matches_requirement("0.2.x"));
+    /// assert!(!SemVer::parse("1.0.0-rc.1").unwrap().matches_requirement(">=0.9"));
+    /// assert!(SemVer::parse("1.0.0-rc.2").unwrap().matches_requirement(">=1.0.0-rc.1"));
+    /// assert!(!v.matches_requirement("not a version"));
+    /// ```
+    pub fn matches_requirement(&self, req: &str) -> bool {
+        let Some(comparators) = req
+            .split(',')
+            .map(|c| Comparator::parse(c.trim()))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        if self.is_prerelease()
+            && !comparators.iter().any(|c| {
+                !c.pre.is_empty()
+                    && (c.major, c.minor, c.patch)
+                        == (self.major, Some(self.minor), Some(self.patch))
+            })
+        {
+            return false;
+        }
+        comparators.iter().all(|c| c.matches(self))
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for SemVer {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for SemVer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_number(part: &str) -> Result<u64, ValidationError> {
+    if part.is_empty() {
+        return Err(ValidationError::InvalidFormat("empty identifier"));
+    }
+    if !part.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ValidationError::InvalidFormat(
+            "version numbers must be digits",
+        ));
+    }
+    if part.len() > 1 && part.starts_with('0') {
+        return Err(ValidationError::InvalidFormat(
+            "numeric identifier has a leading zero",
+        ));
+    }
+    part.parse()
+        .map_err(|_| ValidationError::InvalidFormat("version number too large"))
+}
+
+fn check_identifiers(s: &str, pre: bool) -> Result<(), ValidationError> {
+    for id in s.split('.') {
+        if id.is_empty() {
+            return Err(ValidationError::InvalidFormat("empty identifier"));
+        }
+        if !id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+            return Err(ValidationError::InvalidFormat(
+                "identifiers may only contain [0-9A-Za-z-]",
+            ));
+        }
+        if pre && id.len() > 1 && id.starts_with('0') && id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ValidationError::InvalidFormat(
+                "numeric identifier has a leading zero",
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Pre-release precedence: a release outranks any pre-release; identifiers
+// compare numerically when both are numeric, numeric sorts before
+// alphanumeric, and a longer list wins a shared prefix.
+fn cmp_pre(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+    let mut xs = a.split('.');
+    let mut ys = b.split('.');
+    loop {
+        match (xs.next(), ys.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let is_num = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+                let ord = match (is_num(x), is_num(y)) {
+                    // No leading zeros, so longer means larger
+                    (true, true) => x.len().cmp(&y.len()).then_with(|| x.cmp(y)),
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => x.cmp(y),
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+    Any,
+}
+
+// One comparator; missing (or wildcard) parts are `None`
+#[derive(Debug)]
+struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: String,
+}
+
+impl Comparator {
+    fn parse(s: &str) -> Option<Self> {
+        let (op, rest) = [
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("~", Op::Tilde),
+            ("^", Op::Caret),
+        ]
+        .iter()
+        .find_map(|(prefix, op)| s.strip_prefix(prefix).map(|rest| (*op, rest.trim_start())))
+        .unwrap_or((Op::Caret, s));
+        let wildcard = |p: &str| matches!(p, "*" | "x" | "X");
+        if wildcard(rest) {
+            return (op == Op::Caret || op == Op::Exact).then_some(Comparator {
+                op: Op::Any,
+                major: 0,
+                minor: None,
+                patch: None,
+                pre: String::new(),
+            });
+        }
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, pre),
+            None => (rest, ""),
+        };
+        let mut parts = core.split('.');
+        let major = parse_number(parts.next()?).ok()?;
+        let part = |parts: &mut std::str::Split<'_, char>| match parts.next() {
+            None => Some(None),
+            Some(p) if wildcard(p) => Some(None),
+            Some(p) => parse_number(p).ok().map(Some),
+        };
+        let minor = part(&mut parts)?;
+        let patch = part(&mut parts)?;
+        if parts.next().is_some() || (minor.is_none() && patch.is_some()) {
+            return None;
+        }
+        if !pre.is_empty() {
+            patch?;
+            check_identifiers(pre, true).ok()?;
+        }
+        Some(Comparator {
+            op,
+            major,
+            minor,
+            patch,
+            pre: pre.to_string(),
+        })
+    }
+
+    fn matches(&self, v: &SemVer) -> bool {
+        let base = SemVer {
+            pre: self.pre.clone(),
+            ..SemVer::new(self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0))
+        };
+        // First version past the range named by the given parts (`1.2` -> 1.3.0)
+        let next = || match (self.minor, self.patch) {
+            (None, _) => SemVer::new(self.major.saturating_add(1), 0, 0),
+            (Some(minor), None) => SemVer::new(self.major, minor.saturating_add(1), 0),
+            (Some(minor), Some(patch)) => SemVer::new(self.major, minor, patch.saturating_add(1)),
+        };
+        let ge = |b: &SemVer| v.cmp_precedence(b) != Ordering::Less;
+        let lt = |b: &SemVer| v.cmp_precedence(b) == Ordering::Less;
+        match self.op {
+            Op::Any => true,
+            Op::Exact if self.patch.is_some() => v.cmp_precedence(&base) == Ordering::Equal,
+            Op::Exact => ge(&base) && lt(&next()),
+            Op::GreaterEq => ge(&base),
+            Op::Greater if self.patch.is_some() => v.cmp_precedence(&base) == Ordering::Greater,
+            Op::Greater => ge(&next()),
+            Op::Less => lt(&base),
+            Op::LessEq if self.patch.is_some() => v.cmp_precedence(&base) != Ordering::Greater,
+            Op::LessEq => lt(&next()),
+            Op::Tilde => {
+                let upper = match self.minor {
+                    Some(minor) => SemVer::new(self.major, minor.saturating_add(1), 0),
+                    None => SemVer::new(self.major.saturating_add(1), 0, 0),
+                };
+                ge(&base) && lt(&upper)
+            }
+            Op::Caret => {
+                let upper = match (self.major, self.minor, self.patch) {
+                    (0, Some(0), Some(patch)) => SemVer::new(0, 0, patch.saturating_add(1)),
+                    (0, Some(minor), _) => SemVer::new(0, minor.saturating_add(1), 0),
+                    (major, _, _) => SemVer::new(major.saturating_add(1), 0, 0),
+                };
+                ge(&base) && lt(&upper)
+            }
+        }
+    }
+}
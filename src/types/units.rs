@@ -0,0 +1,320 @@
+//! Unit newtypes: [`Bytes`], [`Percent`], and [`Ratio`].
+//!
+//! Wrapping raw numbers in a unit type keeps a byte count from being added
+//! to a millisecond count or a `0.25` ratio from being printed as `0.25%`.
+//! Each type offers checked arithmetic, conversions, and a human-readable
+//! `Display` built on the [`strings`](crate::strings) formatting helpers.
+//!
+//! Example:
+//! ```rust
+//! use toolchest::types::units::{Bytes, Percent, Ratio};
+//!
+//! let used = Bytes::mib(3) + Bytes::kib(512);
+//! let total = Bytes::gib(1);
+//! assert_eq!(used.to_string(), "3.5 MiB");
+//!
+//! let share = used.ratio_of(total).unwrap();
+//! assert_eq!(Percent::from(share).to_string(), "0.3%");
+//! assert_eq!(Bytes::mib(10).checked_sub(Bytes::mib(20)), None);
+//! assert_eq!(Ratio::new(0.5).unwrap().to_string(), "0.5");
+//! ```
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+use crate::strings::{format_bytes, format_float};
+
+/// A byte count.
+///
+/// The `+`, `-`, `*`, and `/` operators follow `u64` semantics (overflow panics
+/// in debug builds); use the `checked_*` or `saturating_*` methods when the
+/// inputs are untrusted.
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::Bytes;
+/// let b = Bytes::kib(1) * 3;
+/// assert_eq!(b.get(), 3072);
+/// assert_eq!(b.to_string(), "3.0 KiB");
+/// assert_eq!(Bytes::new(u64::MAX).checked_add(Bytes::new(1)), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes(pub u64);
+
+impl Bytes {
+    /// Zero bytes.
+    pub const ZERO: Bytes = Bytes(0);
+
+    /// Wrap a raw byte count.
+    pub const fn new(n: u64) -> Self {
+        Bytes(n)
+    }
+    /// `n` kibibytes (saturating at `u64::MAX` bytes).
+    pub const fn kib(n: u64) -> Self {
+        Bytes(n.saturating_mul(1 << 10))
+    }
+    /// `n` mebibytes (saturating at `u64::MAX` bytes).
+    pub const fn mib(n: u64) -> Self {
+        Bytes(n.saturating_mul(1 << 20))
+    }
+    /// `n` gibibytes (saturating at `u64::MAX` bytes).
+    pub const fn gib(n: u64) -> Self {
+        Bytes(n.saturating_mul(1 << 30))
+    }
+    /// The raw byte count.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Add, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Bytes) -> Option<Bytes> {
+        self.0.checked_add(rhs.0).map(Bytes)
+    }
+    /// Subtract, returning `None` if `rhs` is larger.
+    pub fn checked_sub(self, rhs: Bytes) -> Option<Bytes> {
+        self.0.checked_sub(rhs.0).map(Bytes)
+    }
+    /// Multiply by a count, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: u64) -> Option<Bytes> {
+        self.0.checked_mul(rhs).map(Bytes)
+    }
+    /// Divide by a count, returning `None` when `rhs` is zero.
+    pub fn checked_div(self, rhs: u64) -> Option<Bytes> {
+        self.0.checked_div(rhs).map(Bytes)
+    }
+    /// Add, clamping at `u64::MAX` bytes.
+    pub fn saturating_add(self, rhs: Bytes) -> Bytes {
+        Bytes(self.0.saturating_add(rhs.0))
+    }
+    /// Subtract, clamping at zero.
+    pub fn saturating_sub(self, rhs: Bytes) -> Bytes {
+        Bytes(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Scale by a ratio, rounding to the nearest byte (saturating).
+    ///
+    /// Example:
+    /// ```rust
+    /// use toolchest::types::{Bytes, Ratio};
+    /// let quota = Bytes::mib(100).scale(Ratio::new(0.1).unwrap());
+    /// assert_eq!(quota, Bytes::mib(10));
+    /// ```
+    pub fn scale(self, ratio: Ratio) -> Bytes {
+        let scaled = (self.0 as f64 * ratio.0).round();
+        if scaled <= 0.0 {
+            Bytes(0)
+        } else if scaled >= u64::MAX as f64 {
+            Bytes(u64::MAX)
+        } else {
+            Bytes(scaled as u64)
+        }
+    }
+
+    /// This size as a fraction of `whole`; `None` when `whole` is zero.
+    pub fn ratio_of(self, whole: Bytes) -> Option<Ratio> {
+        Ratio::of(self.0 as f64, whole.0 as f64)
+    }
+}
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_bytes(self.0))
+    }
+}
+
+impl From<u64> for Bytes {
+    fn from(n: u64) -> Self {
+        Bytes(n)
+    }
+}
+
+impl From<Bytes> for u64 {
+    fn from(b: Bytes) -> Self {
+        b.0
+    }
+}
+
+impl Add for Bytes {
+    type Output = Bytes;
+    fn add(self, rhs: Bytes) -> Bytes {
+        Bytes(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Bytes {
+    fn add_assign(&mut self, rhs: Bytes) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Bytes {
+    type Output = Bytes;
+    fn sub(self, rhs: Bytes) -> Bytes {
+        Bytes(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Bytes {
+    fn sub_assign(&mut self, rhs: Bytes) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<u64> for Bytes {
+    type Output = Bytes;
+    fn mul(self, rhs: u64) -> Bytes {
+        Bytes(self.0 * rhs)
+    }
+}
+
+impl Div<u64> for Bytes {
+    type Output = Bytes;
+    fn div(self, rhs: u64) -> Bytes {
+        Bytes(self.0 / rhs)
+    }
+}
+
+impl Sum for Bytes {
+    fn sum<I: Iterator<Item = Bytes>>(iter: I) -> Bytes {
+        iter.fold(Bytes::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Bytes> for Bytes {
+    fn sum<I: Iterator<Item = &'a Bytes>>(iter: I) -> Bytes {
+        iter.copied().sum()
+    }
+}
+
+/// A finite dimensionless ratio where `1.0` means "all of it".
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::{Percent, Ratio};
+/// let r = Ratio::of(1.0, 4.0).unwrap();
+/// assert_eq!(r.get(), 0.25);
+/// assert_eq!(Percent::from(r).get(), 25.0);
+/// assert!(Ratio::new(f64::NAN).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Ratio(f64);
+
+impl Ratio {
+    /// Wrap a ratio; `None` if `value` is NaN or infinite.
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then_some(Ratio(value))
+    }
+    /// `part / whole`; `None` if `whole` is zero or the result is not finite.
+    pub fn of(part: f64, whole: f64) -> Option<Self> {
+        if whole == 0.0 {
+            return None;
+        }
+        Self::new(part / whole)
+    }
+    /// The raw ratio.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+    /// Clamp into `0.0..=1.0`.
+    pub fn clamped(self) -> Self {
+        Ratio(self.0.clamp(0.0, 1.0))
+    }
+    /// Add, returning `None` if the result is not finite.
+    pub fn checked_add(self, rhs: Ratio) -> Option<Ratio> {
+        Self::new(self.0 + rhs.0)
+    }
+    /// Subtract, returning `None` if the result is not finite.
+    pub fn checked_sub(self, rhs: Ratio) -> Option<Ratio> {
+        Self::new(self.0 - rhs.0)
+    }
+    /// Multiply (compose two ratios), returning `None` if the result is not finite.
+    pub fn checked_mul(self, rhs: Ratio) -> Option<Ratio> {
+        Self::new(self.0 * rhs.0)
+    }
+}
+
+impl fmt::Display for Ratio {
+    /// Up to three decimals with trailing zeros trimmed; an explicit precision is honored.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_decimal(self.0, f.precision(), 3))
+    }
+}
+
+impl From<Percent> for Ratio {
+    fn from(p: Percent) -> Self {
+        Ratio(p.0 / 100.0)
+    }
+}
+
+/// A finite percentage where `100.0` means "all of it".
+///
+/// Example:
+/// ```rust
+/// use toolchest::types::{Percent, Ratio};
+/// let p = Percent::of(1.0, 3.0).unwrap();
+/// assert_eq!(p.to_string(), "33.3%");
+/// assert_eq!(format!("{p:.2}"), "33.33%");
+/// assert_eq!(Ratio::from(Percent::new(50.0).unwrap()).get(), 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Percent(f64);
+
+impl Percent {
+    /// Wrap a percentage; `None` if `value` is NaN or infinite.
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then_some(Percent(value))
+    }
+    /// `part / whole * 100`; `None` if `whole` is zero or the result is not finite.
+    pub fn of(part: f64, whole: f64) -> Option<Self> {
+        Ratio::of(part, whole).and_then(|r| Self::new(r.0 * 100.0))
+    }
+    /// The raw percentage.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+    /// Clamp into `0.0..=100.0`.
+    pub fn clamped(self) -> Self {
+        Percent(self.0.clamp(0.0, 100.0))
+    }
+    /// Add, returning `None` if the result is not finite.
+    pub fn checked_add(self, rhs: Percent) -> Option<Percent> {
+        Self::new(self.0 + rhs.0)
+    }
+    /// Subtract, returning `None` if the result is not finite.
+    pub fn checked_sub(self, rhs: Percent) -> Option<Percent> {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl fmt::Display for Percent {
+    /// One decimal with a trailing `.0` trimmed, then `%`; an explicit precision is honored.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", format_decimal(self.0, f.precision(), 1))
+    }
+}
+
+impl From<Ratio> for Percent {
+    fn from(r: Ratio) -> Self {
+        Percent(r.0 * 100.0)
+    }
+}
+
+// Explicit precision prints exactly that many places; otherwise up to
+// `default` places with trailing zeros removed.
+fn format_decimal(x: f64, precision: Option<usize>, default: usize) -> String {
+    if let Some(p) = precision {
+        return format_float(x, p, ",");
+    }
+    let s = format_float(x, default, ",");
+    let s = if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &s
+    };
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
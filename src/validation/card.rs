@@ -0,0 +1,103 @@
+//! Payment card brand detection and display masking
+
+use std::fmt;
+
+use crate::strings::extra::mask;
+
+/// Card network identified from a card number's IIN prefix and length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    /// Visa: prefix 4, 13/16/19 digits
+    Visa,
+    /// Mastercard: 51–55 or 2221–2720, 16 digits
+    Mastercard,
+    /// American Express: 34 or 37, 15 digits
+    Amex,
+    /// Discover: 6011, 622126–622925, 644–649, or 65, 16–19 digits
+    Discover,
+}
+
+impl CardBrand {
+    /// Trailing digits left visible by [`mask_card`]
+    pub fn visible_digits(self) -> usize {
+        match self {
+            CardBrand::Amex => 5,
+            _ => 4,
+        }
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard => "Mastercard",
+            CardBrand::Amex => "American Express",
+            CardBrand::Discover => "Discover",
+        })
+    }
+}
+
+fn card_digits(num: &str) -> String {
+    num.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn prefix_in(digits: &str, len: usize, lo: u32, hi: u32) -> bool {
+    digits
+        .get(..len)
+        .and_then(|p| p.parse::<u32>().ok())
+        .is_some_and(|p| (lo..=hi).contains(&p))
+}
+
+/// Detect the card brand from IIN ranges and length rules.
+///
+/// Spaces and dashes are ignored as in
+/// [`validate_credit_card`](super::validate_credit_card); the Luhn checksum is
+/// not checked, so combine the two for full validation.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{card_brand, CardBrand};
+/// assert_eq!(card_brand("4242 4242 4242 4242"), Some(CardBrand::Visa));
+/// assert_eq!(card_brand("3782-822463-10005"), Some(CardBrand::Amex));
+/// assert_eq!(card_brand("2221000000000009"), Some(CardBrand::Mastercard));
+/// assert_eq!(card_brand("4242"), None);
+/// ```
+pub fn card_brand(num: &str) -> Option<CardBrand> {
+    let d = card_digits(num);
+    let len = d.len();
+    if d.starts_with('4') && matches!(len, 13 | 16 | 19) {
+        Some(CardBrand::Visa)
+    } else if (prefix_in(&d, 2, 51, 55) || prefix_in(&d, 4, 2221, 2720)) && len == 16 {
+        Some(CardBrand::Mastercard)
+    } else if (d.starts_with("34") || d.starts_with("37")) && len == 15 {
+        Some(CardBrand::Amex)
+    } else if (d.starts_with("6011")
+        || d.starts_with("65")
+        || prefix_in(&d, 3, 644, 649)
+        || prefix_in(&d, 6, 622126, 622925))
+        && (16..=19).contains(&len)
+    {
+        Some(CardBrand::Discover)
+    } else {
+        None
+    }
+}
+
+/// Mask a card number for display, keeping the brand's usual trailing digits.
+///
+/// Separators are dropped and every digit except the last 4 (5 for
+/// American Express) is replaced with `*` via
+/// [`strings::extra::mask`](crate::strings::extra::mask). Numbers of unknown
+/// brand keep 4 digits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::mask_card;
+/// assert_eq!(mask_card("4242 4242 4242 4242"), "************4242");
+/// assert_eq!(mask_card("3782 822463 10005"), "**********10005");
+/// ```
+pub fn mask_card(num: &str) -> String {
+    let visible = card_brand(num).map_or(4, CardBrand::visible_digits);
+    mask(&card_digits(num), 0, visible, '*')
+}
@@ -0,0 +1,223 @@
+//! Product/book identifier checksums (ISBN, EAN, UPC) and Verhoeff/Damm check digits
+
+use super::error::{check_length, ValidationError};
+
+// Strip the usual separators and require ASCII digits, except an optional
+// trailing `X` when `allow_x` is set (ISBN-10). `X` maps to 10.
+fn digits(s: &str, len: usize, allow_x: bool) -> Result<Vec<u32>, ValidationError> {
+    let cleaned: Vec<char> = s.chars().filter(|c| *c != '-' && *c != ' ').collect();
+    if cleaned.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    let mut out = Vec::with_capacity(cleaned.len());
+    for (position, &ch) in cleaned.iter().enumerate() {
+        match ch.to_digit(10) {
+            Some(d) => out.push(d),
+            None if allow_x && (ch == 'X' || ch == 'x') && position == len - 1 => out.push(10),
+            None => return Err(ValidationError::InvalidCharacter { ch, position }),
+        }
+    }
+    check_length(out.len(), len, len)?;
+    Ok(out)
+}
+
+// GS1 check: from the right, weights alternate 1 (check digit), 3, 1, 3, ...
+fn gtin_checksum_ok(digits: &[u32]) -> Result<(), ValidationError> {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+        .sum();
+    if sum % 10 == 0 {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidChecksum)
+    }
+}
+
+/// Validate an ISBN-10 (hyphens/spaces allowed, final `X` means 10)
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_isbn10;
+/// assert!(validate_isbn10("0-306-40615-2"));
+/// assert!(validate_isbn10("0-8044-2957-X"));
+/// assert!(!validate_isbn10("0-306-40615-3"));
+/// ```
+pub fn validate_isbn10(isbn: &str) -> bool {
+    validate_isbn10_detailed(isbn).is_ok()
+}
+
+/// Validate an ISBN-10, reporting why it failed.
+pub fn validate_isbn10_detailed(isbn: &str) -> Result<(), ValidationError> {
+    let d = digits(isbn, 10, true)?;
+    let sum: u32 = d.iter().enumerate().map(|(i, v)| (10 - i as u32) * v).sum();
+    if sum % 11 == 0 {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidChecksum)
+    }
+}
+
+/// Validate an ISBN-13 (an EAN-13 with a 978/979 prefix)
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_isbn13;
+/// assert!(validate_isbn13("978-0-306-40615-7"));
+/// assert!(!validate_isbn13("4006381333931")); // valid EAN, not a book
+/// ```
+pub fn validate_isbn13(isbn: &str) -> bool {
+    validate_isbn13_detailed(isbn).is_ok()
+}
+
+/// Validate an ISBN-13, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_isbn13_detailed, ValidationError};
+/// assert_eq!(validate_isbn13_detailed("978-0-306-40615-8"), Err(ValidationError::InvalidChecksum));
+/// assert!(matches!(validate_isbn13_detailed("4006381333931"), Err(ValidationError::InvalidFormat(_))));
+/// ```
+pub fn validate_isbn13_detailed(isbn: &str) -> Result<(), ValidationError> {
+    let d = digits(isbn, 13, false)?;
+    if d[..3] != [9, 7, 8] && d[..3] != [9, 7, 9] {
+        return Err(ValidationError::InvalidFormat(
+            "ISBN-13 must start with 978 or 979",
+        ));
+    }
+    gtin_checksum_ok(&d)
+}
+
+/// Validate an EAN-13 barcode number
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_ean13;
+/// assert!(validate_ean13("4006381333931"));
+/// assert!(!validate_ean13("4006381333932"));
+/// ```
+pub fn validate_ean13(ean: &str) -> bool {
+    validate_ean13_detailed(ean).is_ok()
+}
+
+/// Validate an EAN-13, reporting why it failed.
+pub fn validate_ean13_detailed(ean: &str) -> Result<(), ValidationError> {
+    gtin_checksum_ok(&digits(ean, 13, false)?)
+}
+
+/// Validate a 12-digit UPC-A barcode number
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_upc;
+/// assert!(validate_upc("036000291452"));
+/// assert!(!validate_upc("036000291453"));
+/// ```
+pub fn validate_upc(upc: &str) -> bool {
+    validate_upc_detailed(upc).is_ok()
+}
+
+/// Validate a UPC-A, reporting why it failed.
+pub fn validate_upc_detailed(upc: &str) -> Result<(), ValidationError> {
+    gtin_checksum_ok(&digits(upc, 12, false)?)
+}
+
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+const DAMM: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+// Digits only; `None` if empty or anything else is present
+fn plain_digits(s: &str) -> Option<Vec<u8>> {
+    if s.is_empty() {
+        return None;
+    }
+    s.chars().map(|c| c.to_digit(10).map(|d| d as u8)).collect()
+}
+
+fn verhoeff_state(digits: &[u8], offset: usize) -> u8 {
+    digits.iter().rev().enumerate().fold(0, |c, (i, &d)| {
+        VERHOEFF_D[c as usize][VERHOEFF_P[(i + offset) % 8][d as usize] as usize]
+    })
+}
+
+/// Verhoeff check digit to append to a string of ASCII digits
+///
+/// Returns `None` if the input is empty or contains non-digits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{verhoeff_check_digit, validate_verhoeff};
+/// assert_eq!(verhoeff_check_digit("236"), Some('3'));
+/// assert!(validate_verhoeff("2363"));
+/// assert!(!validate_verhoeff("2336")); // adjacent transposition caught
+/// ```
+pub fn verhoeff_check_digit(digits: &str) -> Option<char> {
+    let d = plain_digits(digits)?;
+    let c = VERHOEFF_INV[verhoeff_state(&d, 1) as usize];
+    char::from_digit(c as u32, 10)
+}
+
+/// True if the final digit is a correct Verhoeff check digit
+pub fn validate_verhoeff(digits: &str) -> bool {
+    plain_digits(digits).is_some_and(|d| verhoeff_state(&d, 0) == 0)
+}
+
+fn damm_state(digits: &[u8]) -> u8 {
+    digits.iter().fold(0, |i, &d| DAMM[i as usize][d as usize])
+}
+
+/// Damm check digit to append to a string of ASCII digits
+///
+/// Returns `None` if the input is empty or contains non-digits.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{damm_check_digit, validate_damm};
+/// assert_eq!(damm_check_digit("572"), Some('4'));
+/// assert!(validate_damm("5724"));
+/// assert!(!validate_damm("5742"));
+/// ```
+pub fn damm_check_digit(digits: &str) -> Option<char> {
+    let d = plain_digits(digits)?;
+    char::from_digit(damm_state(&d) as u32, 10)
+}
+
+/// True if the final digit is a correct Damm check digit
+pub fn validate_damm(digits: &str) -> bool {
+    plain_digits(digits).is_some_and(|d| damm_state(&d) == 0)
+}
@@ -0,0 +1,31 @@
+//! Calendar date sanity checks
+
+use crate::time::civil;
+
+/// True for Gregorian leap years (divisible by 4, except centuries not
+/// divisible by 400).
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::is_leap_year;
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    civil::is_leap_year(year as i64)
+}
+
+/// True if `year-month-day` exists in the Gregorian calendar.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::is_valid_date;
+/// assert!(is_valid_date(2024, 2, 29));
+/// assert!(!is_valid_date(2023, 2, 29));
+/// assert!(!is_valid_date(2024, 4, 31));
+/// assert!(!is_valid_date(2024, 13, 1));
+/// ```
+pub fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
+    (1..=12).contains(&month) && day >= 1 && day <= civil::days_in_month(year as i64, month)
+}
@@ -0,0 +1,80 @@
+//! Structured validation failures.
+
+use std::{error::Error, fmt};
+
+/// Why a value failed validation.
+///
+/// Returned by the `validate_*_detailed` functions so callers can tell users
+/// what is wrong instead of just "invalid".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The input was empty (after normalization)
+    Empty,
+    /// The input length is outside the allowed range
+    InvalidLength {
+        /// Minimum accepted length
+        min: usize,
+        /// Maximum accepted length
+        max: usize,
+        /// Length that was found
+        actual: usize,
+    },
+    /// A character is not allowed at the given (char) position
+    InvalidCharacter {
+        /// Offending character
+        ch: char,
+        /// Zero-based character index in the normalized input
+        position: usize,
+    },
+    /// A required prefix is missing
+    MissingPrefix(&'static str),
+    /// The structure is wrong in a way described by the message
+    InvalidFormat(&'static str),
+    /// A country code is malformed or unsupported
+    InvalidCountry(String),
+    /// The check digits do not match
+    InvalidChecksum,
+    /// The value is syntactically valid but reserved or never issued
+    Reserved(&'static str),
+    /// The URL scheme is not in the allowed list
+    UnsupportedScheme(String),
+    /// The host is not a valid DNS name or allowed IP literal
+    InvalidHost(String),
+    /// The port is missing, malformed, or outside the allowed range
+    InvalidPort(String),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Empty => write!(f, "value is empty"),
+            ValidationError::InvalidLength { min, max, actual } if min == max => {
+                write!(f, "expected length {min}, got {actual}")
+            }
+            ValidationError::InvalidLength { min, max, actual } => {
+                write!(f, "expected length {min}..={max}, got {actual}")
+            }
+            ValidationError::InvalidCharacter { ch, position } => {
+                write!(f, "invalid character {ch:?} at position {position}")
+            }
+            ValidationError::MissingPrefix(p) => write!(f, "missing prefix {p:?}"),
+            ValidationError::InvalidFormat(msg) => write!(f, "invalid format: {msg}"),
+            ValidationError::InvalidCountry(c) => write!(f, "invalid country code {c:?}"),
+            ValidationError::InvalidChecksum => write!(f, "checksum mismatch"),
+            ValidationError::Reserved(msg) => write!(f, "reserved value: {msg}"),
+            ValidationError::UnsupportedScheme(s) => write!(f, "unsupported scheme {s:?}"),
+            ValidationError::InvalidHost(h) => write!(f, "invalid host {h:?}"),
+            ValidationError::InvalidPort(p) => write!(f, "invalid port {p:?}"),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+pub(crate) fn check_length(actual: usize, min: usize, max: usize) -> Result<(), ValidationError> {
+    if actual < min || actual > max {
+        Err(ValidationError::InvalidLength { min, max, actual })
+    } else {
+        Ok(())
+    }
+}
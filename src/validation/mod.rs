@@ -1,8 +1,15 @@
 //! Validation helpers.
 //!
 //! Lightweight validators for common formats. Includes a Luhn
-//! implementation for credit card numbers, IBAN/phone/SSN validation, and
-//! simple ASCII/UTF-8 checks.
+//! implementation for credit card numbers with brand detection and masking,
+//! IBAN/phone/SSN validation, ISBN/EAN/UPC checksums, Verhoeff and Damm check
+//! digits, postal codes for common countries, EU VAT numbers, URL validation
+//! with scheme/host/port rules, MAC addresses, host names and `host:port`
+//! pairs, calendar date checks, semantic versions, and simple ASCII/UTF-8
+//! checks.
+//!
+//! Each `validate_*` returning `bool` has a `validate_*_detailed` sibling that
+//! returns `Result<(), ValidationError>` describing what is wrong.
 //!
 //! Examples:
 //! ```rust
@@ -10,12 +17,70 @@
 //! assert!(validate_credit_card("4242424242424242"));
 //! assert!(is_ascii("hello"));
 //! assert!(is_utf8("ok".as_bytes()));
+//!
+//! use toolchest::validation::{validate_phone_detailed, ValidationError};
+//! let err = validate_phone_detailed("2025550123").unwrap_err();
+//! assert_eq!(err, ValidationError::MissingPrefix("+"));
+//! assert_eq!(err.to_string(), "missing prefix \"+\"");
 //! ```
 
+mod card;
+mod checksum;
+mod date;
+mod error;
+mod network;
+mod postal;
+mod semver;
+mod url;
+mod vat;
+
+pub use card::{card_brand, mask_card, CardBrand};
+pub use checksum::{
+    damm_check_digit, validate_damm, validate_ean13, validate_ean13_detailed, validate_isbn10,
+    validate_isbn10_detailed, validate_isbn13, validate_isbn13_detailed, validate_upc,
+    validate_upc_detailed, validate_verhoeff, verhoeff_check_digit,
+};
+pub use date::{is_leap_year, is_valid_date};
+pub use error::ValidationError;
+pub use network::{
+    validate_host_port, validate_host_port_detailed, validate_hostname, validate_hostname_detailed,
+    validate_mac_address, validate_mac_address_detailed, validate_port, validate_port_detailed,
+};
+pub use postal::{validate_postal_code, validate_postal_code_detailed, CountryCode};
+pub use semver::{validate_semver, validate_semver_detailed};
+pub use url::{validate_url, UrlRules};
+pub use vat::{validate_vat, validate_vat_detailed};
+
+use error::check_length;
+
 /// Validate credit card number using Luhn algorithm
 pub fn validate_credit_card(num: &str) -> bool {
-    luhn(num)
+    validate_credit_card_detailed(num).is_ok()
+}
+
+/// Validate a credit card number, reporting why it failed.
+///
+/// Non-digit characters (spaces, dashes) are ignored, as in
+/// [`validate_credit_card`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_credit_card_detailed, ValidationError};
+/// assert!(validate_credit_card_detailed("4242 4242 4242 4242").is_ok());
+/// assert_eq!(validate_credit_card_detailed("4242 4242 4242 4241"), Err(ValidationError::InvalidChecksum));
+/// assert_eq!(validate_credit_card_detailed("--"), Err(ValidationError::Empty));
+/// ```
+pub fn validate_credit_card_detailed(num: &str) -> Result<(), ValidationError> {
+    if !num.chars().any(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::Empty);
+    }
+    if luhn(num) {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidChecksum)
+    }
 }
+
 fn luhn(num: &str) -> bool {
     let digits: Vec<u32> = num.chars().filter_map(|c| c.to_digit(10)).collect();
     if digits.is_empty() {
@@ -41,6 +106,7 @@ fn luhn(num: &str) -> bool {
 ///
 /// Rules implemented:
 /// - Strip spaces, uppercase; must be 15..=34 alphanumeric characters
+/// - Starts with a two-letter country code followed by two check digits
 /// - Move first 4 chars to the end
 /// - Replace letters A..Z with 10..35 and compute the remainder modulo 97
 /// - Valid when remainder equals 1
@@ -52,14 +118,47 @@ fn luhn(num: &str) -> bool {
 /// assert!(!validate_iban("DE89 3704 0044 0532 0130 0"));
 /// ```
 pub fn validate_iban(iban: &str) -> bool {
+    validate_iban_detailed(iban).is_ok()
+}
+
+/// Validate an IBAN, reporting why it failed.
+///
+/// Applies the same rules as [`validate_iban`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_iban_detailed, ValidationError};
+/// assert!(validate_iban_detailed("GB82 WEST 1234 5698 7654 32").is_ok());
+/// assert_eq!(validate_iban_detailed("GB82 TEST 1234 5698 7654 32"), Err(ValidationError::InvalidChecksum));
+/// assert!(matches!(validate_iban_detailed("DE89 3704"), Err(ValidationError::InvalidLength { .. })));
+/// assert!(matches!(validate_iban_detailed("1289 3704 0044 0532 0130 00"), Err(ValidationError::InvalidCountry(_))));
+/// ```
+pub fn validate_iban_detailed(iban: &str) -> Result<(), ValidationError> {
     // Normalize: remove spaces, uppercase
     let mut s: String = iban.chars().filter(|c| !c.is_whitespace()).collect();
     s.make_ascii_uppercase();
-    // Basic length and charset check
-    if s.len() < 15 || s.len() > 34 || !s.chars().all(|c| c.is_ascii_alphanumeric()) {
-        return false;
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    // Basic charset and length check
+    if let Some((position, ch)) = s
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        return Err(ValidationError::InvalidCharacter { ch, position });
     }
-    // Rearrange: move first 4 chars to the end. Safe due to ASCII-only check
+    check_length(s.len(), 15, 34)?;
+    // Country code letters, then two check digits. Safe due to ASCII-only check
+    if !s[..2].chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(ValidationError::InvalidCountry(s[..2].to_string()));
+    }
+    if !s[2..4].chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::InvalidFormat(
+            "check digits must be numeric",
+        ));
+    }
+    // Rearrange: move first 4 chars to the end
     let rearranged = format!("{}{}", &s[4..], &s[..4]);
     // Compute mod-97 incrementally to avoid big integers
     let mut rem: u32 = 0;
@@ -75,7 +174,11 @@ pub fn validate_iban(iban: &str) -> bool {
             rem = (rem * 10 + digit) % 97;
         }
     }
-    rem == 1
+    if rem == 1 {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidChecksum)
+    }
 }
 
 /// Validate a phone number in E.164 format.
@@ -92,24 +195,42 @@ pub fn validate_iban(iban: &str) -> bool {
 /// assert!(!validate_phone("12025550123"));
 /// ```
 pub fn validate_phone(phone: &str) -> bool {
-    let mut chars = phone.chars();
-    match chars.next() {
-        Some('+') => {}
-        _ => return false,
-    }
-    let digits: String = chars.collect();
-    if digits.is_empty() || digits.len() > 15 {
-        return false;
+    validate_phone_detailed(phone).is_ok()
+}
+
+/// Validate an E.164 phone number, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_phone_detailed, ValidationError};
+/// assert!(validate_phone_detailed("+442071838750").is_ok());
+/// assert_eq!(validate_phone_detailed("442071838750"), Err(ValidationError::MissingPrefix("+")));
+/// assert!(matches!(validate_phone_detailed("+1234567890123456"), Err(ValidationError::InvalidLength { max: 15, .. })));
+/// ```
+pub fn validate_phone_detailed(phone: &str) -> Result<(), ValidationError> {
+    if phone.is_empty() {
+        return Err(ValidationError::Empty);
     }
-    let mut iter = digits.chars();
-    match iter.next() {
-        Some(c) if c.is_ascii_digit() && c != '0' => {}
-        _ => return false,
+    let digits = phone
+        .strip_prefix('+')
+        .ok_or(ValidationError::MissingPrefix("+"))?;
+    if let Some((i, ch)) = digits
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_digit())
+    {
+        return Err(ValidationError::InvalidCharacter {
+            ch,
+            position: i + 1,
+        });
     }
-    if !iter.all(|c| c.is_ascii_digit()) {
-        return false;
+    check_length(digits.len(), 1, 15)?;
+    if digits.starts_with('0') {
+        return Err(ValidationError::InvalidFormat(
+            "country code cannot start with 0",
+        ));
     }
-    true
+    Ok(())
 }
 
 /// Validate a US Social Security Number (SSN).
@@ -126,21 +247,37 @@ pub fn validate_phone(phone: &str) -> bool {
 /// assert!(!validate_ssn("000-12-3456"));
 /// ```
 pub fn validate_ssn(ssn: &str) -> bool {
+    validate_ssn_detailed(ssn).is_ok()
+}
+
+/// Validate a US SSN, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_ssn_detailed, ValidationError};
+/// assert!(validate_ssn_detailed("123-45-6789").is_ok());
+/// assert_eq!(validate_ssn_detailed("666-12-3456"), Err(ValidationError::Reserved("area number")));
+/// ```
+pub fn validate_ssn_detailed(ssn: &str) -> Result<(), ValidationError> {
     let digits: String = ssn.chars().filter(|c| c.is_ascii_digit()).collect();
-    if digits.len() != 9 {
-        return false;
+    if digits.is_empty() {
+        return Err(ValidationError::Empty);
     }
+    check_length(digits.len(), 9, 9)?;
     let area: u32 = digits[0..3].parse().unwrap_or(0);
     let group: u32 = digits[3..5].parse().unwrap_or(0);
     let serial: u32 = digits[5..9].parse().unwrap_or(0);
 
     if area == 0 || area == 666 || (900..=999).contains(&area) {
-        return false;
+        return Err(ValidationError::Reserved("area number"));
     }
-    if group == 0 || serial == 0 {
-        return false;
+    if group == 0 {
+        return Err(ValidationError::Reserved("group number"));
+    }
+    if serial == 0 {
+        return Err(ValidationError::Reserved("serial number"));
     }
-    true
+    Ok(())
 }
 /// True if string is ASCII
 pub fn is_ascii(s: &str) -> bool {
@@ -0,0 +1,191 @@
+//! MAC addresses, host names, and ports
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::url::{check_hostname, check_port};
+use super::ValidationError;
+
+/// Validate a MAC-48 address in colon, dash, or Cisco dot notation
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_mac_address;
+/// assert!(validate_mac_address("00:1A:2b:3c:4D:5e"));
+/// assert!(validate_mac_address("00-1a-2b-3c-4d-5e"));
+/// assert!(validate_mac_address("001a.2b3c.4d5e"));
+/// assert!(!validate_mac_address("00:1a-2b:3c:4d:5e"));
+/// ```
+pub fn validate_mac_address(s: &str) -> bool {
+    validate_mac_address_detailed(s).is_ok()
+}
+
+/// Validate a MAC address, reporting why it failed.
+///
+/// Accepts six two-digit groups separated by `:` or `-` (one separator
+/// throughout), or three four-digit groups separated by `.`. Hex digits may
+/// be either case.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_mac_address_detailed, ValidationError};
+/// assert!(validate_mac_address_detailed("aa:bb:cc:dd:ee:ff").is_ok());
+/// assert_eq!(
+///     validate_mac_address_detailed("aa:bb:cc:dd:ee:fg"),
+///     Err(ValidationError::InvalidCharacter { ch: 'g', position: 16 })
+/// );
+/// ```
+pub fn validate_mac_address_detailed(s: &str) -> Result<(), ValidationError> {
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    let (sep, groups, width) = if s.contains('.') {
+        ('.', 3, 4)
+    } else if s.contains('-') {
+        ('-', 6, 2)
+    } else {
+        (':', 6, 2)
+    };
+    if let Some((position, ch)) = s
+        .chars()
+        .enumerate()
+        .find(|&(_, c)| c != sep && !c.is_ascii_hexdigit())
+    {
+        return Err(ValidationError::InvalidCharacter { ch, position });
+    }
+    let parts: Vec<&str> = s.split(sep).collect();
+    if parts.len() != groups || parts.iter().any(|p| p.len() != width) {
+        return Err(ValidationError::InvalidFormat(
+            "expected xx:xx:xx:xx:xx:xx, xx-xx-xx-xx-xx-xx, or xxxx.xxxx.xxxx",
+        ));
+    }
+    Ok(())
+}
+
+/// Validate a DNS host name per RFC 1123
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_hostname;
+/// assert!(validate_hostname("api.example.com"));
+/// assert!(validate_hostname("3com.net"));
+/// assert!(!validate_hostname("-bad.example"));
+/// ```
+pub fn validate_hostname(host: &str) -> bool {
+    validate_hostname_detailed(host).is_ok()
+}
+
+/// Validate a host name, reporting why it failed.
+///
+/// Rules: at most 253 characters; dot-separated labels of 1–63 ASCII letters,
+/// digits, or hyphens, not starting or ending with `-`; the last label is not
+/// all digits; one trailing dot is allowed. The same rules apply to URL hosts
+/// in [`validate_url`](super::validate_url).
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_hostname_detailed, ValidationError};
+/// assert_eq!(validate_hostname_detailed(""), Err(ValidationError::Empty));
+/// assert_eq!(
+///     validate_hostname_detailed("under_score.io"),
+///     Err(ValidationError::InvalidHost("under_score.io".into()))
+/// );
+/// ```
+pub fn validate_hostname_detailed(host: &str) -> Result<(), ValidationError> {
+    if host.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    check_hostname(host)
+}
+
+/// Validate a TCP/UDP port number string (1–65535)
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_port;
+/// assert!(validate_port("8080"));
+/// assert!(!validate_port("0"));
+/// assert!(!validate_port("65536"));
+/// assert!(!validate_port("+80"));
+/// ```
+pub fn validate_port(port: &str) -> bool {
+    validate_port_detailed(port).is_ok()
+}
+
+/// Validate a port number string, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_port_detailed, ValidationError};
+/// assert_eq!(validate_port_detailed("http"), Err(ValidationError::InvalidPort("http".into())));
+/// ```
+pub fn validate_port_detailed(port: &str) -> Result<(), ValidationError> {
+    if port.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    check_port(port).map(|_| ())
+}
+
+/// Validate a `host:port` pair
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_host_port;
+/// assert!(validate_host_port("example.com:8080"));
+/// assert!(validate_host_port("10.0.0.1:22"));
+/// assert!(validate_host_port("[::1]:443"));
+/// assert!(!validate_host_port("example.com"));
+/// ```
+pub fn validate_host_port(s: &str) -> bool {
+    validate_host_port_detailed(s).is_ok()
+}
+
+/// Validate a `host:port` pair, reporting why it failed.
+///
+/// The host may be a DNS name (see [`validate_hostname`]), an IPv4 address,
+/// or a bracketed IPv6 address; the port is required.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_host_port_detailed, ValidationError};
+/// assert_eq!(
+///     validate_host_port_detailed("db.internal:99999"),
+///     Err(ValidationError::InvalidPort("99999".into()))
+/// );
+/// assert_eq!(
+///     validate_host_port_detailed("db.internal"),
+///     Err(ValidationError::InvalidPort("missing".into()))
+/// );
+/// ```
+pub fn validate_host_port_detailed(s: &str) -> Result<(), ValidationError> {
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    let (host, port) = if let Some(bracketed) = s.strip_prefix('[') {
+        let (literal, after) = bracketed
+            .split_once(']')
+            .ok_or_else(|| ValidationError::InvalidHost(s.to_string()))?;
+        if literal.parse::<Ipv6Addr>().is_err() {
+            return Err(ValidationError::InvalidHost(literal.to_string()));
+        }
+        let port = after
+            .strip_prefix(':')
+            .ok_or_else(|| ValidationError::InvalidPort("missing".to_string()))?;
+        (None, port)
+    } else {
+        let (host, port) = s
+            .rsplit_once(':')
+            .ok_or_else(|| ValidationError::InvalidPort("missing".to_string()))?;
+        (Some(host), port)
+    };
+    if let Some(host) = host {
+        if host.contains(':') {
+            return Err(ValidationError::InvalidFormat(
+                "IPv6 hosts must be bracketed, e.g. [::1]:80",
+            ));
+        }
+        if host.parse::<Ipv4Addr>().is_err() {
+            check_hostname(host)?;
+        }
+    }
+    check_port(port).map(|_| ())
+}
@@ -0,0 +1,166 @@
+//! Postal code formats for common countries
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::error::ValidationError;
+
+macro_rules! country_codes {
+    ($($code:ident => $name:literal),+ $(,)?) => {
+        /// ISO 3166-1 alpha-2 country codes supported by [`validate_postal_code`]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum CountryCode {
+            $(
+                #[doc = $name]
+                $code,
+            )+
+        }
+
+        impl CountryCode {
+            /// All supported countries
+            pub const ALL: &'static [CountryCode] = &[$(CountryCode::$code),+];
+
+            /// Two-letter code, e.g. `"DE"`
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(CountryCode::$code => stringify!($code),)+
+                }
+            }
+        }
+    };
+}
+
+country_codes! {
+    AT => "Austria",
+    AU => "Australia",
+    BE => "Belgium",
+    BR => "Brazil",
+    CA => "Canada",
+    CH => "Switzerland",
+    CN => "China",
+    CZ => "Czechia",
+    DE => "Germany",
+    DK => "Denmark",
+    ES => "Spain",
+    FI => "Finland",
+    FR => "France",
+    GB => "United Kingdom",
+    GR => "Greece",
+    HU => "Hungary",
+    IE => "Ireland",
+    IN => "India",
+    IT => "Italy",
+    JP => "Japan",
+    KR => "South Korea",
+    MX => "Mexico",
+    NL => "Netherlands",
+    NO => "Norway",
+    NZ => "New Zealand",
+    PL => "Poland",
+    PT => "Portugal",
+    RO => "Romania",
+    RU => "Russia",
+    SE => "Sweden",
+    SG => "Singapore",
+    US => "United States",
+    ZA => "South Africa",
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = ValidationError;
+
+    /// Parse a two-letter code, case-insensitively
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.trim().to_ascii_uppercase();
+        CountryCode::ALL
+            .iter()
+            .copied()
+            .find(|c| c.as_str() == upper)
+            .ok_or(ValidationError::InvalidCountry(s.to_string()))
+    }
+}
+
+// Pattern alphabet: '9' digit, 'A' letter, '?' letter or digit; anything else literal
+fn patterns(country: CountryCode) -> &'static [&'static str] {
+    use CountryCode::*;
+    match country {
+        US => &["99999", "99999-9999"],
+        CA => &["A9A 9A9", "A9A9A9"],
+        GB => &[
+            "A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA", "A99AA", "A999AA",
+            "AA99AA", "AA999AA", "A9A9AA", "AA9A9AA",
+        ],
+        DE | FR | IT | ES | FI | MX | KR => &["99999"],
+        NL => &["9999 AA", "9999AA"],
+        AT | BE | CH | NO | DK | HU | AU | NZ | ZA => &["9999"],
+        SE | CZ | GR => &["999 99", "99999"],
+        IE => &["A99 ????", "A99????", "A9A ????", "A9A????"],
+        PT => &["9999-999"],
+        PL => &["99-999"],
+        RO | CN | RU | SG => &["999999"],
+        IN => &["999999", "999 999"],
+        JP => &["999-9999", "9999999"],
+        BR => &["99999-999", "99999999"],
+    }
+}
+
+fn matches_pattern(code: &str, pattern: &str) -> bool {
+    code.len() == pattern.len()
+        && code.bytes().zip(pattern.bytes()).all(|(c, p)| match p {
+            b'9' => c.is_ascii_digit(),
+            b'A' => c.is_ascii_uppercase(),
+            b'?' => c.is_ascii_uppercase() || c.is_ascii_digit(),
+            lit => c == lit,
+        })
+}
+
+/// Validate a postal code against the country's format
+///
+/// Checks the shape only (e.g. `99999-9999` for US ZIP+4), not whether the
+/// code is actually assigned. Input is trimmed and compared case-insensitively.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_postal_code, CountryCode};
+/// assert!(validate_postal_code("94105-1234", CountryCode::US));
+/// assert!(validate_postal_code("sw1a 1aa", CountryCode::GB));
+/// assert!(!validate_postal_code("1234", CountryCode::DE));
+/// ```
+pub fn validate_postal_code(code: &str, country: CountryCode) -> bool {
+    validate_postal_code_detailed(code, country).is_ok()
+}
+
+/// Validate a postal code, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_postal_code_detailed, CountryCode, ValidationError};
+/// assert!(validate_postal_code_detailed("1012 AB", CountryCode::NL).is_ok());
+/// assert_eq!(validate_postal_code_detailed("  ", CountryCode::NL), Err(ValidationError::Empty));
+/// assert!(matches!(validate_postal_code_detailed("ABCDE", CountryCode::FR), Err(ValidationError::InvalidFormat(_))));
+/// ```
+pub fn validate_postal_code_detailed(
+    code: &str,
+    country: CountryCode,
+) -> Result<(), ValidationError> {
+    let code = code.trim().to_ascii_uppercase();
+    if code.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    if code == "GIR 0AA" && country == CountryCode::GB {
+        return Ok(());
+    }
+    if patterns(country).iter().any(|p| matches_pattern(&code, p)) {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidFormat(
+            "postal code does not match the country format",
+        ))
+    }
+}
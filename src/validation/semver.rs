@@ -0,0 +1,30 @@
+//! Semantic version strings
+
+use super::error::ValidationError;
+use crate::types::SemVer;
+
+/// Validate a SemVer 2.0.0 version string such as `1.4.0-rc.1+build.7`.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_semver;
+/// assert!(validate_semver("1.0.0-alpha.1"));
+/// assert!(!validate_semver("v1.0"));
+/// ```
+pub fn validate_semver(s: &str) -> bool {
+    validate_semver_detailed(s).is_ok()
+}
+
+/// [`validate_semver`] with a reason on failure; see [`SemVer::parse`].
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_semver_detailed, ValidationError};
+/// assert_eq!(
+///     validate_semver_detailed("1.2"),
+///     Err(ValidationError::InvalidFormat("expected MAJOR.MINOR.PATCH"))
+/// );
+/// ```
+pub fn validate_semver_detailed(s: &str) -> Result<(), ValidationError> {
+    SemVer::parse(s).map(|_| ())
+}
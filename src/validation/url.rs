@@ -0,0 +1,170 @@
+//! URL validation with configurable scheme, host, and port rules.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::RangeInclusive;
+
+use super::ValidationError;
+
+/// Rules applied by [`validate_url`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlRules {
+    /// Accepted schemes, compared case-insensitively; empty accepts any
+    /// syntactically valid scheme (default `["http", "https"]`)
+    pub schemes: Vec<String>,
+    /// Accept IPv4 and bracketed IPv6 literals as hosts (default `true`)
+    pub allow_ip: bool,
+    /// Require an explicit `:port` (default `false`)
+    pub require_port: bool,
+    /// Accepted explicit ports (default `None`, any of 1..=65535)
+    pub port_range: Option<RangeInclusive<u16>>,
+}
+
+impl Default for UrlRules {
+    fn default() -> Self {
+        Self {
+            schemes: vec!["http".to_string(), "https".to_string()],
+            allow_ip: true,
+            require_port: false,
+            port_range: None,
+        }
+    }
+}
+
+/// Validate a DNS host name: 1..=253 characters, dot-separated labels of
+/// 1..=63 ASCII letters, digits, or hyphens not starting or ending with `-`.
+/// A single trailing dot (fully qualified form) is allowed.
+pub(crate) fn check_hostname(host: &str) -> Result<(), ValidationError> {
+    let name = host.strip_suffix('.').unwrap_or(host);
+    if name.is_empty() {
+        return Err(ValidationError::InvalidHost(host.to_string()));
+    }
+    let labels_ok = name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        });
+    // An all-numeric last label means a malformed IPv4 address, not a name
+    let numeric_tld = name
+        .rsplit('.')
+        .next()
+        .is_some_and(|tld| tld.bytes().all(|b| b.is_ascii_digit()));
+    if labels_ok && !numeric_tld {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidHost(host.to_string()))
+    }
+}
+
+/// Parse a decimal port in 1..=65535 (digits only, no sign).
+pub(crate) fn check_port(p: &str) -> Result<u16, ValidationError> {
+    p.parse::<u16>()
+        .ok()
+        .filter(|&v| v != 0 && p.bytes().all(|b| b.is_ascii_digit()))
+        .ok_or_else(|| ValidationError::InvalidPort(p.to_string()))
+}
+
+/// Validate a URL against `rules`, reporting the first problem found.
+///
+/// Checks, in order: no whitespace or control characters, a `scheme://`
+/// prefix with an allowed scheme, a host that is a valid DNS name or (when
+/// allowed) an IP literal, and an optional port within range. Userinfo
+/// (`user@`) is skipped; path, query, and fragment are not inspected.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_url, UrlRules, ValidationError};
+/// let rules = UrlRules::default();
+/// assert!(validate_url("https://example.com:8443/a?b#c", &rules).is_ok());
+/// assert!(validate_url("http://[::1]/", &rules).is_ok());
+/// assert_eq!(
+///     validate_url("ftp://example.com", &rules),
+///     Err(ValidationError::UnsupportedScheme("ftp".into()))
+/// );
+/// assert_eq!(
+///     validate_url("https://bad_host.com", &rules),
+///     Err(ValidationError::InvalidHost("bad_host.com".into()))
+/// );
+/// ```
+pub fn validate_url(s: &str, rules: &UrlRules) -> Result<(), ValidationError> {
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    if let Some((position, ch)) = s
+        .chars()
+        .enumerate()
+        .find(|(_, c)| c.is_whitespace() || c.is_control())
+    {
+        return Err(ValidationError::InvalidCharacter { ch, position });
+    }
+
+    let (scheme, rest) = s.split_once("://").ok_or(ValidationError::InvalidFormat(
+        "missing \"://\" after scheme",
+    ))?;
+    let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !scheme_ok {
+        return Err(ValidationError::InvalidFormat("malformed scheme"));
+    }
+    if !rules.schemes.is_empty() && !rules.schemes.iter().any(|a| a.eq_ignore_ascii_case(scheme)) {
+        return Err(ValidationError::UnsupportedScheme(scheme.to_string()));
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    if host_port.is_empty() {
+        return Err(ValidationError::InvalidFormat("missing host"));
+    }
+
+    let port = if let Some(bracketed) = host_port.strip_prefix('[') {
+        let (literal, after) = bracketed
+            .split_once(']')
+            .ok_or_else(|| ValidationError::InvalidHost(host_port.to_string()))?;
+        if literal.parse::<Ipv6Addr>().is_err() {
+            return Err(ValidationError::InvalidHost(literal.to_string()));
+        }
+        if !rules.allow_ip {
+            return Err(ValidationError::InvalidHost(literal.to_string()));
+        }
+        match after {
+            "" => None,
+            p => Some(
+                p.strip_prefix(':')
+                    .ok_or_else(|| ValidationError::InvalidHost(host_port.to_string()))?,
+            ),
+        }
+    } else {
+        let (host, port) = match host_port.rsplit_once(':') {
+            Some((h, p)) => (h, Some(p)),
+            None => (host_port, None),
+        };
+        if host.parse::<Ipv4Addr>().is_ok() {
+            if !rules.allow_ip {
+                return Err(ValidationError::InvalidHost(host.to_string()));
+            }
+        } else {
+            check_hostname(host)?;
+        }
+        port
+    };
+
+    match port {
+        None if rules.require_port => Err(ValidationError::InvalidPort("missing".to_string())),
+        None => Ok(()),
+        Some(p) => {
+            let value = check_port(p)?;
+            match &rules.port_range {
+                Some(range) if !range.contains(&value) => {
+                    Err(ValidationError::InvalidPort(p.to_string()))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+}
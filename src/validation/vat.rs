@@ -0,0 +1,381 @@
+//! EU VAT identification numbers
+
+use super::error::ValidationError;
+
+fn digits(s: &str) -> Option<Vec<u32>> {
+    s.chars().map(|c| c.to_digit(10)).collect()
+}
+
+fn weighted(d: &[u32], weights: &[u32]) -> u32 {
+    d.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+fn luhn_ok(d: &[u32]) -> bool {
+    let sum: u32 = d
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &v)| {
+            if i % 2 == 1 {
+                (v * 2) / 10 + (v * 2) % 10
+            } else {
+                v
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+// ISO 7064 MOD 11,10 (DE, HR)
+fn mod11_10_ok(d: &[u32]) -> bool {
+    let (body, check) = d.split_at(d.len() - 1);
+    let mut product = 10;
+    for &v in body {
+        let mut sum = (v + product) % 10;
+        if sum == 0 {
+            sum = 10;
+        }
+        product = (2 * sum) % 11;
+    }
+    (11 - product) % 10 == check[0]
+}
+
+fn to_u64(d: &[u32]) -> u64 {
+    d.iter().fold(0, |acc, &v| acc * 10 + v as u64)
+}
+
+fn all_digits(body: &str, lens: &[usize]) -> Result<Vec<u32>, ValidationError> {
+    if !lens.contains(&body.len()) {
+        return Err(ValidationError::InvalidFormat(
+            "wrong number of digits for country",
+        ));
+    }
+    digits(body).ok_or(ValidationError::InvalidFormat("expected digits only"))
+}
+
+// DNI/NIE control letter
+const ES_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+fn check_es(body: &str) -> Result<bool, ValidationError> {
+    let b = body.as_bytes();
+    if b.len() != 9 {
+        return Err(ValidationError::InvalidFormat(
+            "wrong number of digits for country",
+        ));
+    }
+    let last = b[8];
+    let middle = digits(&body[1..8]).ok_or(ValidationError::InvalidFormat(
+        "expected digits in positions 2-8",
+    ))?;
+    match b[0] {
+        b'0'..=b'9' | b'X' | b'Y' | b'Z' => {
+            // Natural persons (DNI) and foreigners (NIE: X/Y/Z map to 0/1/2)
+            let lead = match b[0] {
+                b'X' => 0,
+                b'Y' => 1,
+                b'Z' => 2,
+                d => (d - b'0') as u64,
+            };
+            let n = lead * 10_000_000 + to_u64(&middle);
+            Ok(ES_LETTERS[(n % 23) as usize] == last)
+        }
+        b'K' | b'L' | b'M' => Ok(ES_LETTERS[(to_u64(&middle) % 23) as usize] == last),
+        b'A'..=b'W' => {
+            // Legal entities (CIF)
+            let sum: u32 = middle
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| {
+                    if i % 2 == 0 {
+                        (v * 2) / 10 + (v * 2) % 10
+                    } else {
+                        v
+                    }
+                })
+                .sum();
+            let control = (10 - sum % 10) % 10;
+            Ok(last == b'0' + control as u8 || last == b"JABCDEFGHI"[control as usize])
+        }
+        _ => Err(ValidationError::InvalidFormat(
+            "unexpected leading character",
+        )),
+    }
+}
+
+fn check_ie(body: &str) -> Result<bool, ValidationError> {
+    let b = body.as_bytes();
+    if !(8..=9).contains(&b.len()) {
+        return Err(ValidationError::InvalidFormat(
+            "wrong number of characters for country",
+        ));
+    }
+    let d =
+        digits(&body[..7]).ok_or(ValidationError::InvalidFormat("expected 7 leading digits"))?;
+    let letters = &b[7..];
+    if !letters.iter().all(u8::is_ascii_uppercase) {
+        return Err(ValidationError::InvalidFormat("expected trailing letters"));
+    }
+    let mut sum = weighted(&d, &[8, 7, 6, 5, 4, 3, 2]);
+    if let Some(&extra) = letters.get(1) {
+        // 2013 format: the second letter (A=1 .. I=9, W=0) carries weight 9
+        sum += 9 * if extra == b'W' {
+            0
+        } else {
+            (extra - b'A' + 1) as u32
+        };
+    }
+    Ok(b"WABCDEFGHIJKLMNOPQRSTUV"[(sum % 23) as usize] == letters[0])
+}
+
+fn check_nl(body: &str) -> Result<bool, ValidationError> {
+    let b = body.as_bytes();
+    if b.len() != 12 || b[9] != b'B' {
+        return Err(ValidationError::InvalidFormat(
+            "expected 9 digits, 'B', and 2 digits",
+        ));
+    }
+    let d = digits(&body[..9]).ok_or(ValidationError::InvalidFormat(
+        "expected 9 digits, 'B', and 2 digits",
+    ))?;
+    digits(&body[10..]).ok_or(ValidationError::InvalidFormat(
+        "expected 9 digits, 'B', and 2 digits",
+    ))?;
+    // Legacy RSIN eleven-test, or the 2020 MOD 97-10 scheme over "NL" + number
+    let sum = weighted(&d[..8], &[9, 8, 7, 6, 5, 4, 3, 2]);
+    if sum % 11 == d[8] {
+        return Ok(true);
+    }
+    let mut rem = 0u32;
+    for ch in format!("NL{body}").chars() {
+        let v = ch.to_digit(36).unwrap_or(0);
+        rem = if v >= 10 {
+            (rem * 100 + v) % 97
+        } else {
+            (rem * 10 + v) % 97
+        };
+    }
+    Ok(rem == 1)
+}
+
+fn check(country: &str, body: &str) -> Result<bool, ValidationError> {
+    Ok(match country {
+        "AT" => {
+            let rest = body
+                .strip_prefix('U')
+                .ok_or(ValidationError::InvalidFormat(
+                    "Austrian VAT numbers start with 'U'",
+                ))?;
+            let d = all_digits(rest, &[8])?;
+            let sum: u32 = d[..7]
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| {
+                    if i % 2 == 1 {
+                        v * 2 / 10 + v * 2 % 10
+                    } else {
+                        v
+                    }
+                })
+                .sum();
+            (10 - (sum + 4) % 10) % 10 == d[7]
+        }
+        "BE" => {
+            let d = all_digits(body, &[9, 10])?;
+            let n = to_u64(&d);
+            97 - (n / 100) % 97 == n % 100
+        }
+        "BG" => {
+            let d = all_digits(body, &[9, 10])?;
+            if d.len() == 10 {
+                // Personal/foreigner numbers use several schemes; format only
+                return Ok(true);
+            }
+            let mut r = weighted(&d, &[1, 2, 3, 4, 5, 6, 7, 8]) % 11;
+            if r == 10 {
+                r = weighted(&d, &[3, 4, 5, 6, 7, 8, 9, 10]) % 11 % 10;
+            }
+            r == d[8]
+        }
+        "CY" => {
+            let b = body.as_bytes();
+            if b.len() != 9 || !b[8].is_ascii_uppercase() {
+                return Err(ValidationError::InvalidFormat(
+                    "expected 8 digits and a letter",
+                ));
+            }
+            let d = all_digits(&body[..8], &[8])?;
+            const ODD: [u32; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+            let sum: u32 = d
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| if i % 2 == 0 { ODD[v as usize] } else { v })
+                .sum();
+            b[8] == b'A' + (sum % 26) as u8
+        }
+        "CZ" => {
+            let d = all_digits(body, &[8, 9, 10])?;
+            if d.len() != 8 {
+                // Individuals use birth-number based formats; format only
+                return Ok(true);
+            }
+            let sum = weighted(&d, &[8, 7, 6, 5, 4, 3, 2]);
+            let c = (11 - sum % 11) % 11;
+            (if c == 0 { 1 } else { c % 10 }) == d[7]
+        }
+        "DE" => {
+            let d = all_digits(body, &[9])?;
+            d[0] != 0 && mod11_10_ok(&d)
+        }
+        "DK" => {
+            let d = all_digits(body, &[8])?;
+            weighted(&d, &[2, 7, 6, 5, 4, 3, 2, 1]) % 11 == 0
+        }
+        "EE" => {
+            let d = all_digits(body, &[9])?;
+            (10 - weighted(&d, &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10 == d[8]
+        }
+        "EL" => {
+            let d = all_digits(body, &[9])?;
+            weighted(&d, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10 == d[8]
+        }
+        "ES" => check_es(body)?,
+        "FI" => {
+            let d = all_digits(body, &[8])?;
+            let r = weighted(&d, &[7, 9, 10, 5, 8, 4, 2]) % 11;
+            r != 1 && (11 - r) % 11 == d[7]
+        }
+        "FR" => {
+            let d = all_digits(body, &[11])?;
+            let siren = to_u64(&d[2..]);
+            (12 + 3 * (siren % 97)) % 97 == to_u64(&d[..2])
+        }
+        "HR" => mod11_10_ok(&all_digits(body, &[11])?),
+        "HU" => {
+            let d = all_digits(body, &[8])?;
+            (10 - weighted(&d, &[9, 7, 3, 1, 9, 7, 3]) % 10) % 10 == d[7]
+        }
+        "IE" => check_ie(body)?,
+        "IT" => {
+            let d = all_digits(body, &[11])?;
+            luhn_ok(&d)
+        }
+        "LT" => {
+            let d = all_digits(body, &[9, 12])?;
+            let n = d.len() - 1;
+            let mut r = d[..n]
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (1 + i as u32 % 9) * v)
+                .sum::<u32>()
+                % 11;
+            if r == 10 {
+                r = d[..n]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (1 + (i as u32 + 2) % 9) * v)
+                    .sum::<u32>()
+                    % 11;
+            }
+            r % 10 == d[n]
+        }
+        "LU" => {
+            let d = all_digits(body, &[8])?;
+            to_u64(&d[..6]) % 89 == to_u64(&d[6..])
+        }
+        "LV" => {
+            let d = all_digits(body, &[11])?;
+            if d[0] <= 3 {
+                // Natural persons: date-based personal code; format only
+                return Ok(true);
+            }
+            weighted(&d, &[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1]) % 11 == 3
+        }
+        "MT" => {
+            let d = all_digits(body, &[8])?;
+            weighted(&d, &[3, 4, 6, 7, 8, 9, 10, 1]) % 37 == 0
+        }
+        "NL" => check_nl(body)?,
+        "PL" => {
+            let d = all_digits(body, &[10])?;
+            weighted(&d, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9]
+        }
+        "PT" => {
+            let d = all_digits(body, &[9])?;
+            let c = 11 - weighted(&d, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+            (if c > 9 { 0 } else { c }) == d[8]
+        }
+        "RO" => {
+            let d = all_digits(body, &[2, 3, 4, 5, 6, 7, 8, 9, 10])?;
+            let mut padded = vec![0; 10 - d.len()];
+            padded.extend_from_slice(&d);
+            weighted(&padded, &[7, 5, 3, 2, 1, 7, 5, 3, 2]) * 10 % 11 % 10 == padded[9]
+        }
+        "SE" => {
+            let d = all_digits(body, &[12])?;
+            body.ends_with("01") && luhn_ok(&d[..10])
+        }
+        "SI" => {
+            let d = all_digits(body, &[8])?;
+            let c = 11 - weighted(&d, &[8, 7, 6, 5, 4, 3, 2]) % 11;
+            c != 11 && c % 10 == d[7] && d[0] != 0
+        }
+        "SK" => {
+            let d = all_digits(body, &[10])?;
+            to_u64(&d) % 11 == 0
+        }
+        other => return Err(ValidationError::InvalidCountry(other.to_string())),
+    })
+}
+
+/// Validate an EU VAT identification number, including its country prefix
+///
+/// Covers all EU member states (Greece uses the `EL` prefix). Spaces, dots and
+/// hyphens are ignored. National checksum rules are applied where they are
+/// defined for all numbers of that country; a few formats for individuals (BG,
+/// CZ, LV) are checked for shape only.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::validate_vat;
+/// assert!(validate_vat("DE 136 695 976"));
+/// assert!(validate_vat("FR40303265045"));
+/// assert!(!validate_vat("DE136695977"));
+/// ```
+pub fn validate_vat(number: &str) -> bool {
+    validate_vat_detailed(number).is_ok()
+}
+
+/// Validate an EU VAT number, reporting why it failed.
+///
+/// Example:
+/// ```rust
+/// use toolchest::validation::{validate_vat_detailed, ValidationError};
+/// assert!(validate_vat_detailed("NL004495445B01").is_ok());
+/// assert_eq!(validate_vat_detailed("US123456789"), Err(ValidationError::InvalidCountry("US".into())));
+/// assert_eq!(validate_vat_detailed("PL8567346216"), Err(ValidationError::InvalidChecksum));
+/// ```
+pub fn validate_vat_detailed(number: &str) -> Result<(), ValidationError> {
+    let s: String = number
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '.' && *c != '-')
+        .collect::<String>()
+        .to_ascii_uppercase();
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    if let Some((position, ch)) = s
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        return Err(ValidationError::InvalidCharacter { ch, position });
+    }
+    if s.len() < 4 || !s[..2].bytes().all(|b| b.is_ascii_uppercase()) {
+        return Err(ValidationError::MissingPrefix("country code"));
+    }
+    if check(&s[..2], &s[2..])? {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidChecksum)
+    }
+}
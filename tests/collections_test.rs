@@ -39,3 +39,531 @@ fn test_zip_unzip_index() {
     assert_eq!(index_of(&[1, 2, 3], &2), Some(1));
     assert_eq!(last_index_of(&[1, 2, 3, 2], &2), Some(3));
 }
+
+#[test]
+fn test_multiset_ops_and_counter() {
+    assert_eq!(multiset_difference(&[1, 1, 2], &[1]), vec![1, 2]);
+    assert_eq!(multiset_difference(&[1, 2], &[1, 1, 1]), vec![2]);
+    assert_eq!(multiset_intersection(&[1, 1, 2, 2], &[2, 1, 3]), vec![1, 2]);
+    assert_eq!(multiset_union(&[1, 1], &[1, 2, 2]), vec![1, 1, 2, 2]);
+
+    let mut a: Counter<&str> = ["x", "x", "y"].into_iter().collect();
+    let b: Counter<&str> = ["x", "z"].into_iter().collect();
+    assert_eq!(a.total(), 3);
+    assert_eq!((a.clone() + b.clone()).get(&"x"), 3);
+    let diff = a.clone() - b.clone();
+    assert_eq!(diff.get(&"x"), 1);
+    assert_eq!(diff.get(&"z"), 0);
+    assert_eq!((a.clone() & b.clone()).len(), 1);
+    assert_eq!((a.clone() | b).get(&"z"), 1);
+    assert_eq!(a.remove_n(&"x", 5), 2);
+    assert_eq!(a.most_common(5), vec![(&"y", 1)]);
+}
+
+#[test]
+fn test_sort_by_keys_and_desc() {
+    use toolchest::collections::{sort_by_keys, sort_desc_by_key, SortKey};
+    let mut people = vec![
+        ("bob", 30, 2),
+        ("amy", 25, 1),
+        ("cat", 30, 1),
+        ("dan", 25, 1),
+    ];
+    sort_by_keys(
+        &mut people,
+        &[
+            SortKey::desc(|p: &(&str, i32, i32)| p.1),
+            SortKey::asc(|p: &(&str, i32, i32)| p.2),
+        ],
+    );
+    assert_eq!(
+        people.iter().map(|p| p.0).collect::<Vec<_>>(),
+        vec!["cat", "bob", "amy", "dan"]
+    );
+    let mut none: Vec<i32> = vec![3, 1, 2];
+    sort_by_keys(&mut none, &[]);
+    assert_eq!(none, vec![3, 1, 2]);
+    let mut v = vec![1, 5, 3, 5];
+    sort_desc_by_key(&mut v, |x| *x);
+    assert_eq!(v, vec![5, 5, 3, 1]);
+}
+
+#[test]
+fn test_uniq_by_and_dedup_consecutive() {
+    use toolchest::collections::{dedup_consecutive_by_key, uniq_by};
+    #[derive(Clone, Debug, PartialEq)]
+    struct Row {
+        id: u32,
+        score: f64,
+    }
+    let rows = vec![
+        Row { id: 2, score: 1.0 },
+        Row { id: 1, score: 2.0 },
+        Row { id: 2, score: 3.0 },
+    ];
+    let ids: Vec<u32> = uniq_by(&rows, |r| r.id).iter().map(|r| r.id).collect();
+    assert_eq!(ids, vec![2, 1]);
+    assert!(uniq_by(&[] as &[Row], |r| r.id).is_empty());
+
+    let mut words = vec!["apple", "avocado", "banana", "blueberry", "apricot"];
+    dedup_consecutive_by_key(&mut words, |w| w.chars().next());
+    assert_eq!(words, vec!["apple", "banana", "apricot"]);
+}
+
+#[test]
+fn test_bloom_filter() {
+    use toolchest::collections::BloomFilter;
+    let mut bf = BloomFilter::new(1000, 0.01);
+    assert!(bf.is_empty());
+    assert!(bf.num_bits() >= 9000 && bf.num_hashes() == 7);
+    for i in 0..1000 {
+        bf.insert(&format!("key-{i}"));
+    }
+    assert_eq!(bf.len(), 1000);
+    assert!((0..1000).all(|i| bf.contains(&format!("key-{i}"))));
+    let false_positives = (0..10_000)
+        .filter(|i| bf.contains(&format!("other-{i}")))
+        .count();
+    assert!(false_positives < 300, "{false_positives}");
+    assert!(bf.estimated_fp_rate() < 0.03);
+
+    let mut other = BloomFilter::new(1000, 0.01);
+    other.insert("extra");
+    assert!(bf.union(&other));
+    assert!(bf.contains("extra"));
+    assert!(!bf.union(&BloomFilter::new(10, 0.5)));
+    bf.clear();
+    assert!(!bf.contains("extra"));
+}
+
+#[test]
+fn test_hyperloglog() {
+    use toolchest::collections::HyperLogLog;
+    let mut a = HyperLogLog::new(12);
+    assert_eq!(a.count(), 0);
+    for i in 0..20_000u32 {
+        a.add(i.to_le_bytes());
+        a.add(i.to_le_bytes()); // duplicates do not count
+    }
+    let est = a.count() as f64;
+    assert!((est - 20_000.0).abs() < 20_000.0 * 0.05, "{est}");
+
+    let mut small = HyperLogLog::new(12);
+    for w in ["a", "b", "c", "a"] {
+        small.add(w);
+    }
+    assert_eq!(small.count(), 3);
+
+    let mut b = HyperLogLog::new(12);
+    for i in 10_000..30_000u32 {
+        b.add(i.to_le_bytes());
+    }
+    assert!(a.merge(&b));
+    let merged = a.count() as f64;
+    assert!((merged - 30_000.0).abs() < 30_000.0 * 0.05, "{merged}");
+    assert!(!a.merge(&HyperLogLog::new(8)));
+    assert_eq!(HyperLogLog::new(30).precision(), 16);
+}
+
+#[test]
+fn test_zip_longest_and_zip3() {
+    use toolchest::collections::{unzip3, zip3, zip_longest};
+    assert_eq!(
+        zip_longest(&["x"], &[1, 2], "?", 0),
+        vec![("x", 1), ("?", 2)]
+    );
+    assert!(zip_longest::<i32, i32>(&[], &[], 0, 0).is_empty());
+    let z = zip3(&[1, 2, 3], &["a", "b", "c"], &[1.5, 2.5]);
+    assert_eq!(z.len(), 2);
+    let (a, b, c) = unzip3(&z);
+    assert_eq!(a, vec![1, 2]);
+    assert_eq!(b, vec!["a", "b"]);
+    assert_eq!(c, vec![1.5, 2.5]);
+}
+
+#[test]
+fn test_sorted_insert_and_merge() {
+    use toolchest::collections::{insert_sorted, lower_bound, merge_sorted, upper_bound};
+    let v = [1, 3, 3, 3, 7];
+    assert_eq!(lower_bound(&v, &3), 1);
+    assert_eq!(upper_bound(&v, &3), 4);
+    assert_eq!(lower_bound(&v, &8), 5);
+    assert_eq!(upper_bound::<i32>(&[], &1), 0);
+
+    let mut w = Vec::new();
+    for x in [5, 1, 4, 1, 9] {
+        insert_sorted(&mut w, x);
+    }
+    assert_eq!(w, vec![1, 1, 4, 5, 9]);
+
+    assert_eq!(merge_sorted(&[1, 2, 2], &[0, 2, 3]), vec![0, 1, 2, 2, 2, 3]);
+    assert_eq!(merge_sorted(&[] as &[i32], &[2, 3]), vec![2, 3]);
+}
+
+#[test]
+fn test_try_key_by_and_index_by() {
+    use toolchest::collections::{index_by, try_key_by, DuplicateKeyError};
+    let rows = [("u1", 10), ("u2", 20), ("u3", 30), ("u2", 40)];
+    let err = try_key_by(&rows, |r| r.0).unwrap_err();
+    assert_eq!(
+        err,
+        DuplicateKeyError {
+            key: "u2",
+            first_index: 1,
+            duplicate_index: 3
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "duplicate key \"u2\" at index 3 (first seen at index 1)"
+    );
+    let ok = try_key_by(&rows[..3], |r| r.0).unwrap();
+    assert_eq!(ok.len(), 3);
+    assert_eq!(ok["u3"].1, 30);
+
+    let idx = index_by(&rows, |r| r.0);
+    assert_eq!(
+        idx["u2"].iter().map(|r| r.1).collect::<Vec<_>>(),
+        vec![20, 40]
+    );
+    assert_eq!(idx["u1"].len(), 1);
+}
+
+#[test]
+fn test_arg_min_max_and_min_max() {
+    let scores = [("ann", 7), ("bob", 3), ("cy", 9), ("dee", 3), ("eve", 9)];
+    assert_eq!(arg_min(&scores, |s| s.1), Some(1));
+    assert_eq!(arg_max(&scores, |s| s.1), Some(2));
+    assert_eq!(arg_max(&[] as &[i32], |x| *x), None);
+    assert_eq!(
+        min_max(&["pear", "apple", "zucchini"]),
+        Some((&"apple", &"zucchini"))
+    );
+    assert_eq!(min_max(&[5]), Some((&5, &5)));
+
+    assert_eq!(min_max_f64(&[], NanPolicy::Ignore), None);
+    assert_eq!(min_max_f64(&[f64::NAN], NanPolicy::Ignore), None);
+    assert_eq!(
+        min_max_f64(&[0.5, f64::NEG_INFINITY, 3.0], NanPolicy::Propagate),
+        Some((f64::NEG_INFINITY, 3.0))
+    );
+    assert!(min_max_f64(&[1.0, f64::NAN], NanPolicy::Propagate)
+        .unwrap()
+        .0
+        .is_nan());
+}
+
+#[test]
+fn test_interleave_and_round_robin() {
+    use toolchest::collections::{interleave, round_robin};
+    assert_eq!(
+        interleave(&[1, 2], &[10, 20, 30, 40]),
+        vec![1, 10, 2, 20, 30, 40]
+    );
+    assert_eq!(interleave::<i32>(&[], &[]), Vec::<i32>::new());
+    assert_eq!(interleave(&["a"], &[]), vec!["a"]);
+
+    let empty: [i32; 0] = [];
+    let a = [1, 2, 3];
+    let b = [10];
+    assert_eq!(
+        round_robin(&[&a[..], &empty[..], &b[..]]),
+        vec![1, 10, 2, 3]
+    );
+    assert!(round_robin::<i32>(&[]).is_empty());
+}
+
+#[test]
+fn test_seeded_shuffle_and_sample() {
+    use toolchest::collections::{sample_seeded, shuffle_seeded};
+    let orig: Vec<u32> = (0..50).collect();
+    let mut a = orig.clone();
+    let mut b = orig.clone();
+    let mut c = orig.clone();
+    shuffle_seeded(&mut a, 1);
+    shuffle_seeded(&mut b, 1);
+    shuffle_seeded(&mut c, 2);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(a, orig);
+    let mut sorted = a.clone();
+    sorted.sort();
+    assert_eq!(sorted, orig);
+
+    let mut empty: [u8; 0] = [];
+    shuffle_seeded(&mut empty, 3);
+    assert_eq!(sample_seeded(&[9], 123), Some(&9));
+    let picks: std::collections::HashSet<_> = (0..200)
+        .filter_map(|s| sample_seeded(&orig[..5], s))
+        .collect();
+    assert_eq!(picks.len(), 5);
+}
+
+#[test]
+fn test_top_k_and_frequency() {
+    use toolchest::collections::{least_common, most_common, top_k_by};
+    let v = [5, 1, 9, 3, 9, 7];
+    assert_eq!(top_k_by(&v, 3, |x| *x), vec![&9, &9, &7]);
+    assert_eq!(top_k_by(&v, 10, |x| *x).len(), 6);
+    assert!(top_k_by(&v, 0, |x| *x).is_empty());
+    // Stable among equal keys
+    let pairs = [("a", 1), ("b", 2), ("c", 2), ("d", 1)];
+    let top: Vec<_> = top_k_by(&pairs, 3, |p| p.1).iter().map(|p| p.0).collect();
+    assert_eq!(top, ["b", "c", "a"]);
+
+    let words = ["x", "y", "z", "y", "z", "w", "z"];
+    assert_eq!(
+        most_common(&words, 10),
+        vec![(&"z", 3), (&"y", 2), (&"x", 1), (&"w", 1)]
+    );
+    assert_eq!(
+        least_common(&words, 3),
+        vec![(&"x", 1), (&"w", 1), (&"y", 2)]
+    );
+    assert!(most_common::<i32>(&[], 3).is_empty());
+    assert!(least_common(&words, 0).is_empty());
+}
+
+#[test]
+fn test_slice_diff() {
+    use toolchest::collections::{diff, SliceDiffOp};
+
+    // Replaying the script on `a` must give `b`
+    fn apply<T: Clone>(a: &[T], ops: &[SliceDiffOp<T>]) -> Vec<T> {
+        let mut out = Vec::new();
+        for op in ops {
+            match op {
+                SliceDiffOp::Equal { a_start, items, .. } => {
+                    out.extend_from_slice(&a[*a_start..a_start + items.len()])
+                }
+                SliceDiffOp::Insert { items, .. } => out.extend_from_slice(items),
+                SliceDiffOp::Delete { .. } => {}
+            }
+        }
+        out
+    }
+
+    let cases: [(&str, &str); 7] = [
+        ("", ""),
+        ("abc", ""),
+        ("", "abc"),
+        ("abcdef", "abcdef"),
+        ("ABCABBA", "CBABAC"),
+        ("kitten", "sitting"),
+        ("the quick fox", "the slow fox"),
+    ];
+    for (a, b) in cases {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let ops = diff(&a, &b);
+        assert_eq!(apply(&a, &ops), b);
+        assert!(ops.iter().all(|op| !op.is_empty()));
+        // Runs alternate kinds
+        for w in ops.windows(2) {
+            assert_ne!(std::mem::discriminant(&w[0]), std::mem::discriminant(&w[1]));
+        }
+    }
+
+    // LCS of ABCABBA / CBABAC has length 4
+    let a: Vec<char> = "ABCABBA".chars().collect();
+    let b: Vec<char> = "CBABAC".chars().collect();
+    let equal: usize = diff(&a, &b)
+        .iter()
+        .filter(|o| o.is_equal())
+        .map(|o| o.len())
+        .sum();
+    assert_eq!(equal, 4);
+
+    assert_eq!(
+        diff(&[1, 2, 3], &[1, 4, 5, 3]),
+        vec![
+            SliceDiffOp::Equal {
+                a_start: 0,
+                b_start: 0,
+                items: vec![1]
+            },
+            SliceDiffOp::Delete {
+                a_start: 1,
+                b_start: 1,
+                items: vec![2]
+            },
+            SliceDiffOp::Insert {
+                a_start: 2,
+                b_start: 1,
+                items: vec![4, 5]
+            },
+            SliceDiffOp::Equal {
+                a_start: 2,
+                b_start: 3,
+                items: vec![3]
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_fixed_size_chunking() {
+    assert_eq!(
+        chunk_padded(&["a", "b", "c"], 2, "-"),
+        vec![vec!["a", "b"], vec!["c", "-"]]
+    );
+    assert_eq!(
+        chunk_padded(&[1, 2, 3, 4], 2, 0),
+        vec![vec![1, 2], vec![3, 4]]
+    );
+    assert!(chunk_padded(&[1, 2], 0, 0).is_empty());
+    assert!(chunk_padded::<i32>(&[], 3, 0).is_empty());
+    assert_eq!(
+        chunk_exact(&[1, 2, 3, 4, 5, 6, 7], 3),
+        vec![vec![1, 2, 3], vec![4, 5, 6]]
+    );
+    assert!(chunk_exact(&[1, 2], 3).is_empty());
+    assert!(chunk_exact(&[1, 2], 0).is_empty());
+
+    let v = [1, 2, 3];
+    assert_eq!(split_at_checked(&v, 0), Some((&[][..], &v[..])));
+    assert_eq!(split_at_checked(&v, 3), Some((&v[..], &[][..])));
+    assert_eq!(split_at_checked(&v, 4), None);
+}
+
+#[test]
+fn test_owned_grouping_and_partition_map() {
+    #[derive(Debug, PartialEq)]
+    struct Job {
+        queue: &'static str,
+        id: u32,
+    }
+    let jobs = vec![
+        Job { queue: "a", id: 1 },
+        Job { queue: "b", id: 2 },
+        Job { queue: "a", id: 3 },
+    ];
+    let by_queue = group_by_owned(jobs, |j| j.queue);
+    assert_eq!(
+        by_queue["a"].iter().map(|j| j.id).collect::<Vec<_>>(),
+        [1, 3]
+    );
+    assert_eq!(by_queue["b"], [Job { queue: "b", id: 2 }]);
+    assert!(group_by_owned(Vec::<u8>::new(), |x| *x).is_empty());
+
+    let (big, small) = partition_owned(vec![String::from("long"), "x".into(), "yy".into()], |s| {
+        s.len() > 1
+    });
+    assert_eq!(big, ["long", "yy"]);
+    assert_eq!(small, ["x"]);
+
+    let results: Vec<Result<u8, String>> = vec![Ok(1), Err("bad".into()), Ok(2)];
+    let (oks, errs) = partition_map(&results, |r| match r {
+        Ok(v) => Either::Left(*v),
+        Err(e) => Either::Right(e.len()),
+    });
+    assert_eq!(oks, [1, 2]);
+    assert_eq!(errs, [3]);
+}
+
+#[test]
+fn test_permutations_and_combinations() {
+    let perms: Vec<Vec<i32>> = permutations(&[1, 2, 3], 3).collect();
+    assert_eq!(
+        perms,
+        [
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1]
+        ]
+    );
+    assert_eq!(permutations(&[1, 2, 3, 4, 5], 2).count(), 20);
+    assert_eq!(permutations(&[1, 2], 3).count(), 0);
+    assert_eq!(
+        permutations(&[1, 2], 0).collect::<Vec<_>>(),
+        [Vec::<i32>::new()]
+    );
+    assert_eq!(permutations::<u8>(&[], 0).count(), 1);
+    let mut uniq: Vec<Vec<u8>> = permutations(&[0, 1, 2, 3], 3).collect();
+    uniq.sort();
+    uniq.dedup();
+    assert_eq!(uniq.len(), 24);
+
+    let combos: Vec<Vec<&str>> = combinations(&["a", "b", "c"], 2).collect();
+    assert_eq!(combos, [["a", "b"], ["a", "c"], ["b", "c"]]);
+    assert_eq!(combinations(&[1, 2, 3], 3).collect::<Vec<_>>(), [[1, 2, 3]]);
+    assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+    assert_eq!(combinations(&[1, 2, 3], usize::MAX).count(), 0);
+    assert_eq!(
+        combinations(&[1, 2], 0).collect::<Vec<_>>(),
+        [Vec::<i32>::new()]
+    );
+    assert_eq!(combinations(&(0..20).collect::<Vec<_>>(), 5).count(), 15504);
+
+    // Lazy: taking a prefix of a huge sequence is cheap
+    let first = permutations(&(0..20).collect::<Vec<_>>(), 20)
+        .nth(1)
+        .unwrap();
+    assert_eq!(&first[17..], [17, 19, 18]);
+}
+
+#[test]
+fn test_default_hash_map() {
+    let mut groups: DefaultHashMap<char, Vec<&str>> = DefaultHashMap::new();
+    for w in ["apple", "bob", "avocado"] {
+        groups[&w.chars().next().unwrap()].push(w);
+    }
+    assert_eq!(groups[&'a'], ["apple", "avocado"]);
+    assert!(groups[&'z'].is_empty());
+    assert!(!groups.contains_key(&'z'));
+    assert_eq!(groups.len(), 2);
+
+    let mut counts: DefaultHashMap<String, usize> = DefaultHashMap::new();
+    counts["x"] += 2;
+    *counts.get_or_insert("y".to_string()) += 1;
+    assert_eq!(*counts.get("x"), 2);
+    assert_eq!(counts.remove("y"), Some(1));
+    let map = counts.into_map();
+    assert_eq!(map.len(), 1);
+    assert_eq!(DefaultHashMap::from(map.clone()).into_map(), map);
+}
+
+#[test]
+fn test_sparse_vec() {
+    let mut v: SparseVec<i32> = [(5, 50), (1, 10), (3, 30)].into_iter().collect();
+    assert_eq!(v.len(), 6);
+    assert_eq!(v.nnz(), 3);
+    assert_eq!(v.iter().collect::<Vec<_>>(), [(1, &10), (3, &30), (5, &50)]);
+    assert_eq!(v.insert(3, 33), Some(30));
+    assert_eq!(v.get(2), None);
+    *v.get_mut(1).unwrap() += 1;
+    assert_eq!(v.to_dense(), [0, 11, 0, 33, 0, 50]);
+    assert_eq!(v.remove(5), Some(50));
+    assert_eq!(v.len(), 6);
+    assert_eq!(v.iter_dense().filter(Option::is_some).count(), 2);
+
+    let w = SparseVec::<u8>::with_len(4);
+    assert_eq!(w.to_dense(), [0; 4]);
+    assert!(SparseVec::<u8>::new().is_empty());
+    assert_eq!(SparseVec::from_dense(&v.to_dense()), v);
+}
+
+#[test]
+fn test_window_map_and_pairwise() {
+    let data = [1, 2, 3, 4, 5, 6, 7];
+    let maxes = window_map(&data, 3, 2, |w| *w.iter().max().unwrap());
+    assert_eq!(maxes, vec![3, 5, 7]);
+    assert_eq!(window_map(&data, 8, 1, |w| w.len()), Vec::<usize>::new());
+    assert_eq!(window_map(&[1, 2, 3], 1, usize::MAX, |w| w[0]), vec![1]);
+    assert_eq!(sliding_window(&[1, 2, 3], 2, usize::MAX), vec![vec![1, 2]]);
+    assert!(window_map(&data, 0, 1, |w| w.len()).is_empty());
+    assert!(window_map(&data, 2, 0, |w| w.len()).is_empty());
+    assert_eq!(window_map(&data, 7, 5, |w| w.len()), vec![7]);
+    // sliding_window is the cloning special case
+    let windows = sliding_window(&data, 3, 2);
+    assert_eq!(windows, window_map(&data, 3, 2, |w| w.to_vec()));
+
+    let words = ["a".to_string(), "b".to_string(), "c".to_string()];
+    let pairs = pairwise(&words);
+    assert_eq!(pairs.len(), 2);
+    assert_eq!((pairs[1].0.as_str(), pairs[1].1.as_str()), ("b", "c"));
+    assert!(pairwise::<u8>(&[]).is_empty());
+}
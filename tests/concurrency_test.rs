@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use toolchest::concurrency::*;
+
+#[test]
+fn test_par_map_preserves_order() {
+    let input: Vec<u32> = (0..1000).collect();
+    for threads in [0, 1, 3, 8, 2000] {
+        let out = par_map(&input, threads, |x| x * 2);
+        assert_eq!(out, input.iter().map(|x| x * 2).collect::<Vec<_>>());
+    }
+    assert!(par_map(&[] as &[u32], 4, |x| *x).is_empty());
+}
+
+#[test]
+fn test_par_for_each_visits_all() {
+    let hits = AtomicUsize::new(0);
+    par_for_each(&[1usize; 100], 4, |x| {
+        hits.fetch_add(*x, Ordering::SeqCst);
+    });
+    assert_eq!(hits.load(Ordering::SeqCst), 100);
+}
+
+#[test]
+fn test_par_map_propagates_panic() {
+    let res = std::panic::catch_unwind(|| {
+        par_map(&[1, 2, 3, 4], 2, |x| {
+            if *x == 3 {
+                panic!("bad item");
+            }
+            *x
+        })
+    });
+    let payload = res.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"bad item"));
+}
+
+#[test]
+fn test_par_map_chunked_order_and_balance() {
+    let v: Vec<usize> = (0..1003).collect();
+    for (chunk, threads) in [(1, 4), (7, 3), (500, 8), (0, 2), (2000, 4), (10, 1)] {
+        let out = par_map_chunked(&v, chunk, threads, |x| x * 3);
+        assert_eq!(out, v.iter().map(|x| x * 3).collect::<Vec<_>>());
+    }
+    assert!(par_map_chunked(&[] as &[u8], 4, 4, |x| *x).is_empty());
+
+    // Every element is processed exactly once
+    let calls = AtomicUsize::new(0);
+    par_map_chunked(&v, 16, 0, |_| calls.fetch_add(1, Ordering::Relaxed));
+    assert_eq!(calls.load(Ordering::Relaxed), v.len());
+
+    let lens = toolchest::collections::map_chunked_parallel(&["x", "yy"], 1, 2, |s| s.len());
+    assert_eq!(lens, [1, 2]);
+}
+
+#[test]
+fn test_par_map_chunked_propagates_panic() {
+    let res = std::panic::catch_unwind(|| {
+        par_map_chunked(&[1, 2, 3, 4], 1, 2, |x| {
+            if *x == 3 {
+                panic!("bad element");
+            }
+            *x
+        })
+    });
+    let payload = res.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"bad element"));
+}
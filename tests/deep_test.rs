@@ -36,3 +36,466 @@ fn test_deep_merge_hashmaps() {
     assert_eq!(merged.get("a"), Some(&2));
     assert_eq!(merged.get("b"), Some(&3));
 }
+
+#[test]
+fn test_deep_merge_leaves_option_vec() {
+    let mut a: HashMap<&str, Option<String>> = HashMap::new();
+    a.insert("name", Some("svc".into()));
+    a.insert("region", None);
+    let mut b: HashMap<&str, Option<String>> = HashMap::new();
+    b.insert("name", None);
+    b.insert("region", Some("eu".into()));
+    let merged = merge(&a, &b);
+    assert_eq!(merged["name"].as_deref(), Some("svc"));
+    assert_eq!(merged["region"].as_deref(), Some("eu"));
+
+    assert_eq!(merge(&vec![1, 2, 3], &vec![9]), vec![9]);
+    let mut v = vec![1.5, 2.5];
+    merge_vec(&mut v, &[0.5, 1.0, 4.0], VecMergeStrategy::MergeByIndex);
+    assert_eq!(v, vec![0.5, 1.0, 4.0]);
+    let mut v = vec![true];
+    merge_vec(&mut v, &[false], VecMergeStrategy::Append);
+    assert_eq!(v, vec![true, false]);
+
+    let nested = vec![Some(vec![1u8])];
+    assert_eq!(deep_clone(&nested), nested);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_deep_merge_json_value() {
+    use serde_json::json;
+    let left = json!({"server": {"host": "a", "port": 80}, "tags": [1, 2]});
+    let right = json!({"server": {"port": 8080, "host": null}, "tags": [3]});
+    let merged = merge(&left, &right);
+    assert_eq!(
+        merged,
+        json!({"server": {"host": "a", "port": 8080}, "tags": [3]})
+    );
+    assert_eq!(deep_clone(&merged), merged);
+}
+
+struct Limits {
+    max: u32,
+    min: u32,
+}
+
+struct Settings {
+    retries: u32,
+    limits: Limits,
+    backup: Limits,
+}
+
+toolchest::impl_path_access!(Limits: u32 { max, min });
+toolchest::impl_path_access!(Settings: u32 { retries; nested: limits, backup });
+
+#[test]
+fn test_impl_path_access_struct() {
+    use toolchest::deep::{get, has, set};
+    let mut s = Settings {
+        retries: 1,
+        limits: Limits { max: 10, min: 2 },
+        backup: Limits { max: 5, min: 0 },
+    };
+    assert_eq!(get(&s, "retries"), Some(&1));
+    assert_eq!(get(&s, "limits.max"), Some(&10));
+    assert_eq!(get(&s, "backup.min"), Some(&0));
+    assert_eq!(get(&s, "limits"), None);
+    assert_eq!(get(&s, "limits.max.deeper"), None);
+    assert!(!has(&s, "unknown.max"));
+    assert!(set(&mut s, "backup.max", 7));
+    assert!(set(&mut s, "retries", 4));
+    assert!(!set(&mut s, "limits.avg", 1));
+    assert_eq!((s.retries, s.backup.max), (4, 7));
+}
+
+#[test]
+fn test_merge_with_strategies() {
+    use std::collections::HashMap;
+    use toolchest::deep::{merge_with, MergeOptions, MergeStrategy, Resolution, VecMergeStrategy};
+
+    type Tree = HashMap<String, HashMap<String, Vec<i32>>>;
+    let mk = |k: &str, inner: &[(&str, Vec<i32>)]| -> Tree {
+        HashMap::from([(
+            k.to_string(),
+            inner
+                .iter()
+                .map(|(a, b)| (a.to_string(), b.clone()))
+                .collect(),
+        )])
+    };
+    let left = mk("db", &[("ports", vec![1, 2]), ("only_left", vec![9])]);
+    let right = mk("db", &[("ports", vec![3]), ("only_right", vec![7])]);
+
+    let default = merge_with(&left, &right, &MergeOptions::new());
+    assert_eq!(default["db"]["ports"], vec![3]);
+    assert_eq!(default["db"]["only_left"], vec![9]);
+    assert_eq!(default["db"]["only_right"], vec![7]);
+
+    let append = merge_with(
+        &left,
+        &right,
+        &MergeOptions::new().with_vec_strategy(VecMergeStrategy::Append),
+    );
+    assert_eq!(append["db"]["ports"], vec![1, 2, 3]);
+
+    let by_index = merge_with(
+        &left,
+        &right,
+        &MergeOptions::new()
+            .with_vec_strategy(VecMergeStrategy::MergeByIndex)
+            .with_strategy(MergeStrategy::PreferLeft),
+    );
+    assert_eq!(by_index["db"]["ports"], vec![1, 2]);
+    assert_eq!(by_index["db"]["only_right"], vec![7]);
+
+    // Shallow: the "db" maps are replaced as a whole
+    let shallow = merge_with(&left, &right, &MergeOptions::new().with_max_depth(1));
+    assert!(!shallow["db"].contains_key("only_left"));
+
+    let paths = std::cell::RefCell::new(Vec::new());
+    let opts = MergeOptions::new().on_conflict(|path, _, _| {
+        paths.borrow_mut().push(path.to_string());
+        if path == "db.ports" {
+            Resolution::KeepLeft
+        } else {
+            Resolution::Merge
+        }
+    });
+    let custom = merge_with(&left, &right, &opts);
+    assert_eq!(custom["db"]["ports"], vec![1, 2]);
+    drop(opts);
+    assert_eq!(paths.into_inner(), vec!["", "db", "db.ports"]);
+
+    let wrong_type = MergeOptions::new().on_conflict(|_, _, _| Resolution::Replace(Box::new("x")));
+    assert_eq!(merge_with(&1u8, &2u8, &wrong_type), 2);
+    let take = MergeOptions::new()
+        .with_strategy(MergeStrategy::PreferLeft)
+        .on_conflict(|_, _, _| Resolution::TakeRight);
+    assert_eq!(merge_with(&Some(1), &Some(2), &take), Some(2));
+    assert_eq!(merge_with(&Some(1), &None, &take), Some(1));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_merge_with_json() {
+    use serde_json::json;
+    use toolchest::deep::{merge_with, MergeOptions, MergeStrategy, VecMergeStrategy};
+    let left = json!({"a": {"x": 1, "tags": ["p"]}, "b": 1});
+    let right = json!({"a": {"x": 2, "y": 3, "tags": ["q"]}, "b": null});
+    let opts = MergeOptions::new()
+        .with_strategy(MergeStrategy::PreferLeft)
+        .with_vec_strategy(VecMergeStrategy::Append);
+    assert_eq!(
+        merge_with(&left, &right, &opts),
+        json!({"a": {"x": 1, "y": 3, "tags": ["p", "q"]}, "b": 1})
+    );
+}
+
+#[test]
+fn test_path_matches_globs() {
+    assert!(path_matches("server.port", "server.port"));
+    assert!(!path_matches("server.port", "server"));
+    assert!(!path_matches("server.port", "server.port.x"));
+    assert!(path_matches("*.port", "db.port"));
+    assert!(!path_matches("*.port", "port"));
+    assert!(path_matches("**", ""));
+    assert!(path_matches("a.**.z", "a.z"));
+    assert!(path_matches("a.**.z", "a.b.c.z"));
+    assert!(!path_matches("a.**.z", "a.b.c"));
+    assert!(path_matches("log*.lev?l", "logging.level"));
+    assert!(path_matches("*_url", "db_url"));
+    assert!(!path_matches("*_url", "db_uri"));
+}
+
+#[test]
+fn test_merge_only_and_except() {
+    let map = |pairs: &[(&str, i32)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), *v))
+            .collect::<HashMap<_, _>>()
+    };
+    let left = HashMap::from([
+        ("server".to_string(), map(&[("port", 80), ("workers", 4)])),
+        ("limits".to_string(), map(&[("cpu", 1)])),
+    ]);
+    let right = HashMap::from([
+        (
+            "server".to_string(),
+            map(&[("port", 8080), ("workers", 16), ("backlog", 9)]),
+        ),
+        ("limits".to_string(), map(&[("cpu", 2), ("mem", 512)])),
+        ("logging".to_string(), map(&[("level", 3), ("color", 1)])),
+    ]);
+
+    let only = merge_only(&left, &right, &["server.port", "logging.level"]);
+    assert_eq!(only["server"], map(&[("port", 8080), ("workers", 4)]));
+    assert_eq!(only["limits"], map(&[("cpu", 1)]));
+    // Right-only sections are copied with just the selected keys
+    assert_eq!(only["logging"], map(&[("level", 3)]));
+
+    let nothing = merge_only(&left, &right, &["server.host", "metrics.*"]);
+    assert_eq!(nothing, left);
+
+    let except = merge_except(&left, &right, &["server.*", "logging"]);
+    assert_eq!(except["server"], left["server"]);
+    assert_eq!(except["limits"], map(&[("cpu", 2), ("mem", 512)]));
+    assert!(!except.contains_key("logging"));
+
+    // Selection composes with strategies, and conflict callbacks only see selected paths
+    let seen = std::cell::RefCell::new(Vec::new());
+    let opts = MergeOptions::new()
+        .with_only(&["**.workers", "limits"])
+        .with_strategy(MergeStrategy::PreferLeft)
+        .on_conflict(|path, _, _| {
+            seen.borrow_mut().push(path.to_string());
+            Resolution::Merge
+        });
+    let merged = merge_with(&left, &right, &opts);
+    assert_eq!(merged["server"], left["server"]);
+    assert_eq!(merged["limits"], map(&[("cpu", 1), ("mem", 512)]));
+    drop(opts);
+    let mut seen = seen.into_inner();
+    seen.sort();
+    assert_eq!(seen, ["limits", "limits.cpu", "server.workers"]);
+
+    // Vectors honor selections only when merged index by index
+    let left = HashMap::from([("hosts".to_string(), vec![1, 2])]);
+    let right = HashMap::from([("hosts".to_string(), vec![7, 8, 9])]);
+    let by_index = MergeOptions::new()
+        .with_vec_strategy(VecMergeStrategy::MergeByIndex)
+        .with_except(&["hosts.1"]);
+    assert_eq!(merge_with(&left, &right, &by_index)["hosts"], [7, 2, 9]);
+    let replace = MergeOptions::new().with_except(&["hosts.1"]);
+    assert_eq!(merge_with(&left, &right, &replace)["hosts"], [1, 2]);
+    assert_eq!(merge_except(&Some(1), &Some(2), &["**"]), Some(1));
+    assert_eq!(merge_only(&Some(1), &Some(2), &["**"]), Some(2));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_merge_only_json() {
+    use serde_json::json;
+    let base = json!({"server": {"host": "localhost", "port": 80}, "debug": false});
+    let layer = json!({
+        "server": {"host": "0.0.0.0", "port": 8080},
+        "logging": {"level": "debug", "file": {"path": "/tmp/app.log"}},
+        "debug": true
+    });
+    assert_eq!(
+        merge_only(&base, &layer, &["server.port", "logging.*"]),
+        json!({
+            "server": {"host": "localhost", "port": 8080},
+            "logging": {"level": "debug", "file": {"path": "/tmp/app.log"}},
+            "debug": false
+        })
+    );
+    assert_eq!(
+        merge_except(&base, &layer, &["server.host", "logging.file"]),
+        json!({
+            "server": {"host": "localhost", "port": 8080},
+            "logging": {"level": "debug"},
+            "debug": true
+        })
+    );
+}
+
+#[test]
+fn test_flatten_and_unflatten_keys() {
+    let flat = HashMap::from([
+        ("APP__NAME".to_string(), "demo"),
+        ("APP__HOSTS__0".to_string(), "a"),
+        ("APP__HOSTS__1".to_string(), "b"),
+        ("APP__SPARSE__0".to_string(), "x"),
+        ("APP__SPARSE__2".to_string(), "y"),
+    ]);
+    let nested = unflatten_keys_with(flat.clone(), "__");
+    let Nested::Map(app) = &nested["APP"] else {
+        panic!("APP should be a map");
+    };
+    assert_eq!(
+        app["HOSTS"],
+        Nested::List(vec![Nested::Leaf("a"), Nested::Leaf("b")])
+    );
+    // non-contiguous indices stay a map
+    assert!(matches!(&app["SPARSE"], Nested::Map(m) if m.len() == 2));
+    assert_eq!(flatten_keys_with(&nested, "__"), flat);
+
+    // deeper keys win over a conflicting leaf
+    let nested = unflatten_keys(HashMap::from([
+        ("a".to_string(), 1),
+        ("a.b".to_string(), 2),
+    ]));
+    assert_eq!(
+        nested["a"],
+        Nested::Map(HashMap::from([("b".to_string(), Nested::Leaf(2))]))
+    );
+    assert!(flatten_keys::<i32>(&HashMap::from([(
+        "empty".to_string(),
+        Nested::List(vec![])
+    )]))
+    .is_empty());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_flatten_json() {
+    use serde_json::json;
+    let v = json!({"a": {"b": [1, {"c": null}], "d": []}, "e": "x"});
+    let flat = flatten_json(&v);
+    assert_eq!(flat.len(), 4);
+    assert_eq!(flat["a.b.1.c"], json!(null));
+    assert_eq!(flat["a.d"], json!([]));
+    assert_eq!(unflatten_json(flat), v);
+    assert_eq!(unflatten_json(flatten_json(&json!(5))), json!(5));
+    let flat = flatten_json_with(&v, "/");
+    assert!(flat.contains_key("a/b/0"));
+    assert_eq!(unflatten_json_with(flat, "/"), v);
+}
+
+#[test]
+fn test_path_unset_and_paths() {
+    use toolchest::deep::{get, paths, unset};
+    use toolchest::impl_path_access;
+
+    struct Inner {
+        a: i32,
+        b: i32,
+    }
+    struct Outer {
+        top: i32,
+        inner: Inner,
+    }
+    impl_path_access!(Inner: i32 { a, b });
+    impl_path_access!(Outer: i32 { top; nested: inner });
+
+    let mut o = Outer {
+        top: 1,
+        inner: Inner { a: 2, b: 3 },
+    };
+    assert_eq!(paths(&o), ["top", "inner.a", "inner.b"]);
+    assert_eq!(unset(&mut o, "top"), None);
+    assert_eq!(unset(&mut o, "inner.a"), None);
+    assert_eq!(get(&o, "inner.b"), Some(&3));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_unset_paths_pick_omit() {
+    use serde_json::json;
+    use toolchest::deep::{omit, paths, pick, unset};
+
+    let mut v = json!({"a": {"b": 1, "c": [10, 20, 30]}, "d": {}, "e": null});
+    assert_eq!(paths(&v), ["a.b", "a.c.0", "a.c.1", "a.c.2", "d", "e"]);
+    assert_eq!(paths(&json!(5)), [""]);
+
+    assert_eq!(unset(&mut v, "a.c.1"), Some(json!(20)));
+    assert_eq!(unset(&mut v, "a.c.5"), None);
+    assert_eq!(unset(&mut v, "a.b.x"), None);
+    assert_eq!(unset(&mut v, "d"), Some(json!({})));
+    assert_eq!(unset(&mut v, "missing.path"), None);
+    assert_eq!(v, json!({"a": {"b": 1, "c": [10, 30]}, "e": null}));
+
+    let v = json!({"a": {"b": 1, "c": [10, 20, 30]}, "d": 4});
+    assert_eq!(pick(&v, &["a"]), json!({"a": {"b": 1, "c": [10, 20, 30]}}));
+    assert_eq!(pick(&v, &["a.c.1", "d"]), json!({"a": {"c": [20]}, "d": 4}));
+    assert_eq!(pick(&v, &["nope", "d.x"]), json!({}));
+    assert_eq!(omit(&v, &["a.c", "d"]), json!({"a": {"b": 1}}));
+    assert_eq!(
+        omit(&v, &["a.c.0", "a.c.2", "zzz"]),
+        json!({"a": {"b": 1, "c": [20]}, "d": 4})
+    );
+    assert_eq!(omit(&v, &[]), v);
+}
+
+#[test]
+fn test_fingerprint() {
+    use std::collections::{BTreeMap, HashSet};
+
+    let mut a: HashMap<String, Vec<Option<u32>>> = HashMap::new();
+    let mut b = HashMap::new();
+    for i in 0..50u32 {
+        a.insert(format!("k{i}"), vec![Some(i), None]);
+    }
+    for i in (0..50u32).rev() {
+        b.insert(format!("k{i}"), vec![Some(i), None]);
+    }
+    assert_eq!(fingerprint(&a), fingerprint(&b));
+    let ordered: BTreeMap<_, _> = a.clone().into_iter().collect();
+    assert_eq!(fingerprint(&a), fingerprint(&ordered));
+    b.insert("k0".into(), vec![None, Some(0)]);
+    assert_ne!(fingerprint(&a), fingerprint(&b));
+
+    // Structure matters, not just the flattened content
+    assert_ne!(fingerprint(&vec!["ab"]), fingerprint(&vec!["a", "b"]));
+    assert_ne!(fingerprint(&(1, 2)), fingerprint(&(2, 1)));
+    assert_ne!(fingerprint(&Some(0)), fingerprint(&None::<i32>));
+    assert_eq!(fingerprint(&0.0f64), fingerprint(&-0.0f64));
+    assert_eq!(fingerprint(&7u8), fingerprint(&7i64));
+    assert_eq!(fingerprint("x"), fingerprint(&String::from("x")));
+    let s1: HashSet<_> = [3, 1, 2].into_iter().collect();
+    let s2: HashSet<_> = [2, 3, 1].into_iter().collect();
+    assert_eq!(fingerprint(&s1), fingerprint(&s2));
+    assert_ne!(fingerprint(&s1), fingerprint(&vec![1, 2, 3]));
+
+    // Stable across runs and platforms
+    assert_eq!(fingerprint(&("config", 1u16)), 809836935884427424);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_fingerprint_json() {
+    use serde_json::json;
+    let a = json!({"name": "svc", "ports": [80, 443], "tls": {"on": true}});
+    let b = json!({"tls": {"on": true}, "ports": [80, 443], "name": "svc"});
+    assert_eq!(fingerprint(&a), fingerprint(&b));
+    assert_ne!(fingerprint(&a), fingerprint(&json!({"name": "svc"})));
+    let native = std::collections::BTreeMap::from([("x", 1i64)]);
+    assert_eq!(fingerprint(&json!({"x": 1})), fingerprint(&native));
+}
+
+#[test]
+fn test_from_vars_and_to_env_lines_round_trip() {
+    let vars = [
+        ("APP__SERVER__PORT", "8080"),
+        ("APP__SERVER__HOST", "0.0.0.0"),
+        ("APP__HOSTS__0", "a"),
+        ("APP__HOSTS__1", "b"),
+        ("APP__GREETING", "hello world"),
+        ("APP____BROKEN", "x"),
+        ("APPLICATION__X", "y"),
+        ("PATH", "/usr/bin"),
+    ];
+    let config = from_vars(vars, "APP", "__");
+    assert_eq!(config.len(), 3);
+    let Nested::Map(server) = &config["server"] else {
+        panic!("server should be a map");
+    };
+    assert_eq!(server["port"], Nested::Leaf("8080".to_string()));
+    assert!(matches!(&config["hosts"], Nested::List(items) if items.len() == 2));
+
+    let lines = to_env_lines(&config, "APP");
+    assert_eq!(
+        lines,
+        [
+            "APP__GREETING=\"hello world\"",
+            "APP__HOSTS__0=a",
+            "APP__HOSTS__1=b",
+            "APP__SERVER__HOST=0.0.0.0",
+            "APP__SERVER__PORT=8080",
+        ]
+    );
+
+    let defaults = from_vars(
+        [("APP__SERVER__PORT", "80"), ("APP__DEBUG", "false")],
+        "APP",
+        "__",
+    );
+    let layered = merge(&defaults, &config);
+    assert_eq!(layered["debug"], Nested::Leaf("false".to_string()));
+    let Nested::Map(server) = &layered["server"] else {
+        panic!("server should be a map");
+    };
+    assert_eq!(server["port"], Nested::Leaf("8080".to_string()));
+}
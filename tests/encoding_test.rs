@@ -9,3 +9,275 @@ fn test_hex_rot13_caesar() {
     assert_eq!(rot13("uryyb"), "hello");
     assert_eq!(caesar_cipher("abc", 3), "def");
 }
+
+#[test]
+fn test_varint_zigzag_roundtrip() {
+    for v in [0u64, 1, 127, 128, 300, 16_384, u32::MAX as u64, u64::MAX] {
+        let enc = varint_encode(v);
+        assert_eq!(varint_decode(&enc), Some((v, enc.len())));
+    }
+    assert_eq!(varint_encode(u64::MAX).len(), 10);
+    // Truncated input and values overflowing u64 are rejected
+    assert_eq!(varint_decode(&[]), None);
+    assert_eq!(varint_decode(&[0xff, 0xff]), None);
+    let mut overflow = vec![0xff; 9];
+    overflow.push(0x02);
+    assert_eq!(varint_decode(&overflow), None);
+
+    for v in [0i64, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+        assert_eq!(zigzag_decode(zigzag_encode(v)), v);
+    }
+    assert_eq!(zigzag_encode(-2), 3);
+}
+
+#[test]
+fn test_base58_ascii85_morse() {
+    for data in [
+        &b""[..],
+        b"\x00",
+        b"\x00\x00abc",
+        b"The quick brown fox",
+        &[255u8; 9],
+    ] {
+        assert_eq!(base58_decode(&base58_encode(data)).unwrap(), data);
+        assert_eq!(ascii85_decode(&ascii85_encode(data)).unwrap(), data);
+    }
+    assert_eq!(base58_encode(b""), "");
+    assert_eq!(ascii85_encode(b"sure."), "F*2M7/c");
+    assert_eq!(ascii85_decode("F*2M7/c").unwrap(), b"sure.");
+    assert_eq!(ascii85_decode("9jqo^v"), None);
+    assert_eq!(ascii85_decode("s8W-\"!"), None); // exceeds u32
+    assert_eq!(ascii85_decode("9jqo^F"), None); // dangling char
+
+    let msg = "Hello, World";
+    assert_eq!(
+        morse_decode(&morse_encode(msg).unwrap()).unwrap(),
+        "HELLO, WORLD"
+    );
+    assert_eq!(morse_encode("").unwrap(), "");
+}
+
+#[test]
+fn test_quoted_printable() {
+    use toolchest::encoding::{quoted_printable_decode, quoted_printable_encode};
+    let text = "Caf\u{e9} au lait \nsecond line\t\r\nx=y";
+    let enc = quoted_printable_encode(text.as_bytes());
+    assert_eq!(enc, "Caf=C3=A9 au lait=20\nsecond line=09\r\nx=3Dy");
+    assert_eq!(quoted_printable_decode(&enc).unwrap(), text.as_bytes());
+
+    let long = "a".repeat(200);
+    let enc = quoted_printable_encode(long.as_bytes());
+    assert!(enc.split("\r\n").all(|l| l.len() <= 76));
+    assert_eq!(quoted_printable_decode(&enc).unwrap(), long.as_bytes());
+
+    let bin: Vec<u8> = (0..=255).collect();
+    assert_eq!(
+        quoted_printable_decode(&quoted_printable_encode(&bin)).unwrap(),
+        bin
+    );
+    assert_eq!(
+        quoted_printable_decode("soft =\nbreak  \n").unwrap(),
+        b"soft break\n"
+    );
+    assert_eq!(quoted_printable_decode("=4"), None);
+}
+
+#[test]
+fn test_punycode() {
+    use toolchest::encoding::{punycode_decode, punycode_encode};
+    let cases = [
+        ("b\u{fc}cher", "bcher-kva"),
+        ("m\u{fc}nchen", "mnchen-3ya"),
+        (
+            "\u{4ed6}\u{4eec}\u{4e3a}\u{4ec0}\u{4e48}\u{4e0d}\u{8bf4}\u{4e2d}\u{6587}",
+            "ihqwcrb4cv8a8dqg056pqjye",
+        ),
+        ("abc", "abc-"),
+        ("", ""),
+    ];
+    for (plain, puny) in cases {
+        assert_eq!(punycode_encode(plain).unwrap(), puny);
+        assert_eq!(punycode_decode(puny).unwrap(), plain);
+    }
+    assert_eq!(punycode_decode("bcher-KVA").unwrap(), "b\u{fc}cher");
+    assert_eq!(punycode_decode("abc-!"), None);
+    assert_eq!(punycode_decode("99999999999"), None);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_unicode_normalization() {
+    let composed = "\u{c5}ngstr\u{f6}m";
+    let decomposed = "A\u{30a}ngstro\u{308}m";
+    assert_eq!(normalize_nfc(decomposed), composed);
+    assert_eq!(normalize_nfd(composed), decomposed);
+    assert_eq!(normalize_nfc(&normalize_nfd(composed)), composed);
+    assert_eq!(normalize_nfkd("\u{2460}"), "1");
+    assert_eq!(normalize_nfkc("x\u{b2}"), "x2");
+    assert_eq!(normalize_nfc("plain ascii"), "plain ascii");
+}
+
+#[test]
+fn test_crockford_and_grouped_hex() {
+    assert_eq!(crockford_encode(b""), "");
+    assert_eq!(crockford_encode(&[0xff]), "ZW");
+    for len in 0..12 {
+        let data: Vec<u8> = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+        assert_eq!(crockford_decode(&crockford_encode(&data)).unwrap(), data);
+        assert_eq!(
+            crockford_decode_check(&crockford_encode_check(&data)).unwrap(),
+            data
+        );
+    }
+    // Ambiguous letters are normalised
+    assert_eq!(crockford_decode("ol"), crockford_decode("01"));
+    assert_eq!(crockford_decode("a!"), None);
+    // Check symbols from the extended set round-trip, in any case
+    let data = [0u8, 32];
+    let enc = crockford_encode_check(&data);
+    assert!(enc.ends_with('*'));
+    assert_eq!(crockford_decode_check(&enc.to_lowercase()).unwrap(), data);
+    assert_eq!(crockford_decode_check(""), None);
+
+    assert_eq!(hex_encode_upper(&[0x0a, 0xbc]), "0ABC");
+    assert_eq!(hex_encode_grouped(&[1, 2, 3], 0, ":"), "010203");
+    assert_eq!(hex_encode_grouped(&[], 1, ":"), "");
+    assert_eq!(
+        hex_encode_grouped(&[0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff], 1, "-"),
+        "AA-BB-CC-DD-EE-FF"
+    );
+    assert_eq!(
+        hex_decode_lenient("aa-bb.cc_dd\nee ff"),
+        Some(vec![0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff])
+    );
+    assert_eq!(hex_decode_lenient(""), Some(vec![]));
+    assert_eq!(hex_decode_lenient("zz"), None);
+}
+
+#[test]
+fn test_bits_helpers() {
+    use toolchest::encoding::bits::*;
+    assert!(get_bit(1 << 63, 63));
+    assert!(!get_bit(0, 200));
+    assert_eq!(set_bit(0, 63, true), 1 << 63);
+    assert_eq!(set_bit(5, 64, true), 5);
+    assert_eq!(toggle_bit(toggle_bit(0xAA, 3), 3), 0xAA);
+    assert_eq!(set_bits_range(0, 0..64), u64::MAX);
+    assert_eq!(set_bits_range(0, 4..4), 0);
+    assert_eq!(clear_bits_range(u64::MAX, 1..64), 1);
+    assert_eq!(extract_bits(u64::MAX, 60..80), 0xF);
+    assert_eq!(extract_bits(0xFF, 64..70), 0);
+    assert_eq!(reverse_bits_u32(0x8000_0001), 0x8000_0001);
+}
+
+#[test]
+fn test_bitset() {
+    let mut set = BitSet::with_capacity(10);
+    assert_eq!(set.capacity(), 64);
+    assert!(set.is_empty());
+    assert!(!set.set(5, true));
+    assert!(set.set(5, true));
+    assert!(!set.set(1000, false));
+    assert_eq!(set.capacity(), 64);
+    set.set(64, true);
+    set.set(1000, true);
+    assert_eq!(set.iter_ones().collect::<Vec<_>>(), [5, 64, 1000]);
+    assert_eq!(set.count_ones(), 3);
+    assert!(!set.toggle(64));
+    assert!(!set.get(64) && !set.get(5000));
+    assert_eq!(format!("{set:?}"), "{5, 1000}");
+
+    let a: BitSet = [1, 2, 3, 200].into_iter().collect();
+    let b: BitSet = [2, 3, 4].into_iter().collect();
+    let mut u = a.clone();
+    u.union_with(&b);
+    assert_eq!(u, [1, 2, 3, 4, 200].into_iter().collect());
+    let mut i = a.clone();
+    i.intersect_with(&b);
+    // Equality ignores the spare capacity left from 200
+    assert_eq!(i, [2, 3].into_iter().collect());
+    i.clear();
+    assert_eq!(i, BitSet::new());
+}
+
+#[test]
+fn test_lz_roundtrip() {
+    let mut rng = toolchest::random::Rng::seeded(11);
+    let noise: Vec<u8> = (0..5000).map(|_| rng.next_u64() as u8).collect();
+    let mut mixed = b"header ".repeat(50);
+    mixed.extend_from_slice(&noise);
+    mixed.extend_from_slice(&noise[..3000]);
+    let samples: Vec<Vec<u8>> = vec![
+        Vec::new(),
+        b"a".to_vec(),
+        b"abcd".to_vec(),
+        vec![0; 100_000],
+        noise.clone(),
+        mixed.clone(),
+    ];
+    for data in &samples {
+        let packed = lz_compress(data);
+        assert_eq!(lz_decompress(&packed).as_deref(), Some(&data[..]));
+        assert!(packed.len() <= data.len() + data.len() / 8 + 4);
+    }
+    assert!(lz_compress(&[0; 100_000]).len() < 200);
+    // Repeated noise is found within the window
+    assert!(lz_compress(&mixed).len() < 5000 + 5000 / 8 + 100);
+}
+
+#[test]
+fn test_lz_rejects_corrupt_input() {
+    let packed = lz_compress(b"hello hello hello hello");
+    assert_eq!(lz_decompress(&[]), None);
+    assert_eq!(lz_decompress(&packed[..packed.len() - 1]), None);
+    let mut trailing = packed.clone();
+    trailing.push(0);
+    assert_eq!(lz_decompress(&trailing), None);
+    // Match pointing before the start of the output
+    assert_eq!(lz_decompress(&[8, 0b1, 1, 4]), None);
+    // Match longer than the recorded length
+    assert_eq!(lz_decompress(&[5, 0b10, b'a', 1, 4]), None);
+    assert_eq!(
+        lz_decompress(&[5, 0b10, b'a', 1, 0]),
+        Some(b"aaaaa".to_vec())
+    );
+    // Header claiming 1 TiB, backed by one huge match
+    let mut bomb = varint_encode(1 << 40);
+    bomb.extend([0b10, b'a', 1]);
+    bomb.extend(varint_encode((1 << 40) - 5));
+    assert_eq!(bomb.len(), 15);
+    assert_eq!(lz_decompress(&bomb), None);
+    // Plausible header, but a match longer than the compressor ever emits
+    let mut long = varint_encode(4098);
+    long.extend([0b10, b'a', 1]);
+    long.extend(varint_encode(4097 - 4));
+    assert_eq!(lz_decompress(&long), None);
+}
+
+#[test]
+fn test_base62_and_id_obfuscator() {
+    for v in [0u128, 1, 61, 62, 3843, 3844, u64::MAX as u128, u128::MAX] {
+        assert_eq!(base62_decode(&base62_encode(v)), Some(v));
+    }
+    assert_eq!(base62_decode(""), None);
+    assert_eq!(base62_decode("zzzzzzzzzzzzzzzzzzzzzzz"), None);
+
+    let ids = IdObfuscator::new("secret").with_min_length(5);
+    let mut seen = std::collections::HashSet::new();
+    for id in (0..2000u64).chain([u64::MAX - 1, u64::MAX, 916_132_831]) {
+        let code = ids.encode(id);
+        assert!(code.len() >= 5, "{code}");
+        assert!(code.bytes().all(|b| b.is_ascii_alphanumeric()));
+        assert_eq!(ids.decode(&code), Some(id), "{id} -> {code}");
+        assert!(seen.insert(code));
+    }
+    assert_eq!(ids.encode(u64::MAX).len(), 11);
+    assert_eq!(ids.decode("abc"), None);
+    assert_eq!(ids.decode("ab-de"), None);
+    // Short codes cover their whole space, so every 5-char code decodes
+    assert!(ids.decode("Zz9aQ").is_some());
+
+    let plain = IdObfuscator::new("secret");
+    assert_eq!(plain.encode(7).len(), 1);
+    assert_ne!(plain.encode(7), IdObfuscator::new("other").encode(7));
+}
@@ -1,6 +1,7 @@
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use toolchest::functions::*;
+use toolchest::time::{BackoffIter, JitterMode};
 
 #[test]
 fn test_debounce_basic() {
@@ -97,7 +98,7 @@ fn test_with_timeout() {
 #[test]
 fn test_retry_with_backoff() {
     let mut attempts = 0u32;
-    let res = retry_with_backoff(3, Duration::from_millis(1), || {
+    let res = retry_with_backoff(3, BackoffIter::new(Duration::from_millis(1)), || {
         attempts += 1;
         if attempts < 2 {
             Err(())
@@ -142,3 +143,601 @@ fn test_throttle_basic() {
     throttled.call();
     assert_eq!(*counter.lock().unwrap(), 2);
 }
+
+#[test]
+fn test_retry_with_backoff_schedule() {
+    let mut calls = 0u32;
+    let res: Result<(), u32> = retry_with_backoff(
+        10,
+        BackoffIter::new(Duration::from_millis(1))
+            .with_jitter(JitterMode::Full)
+            .take_attempts(2),
+        || {
+            calls += 1;
+            Err(calls)
+        },
+    );
+    assert_eq!(res, Err(3));
+    let res: Result<(), ()> = retry_with_backoff(1, Vec::new(), || Err(()));
+    assert_eq!(res, Err(()));
+}
+
+#[test]
+fn test_lazy_once_value_expiring() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    let calls = AtomicU32::new(0);
+    let lazy = Lazy::new(|| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        String::from("value")
+    });
+    assert!(!lazy.is_initialized());
+    assert_eq!(lazy.as_str(), "value");
+    assert_eq!(*lazy.get(), "value");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let cell: OnceValue<u8> = OnceValue::new();
+    assert_eq!(cell.get(), None);
+    assert_eq!(*cell.get_or_init(|| 3), 3);
+    assert_eq!(cell.set(4), Err(4));
+    assert_eq!(cell.into_inner(), Some(3));
+
+    let n = AtomicU32::new(0);
+    let exp = Expiring::new(Duration::from_secs(60), || n.fetch_add(1, Ordering::SeqCst));
+    assert!(!exp.is_fresh());
+    assert_eq!(exp.get(), 0);
+    assert!(exp.is_fresh());
+    exp.invalidate();
+    assert_eq!(exp.get(), 1);
+}
+
+#[test]
+fn test_keyed_rate_limiter() {
+    use toolchest::functions::KeyedRateLimiter;
+    let rl = KeyedRateLimiter::new(2, 1).with_max_keys(2);
+    assert!(rl.allow(&"a"));
+    assert!(rl.allow(&"a"));
+    assert!(!rl.allow(&"a"));
+    assert!(rl.allow(&"b"));
+    assert_eq!(rl.len(), 2);
+    // "a" is least recently used and gets evicted to make room
+    assert!(rl.allow(&"c"));
+    assert_eq!(rl.len(), 2);
+    assert!(rl.allow(&"a"));
+    rl.reset(&"a");
+    assert_eq!(rl.len(), 1);
+
+    let idle = KeyedRateLimiter::new(1, 1).with_idle_timeout(Duration::from_millis(5));
+    assert!(idle.allow(&1u32));
+    std::thread::sleep(Duration::from_millis(15));
+    assert_eq!(idle.evict_idle(), 1);
+    assert!(idle.is_empty());
+}
+
+#[test]
+fn test_compose_arities_and_pipeline() {
+    use toolchest::functions::compose::{compose3, compose4, compose5};
+    use toolchest::functions::Pipeline;
+    let inc = |x: i32| x + 1;
+    let dbl = |x: i32| x * 2;
+    assert_eq!(compose3(dbl, inc, inc)(0), 4);
+    assert_eq!(compose4(inc, dbl, inc, dbl)(1), 7);
+    assert_eq!(compose5(|x: i32| x.to_string(), inc, dbl, inc, dbl)(1), "7");
+
+    let p: Pipeline<i32> = Pipeline::new();
+    assert_eq!(p.run(5), 5);
+    let words = Pipeline::new()
+        .then(|s: String| {
+            s.split_whitespace()
+                .map(str::to_uppercase)
+                .collect::<Vec<_>>()
+        })
+        .then(|v| v.join("-"));
+    assert_eq!(words.run("a b  c".to_string()), "A-B-C");
+    let f = Pipeline::new().then(inc).then(dbl).into_fn();
+    assert_eq!((1..=3).map(f).collect::<Vec<_>>(), vec![4, 6, 8]);
+}
+
+#[test]
+fn test_cancellation_token_and_cancellable_timeout() {
+    let token = CancellationToken::new();
+    let clone = token.clone();
+    let rx = token.cancelled_channel();
+    assert!(!clone.is_cancelled());
+    assert!(!clone.wait_timeout(Duration::from_millis(1)));
+    token.cancel();
+    token.cancel();
+    assert!(clone.is_cancelled());
+    assert!(clone.wait_timeout(Duration::from_secs(5)));
+    assert_eq!(rx.recv_timeout(Duration::from_secs(1)), Ok(()));
+    assert_eq!(clone.cancelled_channel().try_recv(), Ok(()));
+
+    let token = CancellationToken::new();
+    let waiter = token.clone();
+    let handle = std::thread::spawn(move || waiter.wait_timeout(Duration::MAX));
+    std::thread::sleep(Duration::from_millis(10));
+    token.cancel();
+    assert!(handle.join().unwrap());
+
+    assert_eq!(
+        with_timeout_cancellable(Duration::from_secs(5), |t| t.is_cancelled()),
+        Some(false)
+    );
+    let seen = Arc::new(Mutex::new(None));
+    let seen2 = seen.clone();
+    let (done_tx, done_rx) = std::sync::mpsc::channel();
+    let res = with_timeout_cancellable(Duration::from_millis(5), move |t| {
+        let cancelled = t.cancelled_channel().recv().is_ok();
+        *seen2.lock().unwrap() = Some(cancelled);
+        done_tx.send(()).unwrap();
+    });
+    assert_eq!(res, None);
+    done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(*seen.lock().unwrap(), Some(true));
+}
+
+#[test]
+fn test_once_value_and_call_counter() {
+    let counter = CallCounter::new();
+    let make = counter.wrap0(|| vec![1, 2, 3]);
+    let cached = Arc::new(once_value(make));
+    assert_eq!(cached.get(), None);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let c = Arc::clone(&cached);
+            std::thread::spawn(move || c.call().len())
+        })
+        .collect();
+    for h in handles {
+        assert_eq!(h.join().unwrap(), 3);
+    }
+    assert_eq!(counter.times_called(), 1);
+    assert!(cached.has_run());
+
+    let add = counter.wrap(|(a, b): (i32, i32)| a + b);
+    assert_eq!(add((2, 3)), 5);
+    counter.record();
+    assert_eq!(counter.clone().times_called(), 3);
+    counter.reset();
+    assert_eq!(counter.times_called(), 0);
+    assert_eq!(once_value(|| 7).into_inner(), None);
+}
+
+#[test]
+fn test_retry_with_budget() {
+    use toolchest::functions::{retry_with_backoff_budget, retry_with_budget};
+    use toolchest::time::Budget;
+
+    // Expired budget: one attempt, no retries
+    let spent = Budget::new(Duration::ZERO);
+    let mut calls = 0;
+    let res: Result<(), &str> = retry_with_budget(&spent, 10, None, || {
+        calls += 1;
+        Err("x")
+    });
+    assert_eq!(res, Err("x"));
+    assert_eq!(calls, 1);
+
+    // Plenty of budget: behaves like retry
+    let roomy = Budget::new(Duration::from_secs(5));
+    let mut n = 0;
+    let res: Result<i32, &str> = retry_with_budget(&roomy, 5, None, || {
+        n += 1;
+        if n < 3 {
+            Err("again")
+        } else {
+            Ok(n)
+        }
+    });
+    assert_eq!(res, Ok(3));
+
+    // Delay larger than the budget stops immediately
+    let short = Budget::new(Duration::from_millis(50));
+    let mut calls = 0;
+    let res: Result<(), &str> =
+        retry_with_backoff_budget(&short, 10, [Duration::from_secs(1)], || {
+            calls += 1;
+            Err("slow")
+        });
+    assert_eq!(res, Err("slow"));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_memoize_multi_and_recursive() {
+    let calls = Arc::new(Mutex::new(0));
+    let c = calls.clone();
+    let add = memoize2(move |a: i32, b: i32| {
+        *c.lock().unwrap() += 1;
+        a + b
+    });
+    assert_eq!(add(1, 2), 3);
+    assert_eq!(add(1, 2), 3);
+    assert_eq!(add(2, 1), 3);
+    assert_eq!(*calls.lock().unwrap(), 2);
+
+    let join = memoize3(|a: String, b: char, n: usize| format!("{}{}", a, b.to_string().repeat(n)));
+    assert_eq!(join("x".into(), '-', 3), "x---");
+
+    let calls = Arc::new(Mutex::new(0));
+    let c = calls.clone();
+    // Grid paths: counts each sub-problem once
+    let paths = memoize_recursive(move |paths, (r, c_): (u32, u32)| -> u64 {
+        *c.lock().unwrap() += 1;
+        if r == 0 || c_ == 0 {
+            1
+        } else {
+            paths((r - 1, c_)) + paths((r, c_ - 1))
+        }
+    });
+    assert_eq!(paths((16, 16)), 601_080_390);
+    assert_eq!(*calls.lock().unwrap(), 17 * 17 - 1);
+    assert_eq!(paths((16, 16)), 601_080_390);
+    assert_eq!(*calls.lock().unwrap(), 17 * 17 - 1);
+}
+
+#[test]
+fn test_resilience_layers() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    // No layers: single attempt
+    let res: Result<(), _> = Resilience::new().call(|| Err("x"));
+    assert_eq!(res, Err(ResilienceError::Operation("x")));
+
+    // Retries stop when the schedule runs out
+    let calls = Arc::new(AtomicU32::new(0));
+    let c = calls.clone();
+    let policy = Resilience::new().with_retry(10, [Duration::ZERO, Duration::ZERO]);
+    let res: Result<(), _> = policy.call(move || {
+        c.fetch_add(1, Ordering::SeqCst);
+        Err("fail")
+    });
+    assert_eq!(res, Err(ResilienceError::Operation("fail")));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // Rate limiting is not retried
+    let policy = Resilience::new()
+        .with_retry(5, vec![Duration::ZERO; 4])
+        .with_rate_limiter(RateLimiter::new(1, 1));
+    assert_eq!(policy.call(|| Ok::<_, ()>(1)), Ok(1));
+    assert_eq!(
+        policy.call(|| Ok::<_, ()>(1)),
+        Err(ResilienceError::RateLimited)
+    );
+
+    // Timeouts are retried and feed the breaker
+    let calls = Arc::new(AtomicU32::new(0));
+    let c = calls.clone();
+    let policy = Resilience::new()
+        .with_retry(3, vec![Duration::ZERO; 2])
+        .with_timeout(Duration::from_millis(5))
+        .with_circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(60)));
+    let res: Result<u32, ResilienceError<()>> = policy.call(move || {
+        let n = c.fetch_add(1, Ordering::SeqCst);
+        if n == 0 {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        Ok(n)
+    });
+    assert_eq!(res, Ok(1));
+    assert_eq!(
+        policy.circuit_breaker().unwrap().state(),
+        BreakerState::Closed
+    );
+
+    let policy = Resilience::new()
+        .with_timeout(Duration::from_millis(5))
+        .with_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let slow = || {
+        std::thread::sleep(Duration::from_millis(100));
+        Ok::<_, ()>(())
+    };
+    assert_eq!(policy.call(slow), Err(ResilienceError::Timeout));
+    assert_eq!(policy.call(slow), Err(ResilienceError::CircuitOpen));
+    assert_eq!(ResilienceError::<&str>::Timeout.to_string(), "timed out");
+}
+
+#[test]
+fn test_middleware_wrappers() {
+    let events = Mutex::new(Vec::<String>::new());
+    let timings = Mutex::new(Vec::new());
+    let parse = (|s: &str| s.parse::<i32>().ok())
+        .with_before_after(
+            |s: &&str| events.lock().unwrap().push(format!("before {s}")),
+            |r: &Option<i32>| events.lock().unwrap().push(format!("after {r:?}")),
+        )
+        .with_timing(|d| timings.lock().unwrap().push(d))
+        .with_logging(|line| events.lock().unwrap().push(line));
+    assert_eq!(parse("42"), Some(42));
+    assert_eq!(parse("x"), None);
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "before 42",
+            "after Some(42)",
+            "\"42\" -> Some(42)",
+            "before x",
+            "after None",
+            "\"x\" -> None",
+        ]
+    );
+    assert_eq!(timings.lock().unwrap().len(), 2);
+
+    let sum = with_logging(
+        |(a, b): (i32, i32)| a + b,
+        |line| assert_eq!(line, "(2, 3) -> 5"),
+    );
+    assert_eq!(sum((2, 3)), 5);
+}
+
+#[test]
+fn test_debounce_arg_and_introspection() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let s = Arc::clone(&seen);
+    let d = debounce_arg(
+        move |n: u32| s.lock().unwrap().push(n),
+        Duration::from_millis(20),
+    );
+    assert!(!d.pending());
+    for n in 1..=5 {
+        d.call(n);
+    }
+    assert!(d.pending());
+    std::thread::sleep(Duration::from_millis(80));
+    assert!(!d.pending());
+    d.call(9);
+    std::thread::sleep(Duration::from_millis(80));
+    assert_eq!(*seen.lock().unwrap(), [5, 9]);
+    d.call(10);
+    d.stop();
+    assert_eq!(*seen.lock().unwrap(), [5, 9]);
+
+    let t = throttle(|| {}, Duration::from_millis(30));
+    assert_eq!(t.remaining(), Duration::ZERO);
+    t.call();
+    let left = t.remaining();
+    assert!(left > Duration::ZERO && left <= Duration::from_millis(30));
+    std::thread::sleep(Duration::from_millis(40));
+    assert_eq!(t.remaining(), Duration::ZERO);
+}
+
+#[test]
+fn test_supervise_restarts_and_gives_up() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    let runs = Arc::new(AtomicU32::new(0));
+    let r = Arc::clone(&runs);
+    let policy =
+        RestartPolicy::new(BackoffIter::new(Duration::from_millis(1))).with_max_restarts(3);
+    let handle = supervise(
+        move |_token| {
+            let n = r.fetch_add(1, Ordering::SeqCst) + 1;
+            if n % 2 == 0 {
+                panic!("run {n} exploded");
+            }
+            Err::<(), _>(format!("run {n} failed"))
+        },
+        policy,
+    );
+    let start = std::time::Instant::now();
+    while !handle.status().is_terminal() {
+        assert!(start.elapsed() < Duration::from_secs(2));
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(handle.status(), SupervisorStatus::GaveUp);
+    assert_eq!(handle.restarts(), 3);
+    assert_eq!(
+        handle.last_error().as_deref(),
+        Some("panicked: run 4 exploded")
+    );
+    assert_eq!(handle.join(), SupervisorStatus::GaveUp);
+    assert_eq!(runs.load(Ordering::SeqCst), 4);
+
+    // Running out of backoff delays also gives up
+    let schedule = BackoffIter::new(Duration::from_millis(1)).take_attempts(1);
+    let handle = supervise(|_| Err::<(), _>("nope"), RestartPolicy::new(schedule));
+    assert_eq!(handle.join(), SupervisorStatus::GaveUp);
+
+    // Ok(()) finishes without restarting
+    let handle = supervise(|_| Ok::<(), ()>(()), RestartPolicy::default());
+    assert_eq!(handle.join(), SupervisorStatus::Finished);
+}
+
+#[test]
+fn test_supervise_stop_interrupts_backoff() {
+    let policy = RestartPolicy::new(BackoffIter::new(Duration::from_secs(60)));
+    let mut handle = supervise(|_| Err::<(), _>("down"), policy);
+    let start = std::time::Instant::now();
+    while handle.status() != SupervisorStatus::Restarting {
+        assert!(start.elapsed() < Duration::from_secs(2));
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(handle.last_error().as_deref(), Some("\"down\""));
+    assert_eq!(handle.stop(), SupervisorStatus::Stopped);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(handle.restarts(), 0);
+    assert!(handle.status().is_terminal());
+
+    let mut daemon = supervise(
+        |token| {
+            while !token.wait_timeout(Duration::from_millis(1)) {}
+            Ok::<(), ()>(())
+        },
+        RestartPolicy::default(),
+    );
+    assert_eq!(daemon.status(), SupervisorStatus::Running);
+    assert_eq!(daemon.stop(), SupervisorStatus::Stopped);
+    assert_eq!(daemon.stop(), SupervisorStatus::Stopped);
+}
+
+#[test]
+fn test_rate_limiter_mock_clock() {
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    let limiter = RateLimiter::new(2, 4).with_clock(clock.clone());
+    assert!(limiter.allow());
+    assert!(limiter.allow());
+    assert!(!limiter.allow());
+    clock.advance(Duration::from_millis(250));
+    assert!(limiter.allow());
+    assert!(!limiter.allow());
+    clock.advance(Duration::from_secs(10));
+    assert!(limiter.allow());
+    assert!(limiter.allow());
+    assert!(!limiter.allow());
+
+    let keyed = KeyedRateLimiter::new(1, 1).with_clock(clock.clone());
+    assert!(keyed.allow(&"a"));
+    assert!(!keyed.allow(&"a"));
+    assert!(keyed.allow(&"b"));
+    clock.advance(Duration::from_secs(1));
+    assert!(keyed.allow(&"a"));
+}
+
+#[test]
+fn test_keyed_rate_limiter_sweeps_idle_keys() {
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    // Buckets refill in one second, so older keys are idle and get swept
+    let keyed = KeyedRateLimiter::new(1, 1).with_clock(clock.clone());
+    let mut peak = 0;
+    for ip in 0..10_000u32 {
+        assert!(keyed.allow(&ip));
+        clock.advance(Duration::from_millis(10));
+        peak = peak.max(keyed.len());
+    }
+    assert!(peak < 400, "tracked {peak} keys");
+}
+
+#[test]
+fn test_circuit_breaker_mock_clock() {
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    let cb = CircuitBreaker::new(1, Duration::from_secs(30)).with_clock(clock.clone());
+    let _ = cb.call::<_, (), &str>(|| Err("down"));
+    assert_eq!(cb.state(), BreakerState::Open);
+    clock.advance(Duration::from_secs(29));
+    assert!(matches!(
+        cb.call::<_, (), &str>(|| Ok(())),
+        Err(CircuitBreakerError::Open)
+    ));
+    clock.advance(Duration::from_secs(1));
+    assert!(cb.call::<_, (), &str>(|| Ok(())).is_ok());
+    assert_eq!(cb.state(), BreakerState::Closed);
+}
+
+#[test]
+fn test_throttle_mock_clock() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    let hits = AtomicU32::new(0);
+    let t = throttle(
+        || {
+            hits.fetch_add(1, Ordering::SeqCst);
+        },
+        Duration::from_secs(60),
+    )
+    .with_clock(clock.clone());
+    t.call();
+    t.call();
+    assert_eq!(t.remaining(), Duration::from_secs(60));
+    clock.advance(Duration::from_secs(45));
+    t.call();
+    assert_eq!(t.remaining(), Duration::from_secs(15));
+    clock.advance(Duration::from_secs(15));
+    t.call();
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_debounce_mock_clock() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    let hits = Arc::new(AtomicU32::new(0));
+    let h = Arc::clone(&hits);
+    let debounced = debounce(
+        move || {
+            h.fetch_add(1, Ordering::SeqCst);
+        },
+        Duration::from_secs(3600),
+    )
+    .with_clock(clock.clone());
+    debounced.call();
+    debounced.call();
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+    assert!(debounced.pending());
+
+    clock.advance(Duration::from_secs(3600));
+    let start = std::time::Instant::now();
+    while hits.load(Ordering::SeqCst) == 0 && start.elapsed() < Duration::from_secs(2) {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert!(!debounced.pending());
+}
+
+#[test]
+fn test_worker_pool() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    let pool = WorkerPool::new(3);
+    assert_eq!(pool.threads(), 3);
+    let counter = Arc::new(AtomicU32::new(0));
+    let handles: Vec<_> = (0..20)
+        .map(|i| {
+            let counter = Arc::clone(&counter);
+            pool.submit(move || {
+                if i == 7 {
+                    panic!("job {i} failed");
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                i * 2
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join()).collect();
+    assert_eq!(results[3], Ok(6));
+    assert_eq!(results[7], Err(JobError::Panicked("job 7 failed".into())));
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 19);
+    // Workers survive a panicking job
+    assert_eq!(pool.submit(|| "still alive").join(), Ok("still alive"));
+    assert!(pool.shutdown(Duration::from_secs(5)));
+    assert_eq!(counter.load(Ordering::SeqCst), 19);
+}
+
+#[test]
+fn test_worker_pool_shutdown_deadline() {
+    let pool = WorkerPool::new(1);
+    let (started, wait) = std::sync::mpsc::channel();
+    let (release, gate) = std::sync::mpsc::channel::<()>();
+    let running = pool.submit(move || {
+        started.send(()).unwrap();
+        gate.recv().ok();
+        "done"
+    });
+    let queued: Vec<_> = (0..3).map(|i| pool.submit(move || i)).collect();
+    wait.recv().unwrap();
+    assert_eq!(pool.pending(), 4);
+    assert!(!running.is_finished());
+    assert!(!pool.shutdown(Duration::from_millis(20)));
+    release.send(()).unwrap();
+    assert_eq!(running.join(), Ok("done"));
+    for h in queued {
+        assert_eq!(h.join(), Err(JobError::Cancelled));
+    }
+
+    // Dropping the pool still runs queued work
+    let pool = WorkerPool::new(2);
+    let h = pool.submit(|| 5);
+    drop(pool);
+    assert_eq!(h.join(), Ok(5));
+
+    // `Duration::MAX` means wait for everything, with no deadline
+    let pool = WorkerPool::new(2);
+    let slow = pool.submit(|| {
+        std::thread::sleep(Duration::from_millis(30));
+        6
+    });
+    assert!(pool.shutdown(Duration::MAX));
+    assert_eq!(slow.join(), Ok(6));
+}
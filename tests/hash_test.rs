@@ -11,5 +11,149 @@ fn test_hashes_basic() {
     assert_ne!(f_a, f_b);
     let m = murmur3_32(b"hello", 0);
     assert_ne!(m, 0);
-    assert_eq!(consistent_hash("key", 10) < 10, true);
+    assert!(consistent_hash("key", 10) < 10);
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_sha_and_hmac_vectors() {
+    use toolchest::encoding::hex_encode;
+    assert_eq!(
+        hex_encode(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex_encode(&sha1(b"")),
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709"
+    );
+    // Two-block message (padding spills into a second block)
+    let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    assert_eq!(
+        hex_encode(&sha256(msg)),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(
+        hex_encode(&sha1(msg)),
+        "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+    );
+    let million = vec![b'a'; 1_000_000];
+    assert_eq!(
+        hex_encode(&sha256(&million)),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+
+    // RFC 4231 test case 2 and case 6 (key longer than the block size)
+    assert_eq!(
+        hex_encode(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    let long_key = [0xaau8; 131];
+    assert_eq!(
+        hex_encode(&hmac_sha256(
+            &long_key,
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        )),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+    // RFC 2202 test case 2
+    assert_eq!(
+        hex_encode(&hmac_sha1(b"Jefe", b"what do ya want for nothing?")),
+        "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+    );
+
+    let tag = hmac_sha256(b"k", b"m");
+    assert!(verify_hmac_sha256(b"k", b"m", &tag));
+    assert!(!verify_hmac_sha256(b"k", b"m", &tag[..31]));
+    assert!(!constant_time_eq(b"a", b"ab"));
+}
+
+#[test]
+fn test_crc32_known_values() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(
+        crc32(b"The quick brown fox jumps over the lazy dog"),
+        0x414F_A339
+    );
+}
+
+#[test]
+fn test_murmur3_128_and_combine() {
+    use toolchest::hash::{combine, hash_many, murmur3_128};
+    assert_eq!(murmur3_128(b"", 0), 0);
+    assert_eq!(
+        murmur3_128(b"foo", 0).to_le_bytes(),
+        *b"aE\xf5\x01W\x86q\xe2\x87}\xba+\xe4\x87\xaf~"
+    );
+    assert_eq!(
+        murmur3_128(b"The quick brown fox jumps over the lazy dog", 0),
+        0x7a433ca9c49a9347_e34bbc7bbc071b6c
+    );
+    // Tails of every length hash distinctly
+    let data = b"0123456789abcdefXYZ";
+    let hashes: std::collections::HashSet<u128> = (0..=data.len())
+        .map(|n| murmur3_128(&data[..n], 7))
+        .collect();
+    assert_eq!(hashes.len(), data.len() + 1);
+
+    assert_ne!(combine(1, 2), combine(2, 1));
+    assert_ne!(combine(0, 0), 0);
+    assert_ne!(hash_many(&[]), hash_many(&[b""]));
+    assert_ne!(hash_many(&[b"a", b""]), hash_many(&[b"", b"a"]));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_hash_serialize() {
+    use serde::Serialize;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Serialize)]
+    enum Shape {
+        Dot,
+        Circle(f64),
+        Rect { w: u32, h: u32 },
+    }
+
+    #[derive(Serialize)]
+    struct Pair(i32, Option<String>);
+
+    let a: HashMap<&str, i32> = HashMap::from([("k", 0)]);
+    let b: BTreeMap<&str, i64> = BTreeMap::from([("k", 0)]);
+    assert_eq!(hash_serialize(&a), hash_serialize(&b));
+
+    let big: HashMap<String, usize> = (0..100).map(|i| (i.to_string(), i)).collect();
+    let mut rev: Vec<_> = big.iter().collect();
+    rev.reverse();
+    let rebuilt: HashMap<String, usize> = rev.into_iter().map(|(k, v)| (k.clone(), *v)).collect();
+    assert_eq!(hash_serialize(&big), hash_serialize(&rebuilt));
+
+    // Unit variants hash like their name, options like their contents
+    assert_eq!(hash_serialize(&Shape::Dot), hash_serialize("Dot"));
+    assert_eq!(hash_serialize(&Some(5u8)), hash_serialize(&5i64));
+    assert_eq!(hash_serialize(&None::<u8>), hash_serialize(&()));
+    assert_ne!(
+        hash_serialize(&Shape::Circle(1.0)),
+        hash_serialize(&Shape::Circle(2.0))
+    );
+    assert_ne!(
+        hash_serialize(&Shape::Rect { w: 1, h: 2 }),
+        hash_serialize(&Shape::Rect { w: 2, h: 1 })
+    );
+    assert_eq!(
+        hash_serialize(&Shape::Rect { w: 1, h: 2 }),
+        hash_serialize(&BTreeMap::from([(
+            "Rect",
+            BTreeMap::from([("h", 2), ("w", 1)])
+        )]))
+    );
+
+    // Element boundaries are unambiguous
+    assert_ne!(hash_serialize(&("ab", "c")), hash_serialize(&("a", "bc")));
+    assert_ne!(
+        hash_serialize(&vec![vec![1], vec![]]),
+        hash_serialize(&vec![vec![], vec![1]])
+    );
+    assert_eq!(hash_serialize(&Pair(1, None)), hash_serialize(&(1, ())));
+    assert_eq!(hash_serialize(&u128::MAX), hash_serialize(&u128::MAX));
+    assert_ne!(hash_serialize(&u128::MAX), hash_serialize(&-1i128));
 }
@@ -0,0 +1,420 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use toolchest::io::*;
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = PathBuf::from("target/tmp_io_test").join(name);
+    fs::remove_dir_all(&dir).ok();
+    ensure_dir(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_dir_size_and_count() {
+    let dir = scratch("size");
+    ensure_dir(dir.join("a/b")).unwrap();
+    write_atomic(dir.join("one.bin"), &[0u8; 10]).unwrap();
+    write_atomic(dir.join("a/b/two.bin"), &[0u8; 32]).unwrap();
+    assert_eq!(dir_size(&dir).unwrap(), 42);
+    assert_eq!(count_files(&dir).unwrap(), 2);
+    assert!(dir_size(dir.join("missing")).is_err());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_remove_empty_dirs_and_clean() {
+    let dir = scratch("prune");
+    ensure_dir(dir.join("empty/nested")).unwrap();
+    ensure_dir(dir.join("keep")).unwrap();
+    write_atomic(dir.join("keep/file.txt"), b"x").unwrap();
+    assert_eq!(remove_empty_dirs(&dir).unwrap(), 2);
+    assert!(dir.join("keep/file.txt").exists());
+    assert!(!dir.join("empty").exists());
+
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(clean_dir(&dir, Duration::from_millis(1)).unwrap(), 1);
+    assert_eq!(count_files(&dir).unwrap(), 0);
+    assert_eq!(remove_empty_dirs(&dir).unwrap(), 1);
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_config_layers() {
+    #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+    #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        server: Server,
+    }
+
+    let dir = scratch("json");
+    let base = dir.join("base.json");
+    let prod = dir.join("prod.json");
+    let cfg = Config {
+        name: "app".into(),
+        server: Server {
+            host: "localhost".into(),
+            port: 80,
+        },
+    };
+    save_json_atomic(&base, &cfg, JsonFormat::Pretty).unwrap();
+    assert!(fs::read_to_string(&base).unwrap().ends_with("}\n"));
+    assert_eq!(load_json::<Config, _>(&base).unwrap(), cfg);
+
+    write_atomic(&prod, br#"{"server": {"port": 443}, "name": null}"#).unwrap();
+    let merged: Config = load_json_merged(&[&base, &prod]).unwrap();
+    assert_eq!(merged.name, "app");
+    assert_eq!(merged.server.port, 443);
+    assert_eq!(merged.server.host, "localhost");
+
+    write_atomic(&prod, b"{not json").unwrap();
+    let err = load_json_merged::<Config, _>(&[&base, &prod]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(load_json::<Config, _>(dir.join("missing.json")).is_err());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_file_lock() {
+    let dir = scratch("lock");
+    let path = dir.join("state.lock");
+    let a = FileLock::lock_shared(&path).unwrap();
+    let b = FileLock::try_lock_shared(&path)
+        .unwrap()
+        .expect("shared locks coexist");
+    assert!(FileLock::try_lock(&path).unwrap().is_none());
+    assert_eq!(a.path(), path.as_path());
+    drop(a);
+    b.unlock().unwrap();
+
+    let ex = FileLock::lock_exclusive(&path).unwrap();
+    assert!(FileLock::try_lock_shared(&path).unwrap().is_none());
+    let waiter = {
+        let path = path.clone();
+        std::thread::spawn(move || FileLock::lock_exclusive(&path).map(|_| ()))
+    };
+    std::thread::sleep(Duration::from_millis(20));
+    drop(ex);
+    waiter.join().unwrap().unwrap();
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_grep() {
+    let dir = scratch("grep");
+    ensure_dir(dir.join("sub")).unwrap();
+    write_atomic(
+        dir.join("a.txt"),
+        b"Hello world\r\nnothing here\nhello again\n",
+    )
+    .unwrap();
+    write_atomic(dir.join("sub/b.log"), b"say HELLO\n").unwrap();
+    write_atomic(dir.join("bin.dat"), b"hello\0binary\n").unwrap();
+    write_atomic(dir.join("big.txt"), "hello\n".repeat(100).as_bytes()).unwrap();
+
+    let opts = GrepOptions {
+        max_file_size: Some(100),
+        ..Default::default()
+    };
+    let hits = grep(&dir, "hello", &opts).unwrap();
+    assert_eq!(
+        hits,
+        vec![(dir.join("a.txt"), 3, "hello again".to_string())]
+    );
+
+    let opts = GrepOptions {
+        case_insensitive: true,
+        max_file_size: Some(100),
+        ..Default::default()
+    };
+    let hits: Vec<_> = grep(&dir, "hello", &opts)
+        .unwrap()
+        .into_iter()
+        .map(|(p, n, _)| (p.strip_prefix(&dir).unwrap().to_path_buf(), n))
+        .collect();
+    assert_eq!(
+        hits,
+        vec![
+            (PathBuf::from("a.txt"), 1),
+            (PathBuf::from("a.txt"), 3),
+            (PathBuf::from("sub/b.log"), 1),
+        ]
+    );
+
+    let opts = GrepOptions {
+        wildcard: true,
+        file_name_contains: Some(".TXT".into()),
+        max_file_size: None,
+        max_matches: Some(2),
+        ..Default::default()
+    };
+    let hits = grep(&dir, "h?llo*a", &opts).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].2, "hello again");
+    assert_eq!(grep(&dir, "h*o", &opts).unwrap().len(), 2);
+
+    let opts = GrepOptions {
+        skip_binary: false,
+        ..Default::default()
+    };
+    assert_eq!(grep(dir.join("bin.dat"), "binary", &opts).unwrap().len(), 1);
+    assert!(grep(dir.join("missing"), "hello", &opts).is_err());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_grep_skips_unreadable_entries() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = scratch("grep_unreadable");
+    ensure_dir(dir.join("locked")).unwrap();
+    write_atomic(dir.join("a.txt"), b"needle one\n").unwrap();
+    write_atomic(dir.join("locked/c.txt"), b"needle hidden\n").unwrap();
+    write_atomic(dir.join("z.txt"), b"needle two\n").unwrap();
+    fs::set_permissions(dir.join("locked"), fs::Permissions::from_mode(0o000)).unwrap();
+    fs::set_permissions(dir.join("z.txt"), fs::Permissions::from_mode(0o000)).unwrap();
+
+    // Unreadable entries (when not running as root) are skipped, not fatal
+    let hits = grep(&dir, "needle", &GrepOptions::default()).unwrap();
+    assert!(hits.contains(&(dir.join("a.txt"), 1, "needle one".to_string())));
+
+    fs::set_permissions(dir.join("locked"), fs::Permissions::from_mode(0o755)).unwrap();
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_file_hash_and_compare() {
+    use toolchest::hash::{crc32, fnv1a, murmur3_32};
+    let dir = scratch("compare");
+    // Larger than one chunk and not a multiple of 4 to exercise streaming tails
+    let data: Vec<u8> = (0..200_003u32).map(|i| (i * 31 % 251) as u8).collect();
+    let f = dir.join("data.bin");
+    write_atomic(&f, &data).unwrap();
+    assert_eq!(file_hash(&f, Algo::Fnv).unwrap(), fnv1a(&data));
+    assert_eq!(
+        file_hash(&f, Algo::Murmur3).unwrap(),
+        murmur3_32(&data, 0) as u64
+    );
+    assert_eq!(file_hash(&f, Algo::Crc32).unwrap(), crc32(&data) as u64);
+    assert!(file_hash(dir.join("missing"), Algo::Fnv).is_err());
+
+    let g = dir.join("copy.bin");
+    write_atomic(&g, &data).unwrap();
+    assert!(files_identical(&f, &g).unwrap());
+    let mut tweaked = data.clone();
+    tweaked[150_000] ^= 1;
+    write_atomic(&g, &tweaked).unwrap();
+    assert!(!files_identical(&f, &g).unwrap());
+    write_atomic(&g, b"short").unwrap();
+    assert!(!files_identical(&f, &g).unwrap());
+
+    let (a, b) = (dir.join("a"), dir.join("b"));
+    ensure_dir(a.join("sub")).unwrap();
+    ensure_dir(b.join("sub")).unwrap();
+    write_atomic(a.join("same.txt"), b"x").unwrap();
+    write_atomic(b.join("same.txt"), b"x").unwrap();
+    write_atomic(a.join("sub/edit.txt"), b"old").unwrap();
+    write_atomic(b.join("sub/edit.txt"), b"new").unwrap();
+    write_atomic(a.join("gone.txt"), b"bye").unwrap();
+    write_atomic(b.join("sub/new.txt"), b"hi").unwrap();
+    let diff = dir_diff(&a, &b).unwrap();
+    assert_eq!(diff.added, vec![PathBuf::from("sub/new.txt")]);
+    assert_eq!(diff.removed, vec![PathBuf::from("gone.txt")]);
+    assert_eq!(diff.changed, vec![PathBuf::from("sub/edit.txt")]);
+    assert!(dir_diff(&a, &a).unwrap().is_empty());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_resolve_within_and_path_helpers() {
+    use std::io::ErrorKind;
+    let root = scratch("resolve_within");
+    ensure_dir(root.join("a/b")).unwrap();
+    let canon = fs::canonicalize(&root).unwrap();
+
+    assert_eq!(
+        resolve_within(&root, "a/b/c.txt").unwrap(),
+        canon.join("a/b/c.txt")
+    );
+    assert_eq!(resolve_within(&root, "./a/../x").unwrap(), canon.join("x"));
+    assert_eq!(resolve_within(&root, "new/../y").unwrap(), canon.join("y"));
+    assert_eq!(resolve_within(&root, "").unwrap(), canon);
+    let err = resolve_within(&root, "a/../../escape").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    let err = resolve_within(&root, "/abs").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert!(resolve_within(root.join("missing"), "x").is_err());
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink("..", root.join("a/up")).unwrap();
+        std::os::unix::fs::symlink("/", root.join("out")).unwrap();
+        assert_eq!(resolve_within(&root, "a/up/x").unwrap(), canon.join("x"));
+        let err = resolve_within(&root, "out/etc").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+
+    assert!(is_hidden(".git"));
+    assert!(!is_hidden("a/b"));
+    assert!(!is_hidden("."));
+    assert_eq!(file_extension_lower("A.Tar.GZ").as_deref(), Some("gz"));
+    assert_eq!(file_extension_lower("dir/"), None);
+    assert_eq!(
+        with_extension_appended("x.tar", "gz"),
+        PathBuf::from("x.tar.gz")
+    );
+    assert_eq!(with_extension_appended("x", ""), PathBuf::from("x"));
+    assert_eq!(with_extension_appended("a/..", "gz"), PathBuf::from("a/.."));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_kv_store() {
+    let dir = scratch("kv");
+    let path = dir.join("state.toml");
+    {
+        let mut store = KvStore::open(&path).unwrap();
+        assert!(store.is_empty());
+        assert_eq!(store.set("token", "abc").unwrap(), None);
+        assert_eq!(store.set("token", "def").unwrap(), Some("abc".into()));
+        store.set("multi line", "a\n\"b\"").unwrap();
+        assert!(!store.is_dirty());
+        assert!(KvStore::try_open(&path).unwrap().is_none());
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "\"multi line\" = \"a\\n\\\"b\\\"\"\ntoken = \"def\"\n"
+    );
+
+    let mut store = KvStore::open(&path).unwrap().with_write_behind(true);
+    assert_eq!(store.get("multi line"), Some("a\n\"b\""));
+    assert_eq!(store.remove("token").unwrap(), Some("def".into()));
+    assert_eq!(store.remove("missing").unwrap(), None);
+    store.set("new", "1").unwrap();
+    assert!(store.is_dirty());
+    assert!(fs::read_to_string(&path).unwrap().contains("token"));
+    store.flush().unwrap();
+    assert!(!store.is_dirty());
+    assert_eq!(store.keys(), ["multi line", "new"]);
+    drop(store);
+
+    fs::write(
+        &path,
+        "# comment\n\nbare_key = \"v\" # trailing\n\"q\" = \"w\"\n",
+    )
+    .unwrap();
+    let store = KvStore::open(&path).unwrap();
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get("bare_key"), Some("v"));
+    drop(store);
+
+    fs::write(&path, "ok = \"1\"\nbroken\n").unwrap();
+    let err = KvStore::open(&path).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("line 2"));
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_line_endings_and_bom() {
+    let dir = scratch("eol");
+    let path = dir.join("mixed.txt");
+    fs::write(&path, "a\nb\r\nc\r\n").unwrap();
+    assert_eq!(detect_line_ending(&path).unwrap(), Some(LineEnding::CrLf));
+    fs::write(&path, "a\nb\r\n").unwrap();
+    assert_eq!(detect_line_ending(&path).unwrap(), Some(LineEnding::Lf));
+    fs::write(&path, "no breaks").unwrap();
+    assert_eq!(detect_line_ending(&path).unwrap(), None);
+
+    fs::write(&path, "x\ny\r\nz\r").unwrap();
+    assert!(normalize_line_endings(&path, LineEnding::CrLf).unwrap());
+    assert_eq!(fs::read(&path).unwrap(), b"x\r\ny\r\nz\r\n");
+    assert!(!normalize_line_endings(&path, LineEnding::CrLf).unwrap());
+    assert!(normalize_line_endings(&path, LineEnding::Lf).unwrap());
+    assert_eq!(fs::read(&path).unwrap(), b"x\ny\nz\n");
+    assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+
+    assert_eq!(strip_bom("\u{FEFF}\u{FEFF}x"), "\u{FEFF}x");
+    assert_eq!(strip_bom(""), "");
+
+    fs::write(&path, [0xFF, 0xFE, b'h', 0, b'i', 0]).unwrap();
+    assert_eq!(read_to_string_lossy(&path).unwrap(), "hi");
+    fs::write(&path, [0xFE, 0xFF, 0, b'o', 0, b'k']).unwrap();
+    assert_eq!(read_to_string_lossy(&path).unwrap(), "ok");
+    fs::write(&path, b"plain \xFF").unwrap();
+    assert_eq!(read_to_string_lossy(&path).unwrap(), "plain \u{FFFD}");
+    assert!(read_to_string_lossy(dir.join("missing")).is_err());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_copy_with_progress() {
+    let dir = scratch("progress");
+    let data: Vec<u8> = (0..150_000u32).map(|i| (i % 251) as u8).collect();
+    write_atomic(dir.join("big.bin"), &data).unwrap();
+
+    let mut reports = Vec::new();
+    let n = copy_with_progress(dir.join("big.bin"), dir.join("copy.bin"), |done, total| {
+        reports.push((done, total))
+    })
+    .unwrap();
+    assert_eq!(n, 150_000);
+    assert_eq!(fs::read(dir.join("copy.bin")).unwrap(), data);
+    assert!(reports.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(reports.first(), Some(&(0, 150_000)));
+    assert_eq!(reports.last(), Some(&(150_000, 150_000)));
+
+    let mut last = None;
+    let read = read_with_progress(dir.join("big.bin"), |done, total| {
+        last = Some((done, total))
+    })
+    .unwrap();
+    assert_eq!(read, data);
+    assert_eq!(last, Some((150_000, 150_000)));
+
+    let mut chunks = 0;
+    let mut out = Vec::new();
+    copy_stream_with_progress(&mut &data[..1000], &mut out, None, 0, |_, total| {
+        assert_eq!(total, None);
+        chunks += 1;
+    })
+    .unwrap();
+    assert_eq!(chunks, 1001);
+
+    ensure_dir(dir.join("tree/sub")).unwrap();
+    write_atomic(dir.join("tree/a"), b"12345").unwrap();
+    write_atomic(dir.join("tree/sub/b"), &data).unwrap();
+    let mut last = (0, 0);
+    let copied = copy_dir_with_progress(dir.join("tree"), dir.join("tree2"), |done, total| {
+        assert!(done <= total);
+        last = (done, total);
+    })
+    .unwrap();
+    assert_eq!(copied, 150_005);
+    assert_eq!(last, (150_005, 150_005));
+    assert!(dir_diff(dir.join("tree"), dir.join("tree2"))
+        .unwrap()
+        .is_empty());
+    assert!(copy_with_progress(dir.join("missing"), dir.join("x"), |_, _| {}).is_err());
+
+    let mut calls = 0;
+    let n = copy_with_progress_buf(
+        dir.join("tree/sub/b"),
+        dir.join("small.bin"),
+        50_000,
+        |_, _| calls += 1,
+    )
+    .unwrap();
+    assert_eq!(calls as u64, 1 + n.div_ceil(50_000));
+    let mut reads = 0;
+    let bytes = read_with_progress_buf(dir.join("tree/a"), 0, |_, _| reads += 1).unwrap();
+    assert_eq!(bytes, b"12345");
+    assert_eq!(reads, 6);
+}
@@ -26,3 +26,158 @@ fn test_math_extras() {
         [0.0, 0.0, 1.0]
     );
 }
+
+#[test]
+fn test_safe_arithmetic() {
+    use toolchest::math::safe::*;
+    assert_eq!(checked_add(i32::MAX, 1), None);
+    assert_eq!(checked_mul(3usize, 4), Some(12));
+    assert_eq!(checked_pow(3i64, 40), None);
+    assert_eq!(saturating_sub(i16::MIN, 1), i16::MIN);
+    assert_eq!(saturating_pow(3u8, 6), u8::MAX);
+    assert_eq!(wrapping_mul(i8::MAX, 2), -2);
+    assert_eq!(wrapping_abs(i64::MIN), i64::MIN);
+    assert_eq!(mean_i64_no_overflow(&[]), 0.0);
+    assert_eq!(
+        mean_i64_no_overflow(&[i64::MIN, i64::MIN, 0]),
+        i64::MIN as f64 * 2.0 / 3.0
+    );
+}
+
+#[test]
+fn test_linalg_vectors_and_matrices() {
+    use toolchest::math::linalg::*;
+    let a = Vec2::new(3.0, 4.0);
+    assert_eq!(a.length(), 5.0);
+    assert_eq!(a.normalized(), Some(Vec2::new(0.6, 0.8)));
+    assert_eq!(Vec2::ZERO.normalized(), None);
+    assert_eq!(a.perp(), Vec2::new(-4.0, 3.0));
+    assert_eq!(-a + a * 2.0, a);
+    let x = Vec3::new(1.0, 0.0, 0.0);
+    let y = Vec3::new(0.0, 1.0, 0.0);
+    assert_eq!(x.cross(y), Vec3::new(0.0, 0.0, 1.0));
+    assert_eq!(x.lerp(y, 0.5), Vec3::new(0.5, 0.5, 0.0));
+
+    let m = Mat3::new([[2.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+    assert_eq!(m.determinant(), 1.0);
+    assert_eq!(m * m.inverse().unwrap(), Mat3::IDENTITY);
+    assert_eq!(m.transpose().transpose(), m);
+    assert!(Mat3::new([[1.0; 3]; 3]).inverse().is_none());
+    assert!(Mat2::new([[1.0, 2.0], [2.0, 4.0]]).inverse().is_none());
+
+    let r = Mat2::rotation(std::f64::consts::FRAC_PI_2) * Vec2::new(1.0, 0.0);
+    assert!((r.x).abs() < 1e-12 && (r.y - 1.0).abs() < 1e-12);
+    let t = Mat3::translation(5.0, 0.0);
+    assert_eq!(t.transform_vector(Vec2::new(1.0, 1.0)), Vec2::new(1.0, 1.0));
+    assert_eq!(
+        t.inverse().unwrap().transform_point(Vec2::new(5.0, 1.0)),
+        Vec2::new(0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_linalg_geometry() {
+    use toolchest::math::linalg::*;
+    let square = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(4.0, 0.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(0.0, 4.0),
+    ];
+    assert!(point_in_polygon(Vec2::new(2.0, 2.0), &square));
+    assert!(!point_in_polygon(Vec2::new(5.0, 2.0), &square));
+    assert!(!point_in_polygon(Vec2::new(1.0, 1.0), &[]));
+    assert_eq!(polygon_area(&square), 16.0);
+    assert_eq!(
+        bounding_box(&[Vec2::new(1.0, -2.0), Vec2::new(-3.0, 5.0)]),
+        Some((Vec2::new(-3.0, -2.0), Vec2::new(1.0, 5.0)))
+    );
+    assert_eq!(bounding_box(&[]), None);
+    assert_eq!(
+        segment_intersection(square[0], square[2], square[1], square[3]),
+        Some(Vec2::new(2.0, 2.0))
+    );
+    assert_eq!(
+        segment_intersection(square[0], square[1], square[3], square[2]),
+        None
+    );
+    assert_eq!(
+        segment_intersection(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(2.0, 1.0)
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_interpolation() {
+    use toolchest::math::interp::*;
+    use toolchest::math::linalg::Vec2;
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+    assert_eq!(inverse_lerp(2.0, 2.0, 5.0), 0.0);
+    assert_eq!(inverse_lerp(0.0, 10.0, 15.0), 1.5);
+    assert_eq!(remap_clamped(-5.0, 0.0, 10.0, 100.0, 200.0), 100.0);
+    assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+    assert!(close(smoothstep(0.0, 1.0, 0.25), 0.15625));
+    assert!(close(smootherstep(0.0, 1.0, 0.5), 0.5));
+
+    assert_eq!(quadratic_bezier(0.0, 1.0, 0.0, 0.5), 0.5);
+    let p = cubic_bezier(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+        0.0,
+    );
+    assert_eq!(p, Vec2::new(0.0, 0.0));
+
+    // Catmull-Rom passes through every control point
+    let pts = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(6.0, 0.0),
+    ];
+    for (i, expected) in pts.iter().enumerate() {
+        let q = catmull_rom(&pts, i as f64 / 4.0).unwrap();
+        assert!(
+            close(q.x, expected.x) && close(q.y, expected.y),
+            "point {i}"
+        );
+    }
+    assert_eq!(catmull_rom(&[7.0], 0.3), Some(7.0));
+    assert!(close(catmull_rom(&[0.0, 10.0], 0.5).unwrap(), 5.0));
+    assert!(close(catmull_rom_segment(0.0, 1.0, 2.0, 3.0, 0.5), 1.5));
+
+    let curves: [fn(f64) -> f64; 16] = [
+        easing::linear,
+        easing::ease_in_quad,
+        easing::ease_out_quad,
+        easing::ease_in_out_quad,
+        easing::ease_in_cubic,
+        easing::ease_out_cubic,
+        easing::ease_in_out_cubic,
+        easing::ease_in_sine,
+        easing::ease_out_sine,
+        easing::ease_in_out_sine,
+        easing::ease_in_expo,
+        easing::ease_out_expo,
+        easing::ease_in_out_expo,
+        easing::ease_in_back,
+        easing::ease_out_back,
+        easing::ease_out_bounce,
+    ];
+    for f in curves {
+        assert!(close(f(0.0), 0.0));
+        assert!(close(f(1.0), 1.0));
+    }
+    assert!(close(easing::ease_in_bounce(0.0), 0.0) && close(easing::ease_in_bounce(1.0), 1.0));
+    assert!(easing::ease_out_back(0.8) > 1.0);
+    assert!(easing::ease_in_back(0.2) < 0.0);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 use toolchest::math::*;
 
 #[test]
@@ -36,3 +38,67 @@ fn test_percentile_and_numeric() {
     assert_eq!(gcd_u64(12, 18), 6);
     assert_eq!(lcm_u64(12, 18), 36);
 }
+
+#[test]
+fn test_quantiles_histogram_bucketize() {
+    let data = [5.0, 1.0, f64::NAN, 3.0, 2.0, 4.0];
+    assert_eq!(
+        quantiles(&data, &[0.0, 0.5, 1.0, 2.0]),
+        [1.0, 3.0, 5.0, 5.0]
+    );
+    let mut copy = vec![5.0, 1.0, 3.0, 2.0, 4.0];
+    assert_eq!(quantiles(&copy, &[0.9])[0], percentile(&mut copy, 90.0));
+    assert_eq!(quantiles(&[], &[0.5, 0.9]), [0.0, 0.0]);
+
+    let h = histogram(&[0.0, 1.0, 2.0, 3.0, f64::INFINITY, 4.0], 4);
+    assert_eq!(
+        h,
+        vec![(0.0..1.0, 1), (1.0..2.0, 1), (2.0..3.0, 1), (3.0..4.0, 2)]
+    );
+    assert_eq!(h.iter().map(|(_, c)| c).sum::<usize>(), 5);
+    assert_eq!(histogram(&[7.0, 7.0], 3), vec![(7.0..7.0, 2)]);
+    assert!(histogram(&[1.0], 0).is_empty());
+    assert!(histogram(&[f64::NAN], 3).is_empty());
+
+    let bounds = [1.0, 2.0, 2.0, 3.0];
+    assert_eq!(bucketize(0.5, &bounds), 0);
+    assert_eq!(bucketize(2.0, &bounds), 3);
+    assert_eq!(bucketize(3.0, &bounds), 4);
+    assert_eq!(bucketize(f64::NAN, &bounds), 0);
+    assert_eq!(bucketize(1.0, &[]), 0);
+}
+
+#[test]
+fn test_running_statistics_matches_slice_functions() {
+    let values: Vec<f64> = (0..1000)
+        .map(|i| ((i * 37) % 101) as f64 * 0.5 + 1e6)
+        .collect();
+    let stats: Running = values.iter().copied().collect();
+    assert_eq!(stats.count(), 1000);
+    assert!((stats.mean() - mean(&values)).abs() < 1e-6);
+    let exact: f64 = values
+        .iter()
+        .map(|v| (v - mean(&values)).powi(2))
+        .sum::<f64>()
+        / 1000.0;
+    assert!((stats.variance() - exact).abs() < 1e-6);
+    assert!((stats.sample_variance() - exact * 1000.0 / 999.0).abs() < 1e-6);
+    assert_eq!(stats.min(), Some(1e6));
+    assert_eq!(stats.max(), Some(1e6 + 50.0));
+
+    let mut merged = Running::new();
+    for chunk in values.chunks(137) {
+        merged.merge(&chunk.iter().copied().collect());
+    }
+    merged.merge(&Running::new());
+    assert_eq!(merged.count(), stats.count());
+    assert!((merged.mean() - stats.mean()).abs() < 1e-6);
+    assert!((merged.variance() - stats.variance()).abs() < 1e-6);
+    assert_eq!((merged.min(), merged.max()), (stats.min(), stats.max()));
+
+    let empty = Running::new();
+    assert_eq!(
+        (empty.mean(), empty.variance(), empty.min()),
+        (0.0, 0.0, None)
+    );
+}
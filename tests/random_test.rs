@@ -3,11 +3,163 @@ use toolchest::random::*;
 #[test]
 fn test_random_basics() {
     let x = random_range(0, 100);
-    assert!(0 <= x && x < 100);
-    let b = random_bool(0.5);
-    assert!(b == true || b == false);
+    assert!((0..100).contains(&x));
+    let _ = random_bool(0.5);
     let c = random_choice(&[1, 2, 3]).copied();
     assert!(c.is_some());
     let u = uuid_v4();
     assert_eq!(u.len(), 36);
 }
+
+fn sample_mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+#[test]
+fn test_rng_seeded_reproducible() {
+    let mut a = Rng::seeded(123);
+    let mut b = Rng::seeded(123);
+    for _ in 0..10 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+    assert_ne!(Rng::seeded(1).next_u64(), Rng::seeded(2).next_u64());
+    let mut r = Rng::seeded(9);
+    for _ in 0..1000 {
+        let x = r.range(-3, 4);
+        assert!((-3..4).contains(&x));
+    }
+}
+
+#[test]
+fn test_distributions_means() {
+    let mut rng = Rng::seeded(2024);
+    let n = 20_000;
+    let normals: Vec<f64> = (0..n).map(|_| rng.normal(5.0, 2.0)).collect();
+    assert!((sample_mean(&normals) - 5.0).abs() < 0.1);
+    let exps: Vec<f64> = (0..n).map(|_| rng.exponential(4.0)).collect();
+    assert!(exps.iter().all(|&x| x >= 0.0));
+    assert!((sample_mean(&exps) - 0.25).abs() < 0.02);
+    for lambda in [3.0, 100.0] {
+        let counts: Vec<f64> = (0..n).map(|_| rng.poisson(lambda) as f64).collect();
+        assert!((sample_mean(&counts) - lambda).abs() < lambda * 0.03);
+    }
+    let tri: Vec<f64> = (0..n).map(|_| rng.triangular(0.0, 3.0, 6.0)).collect();
+    assert!(tri.iter().all(|x| (0.0..=6.0).contains(x)));
+    assert!((sample_mean(&tri) - 3.0).abs() < 0.1);
+    assert_eq!(rng.triangular(2.0, 2.0, 2.0), 2.0);
+}
+
+#[test]
+#[should_panic]
+fn test_exponential_rejects_zero_rate() {
+    exponential(0.0);
+}
+
+#[test]
+fn test_uuid_v4_unique_and_versioned() {
+    let a = uuid_v4();
+    let b = uuid_v4();
+    assert_ne!(a, b);
+    assert_eq!(&a[14..15], "4");
+    assert!(matches!(&a[19..20], "8" | "9" | "a" | "b"));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn test_secure_bytes_and_token() {
+    assert!(secure_bytes(0).is_empty());
+    let a = secure_bytes(32);
+    assert_eq!(a.len(), 32);
+    assert_ne!(a, secure_bytes(32));
+    let t = secure_token(100);
+    assert_eq!(t.len(), 100);
+    assert!(t.bytes().all(|b| b.is_ascii_alphanumeric()));
+    assert_eq!(secure_token(0), "");
+}
+
+#[test]
+fn test_random_strings() {
+    let mut a = Rng::seeded(42);
+    let mut b = Rng::seeded(42);
+    assert_eq!(a.alphanumeric(16), b.alphanumeric(16));
+    assert_eq!(a.hex(10), b.hex(10));
+    let words = ["alpha", "bravo", "charlie"];
+    assert_eq!(a.passphrase(4, &words), b.passphrase(4, &words));
+
+    let s = random_string_with(50, "äö");
+    assert_eq!(s.chars().count(), 50);
+    assert!(s.chars().all(|c| c == 'ä' || c == 'ö'));
+    assert_eq!(random_string_with(0, "x"), "");
+    assert!(random_hex(32)
+        .chars()
+        .all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+    assert_eq!(random_alphanumeric(7).len(), 7);
+    let p = random_passphrase(5, &words);
+    assert!(p.split('-').all(|w| words.contains(&w)));
+    assert_eq!(random_passphrase(0, &words), "");
+    assert_eq!(toolchest::strings::extra::random_string(9).len(), 9);
+}
+
+#[test]
+#[should_panic(expected = "charset must not be empty")]
+fn test_random_string_empty_charset_panics() {
+    random_string_with(3, "");
+}
+
+#[test]
+fn test_random_temporal() {
+    use std::time::{Duration, Instant};
+    use toolchest::time::Date;
+
+    let mut rng = Rng::seeded(11);
+    let (lo, hi) = (Duration::from_millis(5), Duration::from_millis(6));
+    for _ in 0..100 {
+        let d = rng.duration(lo, hi);
+        assert!(d >= lo && d < hi);
+    }
+    assert_eq!(rng.duration(hi, lo), hi);
+    assert_eq!(rng.duration(lo, lo), lo);
+    // Spans wider than u64 nanoseconds still work
+    let huge = rng.duration(Duration::ZERO, Duration::MAX);
+    assert!(huge < Duration::MAX);
+
+    let base = Instant::now();
+    let t = rng.instant_within(base, Duration::from_secs(1));
+    assert!(t >= base && t < base + Duration::from_secs(1));
+    assert_eq!(rng.instant_within(base, Duration::ZERO), base);
+    // Offsets past what `Instant` can hold fall back to the base
+    for _ in 0..8 {
+        assert!(rng.instant_within(base, Duration::MAX) >= base);
+    }
+    assert!(random_instant_within(Duration::MAX) >= base);
+
+    let ts = rng.unix_timestamp(-100..100);
+    assert!((-100..100).contains(&ts));
+
+    let start = Date::new(2024, 2, 27).unwrap();
+    let end = Date::new(2024, 3, 2).unwrap();
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..200 {
+        let d = rng.date_between(start, end);
+        assert!(d >= start && d < end);
+        seen.insert(d);
+    }
+    assert_eq!(seen.len(), 4);
+    assert_eq!(
+        Rng::seeded(5).date_between(start, end),
+        Rng::seeded(5).date_between(start, end)
+    );
+}
+
+#[test]
+fn test_random_permutation() {
+    let mut p = random_permutation(50);
+    p.sort_unstable();
+    assert_eq!(p, (0..50).collect::<Vec<_>>());
+    assert!(random_permutation(0).is_empty());
+    assert_eq!(
+        Rng::seeded(4).permutation(10),
+        Rng::seeded(4).permutation(10)
+    );
+    assert_ne!(Rng::seeded(4).permutation(10), (0..10).collect::<Vec<_>>());
+}
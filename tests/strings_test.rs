@@ -20,9 +20,40 @@ fn test_escape_and_words() {
     assert_eq!(words::word_count("one two three"), 3);
 }
 
+#[test]
+fn test_escape_formats() {
+    let raw = "line1\n\t\"quoted\" \\ \u{7f} é";
+    assert_eq!(
+        escape::unescape_json_string(&escape::escape_json_string(raw)).unwrap(),
+        raw
+    );
+    assert_eq!(escape::unescape_json_string(r"\u00"), None);
+    assert_eq!(escape::unescape_json_string(r"\ud83d"), None);
+    assert_eq!(escape::escape_csv_field("a\nb"), "\"a\nb\"");
+    assert_eq!(escape::escape_xml("\"&\""), "&quot;&amp;&quot;");
+    assert_eq!(escape::escape_sql_string("it's"), "it''s");
+    assert_eq!(
+        escape::unescape_html("&amp;lt; &#169; &bogus; &"),
+        "&lt; © &bogus; &"
+    );
+}
+
 #[test]
 fn test_extra_strings() {
     assert_eq!(slugify("Hello, World!"), "hello-world");
+    assert_eq!(slugify("  Crème -- Brûlée_ "), "creme-brulee");
+    let opts = SlugOptions {
+        separator: '.',
+        lowercase: false,
+        max_length: Some(9),
+        ..SlugOptions::default()
+    };
+    assert_eq!(slugify_with("Ærø Über Alles", &opts), "AEro.Uber");
+    let opts = SlugOptions {
+        max_length: Some(4),
+        ..SlugOptions::default()
+    };
+    assert_eq!(slugify_with("abcdefgh ij", &opts), "abcd");
     assert_eq!(pluralize("box"), "boxes");
     assert_eq!(singularize("boxes"), "box");
     assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
@@ -71,4 +102,452 @@ proptest! {
         let result = truncate(&s, max_len);
         assert!(result.len() <= max_len.max(3));
     }
+
+    #[test]
+    fn rle_roundtrips(s in "[ab1\\\\é]{0,40}") {
+        prop_assert_eq!(rle_decode(&rle_encode(&s)), Some(s));
+    }
+}
+
+#[test]
+fn test_rle() {
+    assert_eq!(rle_encode(""), "");
+    assert_eq!(rle_encode("abc"), "abc");
+    assert_eq!(rle_encode("ééé  "), "3é2 ");
+    assert_eq!(rle_encode(&"z".repeat(12)), "12z");
+    assert_eq!(rle_encode("\\\\00"), "2\\\\2\\0");
+    assert_eq!(rle_decode("12z").unwrap(), "z".repeat(12));
+    assert_eq!(rle_decode("3\\1"), Some("111".to_string()));
+    assert_eq!(rle_decode("99999999999999999a"), None);
+    assert_eq!(rle_decode(&format!("{}é", usize::MAX / 2 + 1)), None);
+    assert_eq!(rle_decode("a\\"), None);
+    assert_eq!(rle_decode("0a"), None);
+    assert_eq!(rle_decode("99999999999999999999999a"), None);
+}
+
+#[test]
+fn test_natural_ordering() {
+    use std::cmp::Ordering;
+    use toolchest::strings::{natural_cmp, natural_sort};
+    let mut v = vec![
+        "img12.png",
+        "img10.png",
+        "IMG2.png",
+        "img2.png",
+        "img02.png",
+        "img1.png",
+    ];
+    natural_sort(&mut v);
+    assert_eq!(
+        v,
+        vec![
+            "IMG2.png",
+            "img1.png",
+            "img2.png",
+            "img02.png",
+            "img10.png",
+            "img12.png"
+        ]
+    );
+    assert_eq!(natural_cmp("a", "a1"), Ordering::Less);
+    assert_eq!(
+        natural_cmp("x99999999999999999999999", "x100000000000000000000000"),
+        Ordering::Less
+    );
+    assert_eq!(natural_cmp("é2", "é10"), Ordering::Less);
+    assert_eq!(natural_cmp("same", "same"), Ordering::Equal);
+}
+
+#[test]
+fn test_wrap_opts() {
+    use toolchest::strings::extra::wrap;
+    use toolchest::strings::{display_width, wrap_opts, WrapOptions};
+    assert_eq!(wrap("héllo wörld", 11), "héllo wörld");
+    assert_eq!(wrap("a verylongword b", 4), "a\nverylongword\nb");
+
+    let opts = WrapOptions::new(4);
+    assert_eq!(
+        wrap_opts("a verylongword b", &opts),
+        "a ve\nrylo\nngwo\nrd b"
+    );
+    let hyph = WrapOptions {
+        hyphenate: true,
+        ..WrapOptions::new(5)
+    };
+    assert_eq!(wrap_opts("abcdefghij", &hyph), "abcd-\nefgh-\nij");
+
+    let text = "first para here\n\n    indented line wraps";
+    let kept = WrapOptions {
+        preserve_indent: true,
+        ..WrapOptions::new(14)
+    };
+    assert_eq!(
+        wrap_opts(text, &kept),
+        "first para\nhere\n\n    indented\n    line wraps"
+    );
+    let collapsed = WrapOptions {
+        preserve_newlines: false,
+        initial_indent: "* ".into(),
+        subsequent_indent: "  ".into(),
+        ..WrapOptions::new(12)
+    };
+    assert_eq!(
+        wrap_opts("one two\nthree four", &collapsed),
+        "* one two\n  three four"
+    );
+    // A character wider than the line still makes progress
+    assert_eq!(wrap_opts("abc", &WrapOptions::new(0)), "a\nb\nc");
+    assert_eq!(display_width(""), 0);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_wrap_east_asian_width() {
+    use toolchest::strings::{display_width, wrap_opts, WrapOptions};
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("e\u{301}"), 1);
+    assert_eq!(
+        wrap_opts("日本語テキスト", &WrapOptions::new(6)),
+        "日本語\nテキス\nト"
+    );
+}
+
+#[test]
+fn test_inflection_tables() {
+    use toolchest::strings::{pluralize, pluralize_n, register_irregular, singularize};
+    for (one, many) in [
+        ("person", "people"),
+        ("leaf", "leaves"),
+        ("bus", "buses"),
+        ("day", "days"),
+        ("Category", "Categories"),
+        ("BOX", "BOXES"),
+        ("fish", "fish"),
+        ("analysis", "analyses"),
+    ] {
+        assert_eq!(pluralize(one), many, "{one}");
+        assert_eq!(singularize(many), one, "{many}");
+    }
+    assert_eq!(pluralize("people"), "people");
+    assert_eq!(singularize("person"), "person");
+    assert_eq!(singularize("status"), "status");
+    assert_eq!(pluralize_n("mouse", 2), "2 mice");
+    assert_eq!(pluralize_n("mouse", 1.0), "1 mouse");
+    register_irregular("cow", "kine");
+    assert_eq!(pluralize_n("Cow", 5u64), "5 Kine");
+}
+
+#[test]
+fn test_truncate_chars_and_positions() {
+    use toolchest::strings::extra::ellipsis_middle;
+    assert_eq!(truncate_chars("héllo", 4), "h...");
+    assert_eq!(truncate_chars("héllo", 2), "hé");
+    assert_eq!(truncate_chars("", 0), "");
+    let opts = TruncateOptions {
+        position: EllipsisPosition::Start,
+        ..TruncateOptions::new(6)
+    };
+    assert_eq!(truncate_opts("abcdefgh", &opts), "...fgh");
+    let opts = TruncateOptions {
+        unit: TruncateUnit::Width,
+        ellipsis: "~".into(),
+        ..TruncateOptions::new(4)
+    };
+    assert_eq!(truncate_opts("abcdef", &opts), "abc~");
+    assert_eq!(ellipsis_middle("abcdefghij", 7), "ab...ij");
+    assert_eq!(ellipsis_middle("abcdefghij", 8), "abc...ij");
+    assert_eq!(ellipsis_middle("ääääääää", 5), "ä...ä");
+    assert_eq!(ellipsis_middle("abc", 2), "abc");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_truncate_graphemes_and_width() {
+    let flags = "\u{1f1e9}\u{1f1ea}\u{1f1eb}\u{1f1f7}\u{1f1ec}\u{1f1e7}\u{1f1ee}\u{1f1f9}";
+    assert_eq!(truncate_graphemes(flags, 4), flags);
+    assert_eq!(truncate_graphemes(flags, 3), "...");
+    let s = "e\u{301}a\u{301}bcdef";
+    assert_eq!(truncate_graphemes(s, 5), "e\u{301}a\u{301}...");
+    let opts = TruncateOptions {
+        unit: TruncateUnit::Width,
+        ..TruncateOptions::new(7)
+    };
+    // each CJK character is two columns wide
+    assert_eq!(
+        truncate_opts("\u{4e2d}\u{6587}\u{5b57}\u{7b26}", &opts),
+        "\u{4e2d}\u{6587}..."
+    );
+}
+
+proptest! {
+    #[test]
+    fn truncate_chars_never_exceeds_max(s in "\\PC*", max in 0..40usize) {
+        prop_assert!(truncate_chars(&s, max).chars().count() <= max);
+    }
+}
+
+#[test]
+fn test_ansi_helpers() {
+    use toolchest::strings::ansi::*;
+    let s = "\x1b[1m\x1b[38;5;208mwarn\x1b[0m: disk \x1b(Bfull";
+    assert_eq!(strip_ansi(s), "warn: disk full");
+    assert_eq!(visible_width(s), 15);
+    assert_eq!(strip_ansi("trailing \x1b"), "trailing ");
+    assert_eq!(strip_ansi("\x1b]0;title\x1b\\text"), "text");
+    assert_eq!(strip_ansi("h\u{e9}\x1b[2Kllo"), "h\u{e9}llo");
+
+    let t = truncate_ansi(s, 7);
+    assert_eq!(strip_ansi(&t), "warn...");
+    assert!(t.ends_with("\x1b[0m"));
+    assert_eq!(truncate_ansi("plain text", 2), "pl");
+    assert_eq!(truncate_ansi("plain text", 5), "pl...");
+
+    let cells: Vec<String> = ["\x1b[32mok\x1b[0m", "fail"]
+        .iter()
+        .map(|c| pad_start_ansi(c, 6, '.'))
+        .collect();
+    assert!(cells.iter().all(|c| visible_width(c) == 6));
+    assert_eq!(strip_ansi(&cells[0]), "....ok");
+}
+
+#[test]
+fn test_confusables() {
+    assert!(is_confusable("apple", "\u{430}pple"));
+    assert!(is_confusable("ALICE", "\u{391}L\u{399}CE"));
+    assert!(is_confusable("bob", "b\u{200b}ob"));
+    assert!(is_confusable("l00k", "lOOk"));
+    assert!(is_confusable("vvorld", "world"));
+    assert!(!is_confusable("apple", "Apple"));
+    assert!(!is_confusable("alice", "alicia"));
+    assert_eq!(confusable_skeleton("m1"), "rnl");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_confusables_fold_compatibility_forms() {
+    assert!(is_confusable("admin", "\u{ff41}dmin")); // fullwidth 'a'
+    assert!(is_confusable("office", "o\u{fb03}ce")); // "ffi" ligature
+}
+
+#[test]
+fn test_number_formatting() {
+    use toolchest::strings::{format_float, format_int_grouped, format_int_grouped_with, ordinal};
+    assert_eq!(format_int_grouped(0), "0");
+    assert_eq!(format_int_grouped(100), "100");
+    assert_eq!(format_int_grouped(1000), "1,000");
+    assert_eq!(format_int_grouped(-123456), "-123,456");
+    assert_eq!(format_int_grouped(i64::MIN), "-9,223,372,036,854,775,808");
+    assert_eq!(format_int_grouped_with(12345678, "'"), "12'345'678");
+    assert_eq!(format_int_grouped_with(1234, ""), "1234");
+
+    assert_eq!(format_float(1234.5, 2, ","), "1,234.50");
+    assert_eq!(format_float(-0.25, 1, ","), "-0.2");
+    assert_eq!(format_float(999999.999, 2, ","), "1,000,000.00");
+    assert_eq!(format_float(f64::NAN, 2, ","), "NaN");
+    assert_eq!(format_float(f64::NEG_INFINITY, 2, ","), "-inf");
+
+    use toolchest::strings::format_bytes;
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.0 KiB");
+    assert_eq!(format_bytes(10 * 1024 * 1024 * 1024), "10.0 GiB");
+    assert_eq!(format_bytes((1 << 40) + (1 << 39)), "1.5 TiB");
+
+    let got: Vec<String> = [0, 1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 111, 1001, -1]
+        .iter()
+        .map(|&n| ordinal(n))
+        .collect();
+    assert_eq!(
+        got,
+        [
+            "0th", "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd",
+            "111th", "1001st", "-1st"
+        ]
+    );
+}
+
+#[test]
+fn test_acronym_aware_case_conversion() {
+    use toolchest::strings::case::{split_words, split_words_with};
+    assert_eq!(split_words("HTTP2Server"), ["HTTP2", "Server"]);
+    assert_eq!(
+        split_words("getHTTPResponseCode"),
+        ["get", "HTTP", "Response", "Code"]
+    );
+    assert_eq!(split_words("ÉtéChaud"), ["Été", "Chaud"]);
+    assert!(split_words("__").is_empty());
+
+    assert_eq!(to_camel_case("XMLHttpRequest"), "xmlHttpRequest");
+    assert_eq!(to_pascal_case("v2Response"), "V2Response");
+    assert_eq!(to_kebab_case("userID"), "user-id");
+    assert_eq!(to_snake_case("already_snake"), "already_snake");
+    assert_eq!(to_screaming_snake_case("api-key"), "API_KEY");
+    assert_eq!(to_train_case("x-forwarded-for"), "X-Forwarded-For");
+    assert_eq!(to_dot_case("Log Level"), "log.level");
+
+    let opts = CaseOptions::with_acronyms(&["ID", "IPv6", "OAuth"]);
+    assert_eq!(
+        split_words_with("OAuthTokenIPv6", &opts),
+        ["OAuth", "Token", "IPv6"]
+    );
+    assert_eq!(convert_case("user_id", Case::Pascal, &opts), "UserID");
+    assert_eq!(
+        convert_case("oauth_token", Case::Camel, &opts),
+        "oauthToken"
+    );
+    assert_eq!(
+        convert_case("token_oauth", Case::Camel, &opts),
+        "tokenOAuth"
+    );
+    assert_eq!(convert_case("IPv6Addr", Case::Train, &opts), "IPv6-Addr");
+    assert_eq!(
+        convert_case("IPv6Addr", Case::ScreamingSnake, &opts),
+        "IPV6_ADDR"
+    );
+}
+
+#[test]
+fn test_substring_extraction() {
+    assert_eq!(between("f(x, y)", "(", ")"), Some("x, y"));
+    assert_eq!(between("[[nested]]", "[[", "]]"), Some("nested"));
+    assert_eq!(between("start only [", "[", "]"), None);
+    assert_eq!(between("] before [", "[", "]"), None);
+    assert_eq!(between("ünï[cödé]", "[", "]"), Some("cödé"));
+    assert_eq!(between_all("<b>1</b><b>2</b>", "<b>", "</b>"), ["1", "2"]);
+    assert_eq!(between_all("||", "|", "|"), [""]);
+    assert!(between_all("abc", "", "").is_empty());
+    assert!(between_all("a}b}", "", "}").is_empty());
+
+    assert_eq!(before("k=v=w", "="), Some("k"));
+    assert_eq!(after("k=v=w", "="), Some("v=w"));
+    assert_eq!(before_last("k=v=w", "="), Some("k=v"));
+    assert_eq!(after_last("k=v=w", "="), Some("w"));
+    assert_eq!(after("trailing=", "="), Some(""));
+    assert_eq!(before_last("none", "="), None);
+    assert_eq!(after_last("none", "="), None);
+}
+
+#[test]
+fn test_multiline_helpers() {
+    assert_eq!(extra::dedent_auto("  a\n    b\n  c"), "a\n  b\nc");
+    assert_eq!(extra::dedent_auto("\ta\n\t\tb\n"), "a\n\tb\n");
+    // Mixed tabs/spaces share only the literal common prefix
+    assert_eq!(extra::dedent_auto("  \ta\n  b"), "\ta\nb");
+    assert_eq!(extra::dedent_auto("  a\n   \n  b\r\n"), "a\n\nb\r\n");
+    assert_eq!(extra::dedent_auto("a\n  b"), "a\n  b");
+    assert_eq!(extra::dedent_auto(""), "");
+
+    assert_eq!(
+        extra::trim_margin("  |one\n  |  two\nthree", '|'),
+        "one\n  two\nthree"
+    );
+    assert_eq!(extra::trim_margin("\n    > quoted\n    ", '>'), " quoted");
+    assert_eq!(extra::trim_margin("", '|'), "");
+
+    assert_eq!(
+        extra::squeeze_blank_lines("a\n\n\n\nb\n\n", 2),
+        "a\n\n\nb\n\n"
+    );
+    assert_eq!(extra::squeeze_blank_lines("a\n \n\t\nb", 1), "a\n \nb");
+    assert_eq!(extra::squeeze_blank_lines("\n\na", 0), "a");
+}
+
+#[test]
+fn test_phonetic_codes() {
+    for (name, code) in [
+        ("Rubin", "R150"),
+        ("Ashcraft", "A261"),
+        ("Ashcroft", "A261"),
+        ("Pfister", "P236"),
+        ("Honeyman", "H555"),
+        ("Jackson", "J250"),
+        ("Müller", "M460"),
+        ("O'Brien", "O165"),
+        ("", ""),
+        ("123", ""),
+    ] {
+        assert_eq!(soundex(name), code, "{name}");
+    }
+    for (name, code) in [
+        ("Knight", "NT"),
+        ("Xavier", "SFR"),
+        ("Thomas", "0MS"),
+        ("Schmidt", "SKMTT"),
+        ("Science", "SNS"),
+        ("Judge", "JJ"),
+        ("Whistle", "WSTL"),
+        ("Aeon", "EN"),
+        ("Lamb", "LM"),
+        ("Nation", "NXN"),
+        ("Watch", "WX"),
+        ("Box", "BKS"),
+        ("", ""),
+    ] {
+        assert_eq!(metaphone(name), code, "{name}");
+    }
+    assert_eq!(metaphone("Stephen"), metaphone("Steven"));
+}
+
+#[test]
+fn test_title_case_styles() {
+    assert_eq!(
+        to_title_case("the lord of the rings"),
+        "The Lord Of The Rings"
+    );
+    // Leading punctuation is left alone, as before the title styles existed
+    assert_eq!(to_title_case("  \"hello\"   WORLD "), "\"hello\" World");
+    assert_eq!(to_title_case("(foo) bar"), "(foo) Bar");
+
+    let ap = TitleCaseOptions::new(TitleStyle::Ap);
+    let chicago = TitleCaseOptions::new(TitleStyle::Chicago);
+    assert_eq!(to_title_case_with("(foo) bar", &ap), "(Foo) Bar");
+    assert_eq!(
+        to_title_case_with("the lord of the rings", &ap),
+        "The Lord of the Rings"
+    );
+    assert_eq!(
+        to_title_case_with("gone with the wind", &ap),
+        "Gone With the Wind"
+    );
+    assert_eq!(
+        to_title_case_with("gone with the wind", &chicago),
+        "Gone with the Wind"
+    );
+    assert_eq!(
+        to_title_case_with("war and peace", &chicago),
+        "War and Peace"
+    );
+    assert_eq!(
+        to_title_case_with("so it goes yet again", &ap),
+        "So It Goes yet Again"
+    );
+    assert_eq!(
+        to_title_case_with("so it goes yet again", &chicago),
+        "So It Goes Yet Again"
+    );
+    // Boundaries: first word, last word, and after a colon
+    assert_eq!(
+        to_title_case_with("of mice and men", &chicago),
+        "Of Mice and Men"
+    );
+    assert_eq!(
+        to_title_case_with("star wars: a new hope", &ap),
+        "Star Wars: A New Hope"
+    );
+    assert_eq!(
+        to_title_case_with("what are you looking at", &ap),
+        "What Are You Looking At"
+    );
+    assert_eq!(
+        to_title_case_with("a walk (in the park)", &ap),
+        "A Walk (in the Park)"
+    );
+
+    let opts = TitleCaseOptions::new(TitleStyle::Ap).with_exceptions(&["iPhone", "of", "API"]);
+    assert_eq!(
+        to_title_case_with("iphone tips of the api WORLD", &opts),
+        "iPhone Tips of the API World"
+    );
+    assert_eq!(to_title_case_with("of course", &opts), "of Course");
+    assert_eq!(to_title_case_with("", &opts), "");
 }
@@ -13,7 +13,7 @@ fn test_elapsed_and_deadline() {
     let (v, d) = elapsed(|| 42);
     assert_eq!(v, 42);
     assert!(d <= Duration::from_secs(1));
-    assert_eq!(deadline(Instant::now() - Duration::from_secs(1)), true);
+    assert!(deadline(Instant::now() - Duration::from_secs(1)));
 }
 
 #[test]
@@ -22,3 +22,404 @@ fn test_backoff_iter() {
     assert_eq!(it.next().unwrap(), Duration::from_millis(10));
     assert_eq!(it.next().unwrap(), Duration::from_millis(20));
 }
+
+#[test]
+fn test_backoff_iter_builders() {
+    let d: Vec<_> = BackoffIter::new(Duration::from_millis(10))
+        .with_factor(1.5)
+        .with_max(Duration::from_millis(30))
+        .take_attempts(5)
+        .collect();
+    let expected = [10_000, 15_000, 22_500, 30_000, 30_000].map(Duration::from_micros);
+    assert_eq!(d, expected);
+    let full = BackoffIter::new(Duration::from_millis(8)).with_jitter(JitterMode::Full);
+    assert!(full.take(20).all(|x| x <= Duration::from_millis(8 << 19)));
+    let seeded = |seed| {
+        BackoffIter::new(Duration::from_millis(8))
+            .with_jitter(JitterMode::Equal)
+            .with_seed(seed)
+            .take(10)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(seeded(1), seeded(1));
+    assert_ne!(seeded(1), seeded(2));
+    let mut big = BackoffIter::new(Duration::MAX);
+    assert_eq!(big.nth(3), Some(Duration::MAX));
+}
+
+#[test]
+fn test_timings_registry() {
+    use toolchest::time::metrics::Timings;
+    let timings = Timings::new();
+    for ms in 1..=20 {
+        timings.record("q", Duration::from_millis(ms));
+    }
+    let s = timings.stats("q").unwrap();
+    assert_eq!(s.count, 20);
+    assert_eq!(s.min, Duration::from_millis(1));
+    assert_eq!(s.max, Duration::from_millis(20));
+    assert_eq!(s.p95, Duration::from_millis(19));
+    assert!(timings.stats("missing").is_none());
+
+    let v = toolchest::timed!(timings, "work", 6 * 7);
+    assert_eq!(v, 42);
+    assert_eq!(timings.labels(), vec!["q", "work"]);
+    timings.reset();
+    assert!(timings.snapshot().is_empty());
+}
+
+#[test]
+fn test_rfc3339_round_trip() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    assert_eq!(format_rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+    let t = UNIX_EPOCH + Duration::new(951_782_400, 123_456_000); // 2000-02-29
+    let s = format_rfc3339(t);
+    assert_eq!(s, "2000-02-29T00:00:00.123456Z");
+    assert_eq!(parse_rfc3339(&s), Some(t));
+    let nano = UNIX_EPOCH + Duration::new(1, 7);
+    assert_eq!(format_rfc3339(nano), "1970-01-01T00:00:01.000000007Z");
+    assert_eq!(parse_rfc3339("1970-01-01t00:00:01.0000000071z"), Some(nano));
+
+    let before = UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(format_rfc3339(before), "1969-12-31T23:59:58.500Z");
+    assert_eq!(parse_rfc3339("1969-12-31T23:59:58.5Z"), Some(before));
+
+    let offset = parse_rfc3339("2024-01-01 05:30:00+05:30").unwrap();
+    assert_eq!(format_rfc3339(offset), "2024-01-01T00:00:00Z");
+    assert_eq!(
+        format_rfc3339_with_offset(offset, 330),
+        "2024-01-01T05:30:00+05:30"
+    );
+    assert_eq!(
+        format_rfc3339_with_offset(offset, -60),
+        "2023-12-31T23:00:00-01:00"
+    );
+
+    for bad in [
+        "",
+        "2024-13-01T00:00:00Z",
+        "2024-01-01T24:00:00Z",
+        "2024-01-01T00:00:00",
+        "2024-01-01T00:00:00.Z",
+        "2024-01-01T00:00:00+0530",
+        "2024-01-01X00:00:00Z",
+        "2100-02-29T00:00:00Z",
+    ] {
+        assert!(parse_rfc3339(bad).is_none(), "{bad}");
+    }
+
+    let now = SystemTime::now();
+    let secs = now.duration_since(UNIX_EPOCH).unwrap().as_secs();
+    assert!(unix_timestamp() >= secs);
+    assert!(unix_timestamp_millis() >= secs * 1000);
+    assert_eq!(
+        from_unix_timestamp(86_400),
+        Some(UNIX_EPOCH + Duration::from_secs(86_400))
+    );
+    // Extremes are `None` where `SystemTime` is too narrow, but never panic
+    if let Some(t) = from_unix_timestamp(i64::MAX) {
+        assert_eq!(
+            t.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            i64::MAX as u64
+        );
+    }
+    if let Some(t) = from_unix_timestamp(i64::MIN) {
+        assert_eq!(UNIX_EPOCH.duration_since(t).unwrap().as_secs(), 1 << 63);
+    }
+}
+
+#[test]
+fn test_timer_after_every_cancel() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc};
+
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+    Timer::after(Duration::from_millis(30), move || tx.send(2).unwrap());
+    let tx2 = {
+        let (tx2, rx2) = mpsc::channel();
+        Timer::after(Duration::from_millis(5), move || tx2.send(1).unwrap());
+        rx2
+    };
+    assert_eq!(tx2.recv_timeout(Duration::from_secs(2)), Ok(1));
+    assert_eq!(rx.recv_timeout(Duration::from_secs(2)), Ok(2));
+    assert!(start.elapsed() >= Duration::from_millis(30));
+
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    let c = Arc::clone(&cancelled);
+    let h = Timer::after(Duration::from_millis(20), move || {
+        c.fetch_add(1, Ordering::SeqCst);
+    });
+    h.cancel();
+    assert!(h.is_cancelled());
+
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let t = Arc::clone(&ticks);
+    let panicky = Timer::every(Duration::from_millis(2), move || {
+        if t.fetch_add(1, Ordering::SeqCst) == 0 {
+            panic!("first tick fails");
+        }
+    });
+    std::thread::sleep(Duration::from_millis(60));
+    panicky.cancel();
+    std::thread::sleep(Duration::from_millis(10));
+    let seen = ticks.load(Ordering::SeqCst);
+    assert!(seen >= 3, "{seen}");
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(ticks.load(Ordering::SeqCst), seen);
+    assert_eq!(cancelled.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_timer_duration_max_never_fires() {
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel();
+    let never = tx.clone();
+    let once = Timer::after(Duration::MAX, move || never.send("after").unwrap());
+    let never = tx.clone();
+    let every = Timer::every(Duration::MAX, move || never.send("every").unwrap());
+    Timer::after(Duration::from_millis(5), move || tx.send("soon").unwrap());
+    assert_eq!(rx.recv_timeout(Duration::from_secs(2)), Ok("soon"));
+    assert!(rx.recv_timeout(Duration::from_millis(30)).is_err());
+    assert!(!once.is_cancelled() && !every.is_cancelled());
+}
+
+#[test]
+fn test_rate_meter_and_throughput() {
+    let meter = RateMeter::new(Duration::from_millis(200));
+    assert_eq!(meter.rate(), 0.0);
+    for _ in 0..50 {
+        meter.tick();
+    }
+    std::thread::sleep(Duration::from_millis(50));
+    let r = meter.rate();
+    // 50 events in roughly 50ms (scheduler jitter allowed)
+    assert!(r > 50.0 && r <= 1000.0, "rate {r}");
+    std::thread::sleep(Duration::from_millis(250));
+    assert_eq!(meter.rate(), 0.0);
+    assert_eq!(meter.total(), 50);
+    meter.reset();
+    assert_eq!(meter.total(), 0);
+
+    let t = Throughput::new(Duration::from_secs(10));
+    assert_eq!(t.eta(100), None);
+    t.record(1000);
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(t.bytes_per_sec() > 0.0);
+    assert!(t.eta(1000).unwrap() > Duration::ZERO);
+    assert_eq!(t.total_bytes(), 1000);
+}
+
+#[test]
+fn test_budget() {
+    use toolchest::time::Budget;
+    let b = Budget::new(Duration::from_millis(200));
+    assert_eq!(b.total(), Duration::from_millis(200));
+    assert!(!b.expired());
+    assert!(b.remaining() <= Duration::from_millis(200));
+    let half = b.child(0.5);
+    assert!(half.total() <= Duration::from_millis(100));
+    assert!(half.deadline() <= b.deadline());
+    assert!(b.child(f64::NAN).expired());
+    assert!(b.child(-1.0).expired());
+
+    let done = Budget::new(Duration::ZERO);
+    assert!(done.expired());
+    assert_eq!(done.remaining(), Duration::ZERO);
+    assert!(!done.allows(Duration::ZERO));
+    assert!(done.child(1.0).expired());
+
+    let forever = Budget::new(Duration::MAX);
+    assert_eq!(forever.deadline(), None);
+    assert_eq!(forever.remaining(), Duration::MAX);
+    assert!(!forever.expired());
+    assert!(forever.allows(Duration::from_secs(3600)));
+    assert!(!forever.child(0.5).expired());
+    assert!(forever.child(0.0).expired());
+}
+
+#[test]
+fn test_calendar_business_days() {
+    let d = |y, m, day| Date::new(y, m, day).unwrap();
+    assert_eq!(d(1970, 1, 1).weekday(), Weekday::Thu);
+    assert_eq!(d(2000, 1, 1).weekday(), Weekday::Sat);
+    assert_eq!(d(1969, 12, 31).weekday(), Weekday::Wed);
+    assert_eq!(d(2024, 12, 31).add_days(1), d(2025, 1, 1));
+    assert_eq!(d(2024, 3, 1).add_days(-1), d(2024, 2, 29));
+    assert_eq!(d(2024, 1, 1).days_until(d(2025, 1, 1)), 366);
+    assert!(d(2024, 1, 1) < d(2024, 1, 2));
+    assert_eq!(d(5, 7, 9).to_string(), "0005-07-09");
+    assert!(Date::new(2024, 0, 1).is_none());
+
+    assert!(is_weekend(d(2024, 6, 1)));
+    assert!(!is_weekend(d(2024, 6, 3)));
+    let holidays = [d(2024, 12, 25), d(2024, 12, 26), d(2025, 1, 1)];
+    assert!(!is_business_day(d(2024, 12, 25), &holidays));
+    // Sat -> Mon
+    assert_eq!(next_business_day(d(2024, 6, 1), &[]), d(2024, 6, 3));
+    assert_eq!(
+        add_business_days(d(2024, 12, 24), 3, &holidays),
+        d(2024, 12, 31)
+    );
+    assert_eq!(
+        add_business_days(d(2024, 12, 24), 4, &holidays),
+        d(2025, 1, 2)
+    );
+    assert_eq!(
+        add_business_days(d(2025, 1, 2), -3, &holidays),
+        d(2024, 12, 27)
+    );
+    assert_eq!(add_business_days(d(2024, 6, 1), 0, &[]), d(2024, 6, 1));
+    assert_eq!(add_business_days(d(2024, 6, 1), -1, &[]), d(2024, 5, 31));
+}
+
+#[test]
+fn test_expiring_map() {
+    use toolchest::time::MockClock;
+    let clock = MockClock::new();
+    let map: ExpiringMap<String, u32> = ExpiringMap::new().with_clock(clock.clone());
+    assert_eq!(
+        map.insert_ttl("a".into(), 1, Duration::from_millis(30)),
+        None
+    );
+    assert_eq!(map.insert("b".into(), 2), None);
+    assert_eq!(map.insert_ttl("c".into(), 3, Duration::ZERO), None);
+    assert_eq!(map.get("a"), Some(1));
+    assert_eq!(map.get("c"), None);
+    assert_eq!(map.ttl_remaining("a"), Some(Duration::from_millis(30)));
+    assert_eq!(map.ttl_remaining("b"), None);
+    assert_eq!(map.len(), 2);
+
+    clock.advance(Duration::from_millis(30));
+    assert_eq!(map.get("a"), None);
+    assert_eq!(map.get("b"), Some(2));
+    // Replacing an expired entry reports no previous value
+    assert_eq!(map.insert_ttl("x".into(), 9, Duration::ZERO), None);
+    assert_eq!(
+        map.insert_ttl("x".into(), 10, Duration::from_secs(60)),
+        None
+    );
+    assert_eq!(
+        map.insert_ttl("x".into(), 11, Duration::from_secs(60)),
+        Some(10)
+    );
+    assert_eq!(map.remove("x"), Some(11));
+    assert_eq!(map.remove("x"), None);
+    assert_eq!(map.sweep(), 0);
+    // A TTL past the end of time never expires instead of panicking
+    assert_eq!(map.insert_ttl("forever".into(), 1, Duration::MAX), None);
+    clock.advance(Duration::from_secs(1 << 40));
+    assert_eq!(map.get("forever"), Some(1));
+    map.clear();
+    assert!(map.is_empty());
+
+    // The sweeper follows a clock set after it was started
+    let swept = ExpiringMap::new()
+        .with_sweeper(Duration::from_millis(5))
+        .with_clock(clock.clone());
+    swept.insert_ttl(1, (), Duration::from_secs(1));
+    swept.insert(2, ());
+    clock.advance(Duration::from_secs(1));
+    // Poll the raw entry count (`len` would sweep by itself)
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while format!("{swept:?}").contains("entries: 2") && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(swept.sweep(), 0); // background sweeper already removed it
+    assert!(swept.contains_key(&2));
+    drop(swept);
+}
+
+#[test]
+fn test_clock_format_and_parse() {
+    assert_eq!(format_clock(Duration::ZERO), "00:00:00");
+    assert_eq!(format_clock(Duration::from_millis(59_999)), "00:00:59");
+    let days = ClockOptions {
+        days: true,
+        millis: false,
+    };
+    assert_eq!(
+        format_clock_with(Duration::from_secs(86_399), &days),
+        "23:59:59"
+    );
+    assert_eq!(
+        format_clock_with(Duration::from_secs(2 * 86_400), &days),
+        "2d 00:00:00"
+    );
+    let millis = ClockOptions {
+        millis: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        format_clock_with(Duration::from_millis(90_061_007), &millis),
+        "25:01:01.007"
+    );
+
+    for secs in [0, 59, 3600, 86_399, 86_400, 1_000_000] {
+        let d = Duration::from_millis(secs * 1000 + 125);
+        let all = ClockOptions {
+            days: true,
+            millis: true,
+        };
+        assert_eq!(parse_clock(&format_clock_with(d, &all)), Some(d));
+        assert_eq!(parse_clock(&format_clock_with(d, &millis)), Some(d));
+    }
+    assert_eq!(parse_clock("0:7"), Some(Duration::from_secs(7)));
+    assert_eq!(parse_clock(" 90:00 "), Some(Duration::from_secs(5400)));
+    assert_eq!(
+        parse_clock("00:00:00.000000001"),
+        Some(Duration::from_nanos(1))
+    );
+    for bad in [
+        "",
+        "12",
+        "1:2:3:4",
+        "1:123",
+        "a:00",
+        "00:00.",
+        "1:00.1234567890",
+        "1d 1:00",
+        "1d 24:00:00",
+        "-1:00",
+    ] {
+        assert_eq!(parse_clock(bad), None, "{bad}");
+    }
+}
+
+#[test]
+fn test_mock_clock() {
+    let clock = MockClock::new();
+    let t0 = clock.now();
+    let other = clock.clone();
+    other.advance(Duration::from_millis(1500));
+    assert_eq!(clock.now() - t0, Duration::from_millis(1500));
+    assert!(SystemClock.now() <= Instant::now());
+
+    let sw = Stopwatch::start_with(clock.clone());
+    assert_eq!(sw.elapsed(), Duration::ZERO);
+    clock.advance(Duration::from_secs(3));
+    assert_eq!(sw.elapsed(), Duration::from_secs(3));
+}
+
+#[test]
+fn test_rate_meter_mock_clock() {
+    let clock = MockClock::new();
+    let meter = RateMeter::new(Duration::from_secs(10)).with_clock(clock.clone());
+    meter.tick_n(20);
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(meter.rate(), 10.0);
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(meter.rate(), 0.0);
+
+    let tp = Throughput::new(Duration::from_secs(10)).with_clock(clock.clone());
+    tp.record(4096);
+    clock.advance(Duration::from_secs(4));
+    assert_eq!(tp.bytes_per_sec(), 1024.0);
+
+    let slow = Throughput::new(Duration::from_secs(10)).with_clock(clock.clone());
+    slow.record(1);
+    clock.advance(Duration::from_secs(8));
+    assert_eq!(slow.eta(8), Some(Duration::from_secs(64)));
+    assert_eq!(slow.eta(u64::MAX), None);
+}
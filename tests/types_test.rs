@@ -11,6 +11,30 @@ fn test_is_empty() {
     assert!(is_empty::<HashMap<i32, i32>>(&map));
 }
 
+#[test]
+fn test_is_empty_more_types() {
+    use std::borrow::Cow;
+    use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+    use std::path::Path;
+
+    assert!(is_empty(&None::<i32>));
+    assert!(!is_empty(&Some(0)));
+    assert!(is_empty(&Err::<(), _>("e")));
+    assert!(!is_empty(&Ok::<_, ()>(())));
+    assert!(is_empty(&HashSet::<u8>::new()));
+    assert!(is_empty(&BTreeMap::<u8, u8>::new()));
+    assert!(!is_empty(&BTreeSet::from([1])));
+    assert!(is_empty(&VecDeque::<u8>::new()));
+    assert!(is_empty(&Cow::Borrowed("")));
+    assert!(is_empty(&[0u8; 0]));
+    assert!(!is_empty(&[1, 2]));
+    assert!(is_empty(&Path::new("")));
+    assert!(!is_empty(Path::new("/tmp")));
+    assert!(is_empty_iter(Vec::<u8>::new()));
+    assert!(is_blank("   "));
+    assert!(!is_blank(" a"));
+}
+
 #[test]
 fn test_types_extras() {
     assert_eq!(map_some_or(Some(2), 0, |x| x * 3), 6);
@@ -28,3 +52,199 @@ fn test_parse_and_default() {
     assert_eq!(parse_or_default::<i32>("x"), 0);
     assert_eq!(parse_or::<i32>("x", 7), 7);
 }
+
+#[test]
+fn test_numeric_casts() {
+    use toolchest::types::cast::*;
+    assert_eq!(to_u32_clamped(i64::MIN), 0);
+    assert_eq!(to_u32_clamped(42), 42);
+    assert_eq!(to_i32_clamped(i64::MIN), i32::MIN);
+    assert_eq!(to_i32_clamped(i64::MAX), i32::MAX);
+    assert_eq!(to_i32_checked(-7), Some(-7));
+    assert_eq!(to_i32_checked(i32::MAX as i64 + 1), None);
+    assert_eq!(usize_to_u32_saturating(usize::MAX), u32::MAX);
+    assert_eq!(usize_to_u32_saturating(9), 9);
+    assert_eq!(checked_cast::<u64, i8>(127), Some(127i8));
+    assert_eq!(checked_cast::<i32, u64>(-1), None);
+
+    assert_eq!(f64_to_i64(0.49, CastPolicy::Round), Some(0));
+    assert_eq!(f64_to_i64(-0.0, CastPolicy::Fail), Some(0));
+    assert_eq!(f64_to_i64(0.1, CastPolicy::Fail), None);
+    assert_eq!(f64_to_i64(f64::INFINITY, CastPolicy::Trunc), None);
+    assert_eq!(
+        f64_to_i64(-9_223_372_036_854_775_808.0, CastPolicy::Fail),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        f64_to_i64(9_223_372_036_854_775_808.0, CastPolicy::Fail),
+        None
+    );
+}
+
+#[test]
+fn test_option_result_adapters() {
+    let seen = std::cell::RefCell::new(Vec::new());
+    let r: Result<i32, String> = Err("bad".into());
+    assert_eq!(
+        result_to_option_logging(r, |e| seen.borrow_mut().push(e.clone())),
+        None
+    );
+    assert_eq!(
+        result_to_option_logging(Ok::<_, String>(5), |_| panic!()),
+        Some(5)
+    );
+    assert_eq!(seen.into_inner(), ["bad"]);
+    assert_eq!(ok_or_log(Ok::<_, &str>(1), "ctx"), Some(1));
+    assert_eq!(ok_or_log(Err::<i32, _>("boom"), "ctx"), None);
+
+    assert_eq!(some_or_else_lazy(None::<String>, String::new), "");
+    assert_eq!(transpose_vec::<i32, ()>(vec![]), Ok(vec![]));
+    let (ok, err) = partition_results::<i32, &str>(vec![Err("a"), Err("b")]);
+    assert!(ok.is_empty());
+    assert_eq!(err, ["a", "b"]);
+}
+
+#[test]
+fn test_unit_newtypes() {
+    let parts = [Bytes::kib(512), Bytes::kib(512), Bytes::new(100)];
+    let total: Bytes = parts.iter().sum();
+    assert_eq!(total.get(), 1024 * 1024 + 100);
+    assert_eq!(total.to_string(), "1.0 MiB");
+    assert_eq!(Bytes::new(999).to_string(), "999 B");
+    assert_eq!(Bytes::gib(u64::MAX), Bytes::new(u64::MAX));
+
+    let mut b = Bytes::mib(2);
+    b -= Bytes::mib(1);
+    b += Bytes::kib(1);
+    assert_eq!(b / 1025, Bytes::kib(1));
+    assert_eq!(b.checked_div(0), None);
+    assert_eq!(b.checked_mul(u64::MAX), None);
+    assert_eq!(Bytes::ZERO.saturating_sub(b), Bytes::ZERO);
+    assert_eq!(Bytes::new(u64::MAX).saturating_add(b), Bytes::new(u64::MAX));
+    assert_eq!(u64::from(Bytes::from(7)), 7);
+
+    assert_eq!(Bytes::kib(1).ratio_of(Bytes::ZERO), None);
+    let half = Bytes::kib(1).ratio_of(Bytes::kib(2)).unwrap();
+    assert_eq!(half.get(), 0.5);
+    assert_eq!(Bytes::new(3).scale(half), Bytes::new(2));
+    assert_eq!(Bytes::new(10).scale(Ratio::new(-1.0).unwrap()), Bytes::ZERO);
+    assert_eq!(
+        Bytes::new(10).scale(Ratio::new(1e300).unwrap()),
+        Bytes::new(u64::MAX)
+    );
+
+    let r = Ratio::new(1.25).unwrap();
+    assert_eq!(r.to_string(), "1.25");
+    assert_eq!(format!("{r:.1}"), "1.2");
+    assert_eq!(r.clamped().get(), 1.0);
+    assert_eq!(r.checked_mul(half).unwrap().get(), 0.625);
+    assert_eq!(
+        Ratio::new(f64::MAX)
+            .unwrap()
+            .checked_add(Ratio::new(f64::MAX).unwrap()),
+        None
+    );
+    assert_eq!(Ratio::of(1.0, 0.0), None);
+    assert_eq!(Ratio::new(-0.0001).unwrap().to_string(), "0");
+
+    let p = Percent::from(r);
+    assert_eq!(p.get(), 125.0);
+    assert_eq!(p.to_string(), "125%");
+    assert_eq!(p.clamped().to_string(), "100%");
+    assert_eq!(Percent::new(12345.0).unwrap().to_string(), "12,345%");
+    assert_eq!(
+        p.checked_sub(Percent::new(25.0).unwrap()),
+        Percent::new(100.0)
+    );
+    assert_eq!(Ratio::from(Percent::new(5.0).unwrap()).get(), 0.05);
+    assert!(Percent::new(f64::INFINITY).is_none());
+    assert_eq!(Percent::of(3.0, 0.0), None);
+}
+
+#[test]
+fn test_semver_ordering() {
+    let chain = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.1",
+        "1.10.0",
+        "2.0.0",
+    ];
+    let parsed: Vec<SemVer> = chain.iter().map(|s| s.parse().unwrap()).collect();
+    assert!(parsed.windows(2).all(|w| w[0] < w[1]));
+    for (s, v) in chain.iter().zip(&parsed) {
+        assert_eq!(v.to_string(), *s);
+    }
+    let a = SemVer::parse("1.0.0+build.1").unwrap();
+    let b = SemVer::parse("1.0.0+build.2").unwrap();
+    assert_eq!(a.cmp_precedence(&b), std::cmp::Ordering::Equal);
+    assert_ne!(a, b);
+    assert_eq!(a.to_string(), "1.0.0+build.1");
+    assert!(SemVer::parse("1.0.0-rc.1").unwrap().is_prerelease());
+
+    for bad in [
+        "",
+        "1",
+        "1.2",
+        "1.2.3.4",
+        "01.2.3",
+        "1.2.3-",
+        "1.2.3-01",
+        "1.2.3+",
+        "1.2.3-a..b",
+        "v1.2.3",
+        "1.2.x",
+    ] {
+        assert!(SemVer::parse(bad).is_err(), "{bad}");
+    }
+    assert!(SemVer::parse("1.2.3+001").is_ok());
+}
+
+#[test]
+fn test_semver_requirements() {
+    let v = |s: &str| SemVer::parse(s).unwrap();
+    let cases = [
+        ("^1.2.3", "1.2.3", true),
+        ("^1.2.3", "1.9.0", true),
+        ("^1.2.3", "2.0.0", false),
+        ("^1.2.3", "1.2.2", false),
+        ("^0.2.3", "0.2.9", true),
+        ("^0.2.3", "0.3.0", false),
+        ("^0.0.3", "0.0.4", false),
+        ("^0.0", "0.0.9", true),
+        ("^0", "0.9.0", true),
+        ("1.2", "1.5.0", true),
+        ("~1.2.3", "1.2.9", true),
+        ("~1.2.3", "1.3.0", false),
+        ("~1", "1.9.9", true),
+        ("=1.2", "1.2.7", true),
+        ("=1.2.3", "1.2.4", false),
+        (">1.2", "1.2.9", false),
+        (">1.2", "1.3.0", true),
+        (">=1.2.3", "1.2.3", true),
+        ("<2", "1.99.0", true),
+        ("<2", "2.0.0", false),
+        ("<=1.2", "1.2.9", true),
+        ("<=1.2", "1.3.0", false),
+        ("*", "42.0.0", true),
+        ("1.x", "1.4.0", true),
+        ("1.*.*", "2.0.0", false),
+        (">= 1.2, < 1.5", "1.4.9", true),
+        (">= 1.2, < 1.5", "1.5.0", false),
+        ("^1.2", "1.3.0-beta", false),
+        ("^1.3.0-alpha", "1.3.0-beta", true),
+        ("^1.3.0-alpha", "1.4.0-beta", false),
+        ("", "1.0.0", false),
+        ("^1.2.3.4", "1.2.3", false),
+        (">x", "1.0.0", false),
+    ];
+    for (req, ver, expected) in cases {
+        assert_eq!(v(ver).matches_requirement(req), expected, "{ver} vs {req}");
+    }
+}
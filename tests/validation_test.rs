@@ -50,3 +50,376 @@ fn test_validate_ssn_us() {
     assert!(!validate_ssn("123-45-0000")); // serial 0000
     assert!(!validate_ssn("123-45-678")); // too short
 }
+
+#[test]
+fn test_detailed_validators() {
+    assert_eq!(validate_iban_detailed(""), Err(ValidationError::Empty));
+    assert_eq!(
+        validate_iban_detailed("DE89 3704 0044 0532 0130 00!"),
+        Err(ValidationError::InvalidCharacter {
+            ch: '!',
+            position: 22
+        })
+    );
+    assert_eq!(
+        validate_iban_detailed("DE89 3704 0044 0532 0130 01"),
+        Err(ValidationError::InvalidChecksum)
+    );
+
+    assert_eq!(
+        validate_phone_detailed("+0123456789"),
+        Err(ValidationError::InvalidFormat(
+            "country code cannot start with 0"
+        ))
+    );
+    assert_eq!(
+        validate_phone_detailed("+12a"),
+        Err(ValidationError::InvalidCharacter {
+            ch: 'a',
+            position: 3
+        })
+    );
+
+    assert_eq!(
+        validate_ssn_detailed("123-45-678"),
+        Err(ValidationError::InvalidLength {
+            min: 9,
+            max: 9,
+            actual: 8
+        })
+    );
+    assert_eq!(
+        validate_ssn_detailed("123-00-6789"),
+        Err(ValidationError::Reserved("group number"))
+    );
+    assert_eq!(
+        validate_credit_card_detailed("4111111111111112"),
+        Err(ValidationError::InvalidChecksum)
+    );
+}
+
+#[test]
+fn test_validate_url() {
+    let rules = UrlRules::default();
+    for ok in [
+        "http://example.com",
+        "HTTPS://sub.example.co.uk./path?q=1#frag",
+        "https://user:pw@example.com:443",
+        "http://127.0.0.1:8080/x",
+        "http://[2001:db8::1]:80/",
+        "http://localhost",
+    ] {
+        assert!(validate_url(ok, &rules).is_ok(), "{ok}");
+    }
+    assert_eq!(validate_url("", &rules), Err(ValidationError::Empty));
+    assert_eq!(
+        validate_url("http://exa mple.com", &rules),
+        Err(ValidationError::InvalidCharacter {
+            ch: ' ',
+            position: 10
+        })
+    );
+    assert!(matches!(
+        validate_url("example.com", &rules),
+        Err(ValidationError::InvalidFormat(_))
+    ));
+    assert!(matches!(
+        validate_url("http://", &rules),
+        Err(ValidationError::InvalidFormat(_))
+    ));
+    for bad_host in ["-a.com", "a..com", "999.1.1.1", "ex\u{e4}mple.com"] {
+        assert!(matches!(
+            validate_url(&format!("http://{bad_host}/"), &rules),
+            Err(ValidationError::InvalidHost(_))
+        ));
+    }
+    assert!(matches!(
+        validate_url("http://[::zz]/", &rules),
+        Err(ValidationError::InvalidHost(_))
+    ));
+    for bad_port in [
+        "http://a.com:",
+        "http://a.com:0",
+        "http://a.com:70000",
+        "http://a.com:+80",
+    ] {
+        assert!(matches!(
+            validate_url(bad_port, &rules),
+            Err(ValidationError::InvalidPort(_))
+        ));
+    }
+
+    let strict = UrlRules {
+        schemes: vec!["wss".into()],
+        allow_ip: false,
+        require_port: true,
+        port_range: Some(8000..=8999),
+    };
+    assert!(validate_url("wss://chat.example.com:8443", &strict).is_ok());
+    assert_eq!(
+        validate_url("wss://chat.example.com:9000", &strict),
+        Err(ValidationError::InvalidPort("9000".into()))
+    );
+    assert_eq!(
+        validate_url("wss://chat.example.com", &strict),
+        Err(ValidationError::InvalidPort("missing".into()))
+    );
+    assert_eq!(
+        validate_url("wss://10.0.0.1:8000", &strict),
+        Err(ValidationError::InvalidHost("10.0.0.1".into()))
+    );
+    let any = UrlRules {
+        schemes: vec![],
+        ..UrlRules::default()
+    };
+    assert!(validate_url("git+ssh://host.dev/repo", &any).is_ok());
+    assert!(toolchest::strings::extra::is_url("https://rust-lang.org"));
+    assert!(!toolchest::strings::extra::is_url("https://"));
+}
+
+#[test]
+fn test_product_checksums() {
+    assert!(validate_isbn10("0306406152"));
+    assert!(validate_isbn10("0 8044 2957 x"));
+    assert!(!validate_isbn10("X306406152"));
+    assert_eq!(
+        validate_isbn10_detailed("030640615"),
+        Err(ValidationError::InvalidLength {
+            min: 10,
+            max: 10,
+            actual: 9
+        })
+    );
+    assert!(validate_isbn13("9780306406157"));
+    assert!(validate_isbn13("979-10-90636-07-1"));
+    assert_eq!(
+        validate_isbn13_detailed("978-0-306-4O615-7"),
+        Err(ValidationError::InvalidCharacter {
+            ch: 'O',
+            position: 8
+        })
+    );
+    assert!(validate_ean13("5901234123457"));
+    assert_eq!(
+        validate_ean13_detailed("5901234123458"),
+        Err(ValidationError::InvalidChecksum)
+    );
+    assert_eq!(validate_ean13_detailed(" - "), Err(ValidationError::Empty));
+    assert!(validate_upc("012345678905"));
+    assert!(!validate_upc("12345678905"));
+}
+
+#[test]
+fn test_verhoeff_and_damm() {
+    for base in ["0", "12345", "142857", "8473643095483728456789"] {
+        let v = verhoeff_check_digit(base).unwrap();
+        assert!(validate_verhoeff(&format!("{base}{v}")));
+        let d = damm_check_digit(base).unwrap();
+        assert!(validate_damm(&format!("{base}{d}")));
+    }
+    assert_eq!(verhoeff_check_digit("12345"), Some('1'));
+    assert!(!validate_verhoeff("123415")); // transposed check digit
+    assert_eq!(verhoeff_check_digit(""), None);
+    assert_eq!(damm_check_digit("12a"), None);
+    assert!(!validate_damm(""));
+}
+
+#[test]
+fn test_postal_codes() {
+    let ok = [
+        ("10001", CountryCode::US),
+        ("K1A 0B1", CountryCode::CA),
+        ("EC1A 1BB", CountryCode::GB),
+        ("GIR 0AA", CountryCode::GB),
+        ("D02 X285", CountryCode::IE),
+        ("1000-001", CountryCode::PT),
+        ("00-950", CountryCode::PL),
+        ("100-0001", CountryCode::JP),
+        ("01310-100", CountryCode::BR),
+        ("114 55", CountryCode::SE),
+    ];
+    for (code, country) in ok {
+        assert!(validate_postal_code(code, country), "{code} {country}");
+    }
+    assert!(!validate_postal_code("1000001", CountryCode::PT));
+    assert!(!validate_postal_code("K1A0B", CountryCode::CA));
+    assert!(!validate_postal_code("10001", CountryCode::AU));
+    assert_eq!(CountryCode::ALL.len(), 33);
+    assert_eq!("de".parse::<CountryCode>(), Ok(CountryCode::DE));
+    assert_eq!(
+        "XX".parse::<CountryCode>(),
+        Err(ValidationError::InvalidCountry("XX".into()))
+    );
+    assert_eq!(CountryCode::NZ.to_string(), "NZ");
+}
+
+#[test]
+fn test_vat_numbers() {
+    let valid = [
+        "ATU13585627",
+        "BE0403019261",
+        "BG175074752",
+        "CY10259033P",
+        "CZ25123891",
+        "DE136695976",
+        "DK13585628",
+        "EE100931558",
+        "EL094259216",
+        "ESB58378431",
+        "ES54362315K",
+        "ESX2482300W",
+        "FI20774740",
+        "FR40303265045",
+        "HR33392005961",
+        "HU12892312",
+        "IE6433435F",
+        "IE3628739UA",
+        "IT00743110157",
+        "LT119511515",
+        "LU15027442",
+        "LV40003521600",
+        "MT11679112",
+        "NL004495445B01",
+        "NL000099998B57",
+        "PL8567346215",
+        "PT501964843",
+        "RO18547290",
+        "SE123456789701",
+        "SI50223054",
+        "SK2022749619",
+    ];
+    for n in valid {
+        assert_eq!(validate_vat_detailed(n), Ok(()), "{n}");
+    }
+    assert!(validate_vat("at u13.585-627"));
+    for bad in [
+        "ATU13585628",
+        "ES54362315A",
+        "IT00743110158",
+        "SE123456789702",
+        "EL094259217",
+    ] {
+        assert_eq!(
+            validate_vat_detailed(bad),
+            Err(ValidationError::InvalidChecksum),
+            "{bad}"
+        );
+    }
+    assert_eq!(validate_vat_detailed(""), Err(ValidationError::Empty));
+    assert_eq!(
+        validate_vat_detailed("123456789"),
+        Err(ValidationError::MissingPrefix("country code"))
+    );
+    assert!(matches!(
+        validate_vat_detailed("DE12345"),
+        Err(ValidationError::InvalidFormat(_))
+    ));
+    assert!(matches!(
+        validate_vat_detailed("DE13669597_"),
+        Err(ValidationError::InvalidCharacter {
+            ch: '_',
+            position: 10
+        })
+    ));
+}
+
+#[test]
+fn test_date_validation() {
+    assert!(is_leap_year(1600));
+    assert!(!is_leap_year(2100));
+    assert!(!is_leap_year(2023));
+    assert!(is_leap_year(-4));
+    assert!(is_valid_date(2000, 2, 29));
+    assert!(!is_valid_date(1900, 2, 29));
+    assert!(is_valid_date(2024, 12, 31));
+    assert!(!is_valid_date(2024, 6, 31));
+    assert!(!is_valid_date(2024, 1, 0));
+    assert!(!is_valid_date(2024, 0, 1));
+}
+
+#[test]
+fn test_card_brand_and_mask() {
+    assert_eq!(card_brand("4111111111111"), Some(CardBrand::Visa));
+    assert_eq!(card_brand("4111111111111111111"), Some(CardBrand::Visa));
+    assert_eq!(card_brand("41111111111111"), None);
+    assert_eq!(
+        card_brand("5555 5555 5555 4444"),
+        Some(CardBrand::Mastercard)
+    );
+    assert_eq!(card_brand("2720990000000007"), Some(CardBrand::Mastercard));
+    assert_eq!(card_brand("2721000000000000"), None);
+    assert_eq!(card_brand("5055555555554444"), None);
+    assert_eq!(card_brand("371449635398431"), Some(CardBrand::Amex));
+    assert_eq!(card_brand("3714496353984310"), None);
+    assert_eq!(card_brand("6011111111111117"), Some(CardBrand::Discover));
+    assert_eq!(card_brand("6221260000000000"), Some(CardBrand::Discover));
+    assert_eq!(card_brand("6221250000000000"), None);
+    assert_eq!(card_brand("6445644564456445"), Some(CardBrand::Discover));
+    assert_eq!(card_brand(""), None);
+    assert_eq!(CardBrand::Amex.to_string(), "American Express");
+
+    assert_eq!(mask_card("5555-5555-5555-4444"), "************4444");
+    assert_eq!(mask_card("371449635398431"), "**********98431");
+    assert_eq!(mask_card("1234 5678 9012"), "********9012");
+    assert_eq!(mask_card("123"), "123");
+}
+
+#[test]
+fn test_network_validators() {
+    assert!(validate_mac_address("FF:FF:FF:FF:FF:FF"));
+    assert!(!validate_mac_address("00:1a:2b:3c:4d"));
+    assert!(!validate_mac_address("001a.2b3c.4d5e.6f70"));
+    assert!(!validate_mac_address("0:1a:2b:3c:4d:5e:"));
+    assert!(!validate_mac_address("00-1a-2b-3c-4d-5e-"));
+    assert_eq!(
+        validate_mac_address_detailed(""),
+        Err(ValidationError::Empty)
+    );
+    assert!(matches!(
+        validate_mac_address_detailed("001a2b3c4d5e"),
+        Err(ValidationError::InvalidFormat(_))
+    ));
+
+    assert!(validate_hostname("localhost"));
+    assert!(validate_hostname("example.com."));
+    assert!(validate_hostname(&format!("{}.com", "a".repeat(63))));
+    assert!(!validate_hostname(&format!("{}.com", "a".repeat(64))));
+    assert!(!validate_hostname("1.2.3.4"));
+    assert!(!validate_hostname("a..b"));
+
+    assert!(validate_port("1") && validate_port("65535"));
+    assert!(!validate_port("") && !validate_port(" 80") && !validate_port("-1"));
+
+    assert!(validate_host_port("localhost:5432"));
+    assert!(validate_host_port("[2001:db8::1]:8443"));
+    assert!(!validate_host_port("[2001:db8::1]"));
+    assert!(!validate_host_port("[not-ip]:80"));
+    assert_eq!(
+        validate_host_port_detailed("bad_host:80"),
+        Err(ValidationError::InvalidHost("bad_host".into()))
+    );
+    assert!(matches!(
+        validate_host_port_detailed("::1:80"),
+        Err(ValidationError::InvalidFormat(_))
+    ));
+    assert!(!validate_host_port(":80"));
+}
+
+#[test]
+fn test_validate_semver() {
+    assert!(validate_semver("0.0.0"));
+    assert!(validate_semver("10.20.30-rc.1+meta-data.001"));
+    assert!(!validate_semver("1.0"));
+    assert_eq!(validate_semver_detailed(""), Err(ValidationError::Empty));
+    assert_eq!(
+        validate_semver_detailed("1.0.0_beta"),
+        Err(ValidationError::InvalidCharacter {
+            ch: '_',
+            position: 5
+        })
+    );
+    assert_eq!(
+        validate_semver_detailed("1.0.0-beta..1"),
+        Err(ValidationError::InvalidFormat("empty identifier"))
+    );
+}